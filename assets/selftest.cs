@@ -0,0 +1,9 @@
+using System;
+
+public static class Program
+{
+    public static void Main(string[] args)
+    {
+        Console.WriteLine("selftest:" + string.Join(",", args));
+    }
+}
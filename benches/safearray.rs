@@ -0,0 +1,57 @@
+//! Measures the creation of the `SAFEARRAY`s passed to managed code for large arguments.
+
+use {
+    criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput},
+    rustclr::SafeArray,
+    std::io::Cursor,
+};
+
+/// Size of the byte arrays, large enough to land on the large object heap.
+const BUFFER_SIZE: usize = 64 * 1024 * 1024;
+
+/// Number of elements of the `string[]` and `int[]` arguments.
+const ARG_COUNT: usize = 100_000;
+
+fn byte_arrays(c: &mut Criterion) {
+    let data = vec![0x90u8; BUFFER_SIZE];
+    let mut group = c.benchmark_group("byte[]");
+    group.throughput(Throughput::Bytes(BUFFER_SIZE as u64));
+    group.sample_size(20);
+
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| SafeArray::from_bytes(&data).unwrap())
+    });
+
+    group.bench_function("from_reader", |b| {
+        b.iter(|| SafeArray::from_reader(Cursor::new(&data), data.len()).unwrap())
+    });
+
+    group.finish();
+}
+
+fn argument_arrays(c: &mut Criterion) {
+    let strings = (0..ARG_COUNT).map(|i| format!("argument-{i}")).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("args");
+    group.throughput(Throughput::Elements(ARG_COUNT as u64));
+
+    group.bench_function("string[]", |b| {
+        b.iter_batched(
+            || strings.clone(),
+            |args| SafeArray::from_array_arg(&args).unwrap(),
+            BatchSize::LargeInput
+        )
+    });
+
+    group.bench_function("int[]", |b| {
+        b.iter_batched(
+            || (0..ARG_COUNT as i32).collect::<Vec<_>>(),
+            |args| SafeArray::from_array_arg(&args).unwrap(),
+            BatchSize::LargeInput
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, byte_arrays, argument_arrays);
+criterion_main!(benches);
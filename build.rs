@@ -0,0 +1,33 @@
+//! Compiles the self-test assembly in `assets/selftest.cs` with the C# compiler shipped with the .NET Framework.
+
+use std::{env, fs, path::PathBuf, process::Command};
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+    let output = out_dir.join("selftest.exe");
+    println!("cargo:rerun-if-changed=assets/selftest.cs");
+
+    let compiled = find_csc().is_some_and(|csc| {
+        Command::new(csc)
+            .args(["/nologo", "/target:exe", "/optimize+"])
+            .arg(format!("/out:{}", output.display()))
+            .arg("assets/selftest.cs")
+            .status()
+            .is_ok_and(|status| status.success())
+    });
+
+    // Builds without a .NET Framework (e.g. cross-compilation) embed an empty assembly,
+    // which `selftest` reports as unavailable
+    if !compiled {
+        fs::write(&output, []).expect("failed to write placeholder assembly");
+    }
+}
+
+/// Locates `csc.exe` from the .NET Framework 4.x installation.
+fn find_csc() -> Option<PathBuf> {
+    let windir = env::var("WINDIR").ok()?;
+    ["Framework64", "Framework"]
+        .iter()
+        .map(|framework| PathBuf::from(&windir).join("Microsoft.NET").join(framework).join("v4.0.30319").join("csc.exe"))
+        .find(|path| path.exists())
+}
@@ -36,12 +36,7 @@ fn main() -> Result<(), ClrError> {
         .map_err(|_| ClrError::ErrorClr("Failed to read file"))?;
 
     // Convert version string to RuntimeVersion enum
-    let runtime_version = match cli.runtime_version.as_str() {
-        "v2" => RuntimeVersion::V2,
-        "v3" => RuntimeVersion::V3,
-        "v4" => RuntimeVersion::V4,
-        _ => RuntimeVersion::UNKNOWN,
-    };
+    let runtime_version = cli.runtime_version.parse::<RuntimeVersion>()?;
 
     // Initialize and configure the RustClr instance
     let mut clr = RustClr::new(&data)?
@@ -1,8 +1,4 @@
-use rustclr::{
-    create_safe_args,
-    RustClrEnv, InvocationType,
-    schema::_Assembly, Variant, WinStr,
-};
+use rustclr::prelude::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = std::env::args().skip(1).collect::<Vec<String>>();
@@ -17,8 +13,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Resolve and invoke `LoadWithPartialName` method.
     let load_partial_name = reflection_assembly.method_signature("System.Reflection.Assembly LoadWithPartialName(System.String)")?;
-    let param = create_safe_args(vec!["System.Management.Automation".to_variant()])?;
-    let result = load_partial_name.invoke(None, Some(param))?;
+    let param = SafeArray::from_variants(vec!["System.Management.Automation".to_variant()])?;
+    let result = load_partial_name.invoke(None, Some(param.as_ptr()))?;
 
     // Convert result to `_Assembly`.
     let automation = _Assembly::from_raw(unsafe { result.Anonymous.Anonymous.Anonymous.byref })?;
@@ -41,9 +37,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Invoke `AddScript` method.
     let command_collection = automation.resolve_type("System.Management.Automation.Runspaces.CommandCollection")?;
     let cmd= vec![format!("{} | Out-String", command).to_variant()];
-    let args = create_safe_args(cmd)?;
+    let args = SafeArray::from_variants(cmd)?;
     let add_script = command_collection.method_signature("Void AddScript(System.String)")?;
-    add_script.invoke(Some(get_command), Some(args))?;
+    add_script.invoke(Some(get_command), Some(args.as_ptr()))?;
 
     // Invoke `InvokeAsync` method.
     pipeline.invoke("InvokeAsync", Some(pipe), None, InvocationType::Instance)?;
@@ -1,7 +1,4 @@
-use rustclr::{
-    RustClrEnv, ClrOutput, 
-    InvocationType, Variant,
-};
+use rustclr::prelude::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create and initialize the CLR environment
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rustclr::AssemblyMetadata;
+
+// The metadata tables are read straight from the buffer, so row counts, indexes and
+// heap offsets that do not match the image must be rejected without panicking.
+fuzz_target!(|data: &[u8]| {
+    let _ = AssemblyMetadata::parse(data);
+});
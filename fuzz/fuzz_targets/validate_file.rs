@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rustclr::RustClr;
+
+// `RustClr::new` validates the PE headers of the buffer before anything is loaded,
+// so arbitrary input must be rejected without panicking or reading out of bounds.
+fuzz_target!(|data: &[u8]| {
+    let _ = RustClr::new(data);
+});
@@ -0,0 +1,257 @@
+//! Procedural macros for `rustclr`.
+//!
+//! This crate is re-exported by `rustclr` when the `macros` feature is enabled and
+//! should not be used directly.
+
+use {
+    proc_macro::TokenStream,
+    proc_macro2::TokenStream as TokenStream2,
+    quote::quote,
+    syn::{
+        braced, parenthesized,
+        parse::{Parse, ParseStream},
+        parse_macro_input,
+        punctuated::Punctuated,
+        Attribute, Ident, LitStr, Token, Type, Visibility,
+    },
+};
+
+/// A `class Namespace.Type { ... }` block.
+struct ClassBinding {
+    /// Attributes applied to the generated struct, minus `#[assembly = "..."]`.
+    attrs: Vec<Attribute>,
+
+    /// Visibility of the generated struct.
+    vis: Visibility,
+
+    /// Assembly defining the type.
+    assembly: LitStr,
+
+    /// Segments of the full type name.
+    path: Vec<Ident>,
+
+    /// Methods bound on the type.
+    methods: Vec<MethodBinding>,
+}
+
+/// A `fn Name(arg: Type, ...);` declaration.
+struct MethodBinding {
+    /// Attributes applied to the generated method.
+    attrs: Vec<Attribute>,
+
+    /// Name of the managed method.
+    name: Ident,
+
+    /// Whether the method takes `self`, i.e. is an instance method.
+    instance: bool,
+
+    /// Arguments of the method.
+    args: Vec<(Ident, Type)>,
+}
+
+/// The full input of `clr_bind!`.
+struct Bindings(Vec<ClassBinding>);
+
+mod kw {
+    syn::custom_keyword!(class);
+}
+
+impl Parse for ClassBinding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = Vec::new();
+        let mut assembly = None;
+        for attr in input.call(Attribute::parse_outer)? {
+            if attr.path().is_ident("assembly") {
+                let value = &attr.meta.require_name_value()?.value;
+                assembly = Some(syn::parse2::<LitStr>(quote!(#value))?);
+            } else {
+                attrs.push(attr);
+            }
+        }
+
+        let vis = input.parse()?;
+        input.parse::<kw::class>()?;
+
+        let mut path = vec![input.parse::<Ident>()?];
+        while input.peek(Token![.]) {
+            input.parse::<Token![.]>()?;
+            path.push(input.parse()?);
+        }
+
+        let content;
+        braced!(content in input);
+
+        let mut methods = Vec::new();
+        while !content.is_empty() {
+            methods.push(content.parse()?);
+        }
+
+        Ok(Self {
+            attrs,
+            vis,
+            assembly: assembly.unwrap_or_else(|| LitStr::new("mscorlib", proc_macro2::Span::call_site())),
+            path,
+            methods,
+        })
+    }
+}
+
+impl Parse for MethodBinding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        input.parse::<Token![fn]>()?;
+        let name = input.parse()?;
+
+        let content;
+        parenthesized!(content in input);
+
+        let instance = content.peek(Token![self]);
+        if instance {
+            content.parse::<Token![self]>()?;
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        let args = Punctuated::<(Ident, Type), Token![,]>::parse_terminated_with(&content, |input| {
+            let name = input.parse()?;
+            input.parse::<Token![:]>()?;
+            Ok((name, input.parse()?))
+        })?;
+
+        input.parse::<Token![;]>()?;
+
+        Ok(Self {
+            attrs,
+            name,
+            instance,
+            args: args.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for Bindings {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut classes = Vec::new();
+        while !input.is_empty() {
+            classes.push(input.parse()?);
+        }
+
+        Ok(Self(classes))
+    }
+}
+
+impl ClassBinding {
+    /// Generates the wrapper struct and its methods.
+    fn expand(&self) -> TokenStream2 {
+        let Self { attrs, vis, assembly, path, .. } = self;
+        let ident = path.last().expect("a type name has at least one segment");
+        let type_name = path.iter().map(Ident::to_string).collect::<Vec<_>>().join(".");
+        let doc = format!("Typed binding for `{type_name}` in `{}`.", assembly.value());
+        let methods = self.methods.iter().map(MethodBinding::expand);
+
+        quote! {
+            #[doc = #doc]
+            #(#attrs)*
+            #vis struct #ident {
+                ty: ::rustclr::schema::_Type,
+            }
+
+            #[allow(non_snake_case)]
+            impl #ident {
+                /// Resolves the bound type in the given application domain.
+                pub fn resolve(domain: &::rustclr::schema::_AppDomain) -> ::core::result::Result<Self, ::rustclr::error::ClrError> {
+                    let assembly = domain.load_lib(#assembly)?;
+                    ::core::result::Result::Ok(Self { ty: assembly.resolve_type(#type_name)? })
+                }
+
+                /// Wraps an already resolved `_Type`.
+                pub fn from_type(ty: ::rustclr::schema::_Type) -> Self {
+                    Self { ty }
+                }
+
+                /// Returns the underlying `_Type`.
+                pub fn ty(&self) -> &::rustclr::schema::_Type {
+                    &self.ty
+                }
+
+                #(#methods)*
+            }
+        }
+    }
+}
+
+impl MethodBinding {
+    /// Generates a typed wrapper over `_Type::invoke`.
+    fn expand(&self) -> TokenStream2 {
+        let Self { attrs, name, instance, args } = self;
+        let method = name.to_string();
+        let names = args.iter().map(|(name, _)| name);
+        let types = args.iter().map(|(_, ty)| ty);
+        let conversions = args.iter().map(|(name, _)| quote!(::rustclr::Variant::to_variant(&#name)));
+
+        let arguments = if args.is_empty() {
+            quote!(::core::option::Option::None)
+        } else {
+            quote!(::core::option::Option::Some(::std::vec![#(#conversions),*]))
+        };
+
+        let (receiver, target, invocation) = if *instance {
+            (
+                quote!(instance: ::rustclr::__private::VARIANT,),
+                quote!(::core::option::Option::Some(instance)),
+                quote!(::rustclr::InvocationType::Instance),
+            )
+        } else {
+            (
+                TokenStream2::new(),
+                quote!(::core::option::Option::None),
+                quote!(::rustclr::InvocationType::Static),
+            )
+        };
+
+        quote! {
+            #(#attrs)*
+            pub fn #name(&self, #receiver #(#names: #types),*) -> ::core::result::Result<::rustclr::__private::VARIANT, ::rustclr::error::ClrError> {
+                self.ty.invoke(#method, #target, #arguments, #invocation)
+            }
+        }
+    }
+}
+
+/// Declares typed bindings over managed types.
+///
+/// Each `class` block generates a struct named after the last segment of the type name,
+/// with a `resolve(&_AppDomain)` constructor and one method per declared `fn`. Arguments
+/// must implement `rustclr::Variant` and are converted when the method is called, so
+/// passing a wrong type is a compile-time error. Methods taking `self` are instance
+/// methods and receive the target instance as their first argument.
+///
+/// The assembly defaults to `mscorlib` and can be set with `#[assembly = "..."]`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{clr_bind, RustClrEnv};
+///
+/// clr_bind! {
+///     class System.Console {
+///         fn WriteLine(value: &str);
+///     }
+///
+///     #[assembly = "System"]
+///     class System.Net.ServicePointManager {
+///         fn set_Expect100Continue(value: bool);
+///     }
+/// }
+///
+/// let clr = RustClrEnv::new(None)?;
+/// Console::resolve(&clr.app_domain)?.WriteLine("Hello from a typed binding")?;
+/// ```
+#[proc_macro]
+pub fn clr_bind(input: TokenStream) -> TokenStream {
+    let Bindings(classes) = parse_macro_input!(input as Bindings);
+    let classes = classes.iter().map(ClassBinding::expand);
+
+    quote!(#(#classes)*).into()
+}
@@ -0,0 +1,518 @@
+use {
+    std::{
+        collections::BTreeMap,
+        fmt,
+        mem::{offset_of, size_of},
+        ptr::null,
+        sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}},
+        thread::{self, JoinHandle},
+    },
+    windows_sys::{
+        core::GUID,
+        Win32::{
+            Foundation::ERROR_SUCCESS,
+            System::{
+                Diagnostics::Etw::{
+                    CloseTrace, ControlTraceW, EnableTraceEx2, OpenTraceW, ProcessTrace, StartTraceW,
+                    TdhGetProperty, TdhGetPropertySize, CONTROLTRACE_HANDLE, EVENT_CONTROL_CODE_ENABLE_PROVIDER,
+                    EVENT_RECORD, EVENT_TRACE_CONTROL_STOP, EVENT_TRACE_LOGFILEW, EVENT_TRACE_PROPERTIES,
+                    EVENT_TRACE_REAL_TIME_MODE, PROCESSTRACE_HANDLE, PROCESS_TRACE_MODE_EVENT_RECORD,
+                    PROCESS_TRACE_MODE_REAL_TIME, PROPERTY_DATA_DESCRIPTOR, TRACE_LEVEL_INFORMATION,
+                    WNODE_FLAG_TRACED_GUID,
+                },
+                Threading::GetCurrentProcessId,
+            },
+        },
+    },
+};
+
+use crate::error::{ClrError, last_error, win32_error};
+
+/// `Microsoft-Windows-Kernel-File` provider.
+const FILE_PROVIDER: GUID = GUID::from_u128(0xedd08927_9cc4_4e65_b970_c2560fb5c289);
+
+/// `Microsoft-Windows-Kernel-Registry` provider.
+const REGISTRY_PROVIDER: GUID = GUID::from_u128(0x70eb4f03_c1de_4f73_a051_33d13d5413bd);
+
+/// `KERNEL_FILE_KEYWORD_CREATE`, `DELETE_PATH`, `RENAME_SETLINK_PATH` and `CREATE_NEW_FILE`.
+const FILE_KEYWORDS: u64 = 0x80 | 0x400 | 0x800 | 0x1000;
+
+/// Every registry operation, the ones not reported are dropped by the callback.
+const REGISTRY_KEYWORDS: u64 = u64::MAX;
+
+/// Number of sessions started by the process, used to name them uniquely.
+static SESSIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// A file or registry operation recorded by an [`ActivityMonitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActivityKind {
+    /// A file was opened or created (`Kernel-File` event 12).
+    FileOpen,
+
+    /// A new file was created (`Kernel-File` event 30).
+    FileCreate,
+
+    /// A file was deleted (`Kernel-File` event 26).
+    FileDelete,
+
+    /// A file was renamed (`Kernel-File` event 27).
+    FileRename,
+
+    /// A registry key was created or opened with `RegCreateKey` (`Kernel-Registry` event 1).
+    RegistryCreateKey,
+
+    /// A registry key was opened (`Kernel-Registry` event 2).
+    RegistryOpenKey,
+
+    /// A registry key was deleted (`Kernel-Registry` event 3).
+    RegistryDeleteKey,
+
+    /// A registry value was written (`Kernel-Registry` event 5).
+    RegistrySetValue,
+
+    /// A registry value was deleted (`Kernel-Registry` event 6).
+    RegistryDeleteValue,
+}
+
+impl ActivityKind {
+    /// Maps an event of the traced providers to the operation it reports.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The provider of the event.
+    /// * `id` - The event identifier.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ActivityKind)` - If the event is reported.
+    /// * `None` - For any other event.
+    fn from_event(provider: &GUID, id: u16) -> Option<Self> {
+        let same = |guid: &GUID| guid.data1 == provider.data1
+            && guid.data2 == provider.data2
+            && guid.data3 == provider.data3
+            && guid.data4 == provider.data4;
+
+        if same(&FILE_PROVIDER) {
+            return match id {
+                12 => Some(ActivityKind::FileOpen),
+                26 => Some(ActivityKind::FileDelete),
+                27 => Some(ActivityKind::FileRename),
+                30 => Some(ActivityKind::FileCreate),
+                _ => None,
+            };
+        }
+
+        if same(&REGISTRY_PROVIDER) {
+            return match id {
+                1 => Some(ActivityKind::RegistryCreateKey),
+                2 => Some(ActivityKind::RegistryOpenKey),
+                3 => Some(ActivityKind::RegistryDeleteKey),
+                5 => Some(ActivityKind::RegistrySetValue),
+                6 => Some(ActivityKind::RegistryDeleteValue),
+                _ => None,
+            };
+        }
+
+        None
+    }
+
+    /// Returns `true` for the operations on files.
+    pub fn is_file(self) -> bool {
+        matches!(
+            self,
+            ActivityKind::FileOpen | ActivityKind::FileCreate | ActivityKind::FileDelete | ActivityKind::FileRename
+        )
+    }
+
+    /// Returns `true` for the operations on the registry.
+    pub fn is_registry(self) -> bool {
+        !self.is_file()
+    }
+}
+
+impl fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ActivityKind::FileOpen => "file open",
+            ActivityKind::FileCreate => "file create",
+            ActivityKind::FileDelete => "file delete",
+            ActivityKind::FileRename => "file rename",
+            ActivityKind::RegistryCreateKey => "registry create key",
+            ActivityKind::RegistryOpenKey => "registry open key",
+            ActivityKind::RegistryDeleteKey => "registry delete key",
+            ActivityKind::RegistrySetValue => "registry set value",
+            ActivityKind::RegistryDeleteValue => "registry delete value",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// A single operation recorded by an [`ActivityMonitor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Activity {
+    /// The kind of operation.
+    pub kind: ActivityKind,
+
+    /// Path of the file or registry key, as seen by the kernel (e.g.
+    /// `\Device\HarddiskVolume3\Users\...` or `\REGISTRY\USER\S-1-5-...\Software\...`).
+    pub path: String,
+
+    /// Name of the registry value, for `RegistrySetValue` and `RegistryDeleteValue`.
+    pub value: Option<String>,
+}
+
+/// File and registry operations recorded while an assembly ran.
+#[derive(Debug, Clone, Default)]
+pub struct ActivitySummary {
+    /// The operations, in the order they were delivered.
+    pub activities: Vec<Activity>,
+
+    /// Number of events dropped by the trace session, e.g. under heavy I/O.
+    pub events_lost: u32,
+}
+
+impl ActivitySummary {
+    /// Returns the operations of the given kind.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind to filter by.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the matching operations.
+    pub fn of_kind(&self, kind: ActivityKind) -> impl Iterator<Item = &Activity> {
+        self.activities.iter().filter(move |activity| activity.kind == kind)
+    }
+
+    /// Returns the operations on files.
+    pub fn files(&self) -> impl Iterator<Item = &Activity> {
+        self.activities.iter().filter(|activity| activity.kind.is_file())
+    }
+
+    /// Returns the operations on the registry.
+    pub fn registry(&self) -> impl Iterator<Item = &Activity> {
+        self.activities.iter().filter(|activity| activity.kind.is_registry())
+    }
+}
+
+impl fmt::Display for ActivitySummary {
+    /// Formats the summary with one line per distinct operation and path, with its count.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut counts = BTreeMap::new();
+        for activity in &self.activities {
+            *counts.entry((activity.kind, &activity.path, &activity.value)).or_insert(0usize) += 1;
+        }
+
+        for ((kind, path, value), count) in counts {
+            match value {
+                Some(value) => write!(f, "[{kind}] {path} ({value})")?,
+                None => write!(f, "[{kind}] {path}")?,
+            }
+
+            if count > 1 {
+                write!(f, " x{count}")?;
+            }
+
+            writeln!(f)?;
+        }
+
+        if self.events_lost > 0 {
+            writeln!(f, "{} events lost", self.events_lost)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Records the file and registry operations of the current process through ETW.
+///
+/// A real-time session is started with the `Microsoft-Windows-Kernel-File` and
+/// `Microsoft-Windows-Kernel-Registry` providers, and the events issued by the process are
+/// collected until [`ActivityMonitor::stop`]. Every thread of the process is traced, so
+/// operations made by the host or by concurrent executions are recorded as well.
+///
+/// Kernel providers can only be enabled by administrators and members of the
+/// `Performance Log Users` group. Reads and writes are not reported, as their events carry
+/// no file name.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::ActivityMonitor;
+///
+/// let monitor = ActivityMonitor::start()?;
+/// clr.run()?;
+///
+/// let summary = monitor.stop()?;
+/// print!("{summary}");
+/// ```
+pub struct ActivityMonitor {
+    /// Handle of the trace session.
+    session: CONTROLTRACE_HANDLE,
+
+    /// Handle of the consumer of the session.
+    trace: PROCESSTRACE_HANDLE,
+
+    /// Properties of the session, holding its name.
+    properties: Box<SessionProperties>,
+
+    /// Operations collected by the consumer.
+    collector: Arc<Collector>,
+
+    /// Thread running `ProcessTrace` until the session stops.
+    consumer: Option<JoinHandle<u32>>,
+}
+
+/// `EVENT_TRACE_PROPERTIES` followed by the name of the session.
+#[repr(C)]
+struct SessionProperties {
+    /// The properties of the session.
+    properties: EVENT_TRACE_PROPERTIES,
+
+    /// Null-terminated name of the session.
+    name: [u16; 64],
+}
+
+/// Operations issued by the process, filled by the event callback.
+struct Collector {
+    /// Identifier of the current process.
+    process_id: u32,
+
+    /// The operations recorded so far.
+    activities: Mutex<Vec<Activity>>,
+}
+
+impl ActivityMonitor {
+    /// Starts recording the file and registry operations of the current process.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ActivityMonitor)` - The running monitor.
+    /// * `Err(ClrError)` - If the session cannot be started, e.g. without the privileges to
+    ///   enable kernel providers (`ERROR_ACCESS_DENIED`).
+    pub fn start() -> Result<Self, ClrError> {
+        let process_id = unsafe { GetCurrentProcessId() };
+        let session_name = format!("rustclr-activity-{process_id}-{}", SESSIONS.fetch_add(1, Ordering::Relaxed));
+
+        let mut properties = Box::new(unsafe { std::mem::zeroed::<SessionProperties>() });
+        for (slot, c) in properties.name.iter_mut().zip(session_name.encode_utf16()) {
+            *slot = c;
+        }
+
+        properties.properties.Wnode.BufferSize = size_of::<SessionProperties>() as u32;
+        properties.properties.Wnode.Flags = WNODE_FLAG_TRACED_GUID;
+        properties.properties.Wnode.ClientContext = 1;
+        properties.properties.LogFileMode = EVENT_TRACE_REAL_TIME_MODE;
+        properties.properties.FlushTimer = 1;
+        properties.properties.LoggerNameOffset = offset_of!(SessionProperties, name) as u32;
+
+        let mut session = CONTROLTRACE_HANDLE { Value: 0 };
+        let name = properties.name;
+        let status = unsafe { StartTraceW(&mut session, name.as_ptr(), &mut properties.properties) };
+        if status != ERROR_SUCCESS {
+            return Err(win32_error("StartTraceW", status));
+        }
+
+        let collector = Arc::new(Collector { process_id, activities: Mutex::new(Vec::new()) });
+        let mut monitor = Self {
+            session,
+            trace: PROCESSTRACE_HANDLE { Value: u64::MAX },
+            properties,
+            collector,
+            consumer: None,
+        };
+
+        // Connects the consumer before the providers are enabled, so no event is dropped
+        let mut logfile = unsafe { std::mem::zeroed::<EVENT_TRACE_LOGFILEW>() };
+        let mut logger_name = name;
+        logfile.LoggerName = logger_name.as_mut_ptr();
+        logfile.Anonymous1.ProcessTraceMode = PROCESS_TRACE_MODE_REAL_TIME | PROCESS_TRACE_MODE_EVENT_RECORD;
+        logfile.Anonymous2.EventRecordCallback = Some(on_event);
+        // The consumer holds its own reference, released once it has returned
+        let context = Arc::into_raw(Arc::clone(&monitor.collector));
+        logfile.Context = context as *mut _;
+
+        monitor.trace = unsafe { OpenTraceW(&mut logfile) };
+        if monitor.trace.Value == u64::MAX {
+            drop(unsafe { Arc::from_raw(context) });
+            return Err(last_error("OpenTraceW"));
+        }
+
+        let trace = monitor.trace;
+        monitor.consumer = Some(thread::spawn(move || unsafe { ProcessTrace(&trace, 1, null(), null()) }));
+
+        for (provider, keywords) in [(FILE_PROVIDER, FILE_KEYWORDS), (REGISTRY_PROVIDER, REGISTRY_KEYWORDS)] {
+            let status = unsafe {
+                EnableTraceEx2(
+                    session,
+                    &provider,
+                    EVENT_CONTROL_CODE_ENABLE_PROVIDER,
+                    TRACE_LEVEL_INFORMATION as u8,
+                    keywords,
+                    0,
+                    0,
+                    null()
+                )
+            };
+
+            if status != ERROR_SUCCESS {
+                return Err(win32_error("EnableTraceEx2", status));
+            }
+        }
+
+        Ok(monitor)
+    }
+
+    /// Stops the session and returns the operations recorded since [`ActivityMonitor::start`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ActivitySummary)` - The recorded operations.
+    /// * `Err(ClrError)` - If the session cannot be stopped.
+    pub fn stop(mut self) -> Result<ActivitySummary, ClrError> {
+        self.shutdown()?;
+
+        let activities = std::mem::take(&mut *self.collector.activities.lock().unwrap_or_else(|e| e.into_inner()));
+        Ok(ActivitySummary {
+            activities,
+            events_lost: self.properties.properties.EventsLost,
+        })
+    }
+
+    /// Stops the session, waiting for the consumer to deliver the remaining events.
+    ///
+    /// If the session cannot be stopped through its handle, the stop is retried by name.
+    /// The consumer is released and the trace closed on every path.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the session is stopped or was already stopped.
+    /// * `Err(ClrError)` - If `ControlTraceW` fails.
+    fn shutdown(&mut self) -> Result<(), ClrError> {
+        if self.session.Value == 0 {
+            return Ok(());
+        }
+
+        let mut status = unsafe {
+            ControlTraceW(self.session, null(), &mut self.properties.properties, EVENT_TRACE_CONTROL_STOP)
+        };
+
+        // The name reaches the session even if its handle is no longer valid
+        if status != ERROR_SUCCESS {
+            let name = self.properties.name;
+            status = unsafe {
+                ControlTraceW(CONTROLTRACE_HANDLE { Value: 0 }, name.as_ptr(), &mut self.properties.properties, EVENT_TRACE_CONTROL_STOP)
+            };
+        }
+
+        self.session.Value = 0;
+
+        // `ProcessTrace` only returns on its own once the session is stopped
+        if status != ERROR_SUCCESS {
+            self.close_trace();
+        }
+
+        if let Some(consumer) = self.consumer.take() {
+            let _ = consumer.join();
+            unsafe { Arc::decrement_strong_count(Arc::as_ptr(&self.collector)) };
+        }
+
+        self.close_trace();
+        if status != ERROR_SUCCESS {
+            return Err(win32_error("ControlTraceW", status));
+        }
+
+        Ok(())
+    }
+
+    /// Closes the consumer handle of the session, once.
+    fn close_trace(&mut self) {
+        if self.trace.Value != u64::MAX {
+            unsafe { CloseTrace(self.trace) };
+            self.trace.Value = u64::MAX;
+        }
+    }
+}
+
+/// Implements the `Drop` trait to stop the session if [`ActivityMonitor::stop`] was not called.
+impl Drop for ActivityMonitor {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+/// Records the events issued by the current process.
+///
+/// # Arguments
+///
+/// * `record` - The event delivered by `ProcessTrace`, with the `Collector` as user context.
+unsafe extern "system" fn on_event(record: *mut EVENT_RECORD) {
+    let record = &*record;
+    let collector = &*(record.UserContext as *const Collector);
+    if record.EventHeader.ProcessId != collector.process_id {
+        return;
+    }
+
+    let Some(kind) = ActivityKind::from_event(&record.EventHeader.ProviderId, record.EventHeader.EventDescriptor.Id) else {
+        return;
+    };
+
+    let (path, value) = match kind {
+        ActivityKind::FileOpen | ActivityKind::FileCreate => (string_property(record, "FileName"), None),
+        ActivityKind::FileDelete | ActivityKind::FileRename => (string_property(record, "FilePath"), None),
+        ActivityKind::RegistryCreateKey | ActivityKind::RegistryOpenKey => {
+            // `RelativeName` is relative to the key opened as `BaseName`, if any
+            let relative = string_property(record, "RelativeName");
+            match string_property(record, "BaseName").filter(|base| !base.is_empty()) {
+                Some(base) => (relative.map(|relative| format!("{base}\\{relative}")), None),
+                None => (relative, None),
+            }
+        }
+        ActivityKind::RegistryDeleteKey => (string_property(record, "KeyName"), None),
+        ActivityKind::RegistrySetValue | ActivityKind::RegistryDeleteValue => {
+            (string_property(record, "KeyName"), string_property(record, "ValueName"))
+        }
+    };
+
+    if let Some(path) = path {
+        let activity = Activity { kind, path, value };
+        collector.activities.lock().unwrap_or_else(|e| e.into_inner()).push(activity);
+    }
+}
+
+/// Reads a string property of an event, decoded with the manifest of its provider.
+///
+/// # Arguments
+///
+/// * `record` - The event.
+/// * `name` - The name of the property, as declared in the manifest.
+///
+/// # Returns
+///
+/// * `Some(String)` - The value of the property.
+/// * `None` - If the event has no such property.
+fn string_property(record: &EVENT_RECORD, name: &str) -> Option<String> {
+    let name = name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+    let descriptor = PROPERTY_DATA_DESCRIPTOR {
+        PropertyName: name.as_ptr() as u64,
+        ArrayIndex: u32::MAX,
+        Reserved: 0,
+    };
+
+    let mut size = 0;
+    let status = unsafe { TdhGetPropertySize(record, 0, null(), 1, &descriptor, &mut size) };
+    if status != ERROR_SUCCESS || size == 0 {
+        return None;
+    }
+
+    let mut buffer = vec![0u16; (size as usize).div_ceil(2)];
+    let status = unsafe { TdhGetProperty(record, 0, null(), 1, &descriptor, size, buffer.as_mut_ptr().cast()) };
+    if status != ERROR_SUCCESS {
+        return None;
+    }
+
+    let len = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+    Some(String::from_utf16_lossy(&buffer[..len]))
+}
@@ -0,0 +1,262 @@
+use {
+    std::{
+        ffi::c_void,
+        ptr::null_mut,
+        sync::atomic::{AtomicU32, Ordering},
+    },
+    windows_core::{IUnknown, Interface, GUID},
+    crate::com::{IID_IAGILEOBJECT, IID_IDISPATCH},
+    windows_sys::{
+        core::HRESULT,
+        Win32::{
+            Foundation::{E_NOINTERFACE, E_NOTIMPL, E_POINTER},
+            System::Variant::VARIANT,
+        },
+    },
+};
+
+/// Closure type driven by a [`ManagedCallback`].
+///
+/// The closure receives the two `System.Object` arguments passed by the managed side,
+/// already marshaled by the runtime as `VARIANT` values.
+pub(crate) type CallbackFn = dyn Fn(VARIANT, VARIANT) + Send + Sync;
+
+/// A minimal COM object implemented in Rust that lets managed code call back into Rust.
+///
+/// The object answers `IAgileObject`, telling the CLR that it can be called from any
+/// apartment, so the runtime never tries to marshal calls through a proxy.
+///
+/// The object exposes the dual vtable of `System.Collections.IDictionary`. Managed
+/// code sees it as a `__ComObject` implementing `IDictionary`, which makes it a valid
+/// target for `Delegate.CreateDelegate` bound to `IDictionary.Add(object, object)`.
+/// Any `(object, T)` delegate, such as `EventHandler<T>`, can therefore be routed into
+/// a Rust closure without generating managed code or marshaling raw function pointers.
+#[repr(C)]
+pub(crate) struct ManagedCallback {
+    /// Pointer to the static vtable. Must be the first field.
+    vtable: *const ManagedCallback_Vtbl,
+
+    /// COM reference count.
+    refs: AtomicU32,
+
+    /// IID of `System.Collections.IDictionary` as seen by the hosted runtime.
+    iid: GUID,
+
+    /// Rust closure invoked from `IDictionary.Add`.
+    callback: Box<CallbackFn>,
+}
+
+impl ManagedCallback {
+    /// Creates a new callback object and returns it as an owned `IUnknown`.
+    ///
+    /// # Arguments
+    ///
+    /// * `iid` - The `GUID` of `System.Collections.IDictionary` in the hosted runtime.
+    /// * `callback` - The closure invoked every time the managed side calls into the object.
+    ///
+    /// # Returns
+    ///
+    /// * An `IUnknown` holding the only reference to the new object.
+    pub(crate) fn create(iid: GUID, callback: Box<CallbackFn>) -> IUnknown {
+        let object = Box::new(Self {
+            vtable: &VTABLE,
+            refs: AtomicU32::new(1),
+            iid,
+            callback,
+        });
+
+        unsafe { IUnknown::from_raw(Box::into_raw(object) as *mut c_void) }
+    }
+}
+
+/// Static vtable shared by every [`ManagedCallback`] instance.
+static VTABLE: ManagedCallback_Vtbl = ManagedCallback_Vtbl {
+    base__: windows_core::IUnknown_Vtbl {
+        QueryInterface: query_interface,
+        AddRef: add_ref,
+        Release: release,
+    },
+    GetTypeInfoCount: get_type_info_count,
+    GetTypeInfo: get_type_info,
+    GetIDsOfNames: get_ids_of_names,
+    Invoke: invoke,
+    get_Item: get_item,
+    set_Item: set_item,
+    get_Keys: get_collection,
+    get_Values: get_collection,
+    Contains: contains,
+    Add: add,
+    Clear: clear,
+    get_IsReadOnly: get_flag,
+    get_IsFixedSize: get_flag,
+    GetEnumerator: get_enumerator,
+    Remove: remove,
+};
+
+unsafe extern "system" fn query_interface(this: *mut c_void, iid: *const GUID, interface: *mut *mut c_void) -> windows_core::HRESULT {
+    if iid.is_null() || interface.is_null() {
+        return windows_core::HRESULT(E_POINTER);
+    }
+
+    let object = &*(this as *const ManagedCallback);
+    let iid = *iid;
+    if iid == IUnknown::IID || iid == IID_IDISPATCH || iid == IID_IAGILEOBJECT || iid == object.iid {
+        object.refs.fetch_add(1, Ordering::Relaxed);
+        *interface = this;
+        windows_core::HRESULT(0)
+    } else {
+        *interface = null_mut();
+        windows_core::HRESULT(E_NOINTERFACE)
+    }
+}
+
+unsafe extern "system" fn add_ref(this: *mut c_void) -> u32 {
+    let object = &*(this as *const ManagedCallback);
+    object.refs.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+unsafe extern "system" fn release(this: *mut c_void) -> u32 {
+    let object = &*(this as *const ManagedCallback);
+    let refs = object.refs.fetch_sub(1, Ordering::Release) - 1;
+    if refs == 0 {
+        std::sync::atomic::fence(Ordering::Acquire);
+        drop(Box::from_raw(this as *mut ManagedCallback));
+    }
+
+    refs
+}
+
+unsafe extern "system" fn get_type_info_count(_this: *mut c_void, _pctinfo: *mut u32) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_type_info(_this: *mut c_void, _itinfo: u32, _lcid: u32, _pptinfo: *mut *mut c_void) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_ids_of_names(
+    _this: *mut c_void,
+    _riid: *const GUID,
+    _rgsznames: *const *const u16,
+    _cnames: u32,
+    _lcid: u32,
+    _rgdispid: *mut i32,
+) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn invoke(
+    _this: *mut c_void,
+    _dispidmember: i32,
+    _riid: *const GUID,
+    _lcid: u32,
+    _wflags: u16,
+    _pdispparams: *mut c_void,
+    _pvarresult: *mut VARIANT,
+    _pexcepinfo: *mut c_void,
+    _puargerr: *mut u32,
+) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_item(_this: *mut c_void, _key: VARIANT, _pRetVal: *mut VARIANT) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn set_item(_this: *mut c_void, _key: VARIANT, _value: VARIANT) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_collection(_this: *mut c_void, _pRetVal: *mut *mut c_void) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn contains(_this: *mut c_void, _key: VARIANT, _pRetVal: *mut i16) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn add(this: *mut c_void, key: VARIANT, value: VARIANT) -> HRESULT {
+    let object = &*(this as *const ManagedCallback);
+    (object.callback)(key, value);
+    0
+}
+
+unsafe extern "system" fn clear(_this: *mut c_void) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_flag(_this: *mut c_void, _pRetVal: *mut i16) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn get_enumerator(_this: *mut c_void, _pRetVal: *mut *mut c_void) -> HRESULT {
+    E_NOTIMPL
+}
+
+unsafe extern "system" fn remove(_this: *mut c_void, _key: VARIANT) -> HRESULT {
+    E_NOTIMPL
+}
+
+/// Vtable layout of the dual `System.Collections.IDictionary` interface as exported to COM.
+///
+/// Members follow the metadata order of the managed interface, after the `IDispatch` slots.
+#[repr(C)]
+struct ManagedCallback_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    base__: windows_core::IUnknown_Vtbl,
+
+    /// `IDispatch::GetTypeInfoCount`. Not implemented.
+    GetTypeInfoCount: unsafe extern "system" fn(*mut c_void, *mut u32) -> HRESULT,
+
+    /// `IDispatch::GetTypeInfo`. Not implemented.
+    GetTypeInfo: unsafe extern "system" fn(*mut c_void, u32, u32, *mut *mut c_void) -> HRESULT,
+
+    /// `IDispatch::GetIDsOfNames`. Not implemented.
+    GetIDsOfNames: unsafe extern "system" fn(*mut c_void, *const GUID, *const *const u16, u32, u32, *mut i32) -> HRESULT,
+
+    /// `IDispatch::Invoke`. Not implemented.
+    Invoke: unsafe extern "system" fn(*mut c_void, i32, *const GUID, u32, u16, *mut c_void, *mut VARIANT, *mut c_void, *mut u32) -> HRESULT,
+
+    /// `IDictionary.get_Item`. Not implemented.
+    get_Item: unsafe extern "system" fn(*mut c_void, VARIANT, *mut VARIANT) -> HRESULT,
+
+    /// `IDictionary.set_Item`. Not implemented.
+    set_Item: unsafe extern "system" fn(*mut c_void, VARIANT, VARIANT) -> HRESULT,
+
+    /// `IDictionary.get_Keys`. Not implemented.
+    get_Keys: unsafe extern "system" fn(*mut c_void, *mut *mut c_void) -> HRESULT,
+
+    /// `IDictionary.get_Values`. Not implemented.
+    get_Values: unsafe extern "system" fn(*mut c_void, *mut *mut c_void) -> HRESULT,
+
+    /// `IDictionary.Contains`. Not implemented.
+    Contains: unsafe extern "system" fn(*mut c_void, VARIANT, *mut i16) -> HRESULT,
+
+    /// `IDictionary.Add`, forwarded to the Rust closure.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `key` - First argument of the managed call.
+    /// * `value` - Second argument of the managed call.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    Add: unsafe extern "system" fn(*mut c_void, key: VARIANT, value: VARIANT) -> HRESULT,
+
+    /// `IDictionary.Clear`. Not implemented.
+    Clear: unsafe extern "system" fn(*mut c_void) -> HRESULT,
+
+    /// `IDictionary.get_IsReadOnly`. Not implemented.
+    get_IsReadOnly: unsafe extern "system" fn(*mut c_void, *mut i16) -> HRESULT,
+
+    /// `IDictionary.get_IsFixedSize`. Not implemented.
+    get_IsFixedSize: unsafe extern "system" fn(*mut c_void, *mut i16) -> HRESULT,
+
+    /// `IDictionary.GetEnumerator`. Not implemented.
+    GetEnumerator: unsafe extern "system" fn(*mut c_void, *mut *mut c_void) -> HRESULT,
+
+    /// `IDictionary.Remove`. Not implemented.
+    Remove: unsafe extern "system" fn(*mut c_void, VARIANT) -> HRESULT,
+}
@@ -0,0 +1,339 @@
+use std::{env, fmt, mem::size_of, path::PathBuf};
+
+use windows_sys::{
+    Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemCodeIntegrityInformation},
+    Win32::System::{
+        SystemServices::{
+            PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY, PROCESS_MITIGATION_CHILD_PROCESS_POLICY,
+            PROCESS_MITIGATION_DYNAMIC_CODE_POLICY,
+        },
+        Threading::{
+            GetCurrentProcess, GetProcessMitigationPolicy, IsWow64Process, PROCESS_MITIGATION_POLICY,
+            ProcessChildProcessPolicy, ProcessDynamicCodePolicy, ProcessSignaturePolicy,
+        },
+        WindowsProgramming::SYSTEM_CODEINTEGRITY_INFORMATION,
+    },
+};
+
+use crate::{
+    com::{hosting_api, CLRCreateInstance, HostingApi, CLSID_CLRMETAHOST},
+    error::{ClrError, last_error},
+    schema::ICLRMetaHost,
+};
+
+/// `CODEINTEGRITY_OPTION_UMCI_ENABLED`: user-mode code integrity is enabled.
+const CODEINTEGRITY_OPTION_UMCI_ENABLED: u32 = 0x04;
+
+/// `CODEINTEGRITY_OPTION_UMCI_AUDITMODE_ENABLED`: user-mode code integrity only audits.
+const CODEINTEGRITY_OPTION_UMCI_AUDITMODE_ENABLED: u32 = 0x08;
+
+/// A reason why the current process may not host the CLR, or why a fallback is unavailable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostIssue {
+    /// `mscoree.dll` cannot be loaded, so the .NET Framework is not installed.
+    MscoreeUnavailable,
+
+    /// No .NET Framework runtime is installed for the bitness of the process, nor for the other one.
+    NoRuntime,
+
+    /// The .NET Framework is only installed for the other bitness.
+    ///
+    /// A helper process of `runtime_bits` bits can host the assembly instead.
+    BitnessMismatch {
+        /// The bitness of the current process.
+        process_bits: u32,
+
+        /// The bitness for which a runtime is installed.
+        runtime_bits: u32,
+    },
+
+    /// The process forbids dynamic code (Arbitrary Code Guard), so the JIT compiler cannot run.
+    DynamicCodeProhibited,
+
+    /// The process only loads Microsoft-signed images. The runtime itself loads, but native
+    /// images generated on the machine and mixed-mode assemblies are refused.
+    MicrosoftSignedOnly,
+
+    /// User-mode code integrity (WDAC/Device Guard) is enforced. Policies with dynamic code
+    /// security refuse assemblies loaded from memory.
+    CodeIntegrityEnforced,
+
+    /// The process cannot create child processes, so no helper process can be started.
+    ChildProcessesBlocked,
+}
+
+impl HostIssue {
+    /// Returns `true` if the issue prevents hosting the CLR in the current process.
+    pub fn is_blocking(&self) -> bool {
+        matches!(
+            self,
+            HostIssue::MscoreeUnavailable
+                | HostIssue::NoRuntime
+                | HostIssue::BitnessMismatch { .. }
+                | HostIssue::DynamicCodeProhibited
+        )
+    }
+}
+
+impl fmt::Display for HostIssue {
+    /// Describes the issue along with what can be done about it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostIssue::MscoreeUnavailable => write!(f, "mscoree.dll cannot be loaded: the .NET Framework is not installed"),
+            HostIssue::NoRuntime => write!(f, "no .NET Framework runtime is installed"),
+            HostIssue::BitnessMismatch { process_bits, runtime_bits } => write!(
+                f,
+                "no runtime for {process_bits}-bit processes: run the assembly from a {runtime_bits}-bit helper process"
+            ),
+            HostIssue::DynamicCodeProhibited => write!(
+                f,
+                "dynamic code is prohibited in this process, so the JIT cannot run: use a helper process"
+            ),
+            HostIssue::MicrosoftSignedOnly => write!(
+                f,
+                "only Microsoft-signed images may load: mixed-mode assemblies and local native images will fail"
+            ),
+            HostIssue::CodeIntegrityEnforced => write!(
+                f,
+                "user-mode code integrity is enforced: loading assemblies from memory may be refused"
+            ),
+            HostIssue::ChildProcessesBlocked => write!(f, "child process creation is blocked: no helper process can be used"),
+        }
+    }
+}
+
+/// Result of [`can_host`], describing whether and how the current process can host the CLR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostCapability {
+    /// The hosting API exposed by `mscoree.dll`, `None` if it cannot be loaded.
+    pub hosting_api: Option<HostingApi>,
+
+    /// The runtime versions installed for the bitness of the process (e.g. `v4.0.30319`).
+    pub runtimes: Vec<String>,
+
+    /// The bitness of the process, 32 or 64.
+    pub process_bits: u32,
+
+    /// Whether the process is a 32-bit process running on 64-bit Windows.
+    pub wow64: bool,
+
+    /// The issues found, blocking or not.
+    pub issues: Vec<HostIssue>,
+}
+
+impl HostCapability {
+    /// Returns `true` if no issue prevents hosting the CLR in the current process.
+    pub fn in_process(&self) -> bool {
+        !self.issues.iter().any(HostIssue::is_blocking)
+    }
+
+    /// Returns `true` if the process can start a helper process to host the CLR instead.
+    pub fn helper_process(&self) -> bool {
+        !self.issues.contains(&HostIssue::ChildProcessesBlocked)
+    }
+
+    /// Returns the issues preventing hosting the CLR in the current process.
+    pub fn blockers(&self) -> impl Iterator<Item = &HostIssue> {
+        self.issues.iter().filter(|issue| issue.is_blocking())
+    }
+}
+
+impl fmt::Display for HostCapability {
+    /// Formats the capability with one line per issue.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.in_process() { "can host" } else { "cannot host" };
+        writeln!(f, "{}-bit process{}: {status}", self.process_bits, if self.wow64 { " (WOW64)" } else { "" })?;
+        for issue in &self.issues {
+            let kind = if issue.is_blocking() { "blocking" } else { "warning" };
+            writeln!(f, "{kind}: {issue}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks whether the current process can host the CLR, without starting it.
+///
+/// The check covers the availability of `mscoree.dll`, the runtimes installed for the bitness
+/// of the process, the mitigation policies of the process (dynamic code, signed images, child
+/// processes) and the code integrity policy of the system. It lets callers decide up front
+/// between running an assembly in-process and handing it to a helper process.
+///
+/// Policies whose effect depends on the assembly, such as the options of a WDAC policy, are
+/// reported as warnings rather than blockers. AppLocker rules are not evaluated.
+///
+/// # Returns
+///
+/// * `Ok(HostCapability)` - The capability of the process, with the issues found.
+/// * `Err(ClrError)` - If the process information cannot be queried.
+///
+/// # Examples
+///
+/// ```ignore
+/// let capability = rustclr::can_host()?;
+/// if !capability.in_process() {
+///     for reason in capability.blockers() {
+///         eprintln!("{reason}");
+///     }
+/// }
+/// ```
+pub fn can_host() -> Result<HostCapability, ClrError> {
+    let process_bits = usize::BITS;
+    let mut wow64 = 0;
+    if unsafe { IsWow64Process(GetCurrentProcess(), &mut wow64) } == 0 {
+        return Err(last_error("IsWow64Process"));
+    }
+
+    let wow64 = wow64 != 0;
+    let hosting_api = hosting_api();
+    let runtimes = process_runtimes(hosting_api);
+    let mut issues = Vec::new();
+
+    if hosting_api.is_none() {
+        issues.push(HostIssue::MscoreeUnavailable);
+    }
+
+    // A process only loads a runtime of its own bitness, but a 64-bit system can start a
+    // helper process of the other bitness: 32-bit from a 64-bit process, 64-bit from WOW64
+    if runtimes.is_empty() {
+        let runtime_bits = if process_bits == 64 { 32 } else { 64 };
+        if (process_bits == 64 || wow64) && !installed_runtimes(runtime_bits).is_empty() {
+            issues.push(HostIssue::BitnessMismatch { process_bits, runtime_bits });
+        } else {
+            issues.push(HostIssue::NoRuntime);
+        }
+    }
+
+    // The bit fields of the policies start with the flag checked here
+    let dynamic_code = mitigation_policy::<PROCESS_MITIGATION_DYNAMIC_CODE_POLICY>(ProcessDynamicCodePolicy)
+        .map(|policy| unsafe { policy.Anonymous.Flags });
+    if dynamic_code.is_some_and(|flags| flags & 1 != 0) {
+        issues.push(HostIssue::DynamicCodeProhibited);
+    }
+
+    let signature = mitigation_policy::<PROCESS_MITIGATION_BINARY_SIGNATURE_POLICY>(ProcessSignaturePolicy)
+        .map(|policy| unsafe { policy.Anonymous.Flags });
+    if signature.is_some_and(|flags| flags & 1 != 0) {
+        issues.push(HostIssue::MicrosoftSignedOnly);
+    }
+
+    let code_integrity = code_integrity_options();
+    if code_integrity.is_some_and(|options| {
+        options & CODEINTEGRITY_OPTION_UMCI_ENABLED != 0 && options & CODEINTEGRITY_OPTION_UMCI_AUDITMODE_ENABLED == 0
+    }) {
+        issues.push(HostIssue::CodeIntegrityEnforced);
+    }
+
+    let child_process = mitigation_policy::<PROCESS_MITIGATION_CHILD_PROCESS_POLICY>(ProcessChildProcessPolicy)
+        .map(|policy| unsafe { policy.Anonymous.Flags });
+    if child_process.is_some_and(|flags| flags & 1 != 0) {
+        issues.push(HostIssue::ChildProcessesBlocked);
+    }
+
+    Ok(HostCapability { hosting_api, runtimes, process_bits, wow64, issues })
+}
+
+/// Lists the .NET Framework runtimes the current process can load.
+///
+/// The runtimes are enumerated through `ICLRMetaHost::EnumerateInstalledRuntimes`, as the shim
+/// sees them. Without the .NET Framework 4.0+ shim, or if the enumeration fails, the framework
+/// directory of the process bitness is scanned instead.
+///
+/// # Arguments
+///
+/// * `hosting_api` - The hosting API exposed by `mscoree.dll`, if it can be loaded.
+///
+/// # Returns
+///
+/// * The installed versions (e.g. `v4.0.30319`), sorted.
+fn process_runtimes(hosting_api: Option<HostingApi>) -> Vec<String> {
+    if hosting_api == Some(HostingApi::MetaHost) {
+        let runtimes = CLRCreateInstance::<ICLRMetaHost>(&CLSID_CLRMETAHOST).and_then(|meta_host| meta_host.runtimes());
+        if let Ok(runtimes) = runtimes {
+            let mut versions = runtimes.into_keys().collect::<Vec<_>>();
+            versions.sort();
+            return versions;
+        }
+    }
+
+    installed_runtimes(usize::BITS)
+}
+
+/// Lists the .NET Framework runtimes installed for a bitness, by scanning its framework directory.
+///
+/// The shim of the process only enumerates the runtimes of its own bitness, so the other
+/// bitness is always checked this way.
+///
+/// # Arguments
+///
+/// * `bits` - The bitness of the runtimes, 32 or 64.
+///
+/// # Returns
+///
+/// * The versions whose directory contains the runtime (`clr.dll`, or `mscorwks.dll` before 4.0).
+fn installed_runtimes(bits: u32) -> Vec<String> {
+    let Ok(windir) = env::var("WINDIR") else {
+        return Vec::new();
+    };
+
+    let framework = PathBuf::from(windir)
+        .join("Microsoft.NET")
+        .join(if bits == 64 { "Framework64" } else { "Framework" });
+
+    let Ok(entries) = framework.read_dir() else {
+        return Vec::new();
+    };
+
+    let mut runtimes = entries
+        .flatten()
+        .filter(|entry| entry.path().join("clr.dll").exists() || entry.path().join("mscorwks.dll").exists())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| name.starts_with('v'))
+        .collect::<Vec<_>>();
+
+    runtimes.sort();
+    runtimes
+}
+
+/// Reads a mitigation policy of the current process.
+///
+/// # Arguments
+///
+/// * `policy` - The `PROCESS_MITIGATION_POLICY` to read.
+///
+/// # Returns
+///
+/// * `Some(T)` - The policy structure.
+/// * `None` - If the system does not support the policy.
+fn mitigation_policy<T>(policy: PROCESS_MITIGATION_POLICY) -> Option<T> {
+    let mut value = unsafe { std::mem::zeroed::<T>() };
+    let ok = unsafe {
+        GetProcessMitigationPolicy(GetCurrentProcess(), policy, &mut value as *mut T as *mut _, size_of::<T>())
+    };
+
+    (ok != 0).then_some(value)
+}
+
+/// Reads the code integrity options of the system.
+///
+/// # Returns
+///
+/// * `Some(u32)` - The `CODEINTEGRITY_OPTION_*` flags.
+/// * `None` - If the options cannot be queried.
+fn code_integrity_options() -> Option<u32> {
+    let mut info = SYSTEM_CODEINTEGRITY_INFORMATION {
+        Length: size_of::<SYSTEM_CODEINTEGRITY_INFORMATION>() as u32,
+        CodeIntegrityOptions: 0,
+    };
+
+    let status = unsafe {
+        NtQuerySystemInformation(
+            SystemCodeIntegrityInformation,
+            &mut info as *mut _ as *mut _,
+            size_of::<SYSTEM_CODEINTEGRITY_INFORMATION>() as u32,
+            std::ptr::null_mut()
+        )
+    };
+
+    (status >= 0).then_some(info.CodeIntegrityOptions)
+}
@@ -1,32 +1,66 @@
 use crate::{ 
-    WinStr, error::ClrError, InvocationType,
-    file::validate_file, create_safe_array_args,
+    WinStr, InvocationType,
+    error::{ClrError, last_error},
+    observer::{ExceptionObserver, FirstChanceException},
+    gc::GcStats,
+    metadata::{AssemblyMetadata, ExecutionLevel},
+    report::{ArtifactKind, DetectionReport},
+    events::ExecutionEvent,
+    names::ManagedName,
+    hooks::{ManagedCall, enable_tls12, set_app_context_switch, set_thread_pool_limits},
+    spill::{CapturedOutput, SpillWriter},
+    console::{ConsoleGuard, ConsoleMode},
+    dump::{CrashDump, DumpGuard},
+    gate::{ExecutionPermit, lock_runtime_init},
+    embedded::register_embedded_dependencies,
+    service::{ServiceHandle, ServiceOutput},
+    Variant, ControlSequences, ControlSequenceFilter, Instance, OwnedVariant,
+    payload::PayloadImage, SafeArray,
     com::{
-        CLRCreateInstance, 
-        CLSID_CLRMETAHOST, 
-        CLSID_COR_RUNTIME_HOST
+        CLRCreateInstance, CorBindToRuntimeEx,
+        CLSID_CLRMETAHOST, CLSID_COR_RUNTIME_HOST, CLSID_CLR_PROFILING,
+        CLSID_COR_METADATA_DISPENSER,
+        hosting_api, HostingApi,
     }, 
     schema::{
-        _AppDomain, ICLRMetaHost, 
+        _AppDomain, ICLRMetaHost, ICLRProfiling,
+        IMetaDataDispenser, IMetaDataImport,
         ICLRRuntimeInfo, ICorRuntimeHost, 
-        _Assembly 
+        _Assembly, _Type, COR_E_MISSINGMETHOD
     }, 
 };
 
+#[cfg(feature = "activity-monitor")]
+use crate::activity::{ActivityMonitor, ActivitySummary};
+
 use {
-    std::ptr::null_mut,
-    windows_core::PCWSTR,
-    windows_sys::Win32::System::Variant::VARIANT,
+    std::{ops::BitOr, ptr::null_mut, str::FromStr, sync::mpsc::Sender, time::Duration},
+    windows_core::{GUID, IUnknown, Interface, PCWSTR},
+    windows_sys::Win32::{
+        Foundation::{CloseHandle, SysFreeString, SysStringLen},
+        Security::{GetTokenInformation, TokenElevation, TOKEN_ELEVATION, TOKEN_QUERY},
+        System::{
+            Threading::{
+                GetCurrentProcess, OpenProcess, OpenProcessToken,
+                PROCESS_QUERY_INFORMATION, PROCESS_VM_READ,
+            },
+            Variant::VARIANT,
+        },
+    },
 };
 
+/// Name of the application domain created for each run by [`RustClr::with_soft_unload`]
+/// when no domain name is set.
+pub const SOFT_UNLOAD_DOMAIN: &str = "RustClrDomain";
+
 /// Represents a Rust interface to the Common Language Runtime (CLR).
 /// 
 /// This structure allows loading and executing .NET assemblies with specific runtime versions, 
 /// application domains, and arguments.
 #[derive(Debug, Clone)]
 pub struct RustClr<'a> {
-    /// Buffer containing the .NET assembly in bytes.
-    buffer: &'a [u8],
+    /// The .NET assembly, validated once with its headers parsed.
+    image: PayloadImage<'a>,
 
     /// Flag to indicate if output redirection is enabled.
     redirect_output: bool,
@@ -45,6 +79,83 @@ pub struct RustClr<'a> {
 
     /// Host for the CLR runtime.
     cor_runtime_host: Option<ICorRuntimeHost>,
+
+    /// Runtime loaded through `ICLRMetaHost`, used to describe the HRESULTs it reports.
+    runtime_info: Option<ICLRRuntimeInfo>,
+
+    /// Channel receiving first-chance exceptions raised while the assembly runs.
+    exception_sender: Option<Sender<FirstChanceException>>,
+
+    /// Flag to indicate if the runtime should be started with a minimal footprint.
+    minimal_footprint: bool,
+
+    /// Startup flags applied to the runtime before it is started.
+    startup_flags: Option<StartupFlags>,
+
+    /// Flag to indicate if the invariant culture should be the domain default.
+    invariant_culture: bool,
+
+    /// Number of characters above which redirected output is spilled to disk.
+    spill_threshold: Option<usize>,
+
+    /// Initial capacity of the redirection buffer, which is then reused across runs.
+    output_capacity: Option<usize>,
+
+    /// The `StringWriter` kept from the previous run when `output_capacity` is set.
+    output_writer: Option<Instance>,
+
+    /// How control sequences in the redirected output are handled.
+    control_sequences: ControlSequences,
+
+    /// Managed calls executed before the entry point.
+    pre_run: Vec<ManagedCall>,
+
+    /// Managed calls executed after the entry point.
+    post_run: Vec<ManagedCall>,
+
+    /// Flag to indicate if TLS 1.2 should be enabled before the entry point runs.
+    tls12: bool,
+
+    /// `AppContext` switches set before the entry point runs.
+    app_context_switches: Vec<(String, bool)>,
+
+    /// Channel receiving progress events while the assembly runs.
+    event_sender: Option<Sender<ExecutionEvent>>,
+
+    /// Security zone assigned to the application domain through its evidence.
+    zone: Option<Zone>,
+
+    /// Flag to indicate if `Trace`/`Debug` output is captured with the console output.
+    trace_capture: bool,
+
+    /// Console provided to the assembly when the host process has none.
+    console: ConsoleMode,
+
+    /// Flag to unload the application domain after each run while keeping the runtime loaded.
+    soft_unload: bool,
+
+    /// Minidump written when the entry point fails fatally.
+    crash_dump: Option<CrashDump>,
+
+    /// Flag to load the dependencies packed into the assembly before the entry point runs.
+    embedded_dependencies: bool,
+
+    /// Minimum worker and I/O completion threads of the thread pool.
+    min_threads: Option<(i32, i32)>,
+
+    /// Maximum worker and I/O completion threads of the thread pool.
+    max_threads: Option<(i32, i32)>,
+
+    /// Flag to refuse assemblies requiring administrator rights when the process is not elevated.
+    elevation_check: bool,
+
+    /// Flag to record the file and registry operations made while the entry point runs.
+    #[cfg(feature = "activity-monitor")]
+    activity_monitor: bool,
+
+    /// Operations recorded during the last run.
+    #[cfg(feature = "activity-monitor")]
+    activity: Option<ActivitySummary>,
 }
 
 impl<'a> Default for RustClr<'a> {
@@ -55,13 +166,40 @@ impl<'a> Default for RustClr<'a> {
     /// * A default-initialized `RustClr`.
     fn default() -> Self {
         Self { 
-            buffer: &[], 
+            image: PayloadImage::default(),
             runtime_version: None,
             redirect_output: false,
             domain_name: None,
             args: None, 
             app_domain: None,
-            cor_runtime_host: None
+            cor_runtime_host: None,
+            runtime_info: None,
+            exception_sender: None,
+            minimal_footprint: false,
+            startup_flags: None,
+            invariant_culture: false,
+            spill_threshold: None,
+            output_capacity: None,
+            output_writer: None,
+            control_sequences: ControlSequences::Preserve,
+            pre_run: Vec::new(),
+            post_run: Vec::new(),
+            tls12: false,
+            app_context_switches: Vec::new(),
+            event_sender: None,
+            zone: None,
+            trace_capture: false,
+            console: ConsoleMode::None,
+            soft_unload: false,
+            crash_dump: None,
+            embedded_dependencies: false,
+            min_threads: None,
+            max_threads: None,
+            elevation_check: true,
+            #[cfg(feature = "activity-monitor")]
+            activity_monitor: false,
+            #[cfg(feature = "activity-monitor")]
+            activity: None
         }
     }
 }
@@ -97,16 +235,43 @@ impl<'a> RustClr<'a> {
     /// ```
     pub fn new(buffer: &'a [u8]) -> Result<Self, ClrError> {
         // Checks if it is a valid .NET and EXE file
-        validate_file(buffer)?;
+        let image = PayloadImage::parse(buffer)?;
 
         Ok(Self { 
-            buffer, 
+            image, 
             redirect_output: false,
             runtime_version: None,
             domain_name: None, 
             args: None, 
             app_domain: None,
-            cor_runtime_host: None
+            cor_runtime_host: None,
+            runtime_info: None,
+            exception_sender: None,
+            minimal_footprint: false,
+            startup_flags: None,
+            invariant_culture: false,
+            spill_threshold: None,
+            output_capacity: None,
+            output_writer: None,
+            control_sequences: ControlSequences::Preserve,
+            pre_run: Vec::new(),
+            post_run: Vec::new(),
+            tls12: false,
+            app_context_switches: Vec::new(),
+            event_sender: None,
+            zone: None,
+            trace_capture: false,
+            console: ConsoleMode::None,
+            soft_unload: false,
+            crash_dump: None,
+            embedded_dependencies: false,
+            min_threads: None,
+            max_threads: None,
+            elevation_check: true,
+            #[cfg(feature = "activity-monitor")]
+            activity_monitor: false,
+            #[cfg(feature = "activity-monitor")]
+            activity: None
         })
     }
 
@@ -239,179 +404,1781 @@ impl<'a> RustClr<'a> {
         self
     }
 
-    /// Prepares the CLR environment by initializing the runtime and application domain.
-    /// 
-    /// # Returns
-    /// 
-    /// * `Ok(())` - If the environment is successfully prepared.
-    /// * `Err(ClrError)` - If any error occurs during the preparation process.
-    fn prepare(&mut self) -> Result<(), ClrError> {
-        // Creates the MetaHost to access the available CLR versions
-        let meta_host = self.create_meta_host()?;
-
-        // Gets information about the specified (or default) runtime version
-        let runtime_info = self.get_runtime_info(&meta_host)?;
-
-        // Creates the runtime host
-        let cor_runtime_host = self.get_runtime_host(&runtime_info)?;
-
-        // Checks if the runtime is started
-        if runtime_info.IsLoadable().is_ok() && !runtime_info.is_started() {
-            // Starts the CLR runtime
-            self.start_runtime(&cor_runtime_host)?;
-        }
-
-        // Initializes the specified application domain or the default
-        self.init_app_domain(&cor_runtime_host)?;
-
-        // Saves the runtime host for future use
-        self.cor_runtime_host = Some(cor_runtime_host);
-
-        Ok(())
-    }
-
-    /// Runs the .NET assembly by loading it into the application domain and invoking its entry point.
-    /// 
+    /// Captures the messages written through `System.Diagnostics.Trace` and `Debug` with the console output.
+    ///
+    /// A `TextWriterTraceListener` writing to the redirection `StringWriter` is added to
+    /// `Trace.Listeners` for the duration of the run. It has no effect unless output
+    /// redirection is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `capture` - A boolean indicating whether trace output should be captured.
+    ///
     /// # Returns
-    /// 
-    /// * `Ok(String)` - The output from the .NET assembly if executed successfully.
-    /// * `Err(ClrError)` - If an error occurs during execution.
-    /// 
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
     /// # Examples
-    /// 
+    ///
     /// ```ignore
-    /// use rustclr::{RustClr, RuntimeVersion};
+    /// use rustclr::RustClr;
     /// use std::fs;
     ///
     /// fn main() -> Result<(), Box<dyn std::error::Error>> {
     ///     let buffer = fs::read("examples/sample.exe")?;
     ///
-    ///     // Create and configure a RustClr instance
-    ///     let mut clr = RustClr::new(&buffer)?
-    ///         .with_runtime_version(RuntimeVersion::V4)
-    ///         .with_domain("CustomDomain")
-    ///         .with_args(vec!["arg1", "arg2"])
-    ///         .with_output_redirection(true);
+    ///     let output = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_trace_capture(true)
+    ///         .run()?;
     ///
-    ///     // Run the .NET assembly and capture the output
-    ///     let output = clr.run()?;
-    ///     println!("Output: {}", output);
-    /// 
+    ///     println!("{output}");
     ///     Ok(())
     /// }
     /// ```
-    pub fn run(&mut self) -> Result<String, ClrError> {
-        // Prepare the CLR environment
-        self.prepare()?;
-
-        // Gets the current application domain
-        let domain = self.get_app_domain()?;
-
-        // Loads the .NET assembly specified by the buffer
-        let assembly = domain.load_assembly(self.buffer)?;
-
-        // Prepares the parameters for the `Main` method
-        let parameters = self.args.as_ref().map_or_else(
-            || Ok(null_mut()),
-            |args| create_safe_array_args(args.to_vec())
-        )?;
-
-        // Redirects output if enabled
-        let output = if self.redirect_output {
-            // Loads the mscorlib library for output redirection
-            let mscorlib = domain.load_lib("mscorlib")?;
-            let mut output_manager = ClrOutput::new(&mscorlib);
-            
-            // Redirecting output
-            output_manager.redirect()?;
-
-            // Invokes the `Main` method of the assembly
-            assembly.run(parameters)?;
-
-            // Captures and restores output if redirected
-            let result = output_manager.capture()?;
-            output_manager.restore()?;
-            result
-        } else {
-            // Invokes the `Main` method of the assembly
-            assembly.run(parameters)?;
-
-            // Empty output
-            String::new()
-        };
-
-        Ok(output)
+    pub fn with_trace_capture(mut self, capture: bool) -> Self {
+        self.trace_capture = capture;
+        self
     }
 
-    /// Retrieves the current application domain.
-    /// 
+    /// Sets how a console is provided to the assembly when the host process has none.
+    ///
+    /// GUI and service hosts have no console, so `System.Console` APIs that need one fail
+    /// inside the assembly. The console is set up before the runtime is prepared and
+    /// released when the run finishes.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The `ConsoleMode` to apply.
+    ///
     /// # Returns
-    /// 
-    /// * `Ok(_AppDomain)` - If the application domain is available.
-    /// * `Err(ClrError)` - If no application domain is available.
-    fn get_app_domain(&mut self) -> Result<_AppDomain, ClrError> {
-        self.app_domain.clone().ok_or(ClrError::NoDomainAvailable)
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, ConsoleMode};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Give the assembly a hidden console from a GUI process
+    ///     let output = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_console(ConsoleMode::Hidden)
+    ///         .run()?;
+    ///
+    ///     println!("{output}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_console(mut self, mode: ConsoleMode) -> Self {
+        self.console = mode;
+        self
     }
 
-    /// Creates an instance of `ICLRMetaHost`.
-    /// 
+    /// Unloads the application domain of the assembly after each run, keeping the runtime loaded.
+    ///
+    /// Every run creates a fresh application domain, named after [`RustClr::with_domain`] or
+    /// [`SOFT_UNLOAD_DOMAIN`] by default, and unloads it once the entry point returns or fails,
+    /// which releases the assembly and everything it allocated. The started runtime host is kept
+    /// by the `RustClr` instance, so the following runs skip the runtime activation, which is the
+    /// most expensive part of an execution. Combined with [`RustClr::with_assembly`], a single
+    /// instance can run a sequence of assemblies in a long-lived process.
+    ///
+    /// Managed handles (`mscorlib`, the `StringWriter` type used by the output redirection, ...)
+    /// belong to the domain they were resolved in, so they are resolved again in each new domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `soft_unload` - Whether the domain is unloaded after each run.
+    ///
     /// # Returns
-    /// 
-    /// * `Ok(ICLRMetaHost)` - If the instance is created successfully.
-    /// * `Err(ClrError)` - If the instance creation fails.
-    fn create_meta_host(&self) -> Result<ICLRMetaHost, ClrError> {
-        CLRCreateInstance::<ICLRMetaHost>(&CLSID_CLRMETAHOST)
-            .map_err(|e| ClrError::MetaHostCreationError(format!("{e}")))
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let first = fs::read("examples/first.exe")?;
+    ///     let second = fs::read("examples/second.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&first)?
+    ///         .with_output_redirection(true)
+    ///         .with_soft_unload(true);
+    ///
+    ///     println!("{}", clr.run()?);
+    ///
+    ///     // Reuses the runtime started by the first run
+    ///     let mut clr = clr.with_assembly(&second)?;
+    ///     println!("{}", clr.run()?);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_soft_unload(mut self, soft_unload: bool) -> Self {
+        self.soft_unload = soft_unload;
+        self
     }
 
-    /// Retrieves runtime information based on the selected .NET version.
-    /// 
+    /// Writes a minidump of the process when the entry point fails fatally.
+    ///
+    /// While the entry point runs, the dump is written if it returns an HRESULT the runtime
+    /// cannot recover from (`COR_E_EXECUTIONENGINE`, an `AccessViolationException`,
+    /// `RPC_E_SERVERFAULT`, ...), or when a fatal structured exception (illegal instruction,
+    /// heap corruption, stack buffer overrun) is raised, before the process is torn down.
+    /// Managed exceptions thrown by the assembly do not trigger a dump.
+    ///
     /// # Arguments
-    /// 
-    /// * `meta_host` - Reference to the `ICLRMetaHost` instance.
-    /// 
+    ///
+    /// * `dump` - The `CrashDump` describing where and what to write.
+    ///
     /// # Returns
-    /// 
-    /// * `Ok(ICLRRuntimeInfo)` - If runtime information is retrieved successfully.
-    /// * `Err(ClrError)` - If the retrieval fails.
-    fn get_runtime_info(&self, meta_host: &ICLRMetaHost) -> Result<ICLRRuntimeInfo, ClrError> {
-        let runtime_version = self.runtime_version.unwrap_or(RuntimeVersion::V4);
-        let version_wide = runtime_version.to_vec();
-        let version = PCWSTR(version_wide.as_ptr());
-
-        meta_host.GetRuntime::<ICLRRuntimeInfo>(version)
-            .map_err(|e| ClrError::RuntimeInfoError(format!("{e}")))
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{CrashDump, DumpTarget, DumpType, RustClr};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let dump = CrashDump::new(DumpTarget::File("sample.dmp".into()))
+    ///         .with_type(DumpType::WITH_PRIVATE_READ_WRITE_MEMORY);
+    ///
+    ///     let output = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_crash_dump(dump)
+    ///         .run()?;
+    ///
+    ///     println!("{output}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_crash_dump(mut self, dump: CrashDump) -> Self {
+        self.crash_dump = Some(dump);
+        self
     }
 
-    /// Gets the runtime host interface from the provided runtime information.
-    /// 
+    /// Loads the dependencies packed into the assembly before its entry point runs.
+    ///
+    /// Single-file tools packed with Costura carry their references as manifest resources.
+    /// When enabled, each one is extracted, loaded into the application domain and resolved
+    /// through an `AppDomain.AssemblyResolve` handler, so the references bind even when the
+    /// packer's own resolver does not run. An [`ExecutionEvent::DependencyLoaded`] is emitted
+    /// for each of them. Assemblies without packed dependencies are not affected.
+    ///
+    /// Requires `System.Core`, so it is not supported on .NET 2.0.
+    ///
     /// # Arguments
-    /// 
-    /// * `runtime_info` - Reference to the `ICLRRuntimeInfo` instance.
-    /// 
+    ///
+    /// * `enabled` - Whether to load the packed dependencies.
+    ///
     /// # Returns
-    /// 
-    /// * `Ok(ICorRuntimeHost)` - If the interface is obtained successfully.
-    /// * `Err(ClrError)` - If the retrieval fails.
-    fn get_runtime_host(&self, runtime_info: &ICLRRuntimeInfo) -> Result<ICorRuntimeHost, ClrError> {
-        runtime_info.GetInterface::<ICorRuntimeHost>(&CLSID_COR_RUNTIME_HOST)
-            .map_err(|e| ClrError::RuntimeHostError(format!("{e}")))
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/packed.exe")?;
+    ///
+    ///     let output = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_embedded_dependencies(true)
+    ///         .run()?;
+    ///
+    ///     println!("{output}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_embedded_dependencies(mut self, enabled: bool) -> Self {
+        self.embedded_dependencies = enabled;
+        self
     }
 
-    /// Starts the CLR runtime using the provided runtime host.
-    /// 
+    /// Enables or disables the elevation check made before the runtime starts.
+    ///
+    /// Assemblies loaded from memory do not get the token their manifest asks for, so a
+    /// payload declaring `requireAdministrator` would run with the token of the host and
+    /// fail on its first privileged operation. The check, enabled by default, reports
+    /// [`ClrError::RequiresElevation`] instead when the process is not elevated.
+    ///
     /// # Arguments
-    /// 
-    /// * `cor_runtime_host` - Reference to the `ICorRuntimeHost` instance.
-    /// 
+    ///
+    /// * `enabled` - Whether to check the requested execution level.
+    ///
     /// # Returns
-    /// 
-    /// * `Ok(())` - If the runtime starts successfully.
-    /// * `Err(ClrError)` - If the runtime fails to start.
-    fn start_runtime(&self, cor_runtime_host: &ICorRuntimeHost) -> Result<(), ClrError> {
-
-        if cor_runtime_host.Start() != 0 {
-            return Err(ClrError::RuntimeStartError);
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, error::ClrError};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/admin.exe")?;
+    ///
+    ///     match RustClr::new(&buffer)?.run() {
+    ///         Err(ClrError::RequiresElevation) => {
+    ///             // Runs anyway, the payload handles missing privileges itself
+    ///             RustClr::new(&buffer)?.with_elevation_check(false).run()?;
+    ///         }
+    ///         result => { result?; }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_elevation_check(mut self, enabled: bool) -> Self {
+        self.elevation_check = enabled;
+        self
+    }
+
+    /// Records the file and registry operations made while the entry point runs.
+    ///
+    /// An [`ActivityMonitor`] is started before the entry point is invoked and stopped once it
+    /// returns or fails, and its summary is available through [`RustClr::activity`]. The run
+    /// fails if the monitor cannot be started, e.g. when the process lacks the privileges to
+    /// enable the kernel ETW providers.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to record the operations.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&buffer)?.with_activity_monitor(true);
+    ///     clr.run()?;
+    ///
+    ///     if let Some(activity) = clr.activity() {
+    ///         print!("{activity}");
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "activity-monitor")]
+    pub fn with_activity_monitor(mut self, enabled: bool) -> Self {
+        self.activity_monitor = enabled;
+        self
+    }
+
+    /// Returns the file and registry operations recorded during the last run.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&ActivitySummary)` - If [`RustClr::with_activity_monitor`] is enabled and the
+    ///   entry point was invoked.
+    /// * `None` - Otherwise.
+    #[cfg(feature = "activity-monitor")]
+    pub fn activity(&self) -> Option<&ActivitySummary> {
+        self.activity.as_ref()
+    }
+
+    /// Replaces the assembly run by the instance, keeping the rest of the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A reference to a byte slice representing the .NET assembly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The modified `RustClr` instance.
+    /// * `Err(ClrError)` - If the buffer is not a valid .NET executable.
+    pub fn with_assembly(mut self, buffer: &'a [u8]) -> Result<Self, ClrError> {
+        self.image = PayloadImage::parse(buffer)?;
+        Ok(self)
+    }
+
+    /// Reports the first-chance exceptions raised by the assembly through a channel.
+    ///
+    /// Every exception thrown while the entry point runs, including the ones handled
+    /// by the assembly itself, is sent as a `FirstChanceException`.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The channel that receives the exception notifications.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::{fs, sync::mpsc::channel};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///     let (sender, receiver) = channel();
+    ///
+    ///     // Observe the exceptions raised by the assembly
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .with_exception_observer(sender);
+    ///
+    ///     clr.run()?;
+    ///     for exception in receiver.try_iter() {
+    ///         println!("{}: {}", exception.type_name, exception.message);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_exception_observer(mut self, sender: Sender<FirstChanceException>) -> Self {
+        self.exception_sender = Some(sender);
+        self
+    }
+
+    /// Sends progress events through a channel while the assembly runs.
+    ///
+    /// The events mark the start of the runtime, the load of the assembly, the call and
+    /// return of the entry point, the size of the captured output and the end of the run,
+    /// so callers can display progress without waiting for `run` to return.
+    ///
+    /// # Arguments
+    ///
+    /// * `sender` - The channel that receives each `ExecutionEvent`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::{fs, sync::mpsc::channel, thread};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///     let (sender, receiver) = channel();
+    ///
+    ///     let progress = thread::spawn(move || {
+    ///         for event in receiver {
+    ///             println!("[*] {event}");
+    ///         }
+    ///     });
+    ///
+    ///     RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_event_sender(sender)
+    ///         .run()?;
+    ///
+    ///     progress.join().unwrap();
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_event_sender(mut self, sender: Sender<ExecutionEvent>) -> Self {
+        self.event_sender = Some(sender);
+        self
+    }
+
+    /// Runs the assembly in a new application domain whose evidence places it in a security zone.
+    ///
+    /// The domain is created with `ICorRuntimeHost::CreateDomainEx`, using the name set with
+    /// [`RustClr::with_domain`] or the name of the zone. Code Access Security only enforces the
+    /// zone on legacy runtimes (.NET Framework 2.0/3.5), or on 4.0+ when legacy security policy
+    /// is enabled, which makes this useful to test how a payload behaves with restricted evidence.
+    ///
+    /// # Arguments
+    ///
+    /// * `zone` - The `Zone` of the application domain.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, RuntimeVersion, Zone};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let output = RustClr::new(&buffer)?
+    ///         .with_runtime_version(RuntimeVersion::V2)
+    ///         .with_output_redirection(true)
+    ///         .with_zone(Zone::Internet)
+    ///         .run()?;
+    ///
+    ///     println!("{output}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_zone(mut self, zone: Zone) -> Self {
+        self.zone = Some(zone);
+        self
+    }
+
+    /// Starts the runtime with a reduced set of features to minimize its footprint in the process.
+    ///
+    /// When enabled, the runtime is started with single-domain loader optimization, without
+    /// concurrent (background) garbage collection and without committing full thread stacks.
+    /// The UI culture of the domain is also forced to the invariant culture, so `ResourceManager`
+    /// does not probe the disk for culture-specific satellite assemblies.
+    ///
+    /// The startup flags only take effect if the runtime is not already started in the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `minimal` - A boolean indicating whether to minimize the runtime footprint.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Start the runtime with the minimal set of features
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_minimal_footprint(true);
+    ///
+    ///     println!("Minimal footprint enabled.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_minimal_footprint(mut self, minimal: bool) -> Self {
+        self.minimal_footprint = minimal;
+        self
+    }
+
+    /// Sets the startup flags used when the runtime is started.
+    ///
+    /// The flags are applied through `ICLRRuntimeInfo::SetDefaultStartupFlags` and are ignored
+    /// if the runtime is already started in the process. Use `StartupFlags::LOADER_SETPREFERENCE`
+    /// to make the selected runtime the one returned to components that later initialize the
+    /// CLR through the legacy activation APIs, avoiding version-binding conflicts.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The `StartupFlags` to apply.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, StartupFlags};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Make the runtime the preferred one for the rest of the process
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_startup_flags(StartupFlags::LOADER_SETPREFERENCE | StartupFlags::CONCURRENT_GC);
+    ///
+    ///     println!("Startup flags set successfully.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_startup_flags(mut self, flags: StartupFlags) -> Self {
+        self.startup_flags = Some(flags);
+        self
+    }
+
+    /// Forces `CultureInfo.InvariantCulture` as the default culture of the application domain.
+    ///
+    /// Numbers and dates formatted by the assembly then no longer depend on the locale
+    /// of the machine, which keeps the captured output stable when it is parsed by Rust.
+    /// The calling thread gets its own culture back once the run ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `invariant` - A boolean indicating whether to use the invariant culture.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Format numbers and dates independently of the machine locale
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_invariant_culture(true);
+    ///
+    ///     println!("Invariant culture enabled.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_invariant_culture(mut self, invariant: bool) -> Self {
+        self.invariant_culture = invariant;
+        self
+    }
+
+    /// Spills redirected output larger than `threshold` characters to an encrypted temporary file.
+    ///
+    /// The spilled output is returned by [`RustClr::run_captured`] as a reader, avoiding a
+    /// single large allocation for assemblies that write megabytes to the console.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The maximum number of characters kept in memory.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Keep at most 1M characters in memory
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_output_spill(1024 * 1024);
+    ///
+    ///     println!("Output spill enabled.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_output_spill(mut self, threshold: usize) -> Self {
+        self.spill_threshold = Some(threshold);
+        self
+    }
+
+    /// Pre-sizes the buffer receiving redirected output and reuses it across runs.
+    ///
+    /// The `StringWriter` is created once over a `StringBuilder` of `capacity` characters and
+    /// cleared before each later run of the same instance, instead of allocating a new buffer
+    /// that grows chunk by chunk on every run. This avoids churning the large object heap when
+    /// assemblies with large outputs are run repeatedly. The buffer is released with the
+    /// application domain, so [`RustClr::with_soft_unload`] recreates it on each run.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The initial capacity, in characters.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Start with room for 4M characters
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_output_capacity(4 * 1024 * 1024);
+    ///
+    ///     for _ in 0..3 {
+    ///         println!("{}", clr.run()?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_output_capacity(mut self, capacity: usize) -> Self {
+        self.output_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets how console control sequences in the redirected output are handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The `ControlSequences` mode, e.g. `ControlSequences::Strip` to remove ANSI escapes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, ControlSequences};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Remove colors and other escape sequences from the output
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_control_sequences(ControlSequences::Strip);
+    ///
+    ///     println!("Control sequences will be stripped.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_control_sequences(mut self, mode: ControlSequences) -> Self {
+        self.control_sequences = mode;
+        self
+    }
+
+    /// Registers a managed call to execute in the application domain before the entry point.
+    ///
+    /// Calls run in registration order, before output redirection starts.
+    ///
+    /// # Arguments
+    ///
+    /// * `call` - The `ManagedCall` to execute.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, ManagedCall};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Disable `Expect: 100-continue` before the payload runs
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_pre_run(
+    ///             ManagedCall::new("System", "System.Net.ServicePointManager", "set_Expect100Continue")
+    ///                 .arg(false)
+    ///         );
+    ///
+    ///     println!("Pre-run hook registered.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_pre_run(mut self, call: ManagedCall) -> Self {
+        self.pre_run.push(call);
+        self
+    }
+
+    /// Registers a managed call to execute in the application domain after the entry point returns.
+    ///
+    /// Calls run in registration order, after output redirection is restored, and only
+    /// if the entry point completed successfully.
+    ///
+    /// # Arguments
+    ///
+    /// * `call` - The `ManagedCall` to execute.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, ManagedCall};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Flush trace listeners once the payload returns
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_post_run(ManagedCall::new("System", "System.Diagnostics.Trace", "Flush"));
+    ///
+    ///     println!("Post-run hook registered.");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_post_run(mut self, call: ManagedCall) -> Self {
+        self.post_run.push(call);
+        self
+    }
+
+    /// Enables TLS 1.2 in `ServicePointManager.SecurityProtocol` before the entry point runs.
+    ///
+    /// Many payloads built for older .NET Framework versions fail to connect to modern
+    /// endpoints because TLS 1.2 is not enabled by default.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .enable_tls12();
+    ///
+    ///     clr.run()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn enable_tls12(mut self) -> Self {
+        self.tls12 = true;
+        self
+    }
+
+    /// Sets the minimum number of threads the thread pool keeps ready before the entry point runs.
+    ///
+    /// The thread pool is shared by every application domain of the process, so the limits
+    /// remain in effect after the run.
+    ///
+    /// # Arguments
+    ///
+    /// * `worker` - The minimum number of worker threads.
+    /// * `completion_port` - The minimum number of asynchronous I/O completion threads.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    pub fn with_min_threads(mut self, worker: i32, completion_port: i32) -> Self {
+        self.min_threads = Some((worker, completion_port));
+        self
+    }
+
+    /// Caps the number of threads the thread pool can create before the entry point runs.
+    ///
+    /// Limits the worker threads a payload can spin up through `ThreadPool`, `Task` or timers.
+    /// The runtime rejects a maximum below the number of processors, which fails the run. The
+    /// thread pool is shared by every application domain of the process, so the limits remain
+    /// in effect after the run.
+    ///
+    /// # Arguments
+    ///
+    /// * `worker` - The maximum number of worker threads.
+    /// * `completion_port` - The maximum number of asynchronous I/O completion threads.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .with_min_threads(2, 2)
+    ///         .with_max_threads(16, 8);
+    ///
+    ///     clr.run()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_max_threads(mut self, worker: i32, completion_port: i32) -> Self {
+        self.max_threads = Some((worker, completion_port));
+        self
+    }
+
+    /// Sets an `AppContext` switch in the application domain before the entry point runs.
+    ///
+    /// Behavioral switches (e.g. `Switch.System.Net.DontEnableSchUseStrongCrypto`) often decide
+    /// whether tools built for older .NET Framework versions work. Switches are applied before
+    /// TLS 1.2 is enabled and before the pre-run hooks, since most of them are read only once.
+    /// Requires .NET Framework 4.6 or later.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the switch.
+    /// * `enabled` - The value of the switch.
+    ///
+    /// # Returns
+    ///
+    /// * Returns the modified `RustClr` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .with_app_context_switch("Switch.System.Net.DontEnableSchUseStrongCrypto", false);
+    ///
+    ///     clr.run()?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_app_context_switch(mut self, name: &str, enabled: bool) -> Self {
+        self.app_context_switches.push((name.to_string(), enabled));
+        self
+    }
+
+    /// Prepares the CLR environment by initializing the runtime and application domain.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(Option<ThreadCultures>)` - If the environment is successfully prepared, with the
+    ///   cultures of the current thread to restore after the run, if the invariant culture was set.
+    /// * `Err(ClrError)` - If any error occurs during the preparation process.
+    fn prepare(&mut self) -> Result<Option<ThreadCultures>, ClrError> {
+        // Prevents overlapping runtime activations from other threads
+        let _init = lock_runtime_init();
+
+        // Picks the activation path supported by the installed shim, unless
+        // the runtime host of a previous soft-unloaded run can be reused
+        let cor_runtime_host = match (self.soft_unload, self.cor_runtime_host.clone()) {
+            (true, Some(cor_runtime_host)) => cor_runtime_host,
+            _ => match hosting_api() {
+                Some(HostingApi::MetaHost) => self.load_runtime()?,
+                Some(HostingApi::Legacy) => self.load_legacy_runtime()?,
+                None => return Err(ClrError::ErrorClr("mscoree.dll does not expose a supported hosting API")),
+            },
+        };
+
+        // Initializes the specified application domain or the default
+        self.init_app_domain(&cor_runtime_host)?;
+
+        // Saves the runtime host for future use
+        self.cor_runtime_host = Some(cor_runtime_host);
+
+        if !self.minimal_footprint && !self.invariant_culture {
+            return Ok(None);
+        }
+
+        // The cultures of the current thread are restored when the run ends
+        let mut cultures = ThreadCultures::new(&self.get_app_domain()?)?;
+
+        // Prevents satellite assembly probing for the UI culture
+        if self.minimal_footprint {
+            cultures.set_invariant(true)?;
+        }
+
+        // Makes numeric and date formatting locale-independent
+        if self.invariant_culture {
+            cultures.set_invariant(false)?;
+        }
+
+        Ok(Some(cultures))
+    }
+
+    /// Runs the .NET assembly by loading it into the application domain and invoking its entry point.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(String)` - The output from the .NET assembly if executed successfully.
+    /// * `Err(ClrError)` - If an error occurs during execution.
+    /// 
+    /// # Examples
+    /// 
+    /// ```ignore
+    /// use rustclr::{RustClr, RuntimeVersion};
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Create and configure a RustClr instance
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .with_runtime_version(RuntimeVersion::V4)
+    ///         .with_domain("CustomDomain")
+    ///         .with_args(vec!["arg1", "arg2"])
+    ///         .with_output_redirection(true);
+    ///
+    ///     // Run the .NET assembly and capture the output
+    ///     let output = clr.run()?;
+    ///     println!("Output: {}", output);
+    /// 
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn run(&mut self) -> Result<String, ClrError> {
+        self.run_captured()?.into_string()
+    }
+
+    /// Runs the .NET assembly in an application domain restricted to the given security zone.
+    ///
+    /// This is a shorthand for [`RustClr::with_zone`] followed by [`RustClr::run`].
+    ///
+    /// # Arguments
+    ///
+    /// * `zone` - The `Zone` of the application domain.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The output from the .NET assembly if executed successfully.
+    /// * `Err(ClrError)` - If the domain cannot be created or an error occurs during execution.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let output = RustClr::new(&buffer)?
+    ///     .with_runtime_version(RuntimeVersion::V2)
+    ///     .with_output_redirection(true)
+    ///     .run_with_zone(Zone::Internet)?;
+    /// ```
+    pub fn run_with_zone(&mut self, zone: Zone) -> Result<String, ClrError> {
+        self.zone = Some(zone);
+        self.run()
+    }
+
+    /// Runs the .NET assembly and returns its output as a `CapturedOutput`.
+    ///
+    /// Unlike [`RustClr::run`], output larger than the threshold set with
+    /// [`RustClr::with_output_spill`] is not loaded into a single `String`, but
+    /// returned as a reader over an encrypted temporary file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CapturedOutput)` - The output from the .NET assembly if executed successfully.
+    /// * `Err(ClrError)` - If an error occurs during execution.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClr, CapturedOutput};
+    /// use std::{fs, io::copy};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     // Spill outputs larger than 1M characters to disk
+    ///     let mut clr = RustClr::new(&buffer)?
+    ///         .with_output_redirection(true)
+    ///         .with_output_spill(1024 * 1024);
+    ///
+    ///     match clr.run_captured()? {
+    ///         CapturedOutput::Memory(output) => println!("{output}"),
+    ///         CapturedOutput::Spilled(mut file) => {
+    ///             copy(&mut file, &mut fs::File::create("output.txt")?)?;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn run_captured(&mut self) -> Result<CapturedOutput, ClrError> {
+        match self.run_output(None)? {
+            RunOutput::Text(output) => Ok(output),
+            RunOutput::Bytes(output) => Ok(CapturedOutput::Memory(output.into_string())),
+        }
+    }
+
+    /// Runs the .NET assembly and returns its output as bytes, with their encoding.
+    ///
+    /// The output is captured with [`ClrOutput::capture_bytes`], so output that is not valid
+    /// UTF-16 (e.g. binary data written to the console) is returned as raw UTF-16LE instead of
+    /// being converted lossily. The output is empty unless output redirection is enabled.
+    /// Output spilling does not apply, so it cannot be combined with [`RustClr::with_output_spill`].
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The preferred `OutputEncoding`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CapturedBytes)` - The output from the .NET assembly and its encoding.
+    /// * `Err(ClrError)` - If a spill threshold is set or an error occurs during execution.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let captured = RustClr::new(&buffer)?
+    ///     .with_output_redirection(true)
+    ///     .run_bytes(OutputEncoding::Utf8)?;
+    ///
+    /// socket.write_all(&[captured.encoding as u8])?;
+    /// socket.write_all(&captured.bytes)?;
+    /// ```
+    pub fn run_bytes(&mut self, encoding: OutputEncoding) -> Result<CapturedBytes, ClrError> {
+        if self.spill_threshold.is_some() {
+            return Err(ClrError::ErrorClr("Output spilling cannot be combined with a byte capture"));
+        }
+
+        match self.run_output(Some(encoding))? {
+            RunOutput::Bytes(output) => Ok(output),
+            RunOutput::Text(_) => unreachable!("an encoding always captures bytes"),
+        }
+    }
+
+    /// Runs the .NET assembly within the execution limits, releasing its domain afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the output, if it is captured as bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RunOutput)` - The output from the .NET assembly if executed successfully.
+    /// * `Err(ClrError)` - If an error occurs during execution.
+    fn run_output(&mut self, encoding: Option<OutputEncoding>) -> Result<RunOutput, ClrError> {
+        // Waits for the execution limits of the process to allow a new run
+        let _permit = ExecutionPermit::acquire();
+
+        let mut result = self.execute(encoding);
+
+        // Releases the domain even if the run failed
+        if self.soft_unload {
+            let unloaded = self.unload_domain();
+            result = result.and_then(|output| unloaded.map(|_| output));
+        }
+
+        let result = result.map_err(|error| self.describe(error));
+
+        match &result {
+            Ok(_) => self.emit(ExecutionEvent::Completed),
+            Err(error) => self.emit(ExecutionEvent::Failed { error: error.to_string() }),
+        }
+
+        result
+    }
+
+    /// Starts the .NET assembly as a service, running its entry point on a background thread.
+    ///
+    /// The call returns as soon as the entry point is invoked, with a [`ServiceHandle`] to read
+    /// the output written so far and to stop the payload. It is meant for payloads that block
+    /// forever (listeners, implants), which [`RustClr::run`] would wait for indefinitely.
+    ///
+    /// The service counts towards the [`ExecutionLimits`](crate::ExecutionLimits) until it is
+    /// stopped. The post-run hooks, the exception observer, trace capture and output spilling
+    /// are not applied to services.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ServiceHandle)` - The handle of the running service.
+    /// * `Err(ClrError)` - If the runtime cannot be started or the assembly cannot be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::{fs, thread, time::Duration};
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/listener.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&buffer)?.with_output_redirection(true);
+    ///     let service = clr.run_service()?;
+    ///
+    ///     thread::sleep(Duration::from_secs(10));
+    ///     println!("{}", service.output_so_far()?);
+    ///
+    ///     let output = service.stop()?;
+    ///     println!("{output}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn run_service(&mut self) -> Result<ServiceHandle, ClrError> {
+        let permit = ExecutionPermit::acquire();
+        let console = ConsoleGuard::acquire(self.console)?;
+        let (domain, assembly, parameters, cultures) = self.load_payload().map_err(|error| self.describe(error))?;
+
+        // The entry point runs on the thread of the service, not on this one
+        drop(cultures);
+
+        let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+        let output = if self.redirect_output {
+            Some(ServiceOutput::redirect(&mscorlib, self.control_sequences)?)
+        } else {
+            None
+        };
+
+        self.emit(ExecutionEvent::EntryInvoked);
+        ServiceHandle::start(&mscorlib, assembly, parameters, output, console, permit)
+    }
+
+    /// Attaches the message of the loaded runtime to an `ApiError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error reported by the run.
+    ///
+    /// # Returns
+    ///
+    /// * The error, as a `DescribedApiError` if the runtime has a message for its HRESULT.
+    fn describe(&self, error: ClrError) -> ClrError {
+        match &self.runtime_info {
+            Some(runtime_info) => error.describe(runtime_info),
+            None => error,
+        }
+    }
+
+    /// Sends a progress event, if an event channel was set.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The event to send. It is discarded if the receiver was dropped.
+    fn emit(&self, event: ExecutionEvent) {
+        if let Some(sender) = &self.event_sender {
+            let _ = sender.send(event);
+        }
+    }
+
+    /// Starts the runtime, loads the assembly and applies the settings of the builder
+    /// that must precede the entry point.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((_AppDomain, _Assembly, Option<SafeArray>, Option<ThreadCultures>))` - The domain, the
+    ///   loaded assembly, the arguments of `Main`, if any, and the cultures of the current thread
+    ///   to restore after the run.
+    /// * `Err(ClrError)` - If the assembly requires an elevation the process lacks, the runtime
+    ///   cannot be started, the assembly cannot be loaded or a setting cannot be applied.
+    fn load_payload(&mut self) -> Result<(_AppDomain, _Assembly, Option<SafeArray>, Option<ThreadCultures>), ClrError> {
+        // Refuses payloads that would run without the privileges they require
+        if self.elevation_check
+            && self.image.execution_level() == Some(ExecutionLevel::RequireAdministrator)
+            && !is_elevated()
+        {
+            return Err(ClrError::RequiresElevation);
+        }
+
+        // Prepare the CLR environment
+        let cultures = self.prepare()?;
+        self.emit(ExecutionEvent::RuntimeStarted);
+
+        // Gets the current application domain
+        let domain = self.get_app_domain()?;
+
+        // Loads the .NET assembly specified by the buffer
+        let assembly = domain.load_assembly(self.image.bytes())?;
+        if self.event_sender.is_some() {
+            self.emit(ExecutionEvent::AssemblyLoaded { name: assembly.get_FullName()? });
+        }
+
+        // Loads the packed dependencies before any of their types is bound
+        if self.embedded_dependencies {
+            for name in register_embedded_dependencies(&domain, &assembly)? {
+                self.emit(ExecutionEvent::DependencyLoaded { name });
+            }
+        }
+
+        // Prepares the parameters for the `Main` method
+        let parameters = self.args.as_deref().map(SafeArray::from_array_arg).transpose()?;
+
+        // Sets the AppContext switches before any code reads them
+        for (name, enabled) in &self.app_context_switches {
+            set_app_context_switch(&domain, name, *enabled)?;
+        }
+
+        // Enables TLS 1.2 for network payloads
+        if self.tls12 {
+            enable_tls12(&domain)?;
+        }
+
+        // Clamps the threads the payload can get from the thread pool
+        if self.min_threads.is_some() || self.max_threads.is_some() {
+            set_thread_pool_limits(&domain, self.min_threads, self.max_threads)?;
+        }
+
+        // Executes the pre-run hooks
+        for call in &self.pre_run {
+            call.invoke(&domain)?;
+        }
+
+        Ok((domain, assembly, parameters, cultures))
+    }
+
+    /// Loads the assembly, runs its entry point and captures the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The encoding of the output, if it is captured as bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RunOutput)` - The output from the .NET assembly if executed successfully.
+    /// * `Err(ClrError)` - If an error occurs during execution.
+    fn execute(&mut self, encoding: Option<OutputEncoding>) -> Result<RunOutput, ClrError> {
+        // Provides a console before `System.Console` caches its handles
+        let _console = ConsoleGuard::acquire(self.console)?;
+
+        // Starts the runtime and loads the assembly with its dependencies,
+        // restoring the cultures of the thread once the run ends
+        let (domain, assembly, parameters, _cultures) = self.load_payload()?;
+
+        // Subscribes to first-chance exceptions if an observer was requested
+        let mut observer = self.exception_sender.clone().map(|sender| ExceptionObserver::new(&domain, sender));
+        if let Some(observer) = observer.as_mut() {
+            observer.attach()?;
+        }
+
+        // Redirects output if enabled
+        let output = if self.redirect_output {
+            // Loads the mscorlib library for output redirection
+            let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+            let system = if self.trace_capture { Some(domain.load_lib(&ManagedName::System.value())?) } else { None };
+            let mut output_manager = ClrOutput::new(&mscorlib)
+                .with_control_sequences(self.control_sequences);
+
+            if let Some(system) = &system {
+                output_manager = output_manager.with_trace_capture(system);
+            }
+
+            // Reuses the buffer of the previous run when a capacity is set
+            if let Some(capacity) = self.output_capacity {
+                output_manager = output_manager.with_capacity(capacity);
+                if let Some(writer) = &self.output_writer {
+                    output_manager = output_manager.with_writer(writer.variant());
+                }
+            }
+
+            // Redirecting output
+            output_manager.redirect()?;
+
+            // Restores the output on every path, before reporting a failed run or capture
+            let result = self.invoke_redirected(&mscorlib, &output_manager, &assembly, parameters.as_ref(), encoding);
+            let restored = output_manager.restore();
+            let result = result?;
+            restored?;
+
+            let bytes = match &result {
+                RunOutput::Text(CapturedOutput::Memory(output)) => output.len() as u64,
+                RunOutput::Text(CapturedOutput::Spilled(file)) => file.len(),
+                RunOutput::Bytes(output) => output.bytes.len() as u64,
+            };
+
+            self.emit(ExecutionEvent::OutputCaptured { bytes });
+            result
+        } else {
+            // Invokes the `Main` method of the assembly
+            self.invoke_entry(&assembly, parameters.as_ref())?;
+
+            // Empty output
+            match encoding {
+                Some(encoding) => RunOutput::Bytes(CapturedBytes { bytes: Vec::new(), encoding }),
+                None => RunOutput::Text(CapturedOutput::Memory(String::new())),
+            }
+        };
+
+        // Removes the exception subscription, keeping the output already captured
+        if let Some(mut observer) = observer {
+            if let Err(error) = observer.detach() {
+                self.emit(ExecutionEvent::Warning { error: error.to_string() });
+            }
+        }
+
+        // Executes the post-run hooks
+        for call in &self.post_run {
+            call.invoke(&domain)?;
+        }
+
+        Ok(output)
+    }
+
+    /// Invokes the entry point of the assembly while its output is redirected, then captures it.
+    ///
+    /// The caller restores the output streams, whatever the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `mscorlib` - The `mscorlib` assembly of the domain.
+    /// * `output_manager` - The redirected output streams.
+    /// * `assembly` - The loaded `_Assembly`.
+    /// * `parameters` - The arguments passed to `Main`, if any.
+    /// * `encoding` - The encoding of the output, if it is captured as bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RunOutput)` - The output written by the entry point.
+    /// * `Err(ClrError)` - If the entry point fails or the output cannot be captured.
+    fn invoke_redirected(
+        &mut self,
+        mscorlib: &_Assembly,
+        output_manager: &ClrOutput,
+        assembly: &_Assembly,
+        parameters: Option<&SafeArray>,
+        encoding: Option<OutputEncoding>
+    ) -> Result<RunOutput, ClrError> {
+        // Keeps ownership of a newly created buffer for the next runs
+        if self.output_capacity.is_some() && self.output_writer.is_none() {
+            if let Some(writer) = output_manager.writer() {
+                let ty = mscorlib.resolve_type(&ManagedName::StringWriter.value())?;
+                self.output_writer = Some(Instance::new(writer, ty));
+            }
+        }
+
+        // Invokes the `Main` method of the assembly
+        self.invoke_entry(assembly, parameters)?;
+
+        // Captures the output as bytes, or as text spilled to disk past the threshold
+        match (encoding, self.spill_threshold) {
+            (Some(encoding), _) => output_manager.capture_bytes(encoding).map(RunOutput::Bytes),
+            (None, Some(threshold)) => output_manager.capture_spilled(threshold).map(RunOutput::Text),
+            (None, None) => output_manager.capture().map(|output| RunOutput::Text(CapturedOutput::Memory(output))),
+        }
+    }
+
+    /// Invokes the entry point of the assembly, writing the crash dump if it fails fatally.
+    ///
+    /// # Arguments
+    ///
+    /// * `assembly` - The loaded `_Assembly`.
+    /// * `parameters` - The arguments passed to `Main`, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the entry point returned.
+    /// * `Err(ClrError)` - If the entry point could not be invoked or threw.
+    fn invoke_entry(&mut self, assembly: &_Assembly, parameters: Option<&SafeArray>) -> Result<(), ClrError> {
+        let _guard = self.crash_dump.as_ref().map(DumpGuard::arm);
+
+        #[cfg(feature = "activity-monitor")]
+        let monitor = {
+            self.activity = None;
+            if self.activity_monitor { Some(ActivityMonitor::start()?) } else { None }
+        };
+
+        self.emit(ExecutionEvent::EntryInvoked);
+        let result = assembly.run(parameters.map_or(null_mut(), SafeArray::as_ptr));
+
+        // Keeps the operations of failed runs as well
+        #[cfg(feature = "activity-monitor")]
+        if let Some(monitor) = monitor {
+            self.activity = Some(monitor.stop()?);
+        }
+
+        if let Err(error) = result {
+            if let Some(dump) = &self.crash_dump {
+                dump.write_if_fatal(&error);
+            }
+
+            return Err(error);
+        }
+
+        self.emit(ExecutionEvent::EntryReturned);
+        Ok(())
+    }
+
+    /// Collects garbage collector statistics for the runtime hosting the assembly.
+    ///
+    /// The runtime must have been prepared by a previous call to [`RustClr::run`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GcStats)` - The managed memory and collection statistics.
+    /// * `Err(ClrError)` - If no application domain is available or the statistics could not be read.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let mut clr = RustClr::new(&buffer)?;
+    ///     clr.run()?;
+    ///
+    ///     let stats = clr.gc_stats()?;
+    ///     println!("Managed heap: {} bytes", stats.total_memory);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn gc_stats(&self) -> Result<GcStats, ClrError> {
+        let domain = self.app_domain.as_ref().ok_or(ClrError::NoDomainAvailable)?;
+        GcStats::collect(domain)
+    }
+
+    /// Reads the CLI metadata of the assembly without starting a runtime.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AssemblyMetadata)` - The entry point, types and target framework of the assembly.
+    /// * `Err(ClrError)` - If the metadata of the assembly is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let metadata = RustClr::new(&buffer)?.inspect()?;
+    ///     println!("Target framework: {:?}", metadata.target_framework);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn inspect(&self) -> Result<AssemblyMetadata, ClrError> {
+        self.image.metadata().cloned()
+    }
+
+    /// Lists the artifacts that running the current configuration leaves in the process.
+    ///
+    /// Nothing is executed: the report is derived from the builder settings and the
+    /// metadata of the assembly. Module names assume the default activation path of the
+    /// selected runtime version.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(DetectionReport)` - The expected modules, domain, assemblies, managed calls, files and ETW events.
+    /// * `Err(ClrError)` - If the metadata of the assembly is malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClr;
+    /// use std::fs;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let buffer = fs::read("examples/sample.exe")?;
+    ///
+    ///     let clr = RustClr::new(&buffer)?
+    ///         .with_domain("CustomDomain")
+    ///         .with_output_redirection(true);
+    ///
+    ///     print!("{}", clr.detection_report()?);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn detection_report(&self) -> Result<DetectionReport, ClrError> {
+        let metadata = self.image.metadata()?;
+        let payload = metadata.assembly_name.clone().unwrap_or_else(|| String::from("<unnamed>"));
+        let mut report = DetectionReport::default();
+
+        // Modules mapped by the shim and the runtime
+        report.push(ArtifactKind::Module, "mscoree.dll (shim)");
+        match self.runtime_version.unwrap_or(RuntimeVersion::V4) {
+            RuntimeVersion::V2 | RuntimeVersion::V3 => {
+                report.push(ArtifactKind::Module, "mscorwks.dll (runtime)");
+                report.push(ArtifactKind::Module, "mscorjit.dll (JIT compiler)");
+            }
+            _ => {
+                report.push(ArtifactKind::Module, "mscoreei.dll (shim implementation)");
+                report.push(ArtifactKind::Module, "clr.dll (runtime)");
+                report.push(ArtifactKind::Module, "clrjit.dll (JIT compiler)");
+                report.push(ArtifactKind::Module, "amsi.dll (scan of the in-memory assembly, .NET Framework 4.8+)");
+            }
+        }
+
+        report.push(ArtifactKind::Module, "mscorlib.ni.dll (native image of mscorlib)");
+        match self.console {
+            ConsoleMode::Hidden => report.push(ArtifactKind::Process, "conhost.exe hosting a hidden console window, if the host has no console"),
+            ConsoleMode::Attach => report.push(ArtifactKind::Process, "attachment to the console of the parent process, if the host has no console"),
+            ConsoleMode::None => {}
+        }
+
+        // Application domain hosting the payload
+        match (&self.domain_name, self.zone) {
+            (name, Some(zone)) => report.push(ArtifactKind::AppDomain, format!(
+                "'{}' created through ICorRuntimeHost::CreateDomainEx with {zone:?} zone evidence",
+                name.clone().unwrap_or_else(|| format!("{zone:?}"))
+            )),
+            (Some(name), None) => report.push(ArtifactKind::AppDomain, format!("'{name}' created through ICorRuntimeHost::CreateDomain")),
+            (None, None) => report.push(ArtifactKind::AppDomain, "DefaultDomain of the process"),
+        }
+
+        // Assemblies loaded into the domain
+        report.push(ArtifactKind::Assembly, format!("{payload} loaded from a byte array (no file location)"));
+        let mut libraries = self.pre_run.iter().chain(&self.post_run).map(|call| call.assembly().to_string()).collect::<Vec<_>>();
+        if self.tls12 || (self.redirect_output && self.trace_capture) {
+            libraries.push(ManagedName::System.value());
+        }
+
+        libraries.sort_unstable();
+        libraries.dedup();
+        let mscorlib = ManagedName::Mscorlib.value();
+        for library in libraries.into_iter().filter(|library| *library != mscorlib) {
+            report.push(ArtifactKind::Assembly, format!("{library} loaded by name from the GAC"));
+            report.push(ArtifactKind::Module, format!("{library}.ni.dll (native image of {library})"));
+        }
+
+        // Managed calls made around the entry point, in execution order
+        let call = |library: ManagedName, ty: ManagedName, members: &[ManagedName]| {
+            let members = members.iter().map(|member| member.value()).collect::<Vec<_>>();
+            format!("{}.{} ({})", ty.value(), members.join("/"), library.value())
+        };
+
+        if self.minimal_footprint {
+            report.push(ArtifactKind::ManagedCall, call(ManagedName::Mscorlib, ManagedName::Thread, &[ManagedName::SetCurrentUICulture]));
+        }
+
+        if self.invariant_culture {
+            report.push(ArtifactKind::ManagedCall, call(ManagedName::Mscorlib, ManagedName::Thread, &[ManagedName::SetCurrentCulture]));
+        }
+
+        for (name, enabled) in &self.app_context_switches {
+            let set_switch = call(ManagedName::Mscorlib, ManagedName::AppContext, &[ManagedName::SetSwitch]);
+            report.push(ArtifactKind::ManagedCall, format!("{set_switch}: {name} = {enabled}"));
+        }
+
+        if self.tls12 {
+            report.push(ArtifactKind::ManagedCall, call(ManagedName::System, ManagedName::ServicePointManager, &[ManagedName::SetSecurityProtocol]));
+        }
+
+        if self.max_threads.is_some() {
+            report.push(ArtifactKind::ManagedCall, call(ManagedName::Mscorlib, ManagedName::ThreadPool, &[ManagedName::SetMaxThreads]));
+        }
+
+        if self.min_threads.is_some() {
+            report.push(ArtifactKind::ManagedCall, call(ManagedName::Mscorlib, ManagedName::ThreadPool, &[ManagedName::SetMinThreads]));
+        }
+
+        for call in &self.pre_run {
+            report.push(ArtifactKind::ManagedCall, call.to_string());
+        }
+
+        if self.exception_sender.is_some() {
+            report.push(ArtifactKind::ManagedCall, format!(
+                "{} with a delegate bound to a COM object",
+                call(ManagedName::Mscorlib, ManagedName::AppDomain, &[ManagedName::AddFirstChanceException])
+            ));
+        }
+
+        if self.redirect_output {
+            report.push(ArtifactKind::ManagedCall, format!(
+                "{} with a {}",
+                call(ManagedName::Mscorlib, ManagedName::Console, &[ManagedName::SetOut, ManagedName::SetError]),
+                ManagedName::StringWriter.value()
+            ));
+
+            if self.trace_capture {
+                report.push(ArtifactKind::ManagedCall, format!(
+                    "{} with a {}",
+                    call(ManagedName::System, ManagedName::Trace, &[ManagedName::GetListeners, ManagedName::Add]),
+                    ManagedName::TextWriterTraceListener.value()
+                ));
+            }
+        }
+
+        report.push(ArtifactKind::ManagedCall, format!(
+            "entry point of {payload} through {}.{}",
+            ManagedName::MethodInfo.value(),
+            ManagedName::Invoke.value()
+        ));
+        for call in &self.post_run {
+            report.push(ArtifactKind::ManagedCall, call.to_string());
+        }
+
+        // Executable memory and files
+        report.push(ArtifactKind::Memory, format!("JIT code heap pages holding the compiled methods of {payload}"));
+        if let Some(threshold) = self.spill_threshold {
+            report.push(ArtifactKind::File, format!(
+                "encrypted *.tmp file in {} if the output exceeds {threshold} characters",
+                std::env::temp_dir().display()
+            ));
+        }
+
+        // Events of the Microsoft-Windows-DotNETRuntime provider
+        if let Some(name) = &self.domain_name {
+            report.push(ArtifactKind::Etw, format!("Loader/AppDomainLoad for '{name}'"));
+        }
+
+        report.push(ArtifactKind::Etw, format!("Loader/AssemblyLoad for {payload} with no file path"));
+        report.push(ArtifactKind::Etw, format!("Loader/ModuleLoad for {payload} with no file path"));
+        report.push(ArtifactKind::Etw, format!("JIT/MethodLoadVerbose for each method of {payload}"));
+
+        #[cfg(feature = "activity-monitor")]
+        if self.activity_monitor {
+            report.push(ArtifactKind::Etw, "real-time session 'rustclr-activity-<pid>-<n>' enabling Microsoft-Windows-Kernel-File and Microsoft-Windows-Kernel-Registry");
+        }
+
+        Ok(report)
+    }
+
+    /// Computes the startup flags requested through the builder.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(StartupFlags)` - If custom flags or a minimal footprint were requested.
+    /// * `None` - If the runtime defaults should be used.
+    fn startup_flags(&self) -> Option<StartupFlags> {
+        match (self.startup_flags, self.minimal_footprint) {
+            (Some(flags), true) => Some(flags.minimal()),
+            (Some(flags), false) => Some(flags),
+            (None, true) => Some(StartupFlags::MINIMAL),
+            (None, false) => None,
+        }
+    }
+
+    /// Loads and starts the runtime through `ICLRMetaHost` and `ICLRRuntimeInfo` (.NET Framework 4.0+ shim).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ICorRuntimeHost)` - The started runtime host.
+    /// * `Err(ClrError)` - If any step of the runtime initialization fails.
+    fn load_runtime(&mut self) -> Result<ICorRuntimeHost, ClrError> {
+        // Creates the MetaHost to access the available CLR versions
+        let meta_host = self.create_meta_host()?;
+
+        // Gets information about the specified (or default) runtime version
+        let runtime_info = self.get_runtime_info(&meta_host)?;
+        self.runtime_info = Some(runtime_info.clone());
+
+        // Applies the startup flags before the runtime is loaded
+        if let Some(flags) = self.startup_flags() {
+            if !runtime_info.is_started() {
+                runtime_info.SetDefaultStartupFlags(flags.0, PCWSTR::null())?;
+            }
+        }
+
+        // Creates the runtime host
+        let cor_runtime_host = self.get_runtime_host(&runtime_info)?;
+
+        // Checks if the runtime is started
+        if runtime_info.IsLoadable().is_ok() && !runtime_info.is_started() {
+            // Starts the CLR runtime
+            self.start_runtime(&cor_runtime_host)?;
+        }
+
+        Ok(cor_runtime_host)
+    }
+
+    /// Loads and starts the runtime through the legacy `CorBindToRuntimeEx` API.
+    ///
+    /// Used on machines without the .NET Framework 4.0+ shim, where only the 2.0 runtime
+    /// is available, so the version defaults to `RuntimeVersion::V2`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ICorRuntimeHost)` - The started runtime host.
+    /// * `Err(ClrError)` - If the runtime cannot be bound or started.
+    fn load_legacy_runtime(&self) -> Result<ICorRuntimeHost, ClrError> {
+        let version = self.runtime_version.unwrap_or(RuntimeVersion::V2).to_vec();
+        let flags = self.startup_flags().unwrap_or_default();
+        let cor_runtime_host = CorBindToRuntimeEx::<ICorRuntimeHost>(PCWSTR(version.as_ptr()), flags.0, &CLSID_COR_RUNTIME_HOST)
+            .map_err(|e| ClrError::RuntimeHostError(format!("{e}")))?;
+
+        // `Start` returns S_FALSE if the runtime was already started
+        if !matches!(cor_runtime_host.Start(), 0 | 1) {
+            return Err(ClrError::RuntimeStartError);
+        }
+
+        Ok(cor_runtime_host)
+    }
+
+    /// Unloads the application domain created for the run, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the domain was unloaded or no domain was created.
+    /// * `Err(ClrError)` - If the runtime refused to unload the domain.
+    fn unload_domain(&mut self) -> Result<(), ClrError> {
+        // The reused output buffer belongs to the domain
+        self.output_writer = None;
+
+        match (self.app_domain.take(), &self.cor_runtime_host) {
+            (Some(domain), Some(cor_runtime_host)) => cor_runtime_host.UnloadDomain(domain.as_raw() as *mut IUnknown),
+            _ => Ok(()),
+        }
+    }
+
+    /// Retrieves the current application domain.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(_AppDomain)` - If the application domain is available.
+    /// * `Err(ClrError)` - If no application domain is available.
+    fn get_app_domain(&mut self) -> Result<_AppDomain, ClrError> {
+        self.app_domain.clone().ok_or(ClrError::NoDomainAvailable)
+    }
+
+    /// Creates an instance of `ICLRMetaHost`.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(ICLRMetaHost)` - If the instance is created successfully.
+    /// * `Err(ClrError)` - If the instance creation fails.
+    fn create_meta_host(&self) -> Result<ICLRMetaHost, ClrError> {
+        CLRCreateInstance::<ICLRMetaHost>(&CLSID_CLRMETAHOST)
+            .map_err(|e| ClrError::MetaHostCreationError(format!("{e}")))
+    }
+
+    /// Retrieves runtime information based on the selected .NET version.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `meta_host` - Reference to the `ICLRMetaHost` instance.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(ICLRRuntimeInfo)` - If runtime information is retrieved successfully.
+    /// * `Err(ClrError)` - If the retrieval fails.
+    fn get_runtime_info(&self, meta_host: &ICLRMetaHost) -> Result<ICLRRuntimeInfo, ClrError> {
+        let runtime_version = self.runtime_version.unwrap_or(RuntimeVersion::V4);
+        let version_wide = runtime_version.to_vec();
+        let version = PCWSTR(version_wide.as_ptr());
+
+        meta_host.GetRuntime::<ICLRRuntimeInfo>(version)
+            .map_err(|e| ClrError::RuntimeInfoError(format!("{e}")))
+    }
+
+    /// Gets the runtime host interface from the provided runtime information.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `runtime_info` - Reference to the `ICLRRuntimeInfo` instance.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(ICorRuntimeHost)` - If the interface is obtained successfully.
+    /// * `Err(ClrError)` - If the retrieval fails.
+    fn get_runtime_host(&self, runtime_info: &ICLRRuntimeInfo) -> Result<ICorRuntimeHost, ClrError> {
+        runtime_info.GetInterface::<ICorRuntimeHost>(&CLSID_COR_RUNTIME_HOST)
+            .map_err(|e| ClrError::RuntimeHostError(format!("{e}")))
+    }
+
+    /// Starts the CLR runtime using the provided runtime host.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `cor_runtime_host` - Reference to the `ICorRuntimeHost` instance.
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(())` - If the runtime starts successfully.
+    /// * `Err(ClrError)` - If the runtime fails to start.
+    fn start_runtime(&self, cor_runtime_host: &ICorRuntimeHost) -> Result<(), ClrError> {
+
+        if cor_runtime_host.Start() != 0 {
+            return Err(ClrError::RuntimeStartError);
         }
 
         Ok(())
@@ -429,18 +2196,181 @@ impl<'a> RustClr<'a> {
     /// * `Err(ClrError)` - If the initialization fails.
     fn init_app_domain(&mut self, cor_runtime_host: &ICorRuntimeHost) -> Result<(), ClrError> {
         // Creates the application domain based on the specified name or uses the default domain
-        let app_domain = if let Some(domain_name) = &self.domain_name {
+        let app_domain = if let Some(zone) = self.zone {
+            let domain_name = self.domain_name.clone().unwrap_or_else(|| format!("{zone:?}"));
+            cor_runtime_host.create_domain_with_zone(&domain_name, zone)?
+        } else if let Some(domain_name) = self.domain_name.as_deref().or(self.soft_unload.then_some(SOFT_UNLOAD_DOMAIN)) {
+            // The default domain cannot be unloaded, so soft unloading always needs a domain
             let wide_domain_name = domain_name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
             cor_runtime_host.CreateDomain(PCWSTR(wide_domain_name.as_ptr()), null_mut())?
         } else {
             cor_runtime_host.GetDefaultDomain()?
         };
 
-        // Saves the created application domain
-        self.app_domain = Some(app_domain);
+        // Saves the created application domain
+        self.app_domain = Some(app_domain);
+
+        Ok(())
+    }
+}
+
+/// Cultures of the current thread replaced by the invariant culture for a run.
+///
+/// The cultures the thread had before are restored when dropped, so the host thread
+/// keeps its own cultures once the run ends.
+struct ThreadCultures {
+    /// The `System.Threading.Thread` type.
+    thread: _Type,
+
+    /// The `System.Globalization.CultureInfo` type.
+    culture_info: _Type,
+
+    /// The `Thread` object of the current thread.
+    current_thread: OwnedVariant,
+
+    /// The setters of the replaced cultures, with the culture each one held.
+    previous: Vec<(ManagedName, OwnedVariant)>,
+}
+
+impl ThreadCultures {
+    /// Resolves the current thread in the given application domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` in which the run happens.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ThreadCultures)` - The cultures of the current thread, none replaced yet.
+    /// * `Err(ClrError)` - If `Thread.CurrentThread` cannot be read.
+    fn new(domain: &_AppDomain) -> Result<Self, ClrError> {
+        let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+        let thread = mscorlib.resolve_type(&ManagedName::Thread.value())?;
+        let current_thread = thread.invoke(&ManagedName::GetCurrentThread.value(), None, None, InvocationType::Static)?;
+
+        Ok(Self {
+            culture_info: mscorlib.resolve_type(&ManagedName::CultureInfo.value())?,
+            current_thread: OwnedVariant(current_thread),
+            thread,
+            previous: Vec::new(),
+        })
+    }
+
+    /// Sets the invariant culture as a culture of the current thread and of new threads in the domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `ui` - Sets `CurrentUICulture` instead of `CurrentCulture`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the culture of the current thread was set.
+    /// * `Err(ClrError)` - If an error occurs while invoking the culture setters.
+    fn set_invariant(&mut self, ui: bool) -> Result<(), ClrError> {
+        let (getter, setter, default_setter) = if ui {
+            (ManagedName::GetCurrentUICulture, ManagedName::SetCurrentUICulture, ManagedName::SetDefaultThreadCurrentUICulture)
+        } else {
+            (ManagedName::GetCurrentCulture, ManagedName::SetCurrentCulture, ManagedName::SetDefaultThreadCurrentCulture)
+        };
+
+        let invariant = OwnedVariant(self.culture_info.invoke(&ManagedName::GetInvariantCulture.value(), None, None, InvocationType::Static)?);
+        let current = self.thread.invoke(&getter.value(), Some(self.current_thread.0), None, InvocationType::Instance)?;
+        self.previous.push((setter, OwnedVariant(current)));
+        self.thread.invoke(&setter.value(), Some(self.current_thread.0), Some(vec![invariant.0]), InvocationType::Instance)?;
+
+        // `DefaultThreadCurrent*` is only available starting with .NET Framework 4.5
+        match self.culture_info.invoke(&default_setter.value(), None, Some(vec![invariant.0]), InvocationType::Static) {
+            Ok(_) | Err(ClrError::ApiError(_, COR_E_MISSINGMETHOD)) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+/// Implements the `Drop` trait to restore the cultures of the current thread.
+impl Drop for ThreadCultures {
+    fn drop(&mut self) {
+        for (setter, culture) in self.previous.iter().rev() {
+            let _ = self.thread.invoke(&setter.value(), Some(self.current_thread.0), Some(vec![culture.0]), InvocationType::Instance);
+        }
+    }
+}
+
+/// Checks whether the token of the current process is elevated.
+///
+/// # Returns
+///
+/// * `true` - If the process runs with an elevated token, or its token cannot be queried.
+/// * `false` - If the process runs with a limited token.
+fn is_elevated() -> bool {
+    unsafe {
+        let mut token = null_mut();
+        if OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) == 0 {
+            return true;
+        }
+
+        let mut elevation = TOKEN_ELEVATION { TokenIsElevated: 0 };
+        let mut size = 0;
+        let queried = GetTokenInformation(
+            token,
+            TokenElevation,
+            &mut elevation as *mut _ as *mut _,
+            size_of::<TOKEN_ELEVATION>() as u32,
+            &mut size
+        );
+
+        CloseHandle(token);
+        queried == 0 || elevation.TokenIsElevated != 0
+    }
+}
 
-        Ok(())
+/// Lists the CLR versions loaded into another process.
+///
+/// Requires the .NET Framework 4.0+ shim, since `EnumerateLoadedRuntimes` is only exposed
+/// by `ICLRMetaHost`. A process of a different bitness (e.g. a 32-bit target inspected from
+/// a 64-bit host) cannot be inspected and returns an error.
+///
+/// # Arguments
+///
+/// * `pid` - The identifier of the process to inspect.
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The sorted version strings of the loaded runtimes (e.g. `v4.0.30319`),
+///   empty if the process does not host the CLR.
+/// * `Err(ClrError)` - If the process cannot be opened or inspected.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::probe_process;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     for version in probe_process(1234)? {
+///         println!("Loaded runtime: {version}");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub fn probe_process(pid: u32) -> Result<Vec<String>, ClrError> {
+    if hosting_api() != Some(HostingApi::MetaHost) {
+        return Err(ClrError::ErrorClr("Probing a process requires the .NET Framework 4.0+ shim"));
+    }
+
+    let meta_host = CLRCreateInstance::<ICLRMetaHost>(&CLSID_CLRMETAHOST)
+        .map_err(|e| ClrError::MetaHostCreationError(format!("{e}")))?;
+
+    let process = unsafe { OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, 0, pid) };
+    if process.is_null() {
+        return Err(last_error("OpenProcess"));
     }
+
+    let runtimes = meta_host.loaded_runtimes(process);
+    unsafe { CloseHandle(process) };
+
+    let mut versions = runtimes?.into_keys().collect::<Vec<_>>();
+    versions.sort();
+    Ok(versions)
 }
 
 /// Implements the `Drop` trait to release memory when `RustClr` goes out of scope.
@@ -470,6 +2400,18 @@ pub struct ClrOutput<'a> {
 
     /// Reference to the `mscorlib` assembly for creating types.
     mscorlib: &'a _Assembly,
+
+    /// How control sequences in the captured output are handled.
+    control_sequences: ControlSequences,
+
+    /// Reference to the `System` assembly, set when trace output is captured.
+    system: Option<&'a _Assembly>,
+
+    /// The `TextWriterTraceListener` added to `Trace.Listeners`.
+    trace_listener: Option<VARIANT>,
+
+    /// Initial capacity, in characters, of the `StringBuilder` behind the writer.
+    capacity: Option<usize>,
 }
 
 impl<'a> ClrOutput<'a> {
@@ -487,33 +2429,131 @@ impl<'a> ClrOutput<'a> {
             out: None,
             error: None,
             string_writer: None,
-            mscorlib
+            mscorlib,
+            control_sequences: ControlSequences::Preserve,
+            system: None,
+            trace_listener: None,
+            capacity: None,
         }
     }
 
+    /// Pre-sizes the `StringBuilder` receiving the output.
+    ///
+    /// Assemblies writing large outputs otherwise grow the buffer chunk by chunk while they run.
+    ///
+    /// # Arguments
+    ///
+    /// * `capacity` - The initial capacity, in characters.
+    ///
+    /// # Returns
+    ///
+    /// * The modified `ClrOutput` instance.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Reuses the `StringWriter` of a previous redirection instead of creating a new one.
+    ///
+    /// The writer is cleared by [`ClrOutput::redirect`], keeping the memory already allocated
+    /// by its `StringBuilder`. It must belong to the same application domain as `mscorlib`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The `StringWriter` returned by [`ClrOutput::writer`].
+    ///
+    /// # Returns
+    ///
+    /// * The modified `ClrOutput` instance.
+    pub fn with_writer(mut self, writer: VARIANT) -> Self {
+        self.string_writer = Some(writer);
+        self
+    }
+
+    /// Returns the `StringWriter` receiving the output, once [`ClrOutput::redirect`] created it.
+    pub fn writer(&self) -> Option<VARIANT> {
+        self.string_writer
+    }
+
+    /// Sets how console control sequences are handled by [`ClrOutput::capture`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The `ControlSequences` mode to apply.
+    ///
+    /// # Returns
+    ///
+    /// * The modified `ClrOutput` instance.
+    pub fn with_control_sequences(mut self, mode: ControlSequences) -> Self {
+        self.control_sequences = mode;
+        self
+    }
+
+    /// Also captures the messages written through `System.Diagnostics.Trace` and `Debug`.
+    ///
+    /// [`ClrOutput::redirect`] adds a `TextWriterTraceListener` writing to the same
+    /// `StringWriter` as the console, and [`ClrOutput::restore`] removes it. `Debug` shares
+    /// the listeners of `Trace`, but its calls are only compiled into `DEBUG` builds.
+    ///
+    /// # Arguments
+    ///
+    /// * `system` - An instance of the `_Assembly` representing `System`, which declares `Trace`.
+    ///
+    /// # Returns
+    ///
+    /// * The modified `ClrOutput` instance.
+    pub fn with_trace_capture(mut self, system: &'a _Assembly) -> Self {
+        self.system = Some(system);
+        self
+    }
+
     /// Redirects standard output and error streams to a `StringWriter`.
     ///
     /// This function replaces the standard output and error streams with a 
-    /// `StringWriter` to capture any output produced by the .NET code.
+    /// `StringWriter` to capture any output produced by the .NET code. A writer
+    /// left by a previous redirection is cleared and reused.
     ///
     /// # Returns
     ///
     /// * `Ok(())` - If the redirection is successful.
     /// * `Err(ClrError)` - If an error occurs while attempting to redirect the streams.
     pub fn redirect(&mut self) -> Result<(), ClrError> {
-        let console = self.mscorlib.resolve_type("System.Console")?;
-        let string_writer =  self.mscorlib.create_instance("System.IO.StringWriter")?;
+        let console = self.mscorlib.resolve_type(&ManagedName::Console.value())?;
+        let string_writer = match self.string_writer {
+            Some(string_writer) => {
+                self.clear()?;
+                string_writer
+            }
+            None => self.create_writer()?,
+        };
 
         // Save the original output and error streams
-        self.out = Some(console.invoke("get_Out", None, None, InvocationType::Static)?);
-        self.error = Some(console.invoke("get_Error", None, None, InvocationType::Static)?);
+        self.out = Some(console.invoke(&ManagedName::GetOut.value(), None, None, InvocationType::Static)?);
+        self.error = Some(console.invoke(&ManagedName::GetError.value(), None, None, InvocationType::Static)?);
 
         // Invokes the methods
-        console.invoke("SetOut", None, Some(vec![string_writer]), InvocationType::Static)?;
-        console.invoke("SetError", None, Some(vec![string_writer]), InvocationType::Static)?;
+        console.invoke(&ManagedName::SetOut.value(), None, Some(vec![string_writer]), InvocationType::Static)?;
+        console.invoke(&ManagedName::SetError.value(), None, Some(vec![string_writer]), InvocationType::Static)?;
 
         self.string_writer = Some(string_writer);
 
+        // Sends the trace output to the same writer
+        if let Some(system) = self.system {
+            let listener = system.create_instance_with_args(&ManagedName::TextWriterTraceListener.value(), vec![string_writer])?;
+            let listeners = system
+                .resolve_type(&ManagedName::Trace.value())?
+                .invoke(&ManagedName::GetListeners.value(), None, None, InvocationType::Static)?;
+
+            system.resolve_type(&ManagedName::TraceListenerCollection.value())?.invoke(
+                &ManagedName::Add.value(),
+                Some(listeners),
+                Some(vec![listener]),
+                InvocationType::Instance
+            )?;
+
+            self.trace_listener = Some(listener);
+        }
+
         Ok(())
     }
 
@@ -527,19 +2567,71 @@ impl<'a> ClrOutput<'a> {
     /// * `Ok(())` - If the restoration is successful.
     /// * `Err(ClrError)` - If an error occurs while restoring the streams.
     pub fn restore(&mut self) -> Result<(), ClrError> {
-        let console =  self.mscorlib.resolve_type("System.Console")?;
+        let console =  self.mscorlib.resolve_type(&ManagedName::Console.value())?;
 
         if let Some(out) = self.out.take() {
-            console.invoke("SetOut", None, Some(vec![out]), InvocationType::Static)?;
+            console.invoke(&ManagedName::SetOut.value(), None, Some(vec![out]), InvocationType::Static)?;
         }
 
         if let Some(error) = self.error.take() {
-            console.invoke("SetError", None, Some(vec![error]), InvocationType::Static)?;
+            console.invoke(&ManagedName::SetError.value(), None, Some(vec![error]), InvocationType::Static)?;
+        }
+
+        if let (Some(system), Some(listener)) = (self.system, self.trace_listener.take()) {
+            let listeners = system
+                .resolve_type(&ManagedName::Trace.value())?
+                .invoke(&ManagedName::GetListeners.value(), None, None, InvocationType::Static)?;
+
+            system.resolve_type(&ManagedName::TraceListenerCollection.value())?.invoke(
+                &ManagedName::Remove.value(),
+                Some(listeners),
+                Some(vec![listener]),
+                InvocationType::Instance
+            )?;
         }
 
         Ok(())
     }
 
+    /// Discards the output captured so far, keeping the capacity of the writer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the writer was cleared.
+    /// * `Err(ClrError)` - If no writer exists or the call fails.
+    pub fn clear(&self) -> Result<(), ClrError> {
+        let instance = self.string_writer.ok_or(ClrError::ErrorClr("No StringWriter instance found"))?;
+        let string_writer = self.mscorlib.resolve_type(&ManagedName::StringWriter.value())?;
+        let builder = string_writer.invoke(&ManagedName::GetStringBuilder.value(), Some(instance), None, InvocationType::Instance)?;
+
+        // Same as `StringBuilder.Clear`, which only resets the length
+        self.mscorlib.resolve_type(&ManagedName::StringBuilder.value())?.invoke(
+            &ManagedName::SetLength.value(),
+            Some(builder),
+            Some(vec![0.to_variant()]),
+            InvocationType::Instance
+        )?;
+
+        Ok(())
+    }
+
+    /// Creates the `StringWriter`, over a pre-sized `StringBuilder` if a capacity is set.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The new `StringWriter`.
+    /// * `Err(ClrError)` - If the writer cannot be created.
+    fn create_writer(&self) -> Result<VARIANT, ClrError> {
+        match self.capacity {
+            Some(capacity) => {
+                let capacity = capacity.min(i32::MAX as usize) as i32;
+                let builder = self.mscorlib.create_instance_with_args(&ManagedName::StringBuilder.value(), vec![capacity.to_variant()])?;
+                self.mscorlib.create_instance_with_args(&ManagedName::StringWriter.value(), vec![builder])
+            }
+            None => self.mscorlib.create_instance(&ManagedName::StringWriter.value()),
+        }
+    }
+
     /// Captures the content of the `StringWriter` as a `String`.
     ///
     /// This function retrieves the current content of the `StringWriter` used to 
@@ -554,8 +2646,8 @@ impl<'a> ClrOutput<'a> {
         let instance = self.string_writer.ok_or(ClrError::ErrorClr("No StringWriter instance found"))?;
         
         // Resolve the 'ToString' method on the StringWriter type
-        let string_writer = self.mscorlib.resolve_type("System.IO.StringWriter")?;
-        let to_string = string_writer.method("ToString")?;
+        let string_writer = self.mscorlib.resolve_type(&ManagedName::StringWriter.value())?;
+        let to_string = string_writer.method(&ManagedName::ToString.value())?;
         
         // Invoke 'ToString' on the StringWriter instance
         let result = to_string.invoke(Some(instance), None)?;
@@ -564,10 +2656,183 @@ impl<'a> ClrOutput<'a> {
         let bstr = unsafe { result.Anonymous.Anonymous.Anonymous.bstrVal };
 
         // Convert the BSTR to a UTF-8 String
-        Ok(bstr.to_string())
+        Ok(self.control_sequences.apply(bstr.to_string()))
+    }
+
+    /// Captures the content of the `StringWriter` as raw bytes.
+    ///
+    /// The output is transcoded to UTF-8 when requested and the text is valid UTF-16.
+    /// Output containing unpaired surrogates (e.g. binary data written to the console)
+    /// cannot be transcoded without loss, so it is returned as raw UTF-16LE instead.
+    /// The encoding actually used is reported in the returned `CapturedBytes`. Control
+    /// sequences are handled as set with [`ClrOutput::with_control_sequences`].
+    ///
+    /// # Arguments
+    ///
+    /// * `encoding` - The preferred `OutputEncoding`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CapturedBytes)` - The captured output and its encoding.
+    /// * `Err(ClrError)` - If an error occurs while capturing the output.
+    pub fn capture_bytes(&self, encoding: OutputEncoding) -> Result<CapturedBytes, ClrError> {
+        let instance = self.string_writer.ok_or(ClrError::ErrorClr("No StringWriter instance found"))?;
+        let string_writer = self.mscorlib.resolve_type(&ManagedName::StringWriter.value())?;
+        let result = string_writer.method(&ManagedName::ToString.value())?.invoke(Some(instance), None)?;
+
+        // Copies the UTF-16 code units out of the BSTR
+        let units = unsafe {
+            let bstr = result.Anonymous.Anonymous.Anonymous.bstrVal;
+            let len = SysStringLen(bstr) as usize;
+            let units = if len == 0 { Vec::new() } else { std::slice::from_raw_parts(bstr, len).to_vec() };
+            SysFreeString(bstr);
+            units
+        };
+
+        if encoding == OutputEncoding::Utf8 {
+            if let Ok(text) = String::from_utf16(&units) {
+                let text = self.control_sequences.apply(text);
+                return Ok(CapturedBytes { bytes: text.into_bytes(), encoding: OutputEncoding::Utf8 });
+            }
+        }
+
+        // Filters the code units, passing unpaired surrogates through unchanged
+        let mut filter = ControlSequenceFilter::new(self.control_sequences);
+        let mut bytes = Vec::with_capacity(units.len() * 2);
+        for decoded in char::decode_utf16(units.iter().copied()) {
+            match decoded {
+                Ok(c) => {
+                    if let Some(c) = filter.next(c) {
+                        let mut buffer = [0u16; 2];
+                        for unit in c.encode_utf16(&mut buffer) {
+                            bytes.extend_from_slice(&unit.to_le_bytes());
+                        }
+                    }
+                }
+                Err(error) => {
+                    if filter.next(char::REPLACEMENT_CHARACTER).is_some() {
+                        bytes.extend_from_slice(&error.unpaired_surrogate().to_le_bytes());
+                    }
+                }
+            }
+        }
+
+        Ok(CapturedBytes { bytes, encoding: OutputEncoding::Utf16Le })
+    }
+
+    /// Captures the content of the `StringWriter`, spilling it to disk if it is too large.
+    ///
+    /// Output longer than `threshold` characters is copied in chunks from the underlying
+    /// `StringBuilder` to an encrypted temporary file, and the `StringBuilder` is cleared
+    /// to release the managed memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The maximum number of characters kept in memory.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CapturedOutput)` - The captured output, in memory or spilled to disk.
+    /// * `Err(ClrError)` - If an error occurs while capturing the output.
+    pub fn capture_spilled(&self, threshold: usize) -> Result<CapturedOutput, ClrError> {
+        let instance = self.string_writer.ok_or(ClrError::ErrorClr("No StringWriter instance found"))?;
+        let string_writer = self.mscorlib.resolve_type(&ManagedName::StringWriter.value())?;
+        let string_builder = self.mscorlib.resolve_type(&ManagedName::StringBuilder.value())?;
+
+        // Retrieves the StringBuilder holding the output and its length
+        let builder = string_writer.invoke(&ManagedName::GetStringBuilder.value(), Some(instance), None, InvocationType::Instance)?;
+        let length = string_builder.invoke(&ManagedName::GetLength.value(), Some(builder), None, InvocationType::Instance)?;
+        let length = unsafe { length.Anonymous.Anonymous.Anonymous.lVal };
+        if length as usize <= threshold {
+            return self.capture().map(CapturedOutput::Memory);
+        }
+
+        // A single filter keeps sequences split between two chunks
+        let mut filter = ControlSequenceFilter::new(self.control_sequences);
+        let mut writer = SpillWriter::new()?;
+        let mut start = 0;
+        while start < length {
+            let count = SPILL_CHUNK.min(length - start);
+            let chunk = string_builder.invoke(
+                &ManagedName::ToString.value(),
+                Some(builder),
+                Some(vec![start.to_variant(), count.to_variant()]),
+                InvocationType::Instance
+            )?;
+
+            let chunk = unsafe { chunk.Anonymous.Anonymous.Anonymous.bstrVal };
+            let len = unsafe { SysStringLen(chunk) } as usize;
+            let mut units = unsafe { std::slice::from_raw_parts(chunk, len) };
+
+            // Keeps surrogate pairs in the same chunk
+            if start + count < length && units.last().is_some_and(|u| (0xD800..0xDC00).contains(u)) {
+                units = &units[..len - 1];
+            }
+
+            writer.write(&filter.push(&String::from_utf16_lossy(units)))?;
+            start += units.len().max(1) as i32;
+            unsafe { SysFreeString(chunk) };
+        }
+
+        // Releases the managed copy of the output
+        string_builder.invoke(&ManagedName::SetLength.value(), Some(builder), Some(vec![0.to_variant()]), InvocationType::Instance)?;
+
+        Ok(CapturedOutput::Spilled(writer.finish()?))
+    }
+}
+
+/// Encoding of the bytes returned by [`ClrOutput::capture_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputEncoding {
+    /// UTF-8 encoded text.
+    Utf8,
+
+    /// Raw UTF-16 code units in little-endian byte order, as produced by the CLR.
+    Utf16Le,
+}
+
+/// Output captured as bytes, along with the encoding of the bytes.
+#[derive(Debug, Clone)]
+pub struct CapturedBytes {
+    /// The captured output.
+    pub bytes: Vec<u8>,
+
+    /// The encoding of `bytes`.
+    pub encoding: OutputEncoding,
+}
+
+impl CapturedBytes {
+    /// Decodes the bytes into a `String`, replacing the invalid sequences.
+    pub fn into_string(self) -> String {
+        match self.encoding {
+            OutputEncoding::Utf8 => String::from_utf8(self.bytes)
+                .unwrap_or_else(|error| String::from_utf8_lossy(error.as_bytes()).into_owned()),
+            OutputEncoding::Utf16Le => {
+                let units = self.bytes
+                    .as_chunks::<2>()
+                    .0
+                    .iter()
+                    .map(|unit| u16::from_le_bytes(*unit))
+                    .collect::<Vec<_>>();
+
+                String::from_utf16_lossy(&units)
+            }
+        }
     }
 }
 
+/// Output of a run, as text or as bytes depending on the caller.
+enum RunOutput {
+    /// Output captured by [`RustClr::run_captured`].
+    Text(CapturedOutput),
+
+    /// Output captured by [`RustClr::run_bytes`].
+    Bytes(CapturedBytes),
+}
+
+/// Number of characters copied from the `StringBuilder` per spilled chunk.
+const SPILL_CHUNK: i32 = 64 * 1024;
+
 /// Represents a simplified interface to the CLR components without loading assemblies.
 #[derive(Debug)]
 pub struct RustClrEnv {
@@ -613,6 +2878,9 @@ impl RustClrEnv {
     /// }
     /// ```
     pub fn new(runtime_version: Option<RuntimeVersion>) -> Result<Self, ClrError> {
+        // Prevents overlapping runtime activations from other threads
+        let _init = lock_runtime_init();
+
         // Initialize MetaHost
         let meta_host = CLRCreateInstance::<ICLRMetaHost>(&CLSID_CLRMETAHOST)
             .map_err(|e| ClrError::MetaHostCreationError(format!("{e}")))?;
@@ -645,10 +2913,134 @@ impl RustClrEnv {
             app_domain,
         })
     }
+
+    /// Enables TLS 1.2 in `ServicePointManager.SecurityProtocol` for the current domain.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If TLS 1.2 is enabled.
+    /// * `Err(ClrError)` - If the protocol could not be set.
+    pub fn enable_tls12(&self) -> Result<(), ClrError> {
+        enable_tls12(&self.app_domain)
+    }
+
+    /// Collects diagnostic details about the runtime hosted by this environment.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RuntimeDetails)` - The version, directory and startup configuration of the runtime.
+    /// * `Err(ClrError)` - If any of the details could not be read.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let clr = RustClrEnv::new(None)?;
+    /// let details = clr.runtime_details()?;
+    /// println!("{} at {}", details.version, details.directory);
+    /// ```
+    pub fn runtime_details(&self) -> Result<RuntimeDetails, ClrError> {
+        let (startup_flags, config_file) = self.runtime_info.startup_config()?;
+
+        Ok(RuntimeDetails {
+            version: self.runtime_info.version()?,
+            directory: self.runtime_info.runtime_directory()?,
+            started: self.runtime_info.is_started(),
+            startup_flags,
+            config_file,
+        })
+    }
+
+    /// Attaches a profiler DLL to a running process through `ICLRProfiling`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The identifier of the process to attach to. It must run a .NET Framework 4.0+ runtime.
+    /// * `timeout` - How long to wait for the profiler to load.
+    /// * `profiler` - The CLSID of the profiler.
+    /// * `path` - The full path of the profiler DLL.
+    /// * `client_data` - Data passed to the profiler's `InitializeForAttach`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the profiler was loaded and initialized.
+    /// * `Err(ClrError)` - If `ICLRProfiling` is not available or the attach fails.
+    pub fn attach_profiler(&self, pid: u32, timeout: Duration, profiler: &GUID, path: &str, client_data: &[u8]) -> Result<(), ClrError> {
+        self.runtime_info
+            .GetInterface::<ICLRProfiling>(&CLSID_CLR_PROFILING)?
+            .attach(pid, timeout, profiler, path, client_data)
+            .map_err(|error| error.describe(&self.runtime_info))
+    }
+
+    /// Opens the metadata of a .NET image through the runtime's `IMetaDataImport`.
+    ///
+    /// The image is only parsed, not loaded into a domain, so its types, methods and
+    /// P/Invoke imports can be inspected by token before deciding to run it.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The .NET image. It is copied, so it can be dropped afterwards.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IMetaDataImport)` - The import interface over the image's metadata.
+    /// * `Err(ClrError)` - If the dispenser is not available or the image has no valid metadata.
+    pub fn metadata_import(&self, buffer: &[u8]) -> Result<IMetaDataImport, ClrError> {
+        self.runtime_info
+            .GetInterface::<IMetaDataDispenser>(&CLSID_COR_METADATA_DISPENSER)?
+            .open_scope_on_memory(buffer)
+            .map_err(|error| error.describe(&self.runtime_info))
+    }
+
+    /// Sets an `AppContext` switch in the current domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the switch.
+    /// * `enabled` - The value of the switch.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the switch is set.
+    /// * `Err(ClrError)` - If `AppContext` is not available or the call fails.
+    pub fn set_app_context_switch(&self, name: &str, enabled: bool) -> Result<(), ClrError> {
+        set_app_context_switch(&self.app_domain, name, enabled)
+    }
+
+    /// Collects garbage collector statistics for the hosted runtime.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GcStats)` - The managed memory and collection statistics.
+    /// * `Err(ClrError)` - If the statistics could not be read.
+    pub fn gc_stats(&self) -> Result<GcStats, ClrError> {
+        GcStats::collect(&self.app_domain)
+    }
+}
+
+/// Diagnostic details about a runtime, returned by [`RustClrEnv::runtime_details`].
+#[derive(Debug, Clone)]
+pub struct RuntimeDetails {
+    /// Version string of the runtime (e.g. `v4.0.30319`).
+    pub version: String,
+
+    /// Installation directory of the runtime.
+    pub directory: String,
+
+    /// Whether the runtime has been started in the process.
+    pub started: bool,
+
+    /// Startup flags reported by `GetDefaultStartupFlags`.
+    pub startup_flags: StartupFlags,
+
+    /// Host configuration file reported by `GetDefaultStartupFlags`, if any.
+    pub config_file: Option<String>,
 }
 
 /// Represents the .NET runtime versions supported by RustClr.
-#[derive(Debug, Clone, Copy)]
+///
+/// Versions can be parsed from strings such as `v4`, `4.8` or `v4.0.30319` with
+/// [`str::parse`] or `RuntimeVersion::try_from`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeVersion {
     /// .NET Framework 2.0, identified by version `v2.0.50727`.
     V2,
@@ -658,9 +3050,6 @@ pub enum RuntimeVersion {
     
     /// .NET Framework 4.0, identified by version `v4.0.30319`.
     V4,
-
-    /// Represents an unknown or unsupported .NET runtime version.
-    UNKNOWN,
 }
 
 impl RuntimeVersion {
@@ -674,9 +3063,179 @@ impl RuntimeVersion {
             RuntimeVersion::V2 => "v2.0.50727",
             RuntimeVersion::V3 => "v3.0",
             RuntimeVersion::V4 => "v4.0.30319",
-            RuntimeVersion::UNKNOWN => "UNKNOWN",
         };
 
         runtime_version.encode_utf16().chain(Some(0)).collect::<Vec<u16>>()
     }
 }
+
+impl FromStr for RuntimeVersion {
+    type Err = ClrError;
+
+    /// Parses a runtime version such as `v2`, `v3.5`, `4.8` or `v4.0.30319`.
+    ///
+    /// Only the major version selects the runtime, since every 4.x release runs on
+    /// the `v4.0.30319` CLR.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RuntimeVersion)` - The runtime matching the major version.
+    /// * `Err(ClrError)` - If the string is not a version of a supported runtime.
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let trimmed = version.trim();
+        let number = trimmed.strip_prefix(['v', 'V']).unwrap_or(trimmed);
+        let (major, rest) = number.split_once('.').unwrap_or((number, ""));
+        let valid_rest = rest.is_empty() || rest.split('.').all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit()));
+
+        match major {
+            "2" if valid_rest => Ok(RuntimeVersion::V2),
+            "3" if valid_rest => Ok(RuntimeVersion::V3),
+            "4" if valid_rest => Ok(RuntimeVersion::V4),
+            _ => Err(ClrError::UnsupportedRuntimeVersion(version.to_string())),
+        }
+    }
+}
+
+impl TryFrom<&str> for RuntimeVersion {
+    type Error = ClrError;
+
+    /// Parses a runtime version, see [`RuntimeVersion::from_str`].
+    fn try_from(version: &str) -> Result<Self, Self::Error> {
+        version.parse()
+    }
+}
+
+/// Security zones that can be assigned to an application domain, mirroring `System.Security.SecurityZone`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// The local computer.
+    MyComputer = 0,
+
+    /// The local intranet.
+    Intranet = 1,
+
+    /// Sites added to the trusted sites list.
+    Trusted = 2,
+
+    /// The Internet.
+    Internet = 3,
+
+    /// Sites added to the restricted sites list.
+    Untrusted = 4,
+}
+
+/// Flags controlling how the CLR is started, mirroring the `STARTUP_FLAGS` enumeration of `mscoree.h`.
+///
+/// Flags can be combined using the `|` operator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StartupFlags(pub u32);
+
+impl StartupFlags {
+    /// No flags.
+    pub const NONE: Self = Self(0x0);
+
+    /// Enables concurrent (background) garbage collection.
+    pub const CONCURRENT_GC: Self = Self(0x1);
+
+    /// Assemblies are not loaded as domain-neutral.
+    pub const LOADER_OPTIMIZATION_SINGLE_DOMAIN: Self = Self(0x1 << 1);
+
+    /// All assemblies are loaded as domain-neutral.
+    pub const LOADER_OPTIMIZATION_MULTI_DOMAIN: Self = Self(0x2 << 1);
+
+    /// Strong-named assemblies are loaded as domain-neutral.
+    pub const LOADER_OPTIMIZATION_MULTI_DOMAIN_HOST: Self = Self(0x3 << 1);
+
+    /// Disables the safe-mode loader, loading the runtime without applying binding policy.
+    pub const LOADER_SAFEMODE: Self = Self(0x10);
+
+    /// Sets the runtime as the one used by the legacy activation APIs (e.g. `CorBindToRuntimeEx`).
+    pub const LOADER_SETPREFERENCE: Self = Self(0x100);
+
+    /// Enables server garbage collection.
+    pub const SERVER_GC: Self = Self(0x1000);
+
+    /// Retains virtual memory released by the garbage collector.
+    pub const HOARD_GC_VM: Self = Self(0x2000);
+
+    /// Enables the single version hosting interface.
+    pub const SINGLE_VERSION_HOSTING_INTERFACE: Self = Self(0x4000);
+
+    /// Impersonation does not flow through asynchronous points by default.
+    pub const LEGACY_IMPERSONATION: Self = Self(0x10000);
+
+    /// Threads do not commit their full stack when they start.
+    pub const DISABLE_COMMITTHREADSTACK: Self = Self(0x20000);
+
+    /// Impersonation always flows through asynchronous points.
+    pub const ALWAYSFLOW_IMPERSONATION: Self = Self(0x40000);
+
+    /// Trims the memory committed by the garbage collector.
+    pub const TRIM_GC_COMMIT: Self = Self(0x80000);
+
+    /// Enables Event Tracing for Windows (ETW).
+    pub const ETW: Self = Self(0x100000);
+
+    /// Enables application domain resource monitoring.
+    pub const ARM: Self = Self(0x400000);
+
+    /// Allows only a single application domain to be created.
+    pub const SINGLE_APPDOMAIN: Self = Self(0x800000);
+
+    /// Disables randomized string hashing.
+    pub const DISABLE_RANDOMIZED_STRING_HASHING: Self = Self(0x2000000);
+
+    /// Flags used by [`RustClr::with_minimal_footprint`].
+    ///
+    /// `CONCURRENT_GC` is left unset so no background GC thread is created.
+    pub const MINIMAL: Self = Self(Self::LOADER_OPTIMIZATION_SINGLE_DOMAIN.0 | Self::DISABLE_COMMITTHREADSTACK.0);
+
+    /// Checks whether all the bits of `other` are set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The flags to check.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If every flag of `other` is set.
+    /// * `false` - Otherwise.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Applies [`StartupFlags::MINIMAL`] over these flags.
+    ///
+    /// The loader optimization is a 2-bit field rather than independent flags, so it is
+    /// replaced instead of combined, and `CONCURRENT_GC` is cleared. The other flags are kept.
+    ///
+    /// # Returns
+    ///
+    /// * The flags with the loader optimization and garbage collector mode of `MINIMAL`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let flags = (StartupFlags::LOADER_OPTIMIZATION_MULTI_DOMAIN | StartupFlags::SERVER_GC).minimal();
+    /// assert_eq!(flags, StartupFlags::MINIMAL | StartupFlags::SERVER_GC);
+    /// ```
+    pub fn minimal(self) -> Self {
+        let cleared = Self::LOADER_OPTIMIZATION_MULTI_DOMAIN_HOST.0 | Self::CONCURRENT_GC.0;
+        Self(self.0 & !cleared) | Self::MINIMAL
+    }
+}
+
+impl BitOr for StartupFlags {
+    type Output = Self;
+
+    /// Enables combining multiple `StartupFlags` using bitwise OR.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let flags = StartupFlags::LOADER_SETPREFERENCE | StartupFlags::CONCURRENT_GC;
+    /// ```
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
@@ -1,6 +1,6 @@
 use {
     crate::error::ClrError,
-    windows_core::{Interface, GUID},    
+    windows_core::{IUnknown, Interface, GUID, PCWSTR},
     std::{ffi::c_void , sync::OnceLock},
     windows_sys::{
         core::HRESULT, s, 
@@ -13,9 +13,119 @@ use {
 /// CLSID (Class ID) constants for various CLR components.
 /// 
 /// These constants are used to identify specific COM classes within the Common Language Runtime (CLR).
+///
+/// `CLSID_CLRMETAHOST` is only available through the .NET Framework 4.0+ shim (`CLRCreateInstance`).
 pub const CLSID_CLRMETAHOST: GUID = GUID::from_u128(0x9280188d_0e8e_4867_b30c_7fa83884e8de);
+
+/// CLSID of `ICLRRuntimeHost`, available since .NET Framework 2.0.
 pub const CLSID_CLRRUNTIMEHOST: GUID = GUID::from_u128(0x90f1a06e_7712_4762_86b5_7a5eba6bdb02);
-pub const CLSID_COR_RUNTIME_HOST: GUID = GUID::from_u128(0xCB2F6723_AB3A_11d2_9C40_00C04FA30A3E);
+
+/// CLSID of `ICorRuntimeHost`, available in every .NET Framework version.
+pub const CLSID_COR_RUNTIME_HOST: GUID = GUID::from_u128(0xCB2F6723_AB3A_11D2_9C40_00C04FA30A3E);
+
+/// CLSID of `ICLRProfiling`, obtained through `ICLRRuntimeInfo::GetInterface`. Requires .NET Framework 4.0+.
+pub const CLSID_CLR_PROFILING: GUID = GUID::from_u128(0xbd097ed8_733e_43fe_8ed7_a95ff9a8448c);
+
+/// CLSID of the metadata dispenser, obtained through `ICLRRuntimeInfo::GetInterface`. Requires .NET Framework 4.0+.
+pub const CLSID_COR_METADATA_DISPENSER: GUID = GUID::from_u128(0xe5cb7a31_7512_11d2_89ce_0080c792e5d8);
+
+/// IID of `ICLRMetaHost`. Requires the .NET Framework 4.0+ shim.
+pub const IID_ICLRMETAHOST: GUID = GUID::from_u128(0xd332db9e_b9b3_4125_8207_a14884f53216);
+
+/// IID of `ICLRRuntimeInfo`. Requires the .NET Framework 4.0+ shim.
+pub const IID_ICLRRUNTIMEINFO: GUID = GUID::from_u128(0xbd39d1d2_ba2f_486a_89b0_b4b0cb466891);
+
+/// IID of `ICLRProfiling`. Requires .NET Framework 4.0+.
+pub const IID_ICLRPROFILING: GUID = GUID::from_u128(0xb349abe3_b56f_4689_bfcd_76bf39d888ea);
+
+/// IID of `IMetaDataDispenser`. Available in every .NET Framework version.
+pub const IID_IMETADATADISPENSER: GUID = GUID::from_u128(0x809c652e_7396_11d2_9771_00a0c9b4d50c);
+
+/// IID of `IMetaDataImport`. Available in every .NET Framework version.
+pub const IID_IMETADATAIMPORT: GUID = GUID::from_u128(0x7dac8207_d3ae_4c75_9b67_92801a497d44);
+
+/// IID of `ICorRuntimeHost`. Available in every .NET Framework version.
+pub const IID_ICORRUNTIMEHOST: GUID = GUID::from_u128(0xCB2F6722_AB3A_11D2_9C40_00C04FA30A3E);
+
+/// IID of `mscorlib::_AppDomain`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__APPDOMAIN: GUID = GUID::from_u128(0x05F696DC_2B29_3663_AD8B_C4389CF2A713);
+
+/// IID of `mscorlib::_Assembly`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__ASSEMBLY: GUID = GUID::from_u128(0x17156360_2f1a_384a_bc52_fde93c215c5b);
+
+/// IID of `mscorlib::_Type`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__TYPE: GUID = GUID::from_u128(0xbca8b44d_aad6_3a86_8ab7_03349f4f2da2);
+
+/// IID of `mscorlib::_MethodInfo`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__METHODINFO: GUID = GUID::from_u128(0xffcc1b5d_ecb8_38dd_9b01_3dc8abc2aa5f);
+
+/// IID of `mscorlib::_FieldInfo`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__FIELDINFO: GUID = GUID::from_u128(0x8a7c1442_a9fb_366b_80d8_4939ffa6dbe0);
+
+/// IID of `mscorlib::_PropertyInfo`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__PROPERTYINFO: GUID = GUID::from_u128(0xf59ed4e4_e68f_3218_bd77_061aa82824bf);
+
+/// IID of `mscorlib::_EventInfo`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__EVENTINFO: GUID = GUID::from_u128(0x9de59c64_d889_35a1_b897_587d74469e5b);
+
+/// IID of `mscorlib::_ConstructorInfo`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__CONSTRUCTORINFO: GUID = GUID::from_u128(0xe9a19478_9646_3679_9b10_8411ae1fd57d);
+
+/// IID of `mscorlib::_ParameterInfo`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__PARAMETERINFO: GUID = GUID::from_u128(0x993634c4_e47a_32cc_be08_85f567dc27d6);
+
+/// IID of `mscorlib::_Module`. Stable across .NET Framework 2.0 to 4.x.
+pub const IID__MODULE: GUID = GUID::from_u128(0xd002e9ba_d9e3_3749_b1d3_d565a08b13e7);
+
+/// IID of the standard `IEnumUnknown` COM interface.
+pub const IID_IENUMUNKNOWN: GUID = GUID::from_u128(0x00000100_0000_0000_c000_000000000046);
+
+/// IID of the standard `IDispatch` COM interface.
+pub const IID_IDISPATCH: GUID = GUID::from_u128(0x00020400_0000_0000_c000_000000000046);
+
+/// IID of the `IAgileObject` marker interface (Windows 8.1+).
+pub const IID_IAGILEOBJECT: GUID = GUID::from_u128(0x94ea2b94_e9cc_49e0_c0ff_ee64ca8f5b90);
+
+/// Hosting API exposed by the `mscoree.dll` shim installed on the machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostingApi {
+    /// The .NET Framework 4.0+ shim, exposing `CLRCreateInstance` and `ICLRMetaHost`.
+    MetaHost,
+
+    /// A pre-4.0 shim, only exposing the legacy `CorBindToRuntimeEx` activation API.
+    Legacy,
+}
+
+/// Detects which hosting API the `mscoree.dll` shim supports.
+///
+/// # Returns
+///
+/// * `Some(HostingApi::MetaHost)` - If `CLRCreateInstance` is exported.
+/// * `Some(HostingApi::Legacy)` - If only `CorBindToRuntimeEx` is exported.
+/// * `None` - If `mscoree.dll` cannot be loaded.
+pub fn hosting_api() -> Option<HostingApi> {
+    if CLR_CREATE_INSTANCE.get_or_init(init_clr_create_instance).is_some() {
+        Some(HostingApi::MetaHost)
+    } else if COR_BIND_TO_RUNTIME_EX.get_or_init(init_cor_bind_to_runtime_ex).is_some() {
+        Some(HostingApi::Legacy)
+    } else {
+        None
+    }
+}
+
+/// Checks whether a COM object implements the interface `T` using `QueryInterface`.
+///
+/// # Arguments
+///
+/// * `unknown` - The COM object to query.
+///
+/// # Returns
+///
+/// * `true` - If the object implements `T`.
+/// * `false` - Otherwise.
+pub fn supports<T: Interface>(unknown: &IUnknown) -> bool {
+    unknown.cast::<T>().is_ok()
+}
 
 /// Static cache for the `CLRCreateInstance` function.
 /// 
@@ -100,4 +210,83 @@ where
     } else {
         Err(ClrError::ErrorClr("CLRCreateInstance function not found"))
     }
-}
\ No newline at end of file
+}
+
+/// Static cache for the `CorBindToRuntimeEx` function.
+static COR_BIND_TO_RUNTIME_EX: OnceLock<Option<CorBindToRuntimeExFn>> = OnceLock::new();
+
+/// Function type for the legacy `CorBindToRuntimeEx` activation API.
+///
+/// # Arguments
+///
+/// * `pwszVersion` - The runtime version to load, or null for the latest.
+/// * `pwszBuildFlavor` - The build flavor (`wks` or `svr`), or null for the workstation build.
+/// * `startupFlags` - The `STARTUP_FLAGS` used to start the runtime.
+/// * `rclsid` - The CLSID of the host class to instantiate.
+/// * `riid` - The IID of the interface to obtain.
+/// * `ppv` - A pointer to store the resulting interface.
+///
+/// # Returns
+///
+/// * Returns an `HRESULT` indicating success or failure.
+type CorBindToRuntimeExFn = unsafe extern "system" fn(
+    pwszVersion: PCWSTR,
+    pwszBuildFlavor: PCWSTR,
+    startupFlags: u32,
+    rclsid: *const GUID,
+    riid: *const GUID,
+    ppv: *mut *mut c_void,
+) -> HRESULT;
+
+/// Attempts to load the `CorBindToRuntimeEx` function from `mscoree.dll`.
+///
+/// # Returns
+///
+/// * `Some(CorBindToRuntimeExFn)` - if the function is found and loaded successfully.
+/// * `None` - if `mscoree.dll` cannot be loaded or if `CorBindToRuntimeEx` is not found.
+fn init_cor_bind_to_runtime_ex() -> Option<CorBindToRuntimeExFn> {
+    unsafe {
+        let lib = LoadLibraryA(s!("mscoree.dll"));
+        if !lib.is_null() {
+            return GetProcAddress(lib, s!("CorBindToRuntimeEx")).map(|addr| {
+                core::mem::transmute::<*mut c_void, CorBindToRuntimeExFn>(addr as *mut c_void)
+            })
+        }
+
+        None
+    }
+}
+
+/// Loads a runtime through the legacy `CorBindToRuntimeEx` API and returns the requested host interface.
+///
+/// This is the only activation path available when the machine has no .NET Framework 4.0+
+/// shim installed, in which case `ICLRMetaHost` and `ICLRRuntimeInfo` do not exist.
+///
+/// # Arguments
+///
+/// * `version` - The runtime version to load (e.g. `v2.0.50727`), or null for the latest.
+/// * `startup_flags` - The `STARTUP_FLAGS` used to start the runtime.
+/// * `clsid` - A pointer to the GUID of the host class to instantiate.
+///
+/// # Returns
+///
+/// * `Ok(T)` - if the runtime is bound successfully, with `T` representing the interface requested.
+/// * `Err(ClrError)` - if the function cannot be loaded or if the binding fails.
+pub fn CorBindToRuntimeEx<T>(version: PCWSTR, startup_flags: u32, clsid: *const GUID) -> Result<T, ClrError>
+where
+    T: Interface
+{
+    let CorBindToRuntimeEx = COR_BIND_TO_RUNTIME_EX.get_or_init(init_cor_bind_to_runtime_ex);
+
+    if let Some(CorBindToRuntimeEx) = CorBindToRuntimeEx {
+        let mut result = core::ptr::null_mut();
+        let hr = unsafe { CorBindToRuntimeEx(version, PCWSTR::null(), startup_flags, clsid, &T::IID, &mut result) };
+        if hr == 0 {
+            Ok(unsafe { core::mem::transmute_copy(&result) })
+        } else {
+            Err(ClrError::ApiError("CorBindToRuntimeEx", hr))
+        }
+    } else {
+        Err(ClrError::ErrorClr("CorBindToRuntimeEx function not found"))
+    }
+}
@@ -0,0 +1,81 @@
+use windows_sys::Win32::{
+    System::Console::{
+        AllocConsole, AttachConsole, FreeConsole,
+        GetConsoleWindow, ATTACH_PARENT_PROCESS,
+    },
+    UI::WindowsAndMessaging::{ShowWindow, SW_HIDE},
+};
+
+use crate::error::{ClrError, last_error};
+
+/// Specifies how a console is provided to the assembly when the host process has none.
+///
+/// GUI and service processes start without a console. `System.Console` then writes to a
+/// null stream, and APIs that need a real console (`Console.ReadKey`, `Console.WindowWidth`,
+/// `Console.Clear`, ...) throw an `IOException`. Processes that already have a console are
+/// never changed, whatever the mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConsoleMode {
+    /// Leaves the console of the process untouched.
+    #[default]
+    None,
+
+    /// Allocates a new console and hides its window while the assembly runs.
+    ///
+    /// The window may be visible for a moment before it is hidden.
+    Hidden,
+
+    /// Attaches to the console of the parent process while the assembly runs, so the
+    /// output of the assembly shows up in the terminal the host was started from.
+    ///
+    /// If the parent process has no console, the assembly runs without one.
+    Attach,
+}
+
+/// Console set up for the duration of an execution, released when dropped.
+pub(crate) struct ConsoleGuard {
+    /// Whether the console was allocated or attached by the guard.
+    owned: bool,
+}
+
+impl ConsoleGuard {
+    /// Provides a console to the process according to the mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The `ConsoleMode` to apply.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ConsoleGuard)` - The guard releasing the console set up by this call.
+    /// * `Err(ClrError)` - If a new console could not be allocated.
+    pub(crate) fn acquire(mode: ConsoleMode) -> Result<Self, ClrError> {
+        if mode == ConsoleMode::None || unsafe { !GetConsoleWindow().is_null() } {
+            return Ok(Self { owned: false });
+        }
+
+        let owned = match mode {
+            ConsoleMode::Hidden => {
+                if unsafe { AllocConsole() } == 0 {
+                    return Err(last_error("AllocConsole"));
+                }
+
+                unsafe { ShowWindow(GetConsoleWindow(), SW_HIDE) };
+                true
+            }
+            ConsoleMode::Attach => unsafe { AttachConsole(ATTACH_PARENT_PROCESS) != 0 },
+            ConsoleMode::None => false,
+        };
+
+        Ok(Self { owned })
+    }
+}
+
+/// Implements the `Drop` trait to detach the process from the console set up by the guard.
+impl Drop for ConsoleGuard {
+    fn drop(&mut self) {
+        if self.owned {
+            unsafe { FreeConsole() };
+        }
+    }
+}
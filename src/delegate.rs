@@ -0,0 +1,166 @@
+use {
+    std::ffi::c_void,
+    windows_core::{IUnknown, Interface},
+    windows_sys::Win32::System::Variant::{VARIANT, VT_DISPATCH, VT_I8, VT_UNKNOWN},
+};
+
+use crate::{
+    Variant, InvocationType, ManagedName,
+    error::ClrError,
+    schema::{_AppDomain, _Type},
+};
+
+/// A managed delegate that can be invoked from Rust.
+///
+/// A `ClrDelegate` is either created from a native function with [`ClrDelegate::from_fn`],
+/// to pass Rust callbacks to managed APIs expecting a delegate, or wrapped around a delegate
+/// returned by managed code with [`ClrDelegate::from_variant`], to call it from Rust.
+///
+/// To hand a managed delegate to native code instead, root it with [`GcRoot`](crate::GcRoot)
+/// and use [`GcRoot::function_pointer`](crate::GcRoot::function_pointer).
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{ClrDelegate, RustClrEnv};
+/// use std::ffi::c_void;
+///
+/// extern "system" fn on_start() {
+///     println!("Called from managed code");
+/// }
+///
+/// let clr = RustClrEnv::new(None)?;
+/// let thread_start = clr.app_domain
+///     .load_lib("mscorlib")?
+///     .resolve_type("System.Threading.ThreadStart")?;
+///
+/// let delegate = ClrDelegate::from_fn(&clr.app_domain, &thread_start, on_start as *const c_void)?;
+/// delegate.invoke(vec![])?;
+/// ```
+#[derive(Clone)]
+pub struct ClrDelegate {
+    /// The managed delegate.
+    object: IUnknown,
+
+    /// `System.Delegate`, used to invoke the delegate.
+    delegate: _Type,
+}
+
+impl ClrDelegate {
+    /// Creates a delegate of the given type that calls a native function.
+    ///
+    /// The delegate is built with `Marshal.GetDelegateForFunctionPointer`, so its type must be
+    /// a non-generic delegate type, and the function must use the calling convention and the
+    /// marshaled parameter types of that delegate (`extern "system"` for the default
+    /// `StdCall`/`Winapi` convention).
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` in which the delegate is created.
+    /// * `delegate_type` - The `_Type` of the delegate, e.g. `System.Threading.ThreadStart`.
+    /// * `function` - A pointer to the native function, e.g. `callback as *const c_void`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ClrDelegate)` - The delegate calling `function`.
+    /// * `Err(ClrError)` - If `System.Core` cannot be loaded (.NET 2.0) or the delegate type is not supported.
+    pub fn from_fn(domain: &_AppDomain, delegate_type: &_Type, function: *const c_void) -> Result<Self, ClrError> {
+        let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+        let core = domain.load_lib(&ManagedName::SystemCore.value())?;
+        let expression = core.resolve_type(&ManagedName::Expression.value())?;
+        let lambda = core.resolve_type(&ManagedName::LambdaExpression.value())?;
+        let delegate = mscorlib.resolve_type(&ManagedName::Delegate.value())?;
+        let get_delegate = mscorlib
+            .resolve_type(&ManagedName::Marshal.value())?
+            .method_signature(&ManagedName::GetDelegateForFunctionPointer.value())?;
+
+        // `IntPtr` arguments reach managed code as `Int64` through COM, which the binder refuses
+        // to convert, so the pointer goes through a compiled
+        // `(long pointer) => Marshal.GetDelegateForFunctionPointer((IntPtr)pointer, delegate_type)`
+        let int64 = mscorlib.resolve_type(&ManagedName::Int64.value())?;
+        let intptr = mscorlib.resolve_type(&ManagedName::IntPtr.value())?;
+        let build = |name: ManagedName, args: Vec<VARIANT>| {
+            expression.invoke(&name.value(), None, Some(args), InvocationType::Static)
+        };
+
+        let parameter = build(ManagedName::Parameter, vec![int64.to_variant(), "pointer".to_variant()])?;
+        let pointer = build(ManagedName::Convert, vec![parameter, intptr.to_variant()])?;
+        let target = build(ManagedName::Constant, vec![delegate_type.to_variant()])?;
+        let body = build(ManagedName::Call, vec![get_delegate.to_variant(), pointer, target])?;
+        let factory = build(ManagedName::Lambda, vec![body, parameter])?;
+        let factory = lambda.invoke(&ManagedName::Compile.value(), Some(factory), None, InvocationType::Instance)?;
+
+        let mut address = unsafe { std::mem::zeroed::<VARIANT>() };
+        address.Anonymous.Anonymous.vt = VT_I8;
+        address.Anonymous.Anonymous.Anonymous.llVal = function as i64;
+
+        let value = delegate.invoke(
+            &ManagedName::DynamicInvoke.value(),
+            Some(factory),
+            Some(vec![address]),
+            InvocationType::Instance
+        )?;
+
+        Self::from_variant(domain, value)
+    }
+
+    /// Wraps a managed delegate, e.g. one returned by a managed method.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` that owns the delegate.
+    /// * `value` - The `VARIANT` holding the delegate. The `ClrDelegate` takes ownership of its reference.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ClrDelegate)` - The wrapped delegate.
+    /// * `Err(ClrError)` - If the value is not an object.
+    pub fn from_variant(domain: &_AppDomain, value: VARIANT) -> Result<Self, ClrError> {
+        let object = match unsafe { value.Anonymous.Anonymous.vt } {
+            VT_UNKNOWN | VT_DISPATCH => unsafe { IUnknown::from_raw(value.Anonymous.Anonymous.Anonymous.punkVal) },
+            _ => return Err(ClrError::VariantUnsupported),
+        };
+
+        let delegate = domain
+            .load_lib(&ManagedName::Mscorlib.value())?
+            .resolve_type(&ManagedName::Delegate.value())?;
+
+        Ok(Self { object, delegate })
+    }
+
+    /// Invokes the delegate through `Delegate.DynamicInvoke`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments of the delegate, in order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value returned by the delegate, or an empty `VARIANT` for `void` delegates.
+    /// * `Err(ClrError)` - If the arguments do not match the delegate or the target throws.
+    pub fn invoke(&self, args: Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        let args = if args.is_empty() { None } else { Some(args) };
+        self.delegate.invoke(&ManagedName::DynamicInvoke.value(), Some(self.variant()), args, InvocationType::Instance)
+    }
+
+    /// Converts the delegate into a Rust closure calling [`ClrDelegate::invoke`].
+    ///
+    /// # Returns
+    ///
+    /// * A closure owning the delegate.
+    pub fn into_closure(self) -> impl Fn(Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        move |args| self.invoke(args)
+    }
+
+    /// Returns the delegate as a `VARIANT`, e.g. to pass it to a managed method.
+    ///
+    /// The `VARIANT` borrows the reference held by the `ClrDelegate`, and is valid for as
+    /// long as it is alive.
+    pub fn variant(&self) -> VARIANT {
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        variant.Anonymous.Anonymous.vt = VT_UNKNOWN;
+        variant.Anonymous.Anonymous.Anonymous.punkVal = self.object.as_raw();
+
+        variant
+    }
+}
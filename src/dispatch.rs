@@ -0,0 +1,352 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref, ptr::null_mut},
+    windows_sys::{
+        core::HRESULT,
+        Win32::{
+            Foundation::{SysFreeString, DISP_E_EXCEPTION},
+            System::{
+                Com::{DISPATCH_FLAGS, DISPATCH_METHOD, DISPATCH_PROPERTYGET, DISPATCH_PROPERTYPUT, DISPPARAMS, EXCEPINFO},
+                Ole::DISPID_PROPERTYPUT,
+                Variant::{VARIANT, VT_DISPATCH, VT_UNKNOWN},
+            },
+        },
+    },
+};
+
+use crate::{
+    Variant, VariantArgs, WinStr,
+    com::IID_IDISPATCH,
+    error::ClrError,
+};
+
+/// Locale passed to `IDispatch`, as the user default (`LOCALE_USER_DEFAULT`).
+const LOCALE_USER_DEFAULT: u32 = 0x0400;
+
+/// Late-bound access to an object through its `IDispatch` interface.
+///
+/// Some objects, such as COM-visible managed classes with `ClassInterfaceType.AutoDispatch`
+/// or regular automation objects, only expose `IDispatch`. `Dispatch` resolves member names
+/// with `GetIDsOfNames` and calls them with `Invoke`, so properties and methods can be used
+/// without vtable bindings or reflection.
+///
+/// # Examples
+///
+/// ```ignore
+/// let list = Dispatch::from_variant(&mscorlib.create_instance("System.Collections.ArrayList")?)?;
+/// list.call("Add", "item")?;
+/// list.set("Capacity", 16)?;
+///
+/// assert_eq!(i32::from_variant(&list.get("Count")?)?, 1);
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct Dispatch(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `IDispatch` methods.
+impl Dispatch {
+    /// Reads a property of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value of the property.
+    /// * `Err(ClrError)` - If the property does not exist or its getter fails.
+    pub fn get(&self, name: &str) -> Result<VARIANT, ClrError> {
+        self.invoke_named(name, DISPATCH_PROPERTYGET, Vec::new(), false)
+    }
+
+    /// Writes a property of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property.
+    /// * `value` - The new value.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the property was set.
+    /// * `Err(ClrError)` - If the property does not exist, is read-only or its setter fails.
+    pub fn set(&self, name: &str, value: impl Variant) -> Result<(), ClrError> {
+        self.invoke_named(name, DISPATCH_PROPERTYPUT, vec![value.to_variant()], true).map(|_| ())
+    }
+
+    /// Calls a method of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method.
+    /// * `args` - The arguments of the method: a single `Variant` value, a tuple, a vector, or `()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value returned by the method, empty for methods returning nothing.
+    /// * `Err(ClrError)` - If the method does not exist, the arguments do not match or the call fails.
+    pub fn call(&self, name: &str, args: impl VariantArgs) -> Result<VARIANT, ClrError> {
+        self.invoke_named(name, DISPATCH_METHOD, args.to_variants(), false)
+    }
+
+    /// Resolves the name of a member into its dispatch identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the member, matched case-insensitively by most implementations.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i32)` - The `DISPID` of the member.
+    /// * `Err(ClrError)` - If the object does not expose the member (`DISP_E_UNKNOWNNAME`).
+    pub fn id_of_name(&self, name: &str) -> Result<i32, ClrError> {
+        let name = name.to_bstr();
+        let mut dispid = 0;
+        let result = self.GetIDsOfNames(&name, 1, &mut dispid);
+        unsafe { SysFreeString(name) };
+
+        result.map(|_| dispid)
+    }
+
+    /// Resolves a member and invokes it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the member.
+    /// * `flags` - The `DISPATCH_*` kind of access.
+    /// * `args` - The arguments, in declaration order.
+    /// * `put` - Whether the last argument is the value of a property put.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The result of the access.
+    /// * `Err(ClrError)` - If the member cannot be resolved or invoked.
+    fn invoke_named(&self, name: &str, flags: DISPATCH_FLAGS, mut args: Vec<VARIANT>, put: bool) -> Result<VARIANT, ClrError> {
+        let dispid = self.id_of_name(name)?;
+
+        // `IDispatch::Invoke` expects the arguments from last to first
+        args.reverse();
+        let mut named = DISPID_PROPERTYPUT;
+        let mut params = DISPPARAMS {
+            rgvarg: args.as_mut_ptr(),
+            rgdispidNamedArgs: if put { &mut named } else { null_mut() },
+            cArgs: args.len() as u32,
+            cNamedArgs: put as u32,
+        };
+
+        self.Invoke(dispid, flags, &mut params)
+    }
+
+    /// Wraps the object held by a `VARIANT`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A `VT_DISPATCH` or `VT_UNKNOWN` variant.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Dispatch)` - The `IDispatch` of the object, with its own reference.
+    /// * `Err(ClrError)` - If the variant holds no object or the object does not implement `IDispatch`.
+    pub fn from_variant(value: &VARIANT) -> Result<Dispatch, ClrError> {
+        unsafe {
+            match value.Anonymous.Anonymous.vt {
+                VT_DISPATCH | VT_UNKNOWN if !value.Anonymous.Anonymous.Anonymous.punkVal.is_null() => {
+                    let raw = value.Anonymous.Anonymous.Anonymous.punkVal;
+                    IUnknown::from_raw_borrowed(&raw)
+                        .ok_or(ClrError::NullPointerError("IDispatch"))?
+                        .cast::<Dispatch>()
+                        .map_err(|_| ClrError::CastingError("IDispatch"))
+                }
+                _ => Err(ClrError::VariantUnsupported),
+            }
+        }
+    }
+
+    /// Creates a `Dispatch` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Dispatch)` - Wraps the given COM interface as `Dispatch`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<Dispatch, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<Dispatch>().map_err(|_| ClrError::CastingError("IDispatch"))
+    }
+}
+
+/// Implementation of the original `IDispatch` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl Dispatch {
+    /// Maps member names to their dispatch identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgszNames` - Pointer to the array of names to map.
+    /// * `cNames` - The number of names.
+    /// * `rgDispId` - Pointer to the array receiving the identifiers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If every name was mapped.
+    /// * `Err(ClrError)` - If a name is unknown or the call fails.
+    pub fn GetIDsOfNames(&self, rgszNames: *const *const u16, cNames: u32, rgDispId: *mut i32) -> Result<(), ClrError> {
+        let iid_null = GUID::zeroed();
+        let hr = unsafe {
+            (Interface::vtable(self).GetIDsOfNames)(
+                Interface::as_raw(self),
+                &iid_null,
+                rgszNames,
+                cNames,
+                LOCALE_USER_DEFAULT,
+                rgDispId
+            )
+        };
+
+        if hr == 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("GetIDsOfNames", hr))
+        }
+    }
+
+    /// Invokes a member of the object by its dispatch identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `dispIdMember` - The `DISPID` of the member.
+    /// * `wFlags` - The `DISPATCH_*` kind of access.
+    /// * `pDispParams` - The arguments, from last to first.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The result of the access.
+    /// * `Err(ClrError)` - If the invocation fails or the member raises an exception.
+    pub fn Invoke(&self, dispIdMember: i32, wFlags: DISPATCH_FLAGS, pDispParams: *mut DISPPARAMS) -> Result<VARIANT, ClrError> {
+        let iid_null = GUID::zeroed();
+        let mut result = unsafe { std::mem::zeroed::<VARIANT>() };
+        let mut excep_info = unsafe { std::mem::zeroed::<EXCEPINFO>() };
+        let mut arg_err = 0;
+        let hr = unsafe {
+            (Interface::vtable(self).Invoke)(
+                Interface::as_raw(self),
+                dispIdMember,
+                &iid_null,
+                LOCALE_USER_DEFAULT,
+                wFlags,
+                pDispParams,
+                &mut result,
+                &mut excep_info,
+                &mut arg_err
+            )
+        };
+
+        if hr == 0 {
+            return Ok(result);
+        }
+
+        // Reports the HRESULT of the exception raised by the member instead of `DISP_E_EXCEPTION`
+        let hr = if hr == DISP_E_EXCEPTION && excep_info.scode != 0 { excep_info.scode } else { hr };
+        unsafe {
+            SysFreeString(excep_info.bstrSource);
+            SysFreeString(excep_info.bstrDescription);
+            SysFreeString(excep_info.bstrHelpFile);
+        }
+
+        Err(ClrError::ApiError("IDispatch::Invoke", hr))
+    }
+}
+
+unsafe impl Interface for Dispatch {
+    type Vtable = IDispatch_Vtbl;
+
+    /// The interface identifier (IID) for the `IDispatch` COM interface.
+    const IID: GUID = IID_IDISPATCH;
+}
+
+impl Deref for Dispatch {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `Dispatch` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+#[repr(C)]
+pub struct IDispatch_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Maps member names to their dispatch identifiers.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `riid` - Reserved, must be `IID_NULL`.
+    /// * `rgszNames` - Pointer to the array of names to map.
+    /// * `cNames` - The number of names.
+    /// * `lcid` - The locale of the names.
+    /// * `rgDispId` - Pointer to the array receiving the identifiers.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetIDsOfNames: unsafe extern "system" fn(
+        *mut c_void,
+        riid: *const GUID,
+        rgszNames: *const *const u16,
+        cNames: u32,
+        lcid: u32,
+        rgDispId: *mut i32
+    ) -> HRESULT,
+
+    /// Invokes a member of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `dispIdMember` - The `DISPID` of the member.
+    /// * `riid` - Reserved, must be `IID_NULL`.
+    /// * `lcid` - The locale of the arguments.
+    /// * `wFlags` - The `DISPATCH_*` kind of access.
+    /// * `pDispParams` - The arguments, from last to first.
+    /// * `pVarResult` - Pointer to the `VARIANT` receiving the result.
+    /// * `pExcepInfo` - Pointer to the exception information filled on `DISP_E_EXCEPTION`.
+    /// * `puArgErr` - Pointer receiving the index of the first invalid argument.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    Invoke: unsafe extern "system" fn(
+        *mut c_void,
+        dispIdMember: i32,
+        riid: *const GUID,
+        lcid: u32,
+        wFlags: DISPATCH_FLAGS,
+        pDispParams: *mut DISPPARAMS,
+        pVarResult: *mut VARIANT,
+        pExcepInfo: *mut EXCEPINFO,
+        puArgErr: *mut u32
+    ) -> HRESULT,
+}
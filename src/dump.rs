@@ -0,0 +1,304 @@
+use {
+    std::{
+        ffi::c_void,
+        fs::{self, File, OpenOptions},
+        ops::BitOr,
+        os::windows::io::AsRawHandle,
+        path::PathBuf,
+        process,
+        ptr::null,
+        sync::{
+            Mutex,
+            atomic::{AtomicU32, Ordering},
+            mpsc::Sender,
+        },
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    windows_sys::Win32::{
+        Foundation::{
+            EXCEPTION_ILLEGAL_INSTRUCTION, EXCEPTION_PRIV_INSTRUCTION,
+            STATUS_HEAP_CORRUPTION, STATUS_STACK_BUFFER_OVERRUN,
+        },
+        System::{
+            Diagnostics::Debug::{
+                AddVectoredExceptionHandler, MiniDumpWriteDump, RemoveVectoredExceptionHandler,
+                EXCEPTION_CONTINUE_SEARCH, EXCEPTION_POINTERS, MINIDUMP_EXCEPTION_INFORMATION,
+            },
+            Threading::{GetCurrentProcess, GetCurrentThreadId},
+        },
+    },
+};
+
+use crate::error::{ClrError, last_error};
+
+/// Dump written by the vectored exception handler while an entry point runs.
+static ARMED_DUMP: Mutex<Option<CrashDump>> = Mutex::new(None);
+
+/// Counter used to generate unique temporary dump names within the process.
+static DUMP_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+/// HRESULTs reporting a failure the runtime cannot recover from.
+const FATAL_HRESULTS: [u32; 4] = [
+    0x8013_1506, // COR_E_EXECUTIONENGINE
+    0x8013_1023, // HOST_E_CLRNOTAVAILABLE
+    0x8007_03E9, // COR_E_STACKOVERFLOW
+    0x8001_0105, // RPC_E_SERVERFAULT
+];
+
+/// Destination of a minidump written by [`CrashDump`].
+#[derive(Debug, Clone)]
+pub enum DumpTarget {
+    /// Writes the dump to a file, replacing it if it exists.
+    File(PathBuf),
+
+    /// Sends the bytes of the dump through a channel.
+    ///
+    /// The dump is written to a new, uniquely named temporary file first, which is deleted once read.
+    Channel(Sender<Vec<u8>>),
+}
+
+/// Kind of information included in a minidump, mirroring the `MINIDUMP_TYPE` enumeration of `dbghelp.h`.
+///
+/// Flags can be combined using the `|` operator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DumpType(pub i32);
+
+impl DumpType {
+    /// Stack traces, thread and module lists only.
+    pub const NORMAL: Self = Self(0x0);
+
+    /// Includes the data sections of the loaded modules.
+    pub const WITH_DATA_SEGS: Self = Self(0x1);
+
+    /// Includes all the accessible memory of the process. Required to inspect the managed heap.
+    pub const WITH_FULL_MEMORY: Self = Self(0x2);
+
+    /// Includes the handles open in the process.
+    pub const WITH_HANDLE_DATA: Self = Self(0x4);
+
+    /// Includes the modules unloaded by the process.
+    pub const WITH_UNLOADED_MODULES: Self = Self(0x20);
+
+    /// Includes the memory referenced by the stacks of the threads.
+    pub const WITH_INDIRECTLY_REFERENCED_MEMORY: Self = Self(0x40);
+
+    /// Includes the private read-write memory of the process, which holds the managed heap.
+    pub const WITH_PRIVATE_READ_WRITE_MEMORY: Self = Self(0x200);
+
+    /// Includes the layout of the whole address space.
+    pub const WITH_FULL_MEMORY_INFO: Self = Self(0x800);
+
+    /// Includes the times and affinity of the threads.
+    pub const WITH_THREAD_INFO: Self = Self(0x1000);
+}
+
+impl BitOr for DumpType {
+    type Output = Self;
+
+    /// Enables combining multiple `DumpType` flags using bitwise OR.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let dump_type = DumpType::WITH_DATA_SEGS | DumpType::WITH_HANDLE_DATA;
+    /// ```
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Minidump of the current process, written when an assembly fails fatally.
+///
+/// See [`RustClr::with_crash_dump`](crate::RustClr::with_crash_dump) for when the dump is taken.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{CrashDump, DumpTarget, DumpType};
+///
+/// let dump = CrashDump::new(DumpTarget::File("payload.dmp".into()))
+///     .with_type(DumpType::WITH_PRIVATE_READ_WRITE_MEMORY | DumpType::WITH_HANDLE_DATA);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CrashDump {
+    /// Where the dump is written.
+    target: DumpTarget,
+
+    /// Information included in the dump.
+    dump_type: DumpType,
+}
+
+impl CrashDump {
+    /// Creates a `CrashDump` writing a `DumpType::NORMAL` dump to the given target.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The `DumpTarget` receiving the dump.
+    ///
+    /// # Returns
+    ///
+    /// * A new `CrashDump`.
+    pub fn new(target: DumpTarget) -> Self {
+        Self { target, dump_type: DumpType::NORMAL }
+    }
+
+    /// Sets the information included in the dump.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump_type` - The `DumpType` flags passed to `MiniDumpWriteDump`.
+    ///
+    /// # Returns
+    ///
+    /// * The modified `CrashDump` instance.
+    pub fn with_type(mut self, dump_type: DumpType) -> Self {
+        self.dump_type = dump_type;
+        self
+    }
+
+    /// Writes a minidump of the current process to the target.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the dump was written.
+    /// * `Err(ClrError)` - If the dump or the temporary file could not be written.
+    pub fn write(&self) -> Result<(), ClrError> {
+        self.write_with(null())
+    }
+
+    /// Writes a minidump, recording the exception being dispatched if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `exception` - The exception passed to a vectored exception handler, or null.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the dump was written.
+    /// * `Err(ClrError)` - If the dump or the temporary file could not be written.
+    fn write_with(&self, exception: *const EXCEPTION_POINTERS) -> Result<(), ClrError> {
+        let (path, file) = match &self.target {
+            DumpTarget::File(path) => (path.clone(), File::create(path)),
+            DumpTarget::Channel(_) => {
+                // A unique name, created exclusively so an existing file is never reused
+                let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or_default();
+                let name = format!(
+                    "{:x}{:x}{:x}.dmp",
+                    process::id(),
+                    DUMP_COUNTER.fetch_add(1, Ordering::Relaxed),
+                    nanos
+                );
+
+                let path = std::env::temp_dir().join(name);
+                let file = OpenOptions::new().write(true).create_new(true).open(&path);
+                (path, file)
+            }
+        };
+
+        let file = file.map_err(|e| ClrError::IoError(format!("{e}")))?;
+        let information = MINIDUMP_EXCEPTION_INFORMATION {
+            ThreadId: unsafe { GetCurrentThreadId() },
+            ExceptionPointers: exception as *mut EXCEPTION_POINTERS,
+            ClientPointers: 0,
+        };
+
+        let written = unsafe {
+            MiniDumpWriteDump(
+                GetCurrentProcess(),
+                process::id(),
+                file.as_raw_handle(),
+                self.dump_type.0,
+                if exception.is_null() { null() } else { &information },
+                null(),
+                null()
+            )
+        };
+
+        // Captures the error before closing the file resets it
+        let result = if written == 0 { Err(last_error("MiniDumpWriteDump")) } else { Ok(()) };
+        drop(file);
+
+        let DumpTarget::Channel(sender) = &self.target else {
+            return result;
+        };
+
+        let bytes = fs::read(&path).map_err(|e| ClrError::IoError(format!("{e}")));
+        let _ = fs::remove_file(&path);
+        result?;
+
+        let _ = sender.send(bytes?);
+        Ok(())
+    }
+
+    /// Writes the dump if the error reports a fatal failure of the runtime.
+    ///
+    /// A failure to write the dump is ignored, so the original error is reported.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The error returned by the entry point.
+    pub(crate) fn write_if_fatal(&self, error: &ClrError) {
+        if let Some(hr) = error.hresult() {
+            if FATAL_HRESULTS.contains(&(hr as u32)) {
+                let _ = self.write();
+            }
+        }
+    }
+}
+
+/// Vectored exception handler armed by a [`CrashDump`] while an entry point runs, released when dropped.
+pub(crate) struct DumpGuard {
+    /// Handle returned by `AddVectoredExceptionHandler`.
+    handler: *mut c_void,
+}
+
+impl DumpGuard {
+    /// Arms the handler writing the dump when a fatal structured exception is raised.
+    ///
+    /// Access violations are not covered, since the runtime raises them for every
+    /// `NullReferenceException`, and stack overflows leave no stack to write the dump from.
+    ///
+    /// # Arguments
+    ///
+    /// * `dump` - The `CrashDump` to write.
+    ///
+    /// # Returns
+    ///
+    /// * A new `DumpGuard`.
+    pub(crate) fn arm(dump: &CrashDump) -> Self {
+        *ARMED_DUMP.lock().unwrap_or_else(|e| e.into_inner()) = Some(dump.clone());
+        Self { handler: unsafe { AddVectoredExceptionHandler(1, Some(dump_handler)) } }
+    }
+}
+
+/// Implements the `Drop` trait to remove the vectored exception handler.
+impl Drop for DumpGuard {
+    fn drop(&mut self) {
+        if !self.handler.is_null() {
+            unsafe { RemoveVectoredExceptionHandler(self.handler) };
+        }
+
+        ARMED_DUMP.lock().unwrap_or_else(|e| e.into_inner()).take();
+    }
+}
+
+/// Writes the armed dump once when a fatal structured exception is raised.
+///
+/// # Arguments
+///
+/// * `exception` - The exception being dispatched.
+///
+/// # Returns
+///
+/// * `EXCEPTION_CONTINUE_SEARCH`, so the exception is handled as if the handler did not exist.
+unsafe extern "system" fn dump_handler(exception: *mut EXCEPTION_POINTERS) -> i32 {
+    let code = (*(*exception).ExceptionRecord).ExceptionCode;
+    if matches!(code, EXCEPTION_ILLEGAL_INSTRUCTION | EXCEPTION_PRIV_INSTRUCTION | STATUS_HEAP_CORRUPTION | STATUS_STACK_BUFFER_OVERRUN) {
+        // Takes the dump out, so nested or repeated exceptions do not write it again
+        if let Some(dump) = ARMED_DUMP.try_lock().ok().and_then(|mut dump| dump.take()) {
+            let _ = dump.write_with(exception);
+        }
+    }
+
+    EXCEPTION_CONTINUE_SEARCH
+}
@@ -0,0 +1,266 @@
+use {
+    std::ops::Deref,
+    windows_sys::Win32::System::Variant::{VARIANT, VT_ARRAY, VT_EMPTY, VT_NULL, VT_UI1},
+};
+
+use crate::{
+    Variant, Instance, InvocationType, ManagedName, OwnedVariant, SafeArray,
+    error::ClrError,
+    schema::{_AppDomain, _Assembly},
+};
+
+/// Prefix of the manifest resources holding the dependencies packed by Costura.
+const COSTURA_PREFIX: &str = "costura.";
+
+/// Suffix of the resources Costura compressed with `DeflateStream`.
+const COMPRESSED_SUFFIX: &str = ".compressed";
+
+/// A managed dependency packed into an assembly as a manifest resource.
+///
+/// Costura embeds the references of single-file tools as `costura.<file>` resources,
+/// lowercased and compressed with `DeflateStream` unless disabled. Native libraries
+/// (`costura32.`/`costura64.`) and debug symbols are not reported.
+///
+/// # Examples
+///
+/// ```ignore
+/// let assembly = clr.app_domain.load_assembly(&buffer)?;
+/// for dependency in assembly.embedded_dependencies()? {
+///     let bytes = dependency.extract(&clr.app_domain, &assembly)?;
+///     std::fs::write(&dependency.file_name, bytes)?;
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedDependency {
+    /// Name of the manifest resource (e.g. `costura.newtonsoft.json.dll.compressed`).
+    pub resource: String,
+
+    /// File name of the dependency, as stored by the packer (e.g. `newtonsoft.json.dll`).
+    pub file_name: String,
+
+    /// Whether the resource is compressed with `DeflateStream`.
+    pub compressed: bool,
+}
+
+impl EmbeddedDependency {
+    /// Recognizes a manifest resource holding a packed dependency.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource` - The name of the manifest resource.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(EmbeddedDependency)` - If the resource holds a managed `.dll` or `.exe`.
+    /// * `None` - For any other resource.
+    pub fn from_resource(resource: &str) -> Option<Self> {
+        let file_name = resource.strip_prefix(COSTURA_PREFIX)?;
+        let (file_name, compressed) = match file_name.strip_suffix(COMPRESSED_SUFFIX) {
+            Some(file_name) => (file_name, true),
+            None => (file_name, false),
+        };
+
+        if !file_name.ends_with(".dll") && !file_name.ends_with(".exe") {
+            return None;
+        }
+
+        Some(Self {
+            resource: resource.to_string(),
+            file_name: file_name.to_string(),
+            compressed,
+        })
+    }
+
+    /// Reads the dependency out of the assembly embedding it, decompressing it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` in which `assembly` is loaded.
+    /// * `assembly` - The `_Assembly` embedding the dependency.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The image of the dependency.
+    /// * `Err(ClrError)` - If the resource does not exist or cannot be decompressed.
+    pub fn extract(&self, domain: &_AppDomain, assembly: &_Assembly) -> Result<Vec<u8>, ClrError> {
+        let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+        let mut stream = assembly.GetType()?.invoke(
+            &ManagedName::GetManifestResourceStream.value(),
+            Some(assembly.to_variant()),
+            Some(vec![self.resource.to_variant()]),
+            InvocationType::Instance
+        )?;
+
+        if matches!(unsafe { stream.Anonymous.Anonymous.vt }, VT_EMPTY | VT_NULL) {
+            return Err(ClrError::NullPointerError("GetManifestResourceStream"));
+        }
+
+        if self.compressed {
+            let system = domain.load_lib(&ManagedName::System.value())?;
+            let decompress = system
+                .resolve_type(&ManagedName::CompressionMode.value())?
+                .enum_from_value(0)?;
+
+            stream = system.create_instance_with_args(&ManagedName::DeflateStream.value(), vec![stream, decompress])?;
+        }
+
+        // Copies the stream into a `MemoryStream` and reads it back as a `byte[]`
+        let memory = mscorlib.create_instance(&ManagedName::MemoryStream.value())?;
+        mscorlib.resolve_type(&ManagedName::Stream.value())?.invoke(
+            &ManagedName::CopyTo.value(),
+            Some(stream),
+            Some(vec![memory]),
+            InvocationType::Instance
+        )?;
+
+        let bytes = mscorlib.resolve_type(&ManagedName::MemoryStream.value())?.invoke(
+            &ManagedName::ToArray.value(),
+            Some(memory),
+            None,
+            InvocationType::Instance
+        )?;
+
+        byte_array(bytes)
+    }
+}
+
+/// Loads the dependencies packed into an assembly and resolves its references to them.
+///
+/// Each [`EmbeddedDependency`] is extracted and loaded into the domain, then an
+/// `AppDomain.AssemblyResolve` handler returns the loaded assembly whenever the runtime
+/// fails to bind a reference with the same simple name. The handler is compiled from an
+/// expression tree, so it runs without calling back into Rust and stays registered for
+/// the lifetime of the domain. It is registered once per domain: later calls add their
+/// dependencies to the table it reads, kept in the data of the domain.
+///
+/// # Arguments
+///
+/// * `domain` - The `_AppDomain` in which `assembly` is loaded.
+/// * `assembly` - The `_Assembly` embedding the dependencies.
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The full names of the loaded dependencies, empty if the assembly is not packed.
+/// * `Err(ClrError)` - If a dependency cannot be extracted or loaded, or the handler cannot be
+///   created (`System.Core` is not available on .NET 2.0).
+///
+/// # Examples
+///
+/// ```ignore
+/// let assembly = clr.app_domain.load_assembly(&buffer)?;
+/// for name in register_embedded_dependencies(&clr.app_domain, &assembly)? {
+///     println!("registered {name}");
+/// }
+/// ```
+pub fn register_embedded_dependencies(domain: &_AppDomain, assembly: &_Assembly) -> Result<Vec<String>, ClrError> {
+    let dependencies = assembly.embedded_dependencies()?;
+    if dependencies.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Reuses the table of the handler registered by a previous run in the domain
+    let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+    let domain_type = domain.GetType()?;
+    let existing = domain_type.invoke(
+        &ManagedName::GetData.value(),
+        Some(domain.to_variant()),
+        Some(vec![ManagedName::DependencyTable.value().to_variant()]),
+        InvocationType::Instance
+    )?;
+
+    let registered = !matches!(unsafe { existing.Anonymous.Anonymous.vt }, VT_EMPTY | VT_NULL);
+    let table = if registered {
+        Instance::new(existing, mscorlib.resolve_type(&ManagedName::Hashtable.value())?)
+    } else {
+        mscorlib.new(&ManagedName::Hashtable.value())?
+    };
+
+    // Maps the simple name of each dependency to its loaded assembly
+    let mut names = Vec::new();
+    for dependency in &dependencies {
+        let loaded = domain.load_assembly(&dependency.extract(domain, assembly)?)?;
+        let name = loaded.get_FullName()?;
+        let simple_name = name.split(',').next().unwrap_or_default();
+
+        table.set_item(simple_name, loaded.deref().clone())?;
+        names.push(name);
+    }
+
+    if registered {
+        return Ok(names);
+    }
+
+    let core = domain.load_lib(&ManagedName::SystemCore.value())?;
+    let expression = core.resolve_type(&ManagedName::Expression.value())?;
+    let lambda = core.resolve_type(&ManagedName::LambdaExpression.value())?;
+    let build = |name: ManagedName, args: Vec<VARIANT>| {
+        expression.invoke(&name.value(), None, Some(args), InvocationType::Static)
+    };
+
+    // Compiles `(sender, args) => (Assembly) table[new AssemblyName(args.Name).Name]`
+    let object = mscorlib.resolve_type(&ManagedName::Object.value())?;
+    let event_args = mscorlib.resolve_type(&ManagedName::ResolveEventArgs.value())?;
+    let assembly_name = mscorlib
+        .resolve_type(&ManagedName::AssemblyName.value())?
+        .constructor(1)?;
+
+    let sender = build(ManagedName::Parameter, vec![object.to_variant(), "sender".to_variant()])?;
+    let args = build(ManagedName::Parameter, vec![event_args.to_variant(), "args".to_variant()])?;
+    let requested = build(ManagedName::Property, vec![args, ManagedName::Name.value().to_variant()])?;
+    let parsed = build(ManagedName::New, vec![assembly_name.to_variant(), requested])?;
+    let simple_name = build(ManagedName::Property, vec![parsed, ManagedName::Name.value().to_variant()])?;
+    let target = build(ManagedName::Constant, vec![table.variant()])?;
+    let lookup = build(ManagedName::Property, vec![target, ManagedName::Item.value().to_variant(), simple_name])?;
+    let body = build(ManagedName::Convert, vec![
+        lookup,
+        mscorlib.resolve_type(&ManagedName::Assembly.value())?.to_variant()
+    ])?;
+
+    let handler = build(ManagedName::Lambda, vec![
+        mscorlib.resolve_type(&ManagedName::ResolveEventHandler.value())?.to_variant(),
+        body,
+        sender,
+        args
+    ])?;
+
+    let handler = lambda.invoke(&ManagedName::Compile.value(), Some(handler), None, InvocationType::Instance)?;
+    domain_type.invoke(
+        &ManagedName::AddAssemblyResolve.value(),
+        Some(domain.to_variant()),
+        Some(vec![handler]),
+        InvocationType::Instance
+    )?;
+
+    // Marks the handler as registered for the next runs in the domain
+    domain_type.invoke(
+        &ManagedName::SetData.value(),
+        Some(domain.to_variant()),
+        Some(vec![ManagedName::DependencyTable.value().to_variant(), table.variant()]),
+        InvocationType::Instance
+    )?;
+
+    Ok(names)
+}
+
+/// Copies a managed `byte[]` returned through a `VARIANT`, releasing the array.
+///
+/// # Arguments
+///
+/// * `value` - A `VT_ARRAY | VT_UI1` variant.
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The bytes of the array.
+/// * `Err(ClrError)` - If the variant does not hold a byte array or it cannot be read.
+fn byte_array(value: VARIANT) -> Result<Vec<u8>, ClrError> {
+    // Released on every path, unless the array is taken out of it
+    let mut value = OwnedVariant(value);
+    let psa = unsafe { value.0.Anonymous.Anonymous.Anonymous.parray };
+    if unsafe { value.0.Anonymous.Anonymous.vt } != VT_ARRAY | VT_UI1 || psa.is_null() {
+        return Err(ClrError::VariantUnsupported);
+    }
+
+    let array = unsafe { SafeArray::from_raw(psa) };
+    value.0.Anonymous.Anonymous.vt = VT_EMPTY;
+    array.to_bytes()
+}
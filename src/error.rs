@@ -1,4 +1,8 @@
-use thiserror::Error;
+use {
+    thiserror::Error,
+    crate::schema::ICLRRuntimeInfo,
+    windows_sys::Win32::Foundation::GetLastError,
+};
 
 /// Represents errors that can occur when interacting with the .NET runtime 
 /// or while handling .NET-related operations within an unmanaged application.
@@ -13,6 +17,16 @@ pub enum ClrError {
     #[error("{0} Failed With HRESULT: {1}")]
     ApiError(&'static str, i32),
 
+    /// Raised instead of `ApiError` when the loaded runtime has a message for the HRESULT.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The name of the API that failed.
+    /// * `{1}` - The HRESULT code returned by the API indicating the specific failure.
+    /// * `{2}` - The message resolved with `ICLRRuntimeInfo::LoadErrorString`.
+    #[error("{0} Failed With HRESULT: {1} ({2})")]
+    DescribedApiError(&'static str, i32, String),
+
     /// Raised when an entry point expects arguments but receives none.
     #[error("Entrypoint is waiting for arguments, but has been supplied with zero")]
     MissingArguments,
@@ -29,6 +43,15 @@ pub enum ClrError {
     #[error("The buffer does not represent a valid executable")]
     InvalidExecutable,
 
+    /// Raised when an instance of a type cannot be created.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The name of the type.
+    /// * `{1}` - The reason the type cannot be instantiated.
+    #[error("Cannot create an instance of {0}: {1}")]
+    CreateInstanceError(String, &'static str),
+
     /// Raised when a required method is not found in the .NET assembly.
     #[error("Method not found")]
     MethodNotFound,
@@ -37,6 +60,27 @@ pub enum ClrError {
     #[error("The executable is not a .NET application")]
     NotDotNet,
 
+    /// Raised when the manifest of the assembly requires administrator rights and the
+    /// process token is not elevated.
+    #[error("The assembly requires administrator rights, but the process is not elevated")]
+    RequiresElevation,
+
+    /// Raised when the CLI metadata of an assembly is missing or malformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The part of the metadata that could not be read.
+    #[error("Invalid CLI metadata: {0}")]
+    InvalidMetadata(&'static str),
+
+    /// Raised when a string does not name a supported runtime version.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - The version string that could not be parsed.
+    #[error("Unsupported runtime version: {0}")]
+    UnsupportedRuntimeVersion(String),
+
     /// Raised when there is a failure creating the .NET MetaHost.
     ///
     /// # Arguments
@@ -101,9 +145,29 @@ pub enum ClrError {
     #[error("Error creating SafeArray: {0}")]
     SafeArrayError(String),
 
+    /// Raised when a file system operation fails.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - A message describing the I/O error.
+    #[error("I/O error: {0}")]
+    IoError(String),
+
     /// Raised when the type of a VARIANT is unsupported by the current context.
     #[error("Type of VARIANT not supported")]
     VariantUnsupported,
+
+    /// Raised when a value cannot be represented as a `System.Decimal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `{0}` - A message describing why the value is not a valid decimal.
+    #[error("Invalid decimal: {0}")]
+    InvalidDecimal(String),
+
+    /// Raised when a `ClrHandle` does not refer to a registered object, e.g. after being released.
+    #[error("The handle does not refer to a registered managed object")]
+    InvalidHandle,
     
     /// Represents a generic error specific to the CLR.
     ///
@@ -113,3 +177,293 @@ pub enum ClrError {
     #[error("{0}")]
     ErrorClr(&'static str),
 }
+
+impl ClrError {
+    /// Returns the stable code of the error, to branch on its cause without matching messages.
+    ///
+    /// `ApiError`s are classified by their HRESULT (and by the API for host configuration
+    /// calls), so e.g. an `E_ACCESSDENIED` returned by `SetHostControl` maps to
+    /// [`ErrorCode::HostControlRejected`]. Unclassified HRESULTs map to [`ErrorCode::ApiFailure`].
+    ///
+    /// # Returns
+    ///
+    /// * The `ErrorCode` of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// match clr.run() {
+    ///     Err(error) if error.code() == ErrorCode::RuntimeAlreadyStarted => { /* reuse the runtime */ }
+    ///     Err(error) => eprintln!("[{}] {error}", error.code()),
+    ///     Ok(output) => println!("{output}"),
+    /// }
+    /// ```
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ClrError::ApiError(api, hr) | ClrError::DescribedApiError(api, hr, _) => ErrorCode::from_hresult(api, *hr),
+            ClrError::MissingArguments => ErrorCode::MissingArguments,
+            ClrError::CastingError(_) => ErrorCode::CastFailed,
+            ClrError::InvalidExecutable => ErrorCode::InvalidExecutable,
+            ClrError::CreateInstanceError(..) => ErrorCode::CreateInstanceFailed,
+            ClrError::MethodNotFound => ErrorCode::MemberNotFound,
+            ClrError::NotDotNet => ErrorCode::NotDotNet,
+            ClrError::RequiresElevation => ErrorCode::RequiresElevation,
+            ClrError::InvalidMetadata(_) => ErrorCode::InvalidMetadata,
+            ClrError::UnsupportedRuntimeVersion(_) => ErrorCode::UnsupportedRuntimeVersion,
+            ClrError::MetaHostCreationError(_) => ErrorCode::MetaHostUnavailable,
+            ClrError::RuntimeInfoError(_) => ErrorCode::RuntimeUnavailable,
+            ClrError::RuntimeHostError(_) => ErrorCode::RuntimeHostUnavailable,
+            ClrError::RuntimeStartError => ErrorCode::RuntimeStartFailed,
+            ClrError::DomainCreationError(_) => ErrorCode::DomainCreationFailed,
+            ClrError::DefaultDomainError(_) | ClrError::NoDomainAvailable => ErrorCode::NoDomainAvailable,
+            ClrError::NullPointerError(_) => ErrorCode::NullPointer,
+            ClrError::SafeArrayError(_) => ErrorCode::SafeArrayFailed,
+            ClrError::IoError(_) => ErrorCode::Io,
+            ClrError::VariantUnsupported => ErrorCode::VariantUnsupported,
+            ClrError::InvalidDecimal(_) => ErrorCode::InvalidArgument,
+            ClrError::InvalidHandle => ErrorCode::InvalidHandle,
+            ClrError::ErrorClr(_) => ErrorCode::Other,
+        }
+    }
+
+    /// Returns the HRESULT of an `ApiError`, or `None` for errors raised by the crate itself.
+    pub fn hresult(&self) -> Option<i32> {
+        match self {
+            ClrError::ApiError(_, hr) | ClrError::DescribedApiError(_, hr, _) => Some(*hr),
+            _ => None,
+        }
+    }
+
+    /// Attaches the message of the runtime to an `ApiError`, as a `DescribedApiError`.
+    ///
+    /// # Arguments
+    ///
+    /// * `runtime_info` - The `ICLRRuntimeInfo` of the runtime that reported the error.
+    ///
+    /// # Returns
+    ///
+    /// * The described error, or the error unchanged if the runtime has no message for it.
+    pub(crate) fn describe(self, runtime_info: &ICLRRuntimeInfo) -> Self {
+        match self {
+            ClrError::ApiError(api, hr) => match runtime_info.error_string(hr) {
+                Some(message) => ClrError::DescribedApiError(api, hr, message),
+                None => self,
+            },
+            error => error,
+        }
+    }
+}
+
+/// Stable codes identifying the cause of a [`ClrError`], returned by [`ClrError::code`].
+///
+/// The numeric values and names are part of the public API: a code is never renumbered or
+/// reused, and new codes are only added, bumping [`ErrorCode::REVISION`]. Callers matching on
+/// the enum need a wildcard arm, as it is `#[non_exhaustive]`.
+///
+/// Codes `1..100` mirror the variants of `ClrError`, and codes from `100` classify the
+/// HRESULT of an [`ClrError::ApiError`].
+#[non_exhaustive]
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCode {
+    /// An API failed with an HRESULT that has no more specific code.
+    ApiFailure = 1,
+
+    /// The entry point expects arguments but none were supplied.
+    MissingArguments = 2,
+
+    /// A COM interface could not be cast to the expected type.
+    CastFailed = 3,
+
+    /// The buffer is not a valid executable.
+    InvalidExecutable = 4,
+
+    /// An instance of a type could not be created.
+    CreateInstanceFailed = 5,
+
+    /// A method, property or field does not exist (`MissingMemberException`, `DISP_E_UNKNOWNNAME`).
+    MemberNotFound = 6,
+
+    /// The executable is not a .NET application.
+    NotDotNet = 7,
+
+    /// The assembly requires administrator rights and the process is not elevated.
+    RequiresElevation = 8,
+
+    /// The CLI metadata of the assembly is missing or malformed.
+    InvalidMetadata = 9,
+
+    /// The requested runtime version is not supported.
+    UnsupportedRuntimeVersion = 10,
+
+    /// The `ICLRMetaHost` could not be created.
+    MetaHostUnavailable = 11,
+
+    /// The requested runtime is not installed or cannot be loaded.
+    RuntimeUnavailable = 12,
+
+    /// The runtime host interface could not be obtained.
+    RuntimeHostUnavailable = 13,
+
+    /// The runtime failed to start.
+    RuntimeStartFailed = 14,
+
+    /// An application domain could not be created.
+    DomainCreationFailed = 15,
+
+    /// No application domain is available.
+    NoDomainAvailable = 16,
+
+    /// An API returned a null pointer, e.g. a type or member lookup found nothing.
+    NullPointer = 17,
+
+    /// A `SAFEARRAY` could not be created or accessed.
+    SafeArrayFailed = 18,
+
+    /// A file system operation failed.
+    Io = 19,
+
+    /// The type of a `VARIANT` is not supported.
+    VariantUnsupported = 20,
+
+    /// A `ClrHandle` does not refer to a registered object.
+    InvalidHandle = 21,
+
+    /// Any other error raised by the crate.
+    Other = 22,
+
+    /// The operation requires a runtime that is not started yet (`HOST_E_INVALIDOPERATION`).
+    RuntimeAlreadyStarted = 100,
+
+    /// The runtime refused the host control (`SetHostControl`), typically with `E_ACCESSDENIED`
+    /// (-2147024891) because the runtime is already started or another host control is set.
+    HostControlRejected = 101,
+
+    /// Access was denied (`E_ACCESSDENIED`).
+    AccessDenied = 102,
+
+    /// The invoked managed code threw an exception (`TargetInvocationException`).
+    ManagedException = 103,
+
+    /// A file or assembly was not found (`FileNotFoundException`).
+    FileNotFound = 104,
+
+    /// A file or assembly has an invalid format (`BadImageFormatException`).
+    BadImageFormat = 105,
+
+    /// A type could not be loaded (`TypeLoadException`).
+    TypeLoadFailed = 106,
+
+    /// An argument was invalid or of the wrong type (`E_INVALIDARG`, `DISP_E_TYPEMISMATCH`).
+    InvalidArgument = 107,
+
+    /// Memory was exhausted (`E_OUTOFMEMORY`, `OutOfMemoryException`).
+    OutOfMemory = 108,
+}
+
+impl ErrorCode {
+    /// Revision of the set of codes, incremented whenever codes are added.
+    pub const REVISION: u32 = 1;
+
+    /// Returns the numeric value of the code.
+    pub fn value(self) -> u32 {
+        self as u32
+    }
+
+    /// Returns the stable name of the code, e.g. `runtime_already_started`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ErrorCode::ApiFailure => "api_failure",
+            ErrorCode::MissingArguments => "missing_arguments",
+            ErrorCode::CastFailed => "cast_failed",
+            ErrorCode::InvalidExecutable => "invalid_executable",
+            ErrorCode::CreateInstanceFailed => "create_instance_failed",
+            ErrorCode::MemberNotFound => "member_not_found",
+            ErrorCode::NotDotNet => "not_dotnet",
+            ErrorCode::RequiresElevation => "requires_elevation",
+            ErrorCode::InvalidMetadata => "invalid_metadata",
+            ErrorCode::UnsupportedRuntimeVersion => "unsupported_runtime_version",
+            ErrorCode::MetaHostUnavailable => "metahost_unavailable",
+            ErrorCode::RuntimeUnavailable => "runtime_unavailable",
+            ErrorCode::RuntimeHostUnavailable => "runtime_host_unavailable",
+            ErrorCode::RuntimeStartFailed => "runtime_start_failed",
+            ErrorCode::DomainCreationFailed => "domain_creation_failed",
+            ErrorCode::NoDomainAvailable => "no_domain_available",
+            ErrorCode::NullPointer => "null_pointer",
+            ErrorCode::SafeArrayFailed => "safearray_failed",
+            ErrorCode::Io => "io",
+            ErrorCode::VariantUnsupported => "variant_unsupported",
+            ErrorCode::InvalidHandle => "invalid_handle",
+            ErrorCode::Other => "other",
+            ErrorCode::RuntimeAlreadyStarted => "runtime_already_started",
+            ErrorCode::HostControlRejected => "host_control_rejected",
+            ErrorCode::AccessDenied => "access_denied",
+            ErrorCode::ManagedException => "managed_exception",
+            ErrorCode::FileNotFound => "file_not_found",
+            ErrorCode::BadImageFormat => "bad_image_format",
+            ErrorCode::TypeLoadFailed => "type_load_failed",
+            ErrorCode::InvalidArgument => "invalid_argument",
+            ErrorCode::OutOfMemory => "out_of_memory",
+        }
+    }
+
+    /// Classifies the HRESULT returned by an API.
+    ///
+    /// # Arguments
+    ///
+    /// * `api` - The name of the API that failed.
+    /// * `hr` - The HRESULT it returned.
+    ///
+    /// # Returns
+    ///
+    /// * The most specific `ErrorCode`, `ErrorCode::ApiFailure` if the HRESULT is not classified.
+    fn from_hresult(api: &str, hr: i32) -> ErrorCode {
+        match (api, hr as u32) {
+            ("SetHostControl", _) => ErrorCode::HostControlRejected,
+            (_, 0x8013_1022) => ErrorCode::RuntimeAlreadyStarted,
+            (_, 0x8007_0005) => ErrorCode::AccessDenied,
+            (_, 0x8013_1604) => ErrorCode::ManagedException,
+            (_, 0x8013_1511 | 0x8013_1512 | 0x8013_1513 | 0x8002_0003 | 0x8002_0006) => ErrorCode::MemberNotFound,
+            (_, 0x8007_0002 | 0x8007_0003) => ErrorCode::FileNotFound,
+            (_, 0x8007_000B) => ErrorCode::BadImageFormat,
+            (_, 0x8013_1522) => ErrorCode::TypeLoadFailed,
+            (_, 0x8007_0057 | 0x8002_0005 | 0x8013_1537) => ErrorCode::InvalidArgument,
+            (_, 0x8007_000E) => ErrorCode::OutOfMemory,
+            _ => ErrorCode::ApiFailure,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    /// Formats the code as `E<value>:<name>`, e.g. `E101:host_control_rejected`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "E{}:{}", self.value(), self.name())
+    }
+}
+
+/// Builds a `ClrError::ApiError` from the last Win32 error of the calling thread.
+///
+/// # Arguments
+///
+/// * `api` - The name of the Win32 API that failed.
+///
+/// # Returns
+///
+/// * A `ClrError::ApiError` holding the error as an HRESULT (`HRESULT_FROM_WIN32`).
+pub(crate) fn last_error(api: &'static str) -> ClrError {
+    win32_error(api, unsafe { GetLastError() })
+}
+
+/// Builds a `ClrError::ApiError` from a Win32 error code returned by an API.
+///
+/// # Arguments
+///
+/// * `api` - The name of the Win32 API that failed.
+/// * `error` - The `WIN32_ERROR` it returned.
+///
+/// # Returns
+///
+/// * A `ClrError::ApiError` holding the error as an HRESULT (`HRESULT_FROM_WIN32`).
+pub(crate) fn win32_error(api: &'static str, error: u32) -> ClrError {
+    ClrError::ApiError(api, ((error & 0xFFFF) | 0x8007_0000) as i32)
+}
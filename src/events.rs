@@ -0,0 +1,70 @@
+use std::fmt;
+
+/// A progress event emitted while [`RustClr`](crate::RustClr) runs an assembly.
+///
+/// Events are sent in the order below through the channel set with
+/// [`RustClr::with_event_sender`](crate::RustClr::with_event_sender). A run that fails
+/// ends with [`ExecutionEvent::Failed`] instead of [`ExecutionEvent::Completed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionEvent {
+    /// The runtime is started and the application domain is ready.
+    RuntimeStarted,
+
+    /// The assembly was loaded into the application domain.
+    AssemblyLoaded {
+        /// Full name of the loaded assembly.
+        name: String,
+    },
+
+    /// A dependency packed into the assembly was loaded, see
+    /// [`RustClr::with_embedded_dependencies`](crate::RustClr::with_embedded_dependencies).
+    DependencyLoaded {
+        /// Full name of the loaded dependency.
+        name: String,
+    },
+
+    /// The entry point is about to be invoked.
+    EntryInvoked,
+
+    /// The entry point returned.
+    EntryReturned,
+
+    /// The redirected output was captured.
+    OutputCaptured {
+        /// Size of the output in bytes (UTF-8).
+        bytes: u64,
+    },
+
+    /// A step after the entry point failed without affecting the result of the run,
+    /// such as removing the exception observer.
+    Warning {
+        /// Description of the error.
+        error: String,
+    },
+
+    /// The run finished successfully.
+    Completed,
+
+    /// The run stopped with an error.
+    Failed {
+        /// Description of the error.
+        error: String,
+    },
+}
+
+impl fmt::Display for ExecutionEvent {
+    /// Formats the event as a short progress message.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionEvent::RuntimeStarted => write!(f, "runtime started"),
+            ExecutionEvent::AssemblyLoaded { name } => write!(f, "assembly loaded: {name}"),
+            ExecutionEvent::DependencyLoaded { name } => write!(f, "dependency loaded: {name}"),
+            ExecutionEvent::EntryInvoked => write!(f, "entry invoked"),
+            ExecutionEvent::EntryReturned => write!(f, "entry returned"),
+            ExecutionEvent::OutputCaptured { bytes } => write!(f, "captured {bytes} bytes of output"),
+            ExecutionEvent::Warning { error } => write!(f, "warning: {error}"),
+            ExecutionEvent::Completed => write!(f, "completed"),
+            ExecutionEvent::Failed { error } => write!(f, "failed: {error}"),
+        }
+    }
+}
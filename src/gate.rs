@@ -0,0 +1,152 @@
+use std::{
+    sync::{Condvar, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// Process-wide gate shared by every [`RustClr`](crate::RustClr) execution.
+static GATE: Gate = Gate {
+    state: Mutex::new(GateState {
+        limits: ExecutionLimits::UNLIMITED,
+        running: 0,
+        last_start: None,
+    }),
+    released: Condvar::new(),
+};
+
+/// Serializes the activation of runtimes and the creation of their domains.
+static RUNTIME_INIT: Mutex<()> = Mutex::new(());
+
+/// Limits applied to the executions started by [`RustClr`](crate::RustClr) in the process.
+///
+/// Executions that exceed the limits wait until they are allowed to start, so multi-threaded
+/// hosts can share the runtime without overlapping more executions than they can afford.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{set_execution_limits, ExecutionLimits};
+/// use std::time::Duration;
+///
+/// // One execution at a time, at most one start per second
+/// set_execution_limits(ExecutionLimits {
+///     max_concurrent: 1,
+///     min_interval: Duration::from_secs(1),
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionLimits {
+    /// Maximum number of executions running at the same time, or `0` for no limit.
+    pub max_concurrent: usize,
+
+    /// Minimum delay between the start of two executions.
+    pub min_interval: Duration,
+}
+
+impl ExecutionLimits {
+    /// No limit on concurrency or frequency, the default.
+    pub const UNLIMITED: Self = Self { max_concurrent: 0, min_interval: Duration::ZERO };
+}
+
+impl Default for ExecutionLimits {
+    /// Provides `ExecutionLimits::UNLIMITED`.
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// Sets the limits applied to the executions started from now on.
+///
+/// Executions already running are not affected, but count towards the new concurrency limit.
+///
+/// # Arguments
+///
+/// * `limits` - The `ExecutionLimits` to enforce.
+pub fn set_execution_limits(limits: ExecutionLimits) {
+    GATE.lock().limits = limits;
+    GATE.released.notify_all();
+}
+
+/// Returns the limits currently applied to executions.
+pub fn execution_limits() -> ExecutionLimits {
+    GATE.lock().limits
+}
+
+/// Counters of the executions admitted by the gate.
+struct GateState {
+    /// The limits to enforce.
+    limits: ExecutionLimits,
+
+    /// Number of executions currently running.
+    running: usize,
+
+    /// When the last execution started.
+    last_start: Option<Instant>,
+}
+
+/// Counters protected by a lock, with a condition signaled when an execution ends.
+struct Gate {
+    /// The counters of the gate.
+    state: Mutex<GateState>,
+
+    /// Signaled when an execution ends or the limits change.
+    released: Condvar,
+}
+
+impl Gate {
+    /// Locks the counters, ignoring the poisoning left by a panicking execution.
+    fn lock(&self) -> MutexGuard<'_, GateState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Admission of an execution by the gate, released when dropped.
+pub(crate) struct ExecutionPermit(());
+
+impl ExecutionPermit {
+    /// Waits until the limits allow a new execution to start.
+    ///
+    /// # Returns
+    ///
+    /// * The permit of the execution.
+    pub(crate) fn acquire() -> Self {
+        let mut state = GATE.lock();
+        loop {
+            let limits = state.limits;
+            if limits.max_concurrent != 0 && state.running >= limits.max_concurrent {
+                state = GATE.released.wait(state).unwrap_or_else(|e| e.into_inner());
+                continue;
+            }
+
+            let wait = state
+                .last_start
+                .map(|last| limits.min_interval.saturating_sub(last.elapsed()))
+                .unwrap_or_default();
+
+            if !wait.is_zero() {
+                state = GATE.released.wait_timeout(state, wait).unwrap_or_else(|e| e.into_inner()).0;
+                continue;
+            }
+
+            state.running += 1;
+            state.last_start = Some(Instant::now());
+            return Self(());
+        }
+    }
+}
+
+/// Implements the `Drop` trait to let a waiting execution start.
+impl Drop for ExecutionPermit {
+    fn drop(&mut self) {
+        GATE.lock().running -= 1;
+        GATE.released.notify_all();
+    }
+}
+
+/// Locks the runtime initialization, so runtimes are never activated by two threads at once.
+///
+/// # Returns
+///
+/// * The guard holding the lock until the runtime and its domain are ready.
+pub(crate) fn lock_runtime_init() -> MutexGuard<'static, ()> {
+    RUNTIME_INIT.lock().unwrap_or_else(|e| e.into_inner())
+}
@@ -0,0 +1,252 @@
+use {
+    std::sync::atomic::{AtomicU64, Ordering},
+    windows_sys::Win32::{
+        Foundation::VARIANT_FALSE,
+        System::Variant::{
+            VariantClear, VariantCopy, VARIANT,
+            VT_I4, VT_I8, VT_INT, VT_UI4, VT_UI8, VT_UINT,
+        },
+    },
+};
+
+use crate::{
+    Variant, InvocationType, ManagedName,
+    error::ClrError,
+    schema::_AppDomain,
+};
+
+/// Snapshot of the managed heap and garbage collector activity of an application domain.
+///
+/// The values are read through `System.GC` and, when resource monitoring is enabled
+/// for the process (`AppDomain.MonitoringIsEnabled`), through the per-domain
+/// monitoring counters exposed by .NET Framework 4.x.
+#[derive(Debug, Clone, Default)]
+pub struct GcStats {
+    /// Number of bytes currently thought to be allocated (`GC.GetTotalMemory(false)`).
+    pub total_memory: i64,
+
+    /// Highest generation supported by the garbage collector (`GC.MaxGeneration`).
+    pub max_generation: i32,
+
+    /// Number of collections performed for each generation, indexed by generation.
+    pub collection_counts: Vec<i32>,
+
+    /// Total bytes allocated by the domain since monitoring was enabled.
+    pub total_allocated: Option<i64>,
+
+    /// Bytes that survived the last full collection and are referenced by the domain.
+    pub survived: Option<i64>,
+}
+
+impl GcStats {
+    /// Collects the garbage collector statistics for the given application domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` whose runtime is queried.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GcStats)` - The collected statistics.
+    /// * `Err(ClrError)` - If any of the `System.GC` members could not be invoked.
+    pub fn collect(domain: &_AppDomain) -> Result<Self, ClrError> {
+        let mscorlib = domain.load_lib(&ManagedName::Mscorlib.value())?;
+        let gc = mscorlib.resolve_type(&ManagedName::GC.value())?;
+
+        let total_memory = gc.invoke(&ManagedName::GetTotalMemory.value(), None, Some(vec![false.to_variant()]), InvocationType::Static)?;
+        let max_generation = gc.invoke(&ManagedName::GetMaxGeneration.value(), None, None, InvocationType::Static)?;
+        let max_generation = unsafe { max_generation.Anonymous.Anonymous.Anonymous.lVal };
+
+        let mut collection_counts = Vec::with_capacity(max_generation as usize + 1);
+        for generation in 0..=max_generation {
+            let count = gc.invoke(&ManagedName::CollectionCount.value(), None, Some(vec![generation.to_variant()]), InvocationType::Static)?;
+            collection_counts.push(unsafe { count.Anonymous.Anonymous.Anonymous.lVal });
+        }
+
+        // Per-domain counters are only available on 4.x and when monitoring is enabled
+        let (total_allocated, survived) = Self::monitoring(domain).unwrap_or((None, None));
+
+        Ok(Self {
+            total_memory: unsafe { total_memory.Anonymous.Anonymous.Anonymous.llVal },
+            max_generation,
+            collection_counts,
+            total_allocated,
+            survived,
+        })
+    }
+
+    /// Reads the AppDomain resource monitoring counters, if enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` to query.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((Option<i64>, Option<i64>))` - The allocated and survived byte counts, or `None` if monitoring is disabled.
+    /// * `Err(ClrError)` - If the runtime does not expose the monitoring API.
+    fn monitoring(domain: &_AppDomain) -> Result<(Option<i64>, Option<i64>), ClrError> {
+        let domain_type = domain.GetType()?;
+        let enabled = domain_type.invoke(&ManagedName::GetMonitoringIsEnabled.value(), None, None, InvocationType::Static)?;
+        if unsafe { enabled.Anonymous.Anonymous.Anonymous.boolVal } == VARIANT_FALSE {
+            return Ok((None, None));
+        }
+
+        let instance = domain.to_variant();
+        let read = |name: &str| -> Result<i64, ClrError> {
+            let value: VARIANT = domain_type.invoke(name, Some(instance), None, InvocationType::Instance)?;
+            Ok(unsafe { value.Anonymous.Anonymous.Anonymous.llVal })
+        };
+
+        Ok((
+            Some(read(&ManagedName::GetMonitoringTotalAllocatedMemorySize.value())?),
+            Some(read(&ManagedName::GetMonitoringSurvivedMemorySize.value())?),
+        ))
+    }
+}
+
+/// Counter used to generate the names of the domain slots holding rooted objects.
+static NEXT_ROOT: AtomicU64 = AtomicU64::new(1);
+
+/// A managed object kept alive by the garbage collector until the root is dropped.
+///
+/// Delegates handed to native code (e.g. through `Marshal.GetFunctionPointerForDelegate`)
+/// are not tracked by the garbage collector: once the last managed reference goes away,
+/// the delegate is collected and the native side is left with a dangling thunk. `GcRoot`
+/// stores the object in a data slot of the application domain (`AppDomain.SetData`), which
+/// is a strong managed reference, and also holds a COM reference to it. Both are released
+/// when the root is dropped, so the host decides how long the object lives.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{GcRoot, RustClrEnv};
+///
+/// let clr = RustClrEnv::new(None)?;
+/// let root = GcRoot::new(&clr.app_domain, delegate)?;
+/// let pointer = root.function_pointer()?;
+///
+/// // Hand `pointer` to native code, keeping `root` alive while it may be called
+///
+/// root.release()?;
+/// ```
+pub struct GcRoot {
+    /// Application domain holding the managed reference.
+    domain: _AppDomain,
+
+    /// Name of the domain data slot referencing the object.
+    key: String,
+
+    /// COM reference to the rooted object.
+    object: VARIANT,
+
+    /// Set once the data slot has been cleared.
+    released: bool,
+}
+
+impl GcRoot {
+    /// Roots a managed object in the given application domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` that owns the object.
+    /// * `object` - The `VARIANT` holding the object. The root takes ownership of its references.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GcRoot)` - The root keeping the object alive.
+    /// * `Err(ClrError)` - If the object could not be stored in the domain.
+    pub fn new(domain: &_AppDomain, object: VARIANT) -> Result<Self, ClrError> {
+        let key = format!("rustclr.root.{}", NEXT_ROOT.fetch_add(1, Ordering::Relaxed));
+        let mut root = Self {
+            domain: domain.clone(),
+            key,
+            object,
+            released: true,
+        };
+
+        root.set_slot(object)?;
+        root.released = false;
+        Ok(root)
+    }
+
+    /// Returns a copy of the rooted object, holding its own references.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - A copy of the rooted object.
+    /// * `Err(ClrError)` - If the value could not be copied.
+    pub fn object(&self) -> Result<VARIANT, ClrError> {
+        let mut copy = unsafe { std::mem::zeroed::<VARIANT>() };
+        let hr = unsafe { VariantCopy(&mut copy, &self.object) };
+        if hr != 0 {
+            return Err(ClrError::ApiError("VariantCopy", hr));
+        }
+
+        Ok(copy)
+    }
+
+    /// Returns the native function pointer of a rooted delegate.
+    ///
+    /// The pointer stays valid for as long as the root is alive.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The address returned by `Marshal.GetFunctionPointerForDelegate`.
+    /// * `Err(ClrError)` - If the object is not a delegate or the call fails.
+    pub fn function_pointer(&self) -> Result<usize, ClrError> {
+        let marshal = self.domain
+            .load_lib(&ManagedName::Mscorlib.value())?
+            .resolve_type(&ManagedName::Marshal.value())?;
+
+        let pointer = marshal.invoke(&ManagedName::GetFunctionPointerForDelegate.value(), None, Some(vec![self.object]), InvocationType::Static)?;
+        unsafe {
+            match pointer.Anonymous.Anonymous.vt {
+                VT_I8 | VT_UI8 => Ok(pointer.Anonymous.Anonymous.Anonymous.llVal as usize),
+                VT_I4 | VT_UI4 | VT_INT | VT_UINT => Ok(pointer.Anonymous.Anonymous.Anonymous.lVal as u32 as usize),
+                _ => Err(ClrError::VariantUnsupported),
+            }
+        }
+    }
+
+    /// Removes the object from the domain and releases the COM reference.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the root was released.
+    /// * `Err(ClrError)` - If the domain data slot could not be cleared.
+    pub fn release(mut self) -> Result<(), ClrError> {
+        self.clear()
+    }
+
+    /// Clears the domain data slot, once.
+    fn clear(&mut self) -> Result<(), ClrError> {
+        if self.released {
+            return Ok(());
+        }
+
+        self.set_slot(unsafe { std::mem::zeroed() })?;
+        self.released = true;
+        Ok(())
+    }
+
+    /// Stores a value in the domain data slot of the root.
+    fn set_slot(&self, value: VARIANT) -> Result<(), ClrError> {
+        self.domain.GetType()?.invoke(
+            &ManagedName::SetData.value(),
+            Some(self.domain.to_variant()),
+            Some(vec![self.key.as_str().to_variant(), value]),
+            InvocationType::Instance
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Implements the `Drop` trait to release the managed and COM references held by the root.
+impl Drop for GcRoot {
+    fn drop(&mut self) {
+        let _ = self.clear();
+        unsafe { VariantClear(&mut self.object) };
+    }
+}
@@ -0,0 +1,205 @@
+use {
+    std::{
+        collections::HashMap,
+        fmt,
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Mutex, MutexGuard, OnceLock,
+        },
+    },
+    windows_core::{IUnknown, Interface},
+    windows_sys::Win32::System::Variant::{VariantClear, VariantCopy, VARIANT, VT_DISPATCH, VT_UNKNOWN},
+};
+
+use crate::{Variant, error::ClrError};
+
+/// Counter used to generate handle identifiers. Identifiers are never reused.
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Table of registered managed objects, indexed by handle identifier.
+static REGISTRY: OnceLock<Mutex<HashMap<u64, Entry>>> = OnceLock::new();
+
+/// A `VARIANT` owned by the registry.
+struct Entry(VARIANT);
+
+// Managed objects are exposed through free-threaded COM callable wrappers, so the
+// references held by the registry can be used and released from any thread.
+unsafe impl Send for Entry {}
+
+/// Releases the references held by the `VARIANT` when the entry is removed.
+impl Drop for Entry {
+    fn drop(&mut self) {
+        unsafe { VariantClear(&mut self.0) };
+    }
+}
+
+/// Locks the registry, recovering it if a thread panicked while holding the lock.
+fn registry() -> MutexGuard<'static, HashMap<u64, Entry>> {
+    REGISTRY
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// An opaque handle to a managed object kept alive by a process-wide registry.
+///
+/// Registering a `VARIANT` or `IUnknown` moves its references into the registry, which
+/// releases them only when [`ClrHandle::release`] is called. The handle itself is a plain
+/// identifier: it is `Copy`, `Send` and `Sync`, so it can be stored in long-lived structures
+/// or passed between threads without tracking the lifetime of the underlying `VARIANT`.
+///
+/// The type parameter is a marker chosen by the caller to keep handles to different kinds
+/// of objects apart; it has no effect on how the object is stored.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{ClrHandle, InvocationType};
+///
+/// struct StringBuilder;
+///
+/// let builder = mscorlib.create_instance("System.Text.StringBuilder")?;
+/// let handle = ClrHandle::<StringBuilder>::register(builder);
+///
+/// // Later, possibly on another thread
+/// let instance = handle.get()?;
+/// string_builder.invoke("Append", Some(instance), Some(vec!["x".to_variant()]), InvocationType::Instance)?;
+///
+/// handle.release()?;
+/// ```
+pub struct ClrHandle<T = ()> {
+    /// Identifier of the object in the registry.
+    id: u64,
+
+    /// Marker for the kind of object referenced by the handle.
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ClrHandle<T> {
+    /// Registers a `VARIANT`, taking ownership of the references it holds.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `VARIANT` to register. It must not be cleared by the caller afterwards.
+    ///
+    /// # Returns
+    ///
+    /// * A new handle referring to the registered value.
+    pub fn register(value: VARIANT) -> Self {
+        let id = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+        registry().insert(id, Entry(value));
+
+        Self { id, _marker: PhantomData }
+    }
+
+    /// Registers a COM object, adding a reference owned by the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The object to register.
+    ///
+    /// # Returns
+    ///
+    /// * A new handle referring to the object.
+    pub fn from_unknown(object: &IUnknown) -> Self {
+        Self::register(object.to_variant())
+    }
+
+    /// Returns a copy of the registered `VARIANT`.
+    ///
+    /// The copy holds its own references (`VariantCopy`), so it stays valid even if the
+    /// handle is released while it is in use.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - A copy of the registered value.
+    /// * `Err(ClrError)` - If the handle was released or the value could not be copied.
+    pub fn get(&self) -> Result<VARIANT, ClrError> {
+        let registry = registry();
+        let entry = registry.get(&self.id).ok_or(ClrError::InvalidHandle)?;
+
+        let mut copy = unsafe { std::mem::zeroed::<VARIANT>() };
+        let hr = unsafe { VariantCopy(&mut copy, &entry.0) };
+        if hr != 0 {
+            return Err(ClrError::ApiError("VariantCopy", hr));
+        }
+
+        Ok(copy)
+    }
+
+    /// Returns the registered object as a COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(I)` - The interface, if the object is a `VT_UNKNOWN` or `VT_DISPATCH` value supporting it.
+    /// * `Err(ClrError)` - If the handle was released or the object does not support the interface.
+    pub fn cast<I: Interface>(&self) -> Result<I, ClrError> {
+        let registry = registry();
+        let entry = registry.get(&self.id).ok_or(ClrError::InvalidHandle)?;
+
+        let vt = unsafe { entry.0.Anonymous.Anonymous.vt };
+        let unknown = unsafe { entry.0.Anonymous.Anonymous.Anonymous.punkVal };
+        if (vt != VT_UNKNOWN && vt != VT_DISPATCH) || unknown.is_null() {
+            return Err(ClrError::VariantUnsupported);
+        }
+
+        unsafe { IUnknown::from_raw_borrowed(&unknown) }
+            .ok_or(ClrError::VariantUnsupported)?
+            .cast::<I>()
+            .map_err(|_| ClrError::CastingError(std::any::type_name::<I>()))
+    }
+
+    /// Returns `true` if the handle still refers to a registered object.
+    pub fn is_alive(&self) -> bool {
+        registry().contains_key(&self.id)
+    }
+
+    /// Removes the object from the registry and releases its references.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the object was released.
+    /// * `Err(ClrError)` - If the handle was already released.
+    pub fn release(self) -> Result<(), ClrError> {
+        let entry = registry().remove(&self.id).ok_or(ClrError::InvalidHandle)?;
+
+        // Release outside the lock, in case the object's finalization re-enters the registry
+        drop(entry);
+        Ok(())
+    }
+}
+
+/// Returns the number of managed objects currently held by the handle registry.
+pub fn live_handles() -> usize {
+    registry().len()
+}
+
+impl<T> Clone for ClrHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ClrHandle<T> {}
+
+impl<T> PartialEq for ClrHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl<T> Eq for ClrHandle<T> {}
+
+impl<T> Hash for ClrHandle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for ClrHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ClrHandle").field(&self.id).finish()
+    }
+}
@@ -0,0 +1,257 @@
+use {
+    std::fmt,
+    windows_sys::Win32::System::Variant::VARIANT,
+};
+
+use crate::{
+    Variant, InvocationType, ManagedName,
+    error::ClrError,
+    schema::_AppDomain,
+};
+
+/// An argument passed to a [`ManagedCall`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManagedArg {
+    /// A `System.String` argument.
+    String(String),
+
+    /// A `System.Boolean` argument.
+    Bool(bool),
+
+    /// A `System.Int32` argument. Also accepted for enum parameters backed by `Int32`.
+    I32(i32),
+}
+
+impl ManagedArg {
+    /// Converts the argument to a `VARIANT`.
+    fn to_variant(&self) -> VARIANT {
+        match self {
+            ManagedArg::String(value) => value.to_variant(),
+            ManagedArg::Bool(value) => value.to_variant(),
+            ManagedArg::I32(value) => value.to_variant(),
+        }
+    }
+}
+
+impl From<&str> for ManagedArg {
+    fn from(value: &str) -> Self {
+        ManagedArg::String(value.to_string())
+    }
+}
+
+impl From<String> for ManagedArg {
+    fn from(value: String) -> Self {
+        ManagedArg::String(value)
+    }
+}
+
+impl From<bool> for ManagedArg {
+    fn from(value: bool) -> Self {
+        ManagedArg::Bool(value)
+    }
+}
+
+impl From<i32> for ManagedArg {
+    fn from(value: i32) -> Self {
+        ManagedArg::I32(value)
+    }
+}
+
+/// A call to a static managed method, executed inside the application domain of a `RustClr`.
+///
+/// Calls are registered with [`RustClr::with_pre_run`](crate::RustClr::with_pre_run) and
+/// [`RustClr::with_post_run`](crate::RustClr::with_post_run), and are typically used to
+/// configure the domain before the payload runs or to flush its state afterwards.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::ManagedCall;
+///
+/// // System.Net.ServicePointManager.set_Expect100Continue(false)
+/// let call = ManagedCall::new("System", "System.Net.ServicePointManager", "set_Expect100Continue")
+///     .arg(false);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManagedCall {
+    /// Name of the assembly defining the type (e.g. `mscorlib` or `System`).
+    assembly: String,
+
+    /// Full name of the type defining the method.
+    type_name: String,
+
+    /// Name of the static method to invoke. Property accessors use `get_`/`set_` names.
+    method: String,
+
+    /// Arguments passed to the method.
+    args: Vec<ManagedArg>,
+}
+
+impl ManagedCall {
+    /// Creates a new `ManagedCall` without arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `assembly` - The name of the assembly defining the type, loaded into the domain if needed.
+    /// * `type_name` - The full name of the type.
+    /// * `method` - The name of the static method to invoke.
+    ///
+    /// # Returns
+    ///
+    /// * A new `ManagedCall`.
+    pub fn new(assembly: &str, type_name: &str, method: &str) -> Self {
+        Self {
+            assembly: assembly.to_string(),
+            type_name: type_name.to_string(),
+            method: method.to_string(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Appends an argument to the call.
+    ///
+    /// # Arguments
+    ///
+    /// * `arg` - The argument, convertible into a `ManagedArg`.
+    ///
+    /// # Returns
+    ///
+    /// * The modified `ManagedCall`.
+    pub fn arg(mut self, arg: impl Into<ManagedArg>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Returns the name of the assembly defining the type.
+    pub(crate) fn assembly(&self) -> &str {
+        &self.assembly
+    }
+
+    /// Executes the call in the given application domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` in which the method is invoked.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value returned by the method.
+    /// * `Err(ClrError)` - If the assembly, type or method cannot be resolved, or the call fails.
+    pub fn invoke(&self, domain: &_AppDomain) -> Result<VARIANT, ClrError> {
+        let assembly = domain.load_lib(&self.assembly)?;
+        let target = assembly.resolve_type(&self.type_name)?;
+        let args = if self.args.is_empty() {
+            None
+        } else {
+            Some(self.args.iter().map(ManagedArg::to_variant).collect())
+        };
+
+        target.invoke(&self.method, None, args, InvocationType::Static)
+    }
+}
+
+impl fmt::Display for ManagedCall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{} ({})", self.type_name, self.method, self.assembly)
+    }
+}
+
+/// `SecurityProtocolType.Tls12`.
+const SECURITY_PROTOCOL_TLS12: i32 = 3072;
+
+/// Adds TLS 1.2 to `ServicePointManager.SecurityProtocol` in the given application domain.
+///
+/// Older payloads targeting .NET Framework 4.0-4.6 default to SSL3/TLS 1.0, which modern
+/// endpoints reject. The protocols already enabled in the domain are kept.
+///
+/// # Arguments
+///
+/// * `domain` - The `_AppDomain` to configure.
+///
+/// # Returns
+///
+/// * `Ok(())` - If TLS 1.2 is enabled.
+/// * `Err(ClrError)` - If `ServicePointManager` cannot be resolved or the runtime does not support TLS 1.2.
+pub(crate) fn enable_tls12(domain: &_AppDomain) -> Result<(), ClrError> {
+    let system = domain.load_lib(&ManagedName::System.value())?;
+    let service_point_manager = system.resolve_type(&ManagedName::ServicePointManager.value())?;
+
+    // Enum values cross the COM boundary as their underlying `Int32`
+    let current = service_point_manager.invoke(&ManagedName::GetSecurityProtocol.value(), None, None, InvocationType::Static)?;
+    let protocols = unsafe { current.Anonymous.Anonymous.Anonymous.lVal } | SECURITY_PROTOCOL_TLS12;
+
+    service_point_manager.invoke(
+        &ManagedName::SetSecurityProtocol.value(),
+        None,
+        Some(vec![protocols.to_variant()]),
+        InvocationType::Static
+    )?;
+
+    Ok(())
+}
+
+/// Sets an `AppContext` switch in the given application domain.
+///
+/// Switches such as `Switch.System.Net.DontEnableSchUseStrongCrypto` are read once, the
+/// first time the code depending on them runs, so they must be set before the payload
+/// (and before [`enable_tls12`]) touches the affected APIs.
+///
+/// # Arguments
+///
+/// * `domain` - The `_AppDomain` to configure.
+/// * `name` - The name of the switch.
+/// * `enabled` - The value of the switch.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the switch is set.
+/// * `Err(ClrError)` - If `AppContext` is not available (.NET Framework 4.6 or later is required) or the call fails.
+pub(crate) fn set_app_context_switch(domain: &_AppDomain, name: &str, enabled: bool) -> Result<(), ClrError> {
+    ManagedCall::new(&ManagedName::Mscorlib.value(), &ManagedName::AppContext.value(), &ManagedName::SetSwitch.value())
+        .arg(name)
+        .arg(enabled)
+        .invoke(domain)?;
+
+    Ok(())
+}
+
+/// Applies thread count limits to the managed thread pool of the runtime.
+///
+/// The maximum is applied first, so a minimum above the previous maximum is accepted.
+///
+/// # Arguments
+///
+/// * `domain` - The `_AppDomain` through which the thread pool is reached.
+/// * `min` - The minimum worker and I/O completion threads, if changed.
+/// * `max` - The maximum worker and I/O completion threads, if changed.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the limits are applied.
+/// * `Err(ClrError)` - If `ThreadPool` cannot be resolved or the runtime rejects the limits
+///   (e.g. a maximum below the number of processors, or a minimum above the maximum).
+pub(crate) fn set_thread_pool_limits(domain: &_AppDomain, min: Option<(i32, i32)>, max: Option<(i32, i32)>) -> Result<(), ClrError> {
+    let thread_pool = domain
+        .load_lib(&ManagedName::Mscorlib.value())?
+        .resolve_type(&ManagedName::ThreadPool.value())?;
+
+    let limits = [(ManagedName::SetMaxThreads, max), (ManagedName::SetMinThreads, min)];
+    for (method, threads) in limits {
+        let Some((worker, completion_port)) = threads else {
+            continue;
+        };
+
+        let accepted = thread_pool.invoke(
+            &method.value(),
+            None,
+            Some(vec![worker.to_variant(), completion_port.to_variant()]),
+            InvocationType::Static
+        )?;
+
+        if unsafe { accepted.Anonymous.Anonymous.Anonymous.boolVal } == 0 {
+            return Err(ClrError::ErrorClr("The thread pool rejected the requested thread limits"));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,443 @@
+use std::{fmt, ops::Deref};
+
+use windows_sys::Win32::System::{
+    Com::SAFEARRAY,
+    Ole::{SafeArrayDestroy, SafeArrayGetElement, SafeArrayGetLBound, SafeArrayGetUBound},
+    Variant::{
+        VariantClear, VariantCopy, VARIANT, VT_BSTR,
+        VT_DISPATCH, VT_EMPTY, VT_NULL, VT_UNKNOWN,
+    },
+};
+
+use crate::{
+    FromVariant, InvocationType, ManagedName, VariantArgs, WinStr,
+    error::ClrError,
+    schema::{_Type, BindingFlags},
+};
+
+/// A managed object together with its runtime type.
+///
+/// `Instance` keeps the object `VARIANT` and its `_Type` internally, so instance methods can
+/// be chained without passing both around by hand. The value returned by each call is wrapped
+/// in a new `Instance`, whose runtime type is resolved through `Object.GetType()`.
+///
+/// The `Instance` owns its `VARIANT`: the value is released with `VariantClear` when the
+/// `Instance` is dropped, and cloning it copies the value with `VariantCopy`.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+///
+/// let text = mscorlib.new("System.Text.StringBuilder")?
+///     .call("Append", "Hello, ")?
+///     .call("Append", "World!")?
+///     .call("ToString", ())?
+///     .as_string();
+///
+/// assert_eq!(text.as_deref(), Some("Hello, World!"));
+///
+/// let builder = mscorlib.new("System.Text.StringBuilder")?;
+/// builder.set("Capacity", 64)?;
+/// assert_eq!(builder.get("Capacity")?.value::<i32>()?, 64);
+/// ```
+pub struct Instance {
+    /// The managed value.
+    value: VARIANT,
+
+    /// The runtime type of the value, or `None` if the value is null.
+    ty: Option<_Type>,
+}
+
+impl Instance {
+    /// Wraps a managed value whose runtime type is known.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `VARIANT` holding the managed object. The `Instance` takes ownership of it.
+    /// * `ty` - The runtime type of the object.
+    ///
+    /// # Returns
+    ///
+    /// * A new `Instance`.
+    pub fn new(value: VARIANT, ty: _Type) -> Self {
+        Self { value, ty: Some(ty) }
+    }
+
+    /// Invokes an instance method on the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method. Property accessors use `get_`/`set_` names.
+    /// * `args` - The arguments of the method: a single `Variant` value, a tuple, a vector, or `()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The value returned by the method.
+    /// * `Err(ClrError)` - If the object is null, or the call or the resolution of the result type fails.
+    pub fn call(&self, name: &str, args: impl VariantArgs) -> Result<Instance, ClrError> {
+        let ty = self.ty.as_ref().ok_or(ClrError::ErrorClr("Cannot invoke a method on a null instance"))?;
+        let args = args.to_variants();
+        let args = if args.is_empty() { None } else { Some(args) };
+
+        let value = ty.invoke(name, Some(self.value), args, InvocationType::Instance)?;
+        Self::resolve(value, ty)
+    }
+
+    /// Invokes an instance method on the object that may be non-public (`private` or `internal`).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method. Property accessors use `get_`/`set_` names.
+    /// * `args` - The arguments of the method: a single `Variant` value, a tuple, a vector, or `()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The value returned by the method.
+    /// * `Err(ClrError)` - If the object is null, or the call or the resolution of the result type fails.
+    pub fn call_non_public(&self, name: &str, args: impl VariantArgs) -> Result<Instance, ClrError> {
+        let ty = self.ty.as_ref().ok_or(ClrError::ErrorClr("Cannot invoke a method on a null instance"))?;
+        let args = args.to_variants();
+        let args = if args.is_empty() { None } else { Some(args) };
+
+        let value = ty.invoke(name, Some(self.value), args, InvocationType::InstancePrivate)?;
+        Self::resolve(value, ty)
+    }
+
+    /// Reads a property of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property, without the `get_` prefix.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The value of the property.
+    /// * `Err(ClrError)` - If the object is null, or the property does not exist or throws.
+    pub fn get(&self, name: &str) -> Result<Instance, ClrError> {
+        self.access(name, None, BindingFlags::GetProperty)
+    }
+
+    /// Writes a property of the object.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property, without the `set_` prefix.
+    /// * `value` - The new value, as a single `Variant` value (e.g. a `&str`, an `i32` or an `IUnknown`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the property was set.
+    /// * `Err(ClrError)` - If the object is null, or the property does not exist, is read-only or throws.
+    pub fn set(&self, name: &str, value: impl VariantArgs) -> Result<(), ClrError> {
+        self.access(name, Some(value.to_variants()), BindingFlags::SetProperty).map(|_| ())
+    }
+
+    /// Reads an element of the object through its indexer (`list[i]`, `dict[key]`).
+    ///
+    /// Indexers are invoked as the `Item` property, the name C# and VB.NET give them by default.
+    /// Arrays have no public indexer, so their elements are read with `call("GetValue", index)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index arguments: a single `Variant` value or a tuple for multi-dimensional indexers.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The element at the index.
+    /// * `Err(ClrError)` - If the object has no matching indexer, or the getter throws (e.g.
+    ///   `ArgumentOutOfRangeException` or `KeyNotFoundException`).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let table = mscorlib.new("System.Collections.Hashtable")?;
+    /// table.set_item("key", "value")?;
+    /// assert_eq!(table.item("key")?.as_string().as_deref(), Some("value"));
+    /// ```
+    pub fn item(&self, index: impl VariantArgs) -> Result<Instance, ClrError> {
+        self.access(&ManagedName::Item.value(), Some(index.to_variants()), BindingFlags::GetProperty)
+    }
+
+    /// Writes an element of the object through its indexer (`list[i] = value`, `dict[key] = value`).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index arguments: a single `Variant` value or a tuple for multi-dimensional indexers.
+    /// * `value` - The new value, as a single `Variant` value.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the element was written.
+    /// * `Err(ClrError)` - If the object has no matching indexer, or the setter throws.
+    pub fn set_item(&self, index: impl VariantArgs, value: impl VariantArgs) -> Result<(), ClrError> {
+        let mut args = index.to_variants();
+        args.extend(value.to_variants());
+
+        self.access(&ManagedName::Item.value(), Some(args), BindingFlags::SetProperty).map(|_| ())
+    }
+
+    /// Reads or writes a public instance property through `InvokeMember`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property.
+    /// * `args` - The value to set, or `None` to read the property.
+    /// * `access` - `BindingFlags::GetProperty` or `BindingFlags::SetProperty`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The value of the property, or a null `Instance` when it is set.
+    /// * `Err(ClrError)` - If the object is null or the access fails.
+    fn access(&self, name: &str, args: Option<Vec<VARIANT>>, access: BindingFlags) -> Result<Instance, ClrError> {
+        let ty = self.ty.as_ref().ok_or(ClrError::ErrorClr("Cannot access a property of a null instance"))?;
+        let flags = BindingFlags::Public | BindingFlags::Instance | access;
+
+        let value = ty.invoke_with_flags(name, Some(self.value), args, flags)?;
+        Self::resolve(value, ty)
+    }
+
+    /// Wraps a managed value, resolving its runtime type through `Object.GetType()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The `VARIANT` holding the managed value.
+    /// * `via` - Any `_Type` of the runtime, used to invoke `GetType`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The wrapped value, with no type if it is null.
+    /// * `Err(ClrError)` - If the runtime type cannot be resolved.
+    pub(crate) fn resolve(value: VARIANT, via: &_Type) -> Result<Instance, ClrError> {
+        if matches!(unsafe { value.Anonymous.Anonymous.vt }, VT_EMPTY | VT_NULL) {
+            return Ok(Self { value, ty: None });
+        }
+
+        // `GetType` is declared by `System.Object`, so it can be invoked through any type
+        let result_type = via.invoke(&ManagedName::GetType.value(), Some(value), None, InvocationType::Instance)?;
+        let result_type = match unsafe { result_type.Anonymous.Anonymous.vt } {
+            VT_UNKNOWN | VT_DISPATCH => _Type::from_raw(unsafe { result_type.Anonymous.Anonymous.Anonymous.punkVal })?,
+            _ => return Err(ClrError::VariantUnsupported),
+        };
+
+        Ok(Self::new(value, result_type))
+    }
+
+    /// Returns the runtime type of the object, or `None` if the value is null.
+    pub fn runtime_type(&self) -> Option<&_Type> {
+        self.ty.as_ref()
+    }
+
+    /// Returns `true` if the value is `null` or empty (e.g. the result of a `void` method).
+    pub fn is_null(&self) -> bool {
+        self.ty.is_none()
+    }
+
+    /// Returns the value as a `String`, if it is a managed string.
+    pub fn as_string(&self) -> Option<String> {
+        if unsafe { self.value.Anonymous.Anonymous.vt } != VT_BSTR {
+            return None;
+        }
+
+        Some(unsafe { self.value.Anonymous.Anonymous.Anonymous.bstrVal }.to_string())
+    }
+
+    /// Converts the value to a Rust type.
+    ///
+    /// `Option<T>` reads `null` as `None`, which is how `Nullable<T>` return values without
+    /// a value are received.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The converted value.
+    /// * `Err(ClrError)` - If the value does not hold the expected type.
+    pub fn value<T: FromVariant>(&self) -> Result<T, ClrError> {
+        T::from_variant(&self.value)
+    }
+
+    /// Returns the underlying `VARIANT`, e.g. to pass the object to a managed method.
+    ///
+    /// The `VARIANT` borrows the value owned by the `Instance`, and is valid for as long
+    /// as it is alive.
+    pub fn variant(&self) -> VARIANT {
+        self.value
+    }
+}
+
+impl Clone for Instance {
+    /// Copies the value with `VariantCopy`, adding a reference to the managed object.
+    fn clone(&self) -> Self {
+        let mut value = unsafe { std::mem::zeroed::<VARIANT>() };
+        unsafe { VariantCopy(&mut value, &self.value) };
+
+        Self { value, ty: self.ty.clone() }
+    }
+}
+
+impl fmt::Debug for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Instance")
+            .field("vt", &unsafe { self.value.Anonymous.Anonymous.vt })
+            .field("ty", &self.ty)
+            .finish()
+    }
+}
+
+/// Implements the `Drop` trait to release the value with `VariantClear`.
+impl Drop for Instance {
+    fn drop(&mut self) {
+        unsafe { VariantClear(&mut self.value) };
+    }
+}
+
+/// A managed object that implements `IDisposable`, disposed when the wrapper is dropped.
+///
+/// Objects holding unmanaged resources (streams, writers, `Runspace`s) are otherwise only
+/// released by their finalizer, if ever, once the Rust side lets go of them. `ClrDisposable`
+/// calls `IDisposable.Dispose` exactly once, either through [`ClrDisposable::dispose`], which
+/// reports errors, or when it is dropped, which ignores them. It dereferences to the wrapped
+/// [`Instance`], so the object can be used as usual until then.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+///
+/// let writer = ClrDisposable::new(mscorlib.new("System.IO.StringWriter")?)?;
+/// writer.call("Write", "buffered")?;
+/// let text = writer.call("ToString", ())?.as_string();
+/// // `writer` is disposed here
+/// ```
+pub struct ClrDisposable {
+    /// The wrapped object, taken once it is disposed.
+    instance: Option<Instance>,
+
+    /// `System.IDisposable`, through which `Dispose` is invoked.
+    disposable: _Type,
+}
+
+impl ClrDisposable {
+    /// Wraps a managed object that implements `IDisposable`.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The object to dispose when the wrapper is dropped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ClrDisposable)` - The wrapped object.
+    /// * `Err(ClrError)` - If the object is null or does not implement `IDisposable`.
+    pub fn new(instance: Instance) -> Result<Self, ClrError> {
+        let ty = instance.runtime_type().ok_or(ClrError::ErrorClr("Cannot dispose a null instance"))?;
+        let disposable = ty
+            .GetType()?
+            .get_Assembly()?
+            .resolve_type(&ManagedName::IDisposable.value())?;
+
+        if !disposable.is_assignable_from(ty)? {
+            return Err(ClrError::ErrorClr("The instance does not implement IDisposable"));
+        }
+
+        Ok(Self { instance: Some(instance), disposable })
+    }
+
+    /// Disposes the object now, reporting the exception thrown by `Dispose`, if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If `Dispose` returned.
+    /// * `Err(ClrError)` - If `Dispose` threw.
+    pub fn dispose(mut self) -> Result<(), ClrError> {
+        self.dispose_now()
+    }
+
+    /// Releases the object without disposing it, e.g. to hand its ownership to managed code.
+    pub fn into_inner(mut self) -> Instance {
+        self.instance.take().expect("the instance is only taken once")
+    }
+
+    /// Invokes `IDisposable.Dispose` on the object, if not done yet.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the object is disposed.
+    /// * `Err(ClrError)` - If `Dispose` threw.
+    fn dispose_now(&mut self) -> Result<(), ClrError> {
+        match self.instance.take() {
+            Some(instance) => self.disposable
+                .invoke(&ManagedName::Dispose.value(), Some(instance.variant()), None, InvocationType::Instance)
+                .map(|_| ()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Deref for ClrDisposable {
+    type Target = Instance;
+
+    /// Provides access to the wrapped `Instance`.
+    fn deref(&self) -> &Self::Target {
+        self.instance.as_ref().expect("the instance is only taken once")
+    }
+}
+
+/// Implements the `Drop` trait to dispose the object, ignoring the exception `Dispose` may throw.
+impl Drop for ClrDisposable {
+    fn drop(&mut self) {
+        let _ = self.dispose_now();
+    }
+}
+
+/// Alias of [`Instance`], a managed object bundled with its runtime type.
+pub type ClrObject = Instance;
+
+/// Reads the attribute instances returned by a `GetCustomAttributes` method.
+///
+/// # Arguments
+///
+/// * `sa_attributes` - The `SAFEARRAY` of `VARIANT` attributes. It is destroyed once read.
+/// * `via` - Any `_Type` of the runtime, used to resolve the attribute types.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(String, Instance)>)` - The full name of each attribute type, with the attribute instance.
+/// * `Err(ClrError)` - If an element cannot be read or its type cannot be resolved.
+pub(crate) fn custom_attributes(sa_attributes: *mut SAFEARRAY, via: &_Type) -> Result<Vec<(String, Instance)>, ClrError> {
+    if sa_attributes.is_null() {
+        return Err(ClrError::NullPointerError("GetCustomAttributes"));
+    }
+
+    let mut lbound = 0;
+    let mut ubound = -1;
+    let mut values = Vec::new();
+    unsafe {
+        SafeArrayGetLBound(sa_attributes, 1, &mut lbound);
+        SafeArrayGetUBound(sa_attributes, 1, &mut ubound);
+
+        for i in lbound..=ubound {
+            let mut value = std::mem::zeroed::<VARIANT>();
+            let hr = SafeArrayGetElement(sa_attributes, &i, &mut value as *mut _ as *mut _);
+            if hr != 0 {
+                SafeArrayDestroy(sa_attributes);
+                return Err(ClrError::ApiError("SafeArrayGetElement", hr));
+            }
+
+            values.push(value);
+        }
+
+        SafeArrayDestroy(sa_attributes);
+    }
+
+    values
+        .into_iter()
+        .map(|value| {
+            let attribute = Instance::resolve(value, via)?;
+            let name = attribute
+                .runtime_type()
+                .ok_or(ClrError::NullPointerError("GetCustomAttributes"))?
+                .ToString()?;
+
+            Ok((name, attribute))
+        })
+        .collect()
+}
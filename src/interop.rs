@@ -0,0 +1,160 @@
+//! Conversions between the values of this crate and the types of the `windows` crate.
+//!
+//! `windows` 0.58 shares its `VARIANT` and `BSTR` with `windows-core` 0.58, which this crate
+//! already depends on, so they convert without any feature. `IDispatch` is defined by the
+//! `windows` crate itself and its conversions require the `windows` feature.
+
+use windows_core::BSTR;
+use windows_sys::Win32::{
+    Foundation::{SysAllocStringLen, SysStringLen},
+    System::Variant::{VariantCopy, VARIANT, VT_BSTR, VT_VARIANT},
+};
+
+use crate::{FromVariant, Instance, Variant, WinStr, error::ClrError};
+
+impl Variant for windows_core::VARIANT {
+    /// Copies the `windows` `VARIANT` with `VariantCopy`, so it can be passed to a managed call.
+    ///
+    /// `to_variant` cannot report errors, so a copy that fails (out of memory, or a type
+    /// `VariantCopy` does not support) yields an empty `VARIANT`.
+    fn to_variant(&self) -> VARIANT {
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+
+        // `windows_core::VARIANT` is a transparent wrapper around the same structure
+        let hr = unsafe { VariantCopy(&mut variant, self as *const windows_core::VARIANT as *const VARIANT) };
+        if hr != 0 {
+            variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        }
+
+        variant
+    }
+
+    /// Returns the VARIANT type ID for variants of any type.
+    fn var_type() -> u16 {
+        VT_VARIANT
+    }
+}
+
+impl FromVariant for windows_core::VARIANT {
+    /// Copies a `VARIANT` of any type into a `windows` `VARIANT`, which releases it when dropped.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        let mut value = windows_core::VARIANT::new();
+        let hr = unsafe { VariantCopy(&mut value as *mut windows_core::VARIANT as *mut VARIANT, variant) };
+        if hr != 0 {
+            return Err(ClrError::ApiError("VariantCopy", hr));
+        }
+
+        Ok(value)
+    }
+}
+
+impl TryFrom<&Instance> for windows_core::VARIANT {
+    type Error = ClrError;
+
+    /// Copies the value of the instance, adding a reference to the managed object.
+    fn try_from(instance: &Instance) -> Result<Self, Self::Error> {
+        windows_core::VARIANT::from_variant(&instance.variant())
+    }
+}
+
+impl Variant for BSTR {
+    /// Copies the `BSTR` into a `VT_BSTR` `VARIANT`, keeping embedded null characters.
+    fn to_variant(&self) -> VARIANT {
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        variant.Anonymous.Anonymous.vt = Self::var_type();
+        variant.Anonymous.Anonymous.Anonymous.bstrVal = self.to_bstr();
+
+        variant
+    }
+
+    /// Returns the VARIANT type ID for BSTRs.
+    fn var_type() -> u16 {
+        VT_BSTR
+    }
+}
+
+impl FromVariant for BSTR {
+    /// Copies the string of a `VT_BSTR` `VARIANT` into a `BSTR`.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        match unsafe { variant.Anonymous.Anonymous.vt } {
+            VT_BSTR => Ok(unsafe { BSTR::from_raw(copy_bstr(variant.Anonymous.Anonymous.Anonymous.bstrVal)) }),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+}
+
+impl WinStr for BSTR {
+    /// Copies the `BSTR` into a new one, owned by the caller.
+    fn to_bstr(&self) -> *const u16 {
+        copy_bstr(self.as_ptr())
+    }
+
+    /// Converts the `BSTR` to a `String`.
+    fn to_string(&self) -> String {
+        self.as_ptr().to_string()
+    }
+}
+
+/// Copies a `BSTR` pointer, which may be null, with its length.
+///
+/// # Arguments
+///
+/// * `bstr` - The `BSTR` to copy.
+///
+/// # Returns
+///
+/// * A new `BSTR` owned by the caller, or null if `bstr` is null.
+fn copy_bstr(bstr: *const u16) -> *const u16 {
+    if bstr.is_null() {
+        return bstr;
+    }
+
+    unsafe { SysAllocStringLen(bstr, SysStringLen(bstr)) }
+}
+
+#[cfg(feature = "windows")]
+mod com_impls {
+    use windows::Win32::System::Com::IDispatch;
+    use windows_core::Interface;
+    use windows_sys::Win32::System::Variant::{VARIANT, VT_DISPATCH};
+
+    use crate::{Dispatch, FromVariant, Variant, error::ClrError};
+
+    impl From<IDispatch> for Dispatch {
+        /// Wraps a `windows` `IDispatch`, taking over its reference.
+        fn from(dispatch: IDispatch) -> Self {
+            // Both wrap the same interface, with the same IID and vtable
+            unsafe { <Dispatch as Interface>::from_raw(dispatch.into_raw()) }
+        }
+    }
+
+    impl From<Dispatch> for IDispatch {
+        /// Unwraps the `IDispatch` of the object, taking over its reference.
+        fn from(dispatch: Dispatch) -> Self {
+            unsafe { IDispatch::from_raw(Interface::into_raw(dispatch)) }
+        }
+    }
+
+    impl Variant for IDispatch {
+        /// Converts the `IDispatch` to a `VT_DISPATCH` `VARIANT`, adding a reference owned by the `VARIANT`.
+        fn to_variant(&self) -> VARIANT {
+            let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+            variant.Anonymous.Anonymous.vt = Self::var_type();
+            variant.Anonymous.Anonymous.Anonymous.pdispVal = self.clone().into_raw();
+
+            variant
+        }
+
+        /// Returns the VARIANT type ID for `IDispatch` objects.
+        fn var_type() -> u16 {
+            VT_DISPATCH
+        }
+    }
+
+    impl FromVariant for IDispatch {
+        /// Reads the `IDispatch` of the object held by a `VT_DISPATCH` or `VT_UNKNOWN` `VARIANT`.
+        fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+            Dispatch::from_variant(variant).map(IDispatch::from)
+        }
+    }
+}
@@ -14,7 +14,7 @@ pub mod schema;
 pub mod com;
 
 /// Manages specific error types used when interacting with the CLR and COM APIs.
-mod error;
+pub mod error;
 
 /// Main CLR module, providing functions and structures for working with the Common Language Runtime.
 mod clr;
@@ -22,5 +22,111 @@ mod clr;
 /// Auxiliary functions for common manipulations and conversions needed when interacting with the CLR and COM.
 mod utils;
 
+/// Rust-implemented COM objects that allow managed code to call back into Rust.
+mod callback;
+
+/// Observers for runtime events raised inside an application domain.
+mod observer;
+
+/// Garbage collector statistics and rooting of managed objects.
+mod gc;
+
+/// Spilling of large captured output to encrypted temporary files.
+mod spill;
+
+/// Managed calls executed around the entry point of an assembly.
+mod hooks;
+
+/// Process-wide registry of managed objects referenced by opaque handles.
+mod handle;
+
+/// Managed objects wrapped for chained instance method calls.
+mod instance;
+
+/// Late-bound access to objects through `IDispatch`.
+mod dispatch;
+
+/// Pure-Rust reader for the CLI metadata of .NET images.
+mod metadata;
+
+/// Assemblies validated once, with their parsed headers and metadata cached.
+mod payload;
+
+/// Reports of the artifacts an execution leaves in the process.
+mod report;
+
+/// Progress events emitted while an assembly runs.
+mod events;
+
+/// Encoded table of the managed names used internally.
+mod names;
+
+/// Console provided to assemblies run from processes without one.
+mod console;
+
+/// Conversions between managed delegates and Rust functions.
+mod delegate;
+
+/// Minidumps written when an assembly fails fatally.
+mod dump;
+
+/// End-to-end check of the host environment.
+mod selftest;
+
+/// Up-front check of whether the current process can host the CLR.
+mod capability;
+
+/// Conversions with the `VARIANT`, `BSTR` and `IDispatch` of the `windows` crate.
+mod interop;
+
+/// Process-wide limits on concurrent executions.
+mod gate;
+
+/// Dependencies packed into assemblies as manifest resources.
+mod embedded;
+
+/// Entry points running on a background thread until stopped.
+mod service;
+
+/// File and registry tracing of an execution through ETW.
+#[cfg(feature = "activity-monitor")]
+mod activity;
+
+/// Commonly used items, re-exported for convenient glob imports.
+pub mod prelude;
+
 pub use clr::*;
-pub use utils::*;
\ No newline at end of file
+pub use utils::*;
+pub use observer::*;
+pub use gc::*;
+pub use spill::{CapturedOutput, SpillFile};
+pub use hooks::*;
+pub use metadata::*;
+pub use handle::*;
+pub use instance::*;
+pub use dispatch::Dispatch;
+pub use report::*;
+pub use events::*;
+pub use names::*;
+pub use console::ConsoleMode;
+pub use delegate::ClrDelegate;
+pub use dump::{CrashDump, DumpTarget, DumpType};
+pub use gate::{ExecutionLimits, execution_limits, set_execution_limits};
+pub use selftest::{selftest, SelfTestReport, SelfTestStep};
+pub use capability::{can_host, HostCapability, HostIssue};
+pub use embedded::{EmbeddedDependency, register_embedded_dependencies};
+pub use service::ServiceHandle;
+
+#[cfg(feature = "activity-monitor")]
+pub use activity::{Activity, ActivityKind, ActivityMonitor, ActivitySummary};
+
+/// Typed bindings over managed types, generated at compile time.
+#[cfg(feature = "macros")]
+pub use rustclr_macros::clr_bind;
+
+/// Items used by the code generated by `clr_bind!`. Not part of the public API.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use windows_sys::Win32::System::Variant::VARIANT;
+}
\ No newline at end of file
@@ -0,0 +1,685 @@
+use crate::{
+    ManagedName,
+    error::ClrError,
+    file::{PeImage, read_u16, read_u32},
+};
+
+/// Signature of the metadata root (`BSJB`).
+const METADATA_SIGNATURE: u32 = 0x424A_5342;
+
+/// `COMIMAGE_FLAGS_NATIVE_ENTRYPOINT`: the entry point field holds an RVA instead of a token.
+const COMIMAGE_FLAGS_NATIVE_ENTRYPOINT: u32 = 0x10;
+
+/// Number of metadata tables defined by ECMA-335.
+const TABLE_COUNT: usize = 0x2D;
+
+/// Table identifiers used by the reader.
+const MODULE: usize = 0x00;
+const TYPE_REF: usize = 0x01;
+const TYPE_DEF: usize = 0x02;
+const FIELD: usize = 0x04;
+const METHOD_DEF: usize = 0x06;
+const PARAM: usize = 0x08;
+const INTERFACE_IMPL: usize = 0x09;
+const MEMBER_REF: usize = 0x0A;
+const CUSTOM_ATTRIBUTE: usize = 0x0C;
+const DECL_SECURITY: usize = 0x0E;
+const EVENT: usize = 0x14;
+const PROPERTY: usize = 0x17;
+const STAND_ALONE_SIG: usize = 0x11;
+const MODULE_REF: usize = 0x1A;
+const TYPE_SPEC: usize = 0x1B;
+const IMPL_MAP: usize = 0x1C;
+const ASSEMBLY: usize = 0x20;
+const ASSEMBLY_REF: usize = 0x23;
+const FILE: usize = 0x26;
+const EXPORTED_TYPE: usize = 0x27;
+const MANIFEST_RESOURCE: usize = 0x28;
+const GENERIC_PARAM: usize = 0x2A;
+const METHOD_SPEC: usize = 0x2B;
+const GENERIC_PARAM_CONSTRAINT: usize = 0x2C;
+
+/// Marks an unused slot of a coded index.
+const UNUSED: usize = usize::MAX;
+
+/// A coded index: the number of tag bits and the tables it can reference.
+type CodedIndex = (u32, &'static [usize]);
+
+const TYPE_DEF_OR_REF: CodedIndex = (2, &[TYPE_DEF, TYPE_REF, TYPE_SPEC]);
+const HAS_CONSTANT: CodedIndex = (2, &[FIELD, PARAM, PROPERTY]);
+const HAS_CUSTOM_ATTRIBUTE: CodedIndex = (5, &[
+    METHOD_DEF, FIELD, TYPE_REF, TYPE_DEF, PARAM, INTERFACE_IMPL, MEMBER_REF, MODULE,
+    DECL_SECURITY, PROPERTY, EVENT, STAND_ALONE_SIG, MODULE_REF, TYPE_SPEC, ASSEMBLY,
+    ASSEMBLY_REF, FILE, EXPORTED_TYPE, MANIFEST_RESOURCE, GENERIC_PARAM,
+    GENERIC_PARAM_CONSTRAINT, METHOD_SPEC,
+]);
+const HAS_FIELD_MARSHAL: CodedIndex = (1, &[FIELD, PARAM]);
+const HAS_DECL_SECURITY: CodedIndex = (2, &[TYPE_DEF, METHOD_DEF, ASSEMBLY]);
+const MEMBER_REF_PARENT: CodedIndex = (3, &[TYPE_DEF, TYPE_REF, MODULE_REF, METHOD_DEF, TYPE_SPEC]);
+const HAS_SEMANTICS: CodedIndex = (1, &[EVENT, PROPERTY]);
+const METHOD_DEF_OR_REF: CodedIndex = (1, &[METHOD_DEF, MEMBER_REF]);
+const MEMBER_FORWARDED: CodedIndex = (1, &[FIELD, METHOD_DEF]);
+const IMPLEMENTATION: CodedIndex = (2, &[FILE, ASSEMBLY_REF, EXPORTED_TYPE]);
+const CUSTOM_ATTRIBUTE_TYPE: CodedIndex = (3, &[UNUSED, UNUSED, METHOD_DEF, MEMBER_REF, UNUSED]);
+const RESOLUTION_SCOPE: CodedIndex = (2, &[MODULE, MODULE_REF, ASSEMBLY_REF, TYPE_REF]);
+const TYPE_OR_METHOD_DEF: CodedIndex = (1, &[TYPE_DEF, METHOD_DEF]);
+
+/// A column of a metadata table.
+#[derive(Clone, Copy)]
+enum Column {
+    /// A constant of the given size in bytes.
+    Fixed(usize),
+
+    /// An index into the `#Strings` heap.
+    String,
+
+    /// An index into the `#GUID` heap.
+    Guid,
+
+    /// An index into the `#Blob` heap.
+    Blob,
+
+    /// An index into another table.
+    Table(usize),
+
+    /// A coded index into one of several tables.
+    Coded(CodedIndex),
+}
+
+/// Returns the columns of a metadata table, as defined in ECMA-335 §II.22.
+fn columns(table: usize) -> &'static [Column] {
+    use Column::*;
+
+    match table {
+        0x00 => &[Fixed(2), String, Guid, Guid, Guid],
+        0x01 => &[Coded(RESOLUTION_SCOPE), String, String],
+        0x02 => &[Fixed(4), String, String, Coded(TYPE_DEF_OR_REF), Table(FIELD), Table(METHOD_DEF)],
+        0x03 => &[Table(FIELD)],
+        0x04 => &[Fixed(2), String, Blob],
+        0x05 => &[Table(METHOD_DEF)],
+        0x06 => &[Fixed(4), Fixed(2), Fixed(2), String, Blob, Table(PARAM)],
+        0x07 => &[Table(PARAM)],
+        0x08 => &[Fixed(2), Fixed(2), String],
+        0x09 => &[Table(TYPE_DEF), Coded(TYPE_DEF_OR_REF)],
+        0x0A => &[Coded(MEMBER_REF_PARENT), String, Blob],
+        0x0B => &[Fixed(2), Coded(HAS_CONSTANT), Blob],
+        0x0C => &[Coded(HAS_CUSTOM_ATTRIBUTE), Coded(CUSTOM_ATTRIBUTE_TYPE), Blob],
+        0x0D => &[Coded(HAS_FIELD_MARSHAL), Blob],
+        0x0E => &[Fixed(2), Coded(HAS_DECL_SECURITY), Blob],
+        0x0F => &[Fixed(2), Fixed(4), Table(TYPE_DEF)],
+        0x10 => &[Fixed(4), Table(FIELD)],
+        0x11 => &[Blob],
+        0x12 => &[Table(TYPE_DEF), Table(EVENT)],
+        0x13 => &[Table(EVENT)],
+        0x14 => &[Fixed(2), String, Coded(TYPE_DEF_OR_REF)],
+        0x15 => &[Table(TYPE_DEF), Table(PROPERTY)],
+        0x16 => &[Table(PROPERTY)],
+        0x17 => &[Fixed(2), String, Blob],
+        0x18 => &[Fixed(2), Table(METHOD_DEF), Coded(HAS_SEMANTICS)],
+        0x19 => &[Table(TYPE_DEF), Coded(METHOD_DEF_OR_REF), Coded(METHOD_DEF_OR_REF)],
+        0x1A => &[String],
+        0x1B => &[Blob],
+        0x1C => &[Fixed(2), Coded(MEMBER_FORWARDED), String, Table(MODULE_REF)],
+        0x1D => &[Fixed(4), Table(FIELD)],
+        0x1E => &[Fixed(4), Fixed(4)],
+        0x1F => &[Fixed(4)],
+        0x20 => &[Fixed(4), Fixed(2), Fixed(2), Fixed(2), Fixed(2), Fixed(4), Blob, String, String],
+        0x21 => &[Fixed(4)],
+        0x22 => &[Fixed(4), Fixed(4), Fixed(4)],
+        0x23 => &[Fixed(2), Fixed(2), Fixed(2), Fixed(2), Fixed(4), Blob, String, String, Blob],
+        0x24 => &[Fixed(4), Table(ASSEMBLY_REF)],
+        0x25 => &[Fixed(4), Fixed(4), Fixed(4), Table(ASSEMBLY_REF)],
+        0x26 => &[Fixed(4), String, Blob],
+        0x27 => &[Fixed(4), Fixed(4), String, String, Coded(IMPLEMENTATION)],
+        0x28 => &[Fixed(4), Fixed(4), String, Coded(IMPLEMENTATION)],
+        0x29 => &[Table(TYPE_DEF), Table(TYPE_DEF)],
+        0x2A => &[Fixed(2), Fixed(2), Coded(TYPE_OR_METHOD_DEF), String],
+        0x2B => &[Coded(METHOD_DEF_OR_REF), Blob],
+        0x2C => &[Table(GENERIC_PARAM), Coded(TYPE_DEF_OR_REF)],
+        _ => &[],
+    }
+}
+
+/// The method designated as the entry point of an assembly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryPoint {
+    /// The `MethodDef` token of the entry point (`0x06xxxxxx`).
+    pub token: u32,
+
+    /// Full name of the type declaring the entry point.
+    pub type_name: String,
+
+    /// Name of the entry point method (usually `Main`).
+    pub method_name: String,
+}
+
+/// A type defined in an assembly, with the names of its methods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDefinition {
+    /// Namespace of the type. Empty for nested types and types in the global namespace.
+    pub namespace: String,
+
+    /// Name of the type.
+    pub name: String,
+
+    /// Names of the methods defined by the type, in declaration order.
+    pub methods: Vec<String>,
+}
+
+impl TypeDefinition {
+    /// Returns the namespace-qualified name of the type.
+    pub fn full_name(&self) -> String {
+        if self.namespace.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", self.namespace, self.name)
+        }
+    }
+}
+
+/// Privileges an executable requests through the `requestedExecutionLevel` of its manifest.
+///
+/// The loader only honors the manifest of the process image: an assembly loaded from
+/// memory runs with the token of the host, whatever level it requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionLevel {
+    /// Runs with the token of the caller (`asInvoker`).
+    AsInvoker,
+
+    /// Runs with the highest token available to the user (`highestAvailable`).
+    HighestAvailable,
+
+    /// Requires an administrator token (`requireAdministrator`).
+    RequireAdministrator,
+}
+
+impl ExecutionLevel {
+    /// Reads the execution level requested by the manifest embedded in a PE image.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A reference to a byte slice representing the executable.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ExecutionLevel)` - The requested level.
+    /// * `None` - If the image has no manifest or the manifest does not request a level.
+    pub fn from_image(buffer: &[u8]) -> Option<Self> {
+        Self::from_manifest(PeImage::parse(buffer)?.manifest()?)
+    }
+
+    /// Reads the `level` attribute of the `requestedExecutionLevel` element of a manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `manifest` - The manifest, in UTF-8 or UTF-16 with a byte order mark.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(ExecutionLevel)` - The requested level.
+    /// * `None` - If the element or attribute is missing or holds an unknown level.
+    pub fn from_manifest(manifest: &[u8]) -> Option<Self> {
+        let text = match manifest {
+            [0xFF, 0xFE, utf16 @ ..] => String::from_utf16_lossy(
+                &utf16.as_chunks::<2>().0.iter().map(|pair| u16::from_le_bytes(*pair)).collect::<Vec<_>>()
+            ),
+            _ => String::from_utf8_lossy(manifest).into_owned(),
+        };
+
+        const ELEMENT: &str = "requestedExecutionLevel";
+        let element = &text[text.find(ELEMENT)? + ELEMENT.len()..];
+        let element = &element[..element.find('>')?];
+
+        // `level = "..."`, with either quote character
+        let value = element[element.find("level")? + "level".len()..]
+            .trim_start()
+            .strip_prefix('=')?
+            .trim_start();
+
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        match &value[..value.find(quote)?] {
+            "asInvoker" => Some(Self::AsInvoker),
+            "highestAvailable" => Some(Self::HighestAvailable),
+            "requireAdministrator" => Some(Self::RequireAdministrator),
+            _ => None,
+        }
+    }
+}
+
+/// Information read from the CLI metadata of an assembly without starting a runtime.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::AssemblyMetadata;
+///
+/// let buffer = std::fs::read("examples/sample.exe")?;
+/// let metadata = AssemblyMetadata::parse(&buffer)?;
+///
+/// println!("{:?}", metadata.target_framework); // Some(".NETFramework,Version=v4.8")
+/// if let Some(entry) = metadata.entry_point {
+///     println!("{}::{}", entry.type_name, entry.method_name);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyMetadata {
+    /// The runtime version the assembly was built against (e.g. `v4.0.30319`).
+    pub runtime_version: String,
+
+    /// Simple name of the assembly, if the image contains an assembly manifest.
+    pub assembly_name: Option<String>,
+
+    /// Value of the `TargetFrameworkAttribute` (e.g. `.NETFramework,Version=v4.8`), if present.
+    pub target_framework: Option<String>,
+
+    /// The managed entry point, if the image has one.
+    pub entry_point: Option<EntryPoint>,
+
+    /// Types defined in the assembly, excluding the `<Module>` pseudo-type.
+    pub types: Vec<TypeDefinition>,
+
+    /// Native functions imported through P/Invoke, as `(library, function)` pairs.
+    pub pinvokes: Vec<(String, String)>,
+
+    /// The execution level requested by the embedded manifest, if any.
+    pub execution_level: Option<ExecutionLevel>,
+}
+
+impl AssemblyMetadata {
+    /// Reads the CLI metadata of a .NET image.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A reference to a byte slice representing the .NET assembly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AssemblyMetadata)` - The information read from the metadata.
+    /// * `Err(ClrError)` - If the buffer is not a .NET image or its metadata is malformed.
+    pub fn parse(buffer: &[u8]) -> Result<Self, ClrError> {
+        Self::from_image(&PeImage::parse(buffer).ok_or(ClrError::InvalidExecutable)?)
+    }
+
+    /// Reads the CLI metadata from the parsed headers of a .NET image.
+    ///
+    /// # Arguments
+    ///
+    /// * `pe` - The headers of the image.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AssemblyMetadata)` - The information read from the metadata.
+    /// * `Err(ClrError)` - If the image has no CLI header or its metadata is malformed.
+    pub(crate) fn from_image(pe: &PeImage) -> Result<Self, ClrError> {
+        let cor20 = pe.cor20_header().ok_or(ClrError::NotDotNet)?;
+
+        let root = read_u32(cor20, 8)
+            .zip(read_u32(cor20, 12))
+            .and_then(|(rva, size)| pe.slice_at(rva, size as usize))
+            .ok_or(ClrError::InvalidMetadata("metadata directory"))?;
+
+        let flags = read_u32(cor20, 16).unwrap_or_default();
+        let entry_token = read_u32(cor20, 20).unwrap_or_default();
+
+        let metadata = Metadata::parse(root)?;
+        let types = metadata.types()?;
+
+        let entry_point = if flags & COMIMAGE_FLAGS_NATIVE_ENTRYPOINT == 0 && entry_token >> 24 == METHOD_DEF as u32 {
+            metadata.entry_point(entry_token, &types)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            runtime_version: metadata.version.clone(),
+            assembly_name: metadata.assembly_name(),
+            target_framework: metadata.target_framework(),
+            entry_point,
+            types: types.into_iter().skip(1).collect(),
+            pinvokes: metadata.pinvokes(),
+            execution_level: pe.manifest().and_then(ExecutionLevel::from_manifest),
+        })
+    }
+}
+
+/// Lists the native functions a .NET image imports through P/Invoke, without starting a runtime.
+///
+/// Each function is reported once, in the order of the `ImplMap` table, with the library
+/// name exactly as written in the `DllImport` attribute.
+///
+/// # Arguments
+///
+/// * `buffer` - A reference to a byte slice representing the .NET assembly.
+///
+/// # Returns
+///
+/// * `Ok(Vec<(String, String)>)` - The imported functions, as `(library, function)` pairs.
+/// * `Err(ClrError)` - If the buffer is not a .NET image or its metadata is malformed.
+///
+/// # Examples
+///
+/// ```ignore
+/// let buffer = std::fs::read("examples/sample.exe")?;
+/// for (dll, function) in rustclr::pinvokes(&buffer)? {
+///     println!("{dll}!{function}");
+/// }
+/// ```
+pub fn pinvokes(buffer: &[u8]) -> Result<Vec<(String, String)>, ClrError> {
+    AssemblyMetadata::parse(buffer).map(|metadata| metadata.pinvokes)
+}
+
+/// The heaps and tables of a metadata root.
+struct Metadata<'a> {
+    /// The version string of the metadata root.
+    version: String,
+
+    /// The `#Strings` heap.
+    strings: &'a [u8],
+
+    /// The `#Blob` heap.
+    blobs: &'a [u8],
+
+    /// The table data of the `#~` stream, starting at the first row of the first table.
+    tables: &'a [u8],
+
+    /// Heap index sizes, as flags of the `HeapSizes` field.
+    heap_sizes: u8,
+
+    /// Number of rows of each table.
+    rows: [u32; TABLE_COUNT],
+
+    /// Offset of each table inside `tables`.
+    offsets: [usize; TABLE_COUNT],
+}
+
+impl<'a> Metadata<'a> {
+    /// Parses a metadata root (ECMA-335 §II.24.2).
+    fn parse(root: &'a [u8]) -> Result<Self, ClrError> {
+        if read_u32(root, 0) != Some(METADATA_SIGNATURE) {
+            return Err(ClrError::InvalidMetadata("metadata signature"));
+        }
+
+        let version_len = read_u32(root, 12).ok_or(ClrError::InvalidMetadata("metadata root"))? as usize;
+        let version = root.get(16..16usize.saturating_add(version_len))
+            .map(|bytes| {
+                let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                String::from_utf8_lossy(&bytes[..end]).into_owned()
+            })
+            .ok_or(ClrError::InvalidMetadata("metadata version"))?;
+
+        let mut offset = 16 + version_len;
+        let stream_count = read_u16(root, offset + 2).ok_or(ClrError::InvalidMetadata("stream headers"))?;
+        offset += 4;
+
+        let (mut strings, mut blobs, mut table_stream): (&[u8], &[u8], Option<&[u8]>) = (&[], &[], None);
+        for _ in 0..stream_count {
+            let stream = read_u32(root, offset)
+                .zip(read_u32(root, offset + 4))
+                .and_then(|(start, size)| root.get(start as usize..(start as usize).checked_add(size as usize)?))
+                .ok_or(ClrError::InvalidMetadata("stream header"))?;
+
+            let name = root.get(offset + 8..)
+                .and_then(|bytes| bytes.iter().position(|&b| b == 0).map(|end| &bytes[..end]))
+                .ok_or(ClrError::InvalidMetadata("stream name"))?;
+
+            match name {
+                b"#Strings" => strings = stream,
+                b"#Blob" => blobs = stream,
+                b"#~" | b"#-" => table_stream = Some(stream),
+                _ => {}
+            }
+
+            // The name is null-terminated and padded to a 4-byte boundary
+            offset += 8 + (name.len() + 4) / 4 * 4;
+        }
+
+        let stream = table_stream.ok_or(ClrError::InvalidMetadata("#~ stream"))?;
+        let heap_sizes = *stream.get(6).ok_or(ClrError::InvalidMetadata("#~ stream"))?;
+        let valid = read_u32(stream, 8)
+            .zip(read_u32(stream, 12))
+            .map(|(low, high)| (high as u64) << 32 | low as u64)
+            .ok_or(ClrError::InvalidMetadata("#~ stream"))?;
+
+        let mut rows = [0u32; TABLE_COUNT];
+        let mut offset = 24;
+        for (table, count) in rows.iter_mut().enumerate() {
+            if valid & (1 << table) != 0 {
+                *count = read_u32(stream, offset).ok_or(ClrError::InvalidMetadata("table row counts"))?;
+                offset += 4;
+            }
+        }
+
+        // Tables beyond the ones defined by ECMA-335 have unknown layouts
+        if valid >> TABLE_COUNT != 0 {
+            return Err(ClrError::InvalidMetadata("unknown metadata tables"));
+        }
+
+        // Uncompressed (`#-`) streams written by Edit and Continue carry four extra bytes
+        if heap_sizes & 0x40 != 0 {
+            offset += 4;
+        }
+
+        let mut metadata = Self {
+            version,
+            strings,
+            blobs,
+            tables: stream.get(offset..).ok_or(ClrError::InvalidMetadata("#~ stream"))?,
+            heap_sizes,
+            rows,
+            offsets: [0; TABLE_COUNT],
+        };
+
+        let mut position = 0usize;
+        for table in 0..TABLE_COUNT {
+            metadata.offsets[table] = position;
+            position = metadata.row_size(table)
+                .checked_mul(metadata.rows[table] as usize)
+                .and_then(|size| position.checked_add(size))
+                .ok_or(ClrError::InvalidMetadata("table sizes"))?;
+        }
+
+        if position > metadata.tables.len() {
+            return Err(ClrError::InvalidMetadata("table data"));
+        }
+
+        Ok(metadata)
+    }
+
+    /// Returns the size in bytes of a column.
+    fn column_size(&self, column: Column) -> usize {
+        let wide = |flag: u8| if self.heap_sizes & flag != 0 { 4 } else { 2 };
+
+        match column {
+            Column::Fixed(size) => size,
+            Column::String => wide(0x01),
+            Column::Guid => wide(0x02),
+            Column::Blob => wide(0x04),
+            Column::Table(table) => if self.rows[table] < 1 << 16 { 2 } else { 4 },
+            Column::Coded((bits, tables)) => {
+                let max = tables.iter()
+                    .filter(|&&table| table != UNUSED)
+                    .map(|&table| self.rows[table])
+                    .max()
+                    .unwrap_or_default();
+
+                if max < 1 << (16 - bits) { 2 } else { 4 }
+            }
+        }
+    }
+
+    /// Returns the size in bytes of a row of `table`.
+    fn row_size(&self, table: usize) -> usize {
+        columns(table).iter().map(|&column| self.column_size(column)).sum()
+    }
+
+    /// Reads a cell of a table. `row` is 1-based, as in metadata tokens.
+    fn cell(&self, table: usize, row: u32, column: usize) -> Option<u32> {
+        if row == 0 || row > self.rows[table] {
+            return None;
+        }
+
+        let layout = columns(table);
+        let start = self.offsets[table]
+            + (row as usize - 1) * self.row_size(table)
+            + layout[..column].iter().map(|&c| self.column_size(c)).sum::<usize>();
+
+        match self.column_size(*layout.get(column)?) {
+            1 => self.tables.get(start).map(|&b| b as u32),
+            2 => read_u16(self.tables, start).map(u32::from),
+            _ => read_u32(self.tables, start),
+        }
+    }
+
+    /// Reads a null-terminated UTF-8 string from the `#Strings` heap.
+    fn string(&self, index: u32) -> String {
+        self.strings.get(index as usize..)
+            .map(|bytes| {
+                let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+                String::from_utf8_lossy(&bytes[..end]).into_owned()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Reads a blob from the `#Blob` heap, decoding its compressed length prefix.
+    fn blob(&self, index: u32) -> Option<&'a [u8]> {
+        let data = self.blobs.get(index as usize..)?;
+        let (len, skip) = decode_compressed(data)?;
+        data.get(skip..skip.checked_add(len)?)
+    }
+
+    /// Reads every `TypeDef`, including the `<Module>` pseudo-type in the first row.
+    fn types(&self) -> Result<Vec<TypeDefinition>, ClrError> {
+        let count = self.rows[TYPE_DEF];
+        let end = self.rows[METHOD_DEF] + 1;
+        let mut types = Vec::with_capacity(count as usize);
+
+        for row in 1..=count {
+            // Each type owns the methods from its MethodList up to the next type's MethodList,
+            // bounded by the MethodDef table whatever the list values claim
+            let first = self.cell(TYPE_DEF, row, 5).ok_or(ClrError::InvalidMetadata("TypeDef table"))?;
+            let last = if row < count {
+                self.cell(TYPE_DEF, row + 1, 5).ok_or(ClrError::InvalidMetadata("TypeDef table"))?
+            } else {
+                end
+            };
+
+            let first = first.min(end);
+            let methods = (first..last.clamp(first, end))
+                .filter_map(|method| self.cell(METHOD_DEF, method, 3))
+                .map(|name| self.string(name))
+                .collect();
+
+            types.push(TypeDefinition {
+                namespace: self.string(self.cell(TYPE_DEF, row, 2).unwrap_or_default()),
+                name: self.string(self.cell(TYPE_DEF, row, 1).unwrap_or_default()),
+                methods,
+            });
+        }
+
+        Ok(types)
+    }
+
+    /// Resolves the declaring type and name of the entry point method.
+    fn entry_point(&self, token: u32, types: &[TypeDefinition]) -> Option<EntryPoint> {
+        let row = token & 0x00FF_FFFF;
+        let method_name = self.string(self.cell(METHOD_DEF, row, 3)?);
+
+        let owner = (1..=self.rows[TYPE_DEF])
+            .take_while(|&ty| self.cell(TYPE_DEF, ty, 5).is_some_and(|first| first <= row))
+            .last()?;
+
+        Some(EntryPoint {
+            token,
+            type_name: types.get(owner as usize - 1)?.full_name(),
+            method_name,
+        })
+    }
+
+    /// Reads the `(library, function)` pairs of the `ImplMap` table, without duplicates.
+    fn pinvokes(&self) -> Vec<(String, String)> {
+        let mut pinvokes = Vec::new();
+        for row in 1..=self.rows[IMPL_MAP] {
+            let Some(function) = self.cell(IMPL_MAP, row, 2).map(|name| self.string(name)) else {
+                continue;
+            };
+
+            let Some(library) = self.cell(IMPL_MAP, row, 3)
+                .and_then(|module| self.cell(MODULE_REF, module, 0))
+                .map(|name| self.string(name))
+            else {
+                continue;
+            };
+
+            let import = (library, function);
+            if !pinvokes.contains(&import) {
+                pinvokes.push(import);
+            }
+        }
+
+        pinvokes
+    }
+
+    /// Reads the simple name of the assembly from the `Assembly` table.
+    fn assembly_name(&self) -> Option<String> {
+        self.cell(ASSEMBLY, 1, 7).map(|name| self.string(name))
+    }
+
+    /// Reads the framework name from the `TargetFrameworkAttribute` applied to the assembly.
+    fn target_framework(&self) -> Option<String> {
+        // HasCustomAttribute tag 14 = Assembly, row 1
+        let assembly = (1 << 5) | 14;
+
+        (1..=self.rows[CUSTOM_ATTRIBUTE])
+            .filter(|&row| self.cell(CUSTOM_ATTRIBUTE, row, 0) == Some(assembly))
+            .find_map(|row| {
+                // CustomAttributeType tag 3 = MemberRef
+                let constructor = self.cell(CUSTOM_ATTRIBUTE, row, 1)?;
+                if constructor & 0b111 != 3 {
+                    return None;
+                }
+
+                // MemberRefParent tag 1 = TypeRef
+                let parent = self.cell(MEMBER_REF, constructor >> 3, 0)?;
+                if parent & 0b111 != 1 {
+                    return None;
+                }
+
+                let type_ref = parent >> 3;
+                let name = self.string(self.cell(TYPE_REF, type_ref, 1)?);
+                let namespace = self.string(self.cell(TYPE_REF, type_ref, 2)?);
+                if format!("{namespace}.{name}") != ManagedName::TargetFrameworkAttribute.value() {
+                    return None;
+                }
+
+                // Prolog 0x0001 followed by the SerString of the first fixed argument
+                let value = self.blob(self.cell(CUSTOM_ATTRIBUTE, row, 2)?)?;
+                if read_u16(value, 0)? != 1 {
+                    return None;
+                }
+
+                let (len, skip) = decode_compressed(value.get(2..)?)?;
+                let bytes = value.get(2 + skip..2 + skip + len)?;
+                Some(String::from_utf8_lossy(bytes).into_owned())
+            })
+    }
+}
+
+/// Decodes a compressed unsigned integer (ECMA-335 §II.23.2).
+///
+/// # Returns
+///
+/// * `Some((value, size))` - The decoded value and the number of bytes it occupies.
+/// * `None` - If the data is truncated or the encoding is invalid.
+fn decode_compressed(data: &[u8]) -> Option<(usize, usize)> {
+    let first = *data.first()? as usize;
+    match first {
+        b if b & 0x80 == 0 => Some((b, 1)),
+        b if b & 0xC0 == 0x80 => Some(((b & 0x3F) << 8 | *data.get(1)? as usize, 2)),
+        b if b & 0xE0 == 0xC0 => {
+            let bytes = data.get(1..4)?;
+            Some(((b & 0x1F) << 24 | (bytes[0] as usize) << 16 | (bytes[1] as usize) << 8 | bytes[2] as usize, 4))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,422 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, OnceLock},
+};
+
+/// Seed of the keystream used to encode the table.
+///
+/// Set `RUSTCLR_NAME_KEY` at build time to change the encoded bytes. When it is not
+/// set, the package version is used, so every release encodes the table differently.
+const SEED: u64 = fnv1a(match option_env!("RUSTCLR_NAME_KEY") {
+    Some(key) => key,
+    None => env!("CARGO_PKG_VERSION"),
+});
+
+/// Alternate lookup strings registered with [`set_name_override`].
+static OVERRIDES: OnceLock<Mutex<HashMap<ManagedName, String>>> = OnceLock::new();
+
+/// Hashes a string with 64-bit FNV-1a.
+const fn fnv1a(text: &str) -> u64 {
+    let bytes = text.as_bytes();
+    let mut hash = 0xcbf2_9ce4_8422_2325_u64;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Advances a xorshift64* state and returns the next keystream byte.
+const fn next_byte(state: &mut u64) -> u8 {
+    *state ^= *state >> 12;
+    *state ^= *state << 25;
+    *state ^= *state >> 27;
+    (state.wrapping_mul(0x2545_f491_4f6c_dd1d) >> 56) as u8
+}
+
+/// Returns the initial keystream state of the entry at `index`.
+const fn key(index: usize) -> u64 {
+    // xorshift must never start from zero
+    (SEED ^ (index as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)) | 1
+}
+
+/// Encodes a name at compile time. Encoding and decoding are the same operation.
+const fn encode<const N: usize>(text: &str, index: usize) -> [u8; N] {
+    let bytes = text.as_bytes();
+    let mut state = key(index);
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[i] ^ next_byte(&mut state);
+        i += 1;
+    }
+
+    out
+}
+
+/// Decodes a name encoded by [`encode`].
+fn decode(bytes: &[u8], index: usize) -> String {
+    let mut state = key(index);
+    let bytes = bytes.iter().map(|byte| byte ^ next_byte(&mut state)).collect::<Vec<u8>>();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Declares the table of managed names, encoding each literal at compile time.
+macro_rules! names {
+    ($($(#[$doc:meta])* $variant:ident => $value:literal,)*) => {
+        /// The managed assembly, type and member names used internally by the crate.
+        ///
+        /// The names are stored encoded in the binary and decoded only when they are used,
+        /// so they do not show up as plain strings. Each one can be replaced with
+        /// [`set_name_override`], e.g. to load `mscorlib` through its full display name.
+        /// The same table can be used from your own call sites through [`ManagedName::value`].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum ManagedName {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl ManagedName {
+            /// Returns the encoded bytes of the name.
+            fn encoded(self) -> &'static [u8] {
+                match self {
+                    $(ManagedName::$variant => {
+                        const ENCODED: [u8; $value.len()] = encode($value, ManagedName::$variant as usize);
+                        &ENCODED
+                    })*
+                }
+            }
+        }
+    };
+}
+
+names! {
+    /// `mscorlib`.
+    Mscorlib => "mscorlib",
+    /// `System`.
+    System => "System",
+    /// `System.Core`.
+    SystemCore => "System.Core",
+    /// `System.AppContext`.
+    AppContext => "System.AppContext",
+    /// `System.AppDomain`.
+    AppDomain => "System.AppDomain",
+    /// `System.Array`.
+    Array => "System.Array",
+    /// `System.Boolean`.
+    Boolean => "System.Boolean",
+    /// `System.Collections.Hashtable`.
+    Hashtable => "System.Collections.Hashtable",
+    /// `System.Collections.IDictionary`.
+    IDictionary => "System.Collections.IDictionary",
+    /// `System.Console`.
+    Console => "System.Console",
+    /// `System.Delegate`.
+    Delegate => "System.Delegate",
+    /// `System.EventHandler<FirstChanceExceptionEventArgs>`.
+    FirstChanceExceptionHandler => "System.EventHandler`1[System.Runtime.ExceptionServices.FirstChanceExceptionEventArgs]",
+    /// `System.Diagnostics.TextWriterTraceListener`.
+    TextWriterTraceListener => "System.Diagnostics.TextWriterTraceListener",
+    /// `System.Diagnostics.Trace`.
+    Trace => "System.Diagnostics.Trace",
+    /// `System.Diagnostics.TraceListenerCollection`.
+    TraceListenerCollection => "System.Diagnostics.TraceListenerCollection",
+    /// `System.Enum`.
+    Enum => "System.Enum",
+    /// `System.Exception`.
+    Exception => "System.Exception",
+    /// `System.GC`.
+    GC => "System.GC",
+    /// `System.Globalization.CultureInfo`.
+    CultureInfo => "System.Globalization.CultureInfo",
+    /// `System.IDisposable`.
+    IDisposable => "System.IDisposable",
+    /// `System.IO.Compression.CompressionMode`.
+    CompressionMode => "System.IO.Compression.CompressionMode",
+    /// `System.IO.Compression.DeflateStream`.
+    DeflateStream => "System.IO.Compression.DeflateStream",
+    /// `System.IO.MemoryStream`.
+    MemoryStream => "System.IO.MemoryStream",
+    /// `System.IO.Stream`.
+    Stream => "System.IO.Stream",
+    /// `System.IO.StringWriter`.
+    StringWriter => "System.IO.StringWriter",
+    /// `System.Int32`.
+    Int32 => "System.Int32",
+    /// `System.Int64`.
+    Int64 => "System.Int64",
+    /// `System.IntPtr`.
+    IntPtr => "System.IntPtr",
+    /// `System.Linq.Expressions.Expression`.
+    Expression => "System.Linq.Expressions.Expression",
+    /// `System.Linq.Expressions.LambdaExpression`.
+    LambdaExpression => "System.Linq.Expressions.LambdaExpression",
+    /// `System.Net.ServicePointManager`.
+    ServicePointManager => "System.Net.ServicePointManager",
+    /// `System.Object`.
+    Object => "System.Object",
+    /// `System.ParamArrayAttribute`.
+    ParamArrayAttribute => "System.ParamArrayAttribute",
+    /// `System.Reflection.Assembly`.
+    Assembly => "System.Reflection.Assembly",
+    /// `System.Reflection.AssemblyName`.
+    AssemblyName => "System.Reflection.AssemblyName",
+    /// `System.Reflection.BindingFlags`.
+    BindingFlags => "System.Reflection.BindingFlags",
+    /// `System.Reflection.MethodBase`.
+    MethodBase => "System.Reflection.MethodBase",
+    /// `System.Reflection.MethodInfo`.
+    MethodInfo => "System.Reflection.MethodInfo",
+    /// `System.Reflection.Module`.
+    Module => "System.Reflection.Module",
+    /// `System.Reflection.ParameterInfo`.
+    ParameterInfo => "System.Reflection.ParameterInfo",
+    /// `System.ResolveEventArgs`.
+    ResolveEventArgs => "System.ResolveEventArgs",
+    /// `System.ResolveEventHandler`.
+    ResolveEventHandler => "System.ResolveEventHandler",
+    /// `System.Runtime.CompilerServices.TaskAwaiter`.
+    TaskAwaiter => "System.Runtime.CompilerServices.TaskAwaiter",
+    /// `System.Runtime.ExceptionServices.FirstChanceExceptionEventArgs`.
+    FirstChanceExceptionEventArgs => "System.Runtime.ExceptionServices.FirstChanceExceptionEventArgs",
+    /// `System.Runtime.InteropServices.Marshal`.
+    Marshal => "System.Runtime.InteropServices.Marshal",
+    /// `System.Runtime.Versioning.TargetFrameworkAttribute`.
+    TargetFrameworkAttribute => "System.Runtime.Versioning.TargetFrameworkAttribute",
+    /// `System.Security.Policy.Evidence`.
+    Evidence => "System.Security.Policy.Evidence",
+    /// `System.Security.Policy.Zone`.
+    Zone => "System.Security.Policy.Zone",
+    /// `System.String`.
+    String => "System.String",
+    /// `System.String[]`.
+    StringArray => "System.String[]",
+    /// `System.Text.StringBuilder`.
+    StringBuilder => "System.Text.StringBuilder",
+    /// `System.Threading.Thread`.
+    Thread => "System.Threading.Thread",
+    /// `System.Threading.ThreadPool`.
+    ThreadPool => "System.Threading.ThreadPool",
+    /// `System.Threading.Tasks.Task`.
+    Task => "System.Threading.Tasks.Task",
+    /// `System.Threading.Tasks.Task<VoidTaskResult>`, the task completed by `async Task` methods.
+    VoidTask => "System.Threading.Tasks.Task`1[System.Threading.Tasks.VoidTaskResult]",
+    /// `System.Type`.
+    Type => "System.Type",
+    /// Domain data slot holding the dependencies resolved by the `AssemblyResolve` handler.
+    DependencyTable => "rustclr.dependencies",
+    /// `Thread.Abort`.
+    Abort => "Abort",
+    /// `IDictionary.Add`.
+    Add => "Add",
+    /// `Evidence.AddHost`.
+    AddHost => "AddHost",
+    /// `Expression.Call`.
+    Call => "Call",
+    /// `GC.CollectionCount`.
+    CollectionCount => "CollectionCount",
+    /// `LambdaExpression.Compile`.
+    Compile => "Compile",
+    /// `Expression.Constant`.
+    Constant => "Constant",
+    /// `Expression.Convert`.
+    Convert => "Convert",
+    /// `Stream.CopyTo`.
+    CopyTo => "CopyTo",
+    /// `Delegate.CreateDelegate`.
+    CreateDelegate => "CreateDelegate",
+    /// `IDisposable.Dispose`.
+    Dispose => "Dispose",
+    /// `Delegate.DynamicInvoke`.
+    DynamicInvoke => "DynamicInvoke",
+    /// `Task.GetAwaiter`.
+    GetAwaiter => "GetAwaiter",
+    /// `AppDomain.GetData`.
+    GetData => "GetData",
+    /// `Marshal.GetDelegateForFunctionPointer(IntPtr, Type)`, as formatted by `MethodInfo.ToString`.
+    GetDelegateForFunctionPointer => "System.Delegate GetDelegateForFunctionPointer(IntPtr, System.Type)",
+    /// `Type.GetElementType`.
+    GetElementType => "GetElementType",
+    /// `Marshal.GetFunctionPointerForDelegate`.
+    GetFunctionPointerForDelegate => "GetFunctionPointerForDelegate",
+    /// `Marshal.GetIUnknownForObject`.
+    GetIUnknownForObject => "GetIUnknownForObject",
+    /// `Assembly.GetManifestResourceStream`.
+    GetManifestResourceStream => "GetManifestResourceStream",
+    /// `Module.GetMethods`.
+    GetMethods => "GetMethods",
+    /// `TaskAwaiter.GetResult`.
+    GetResult => "GetResult",
+    /// `StringWriter.GetStringBuilder`.
+    GetStringBuilder => "GetStringBuilder",
+    /// `GC.GetTotalMemory`.
+    GetTotalMemory => "GetTotalMemory",
+    /// `Object.GetType`.
+    GetType => "GetType",
+    /// `Array.GetValue`.
+    GetValue => "GetValue",
+    /// `MethodBase.Invoke`.
+    Invoke => "Invoke",
+    /// `ParameterInfo.IsDefined`.
+    IsDefined => "IsDefined",
+    /// Default name of indexers (`this[...]`).
+    Item => "Item",
+    /// `Expression.Lambda`.
+    Lambda => "Lambda",
+    /// `MethodInfo.MakeGenericMethod`.
+    MakeGenericMethod => "MakeGenericMethod",
+    /// `AssemblyName.Name` and `ResolveEventArgs.Name`.
+    Name => "Name",
+    /// `Expression.New`.
+    New => "New",
+    /// `Expression.Parameter`.
+    Parameter => "Parameter",
+    /// `Enum.Parse`.
+    Parse => "Parse",
+    /// `Expression.Property`.
+    Property => "Property",
+    /// `TraceListenerCollection.Remove`.
+    Remove => "Remove",
+    /// `Task<TResult>.Result`.
+    Result => "Result",
+    /// `Console.SetError`.
+    SetError => "SetError",
+    /// `Console.SetOut`.
+    SetOut => "SetOut",
+    /// `AppDomain.SetData`.
+    SetData => "SetData",
+    /// `ThreadPool.SetMaxThreads`.
+    SetMaxThreads => "SetMaxThreads",
+    /// `ThreadPool.SetMinThreads`.
+    SetMinThreads => "SetMinThreads",
+    /// `AppContext.SetSwitch`.
+    SetSwitch => "SetSwitch",
+    /// `MemoryStream.ToArray`.
+    ToArray => "ToArray",
+    /// `Enum.ToObject`.
+    ToObject => "ToObject",
+    /// `Object.ToString`.
+    ToString => "ToString",
+    /// `AppDomain.AssemblyResolve` (add accessor).
+    AddAssemblyResolve => "add_AssemblyResolve",
+    /// `AppDomain.FirstChanceException` (add accessor).
+    AddFirstChanceException => "add_FirstChanceException",
+    /// `AppDomain.FirstChanceException` (remove accessor).
+    RemoveFirstChanceException => "remove_FirstChanceException",
+    /// `Thread.CurrentCulture` getter.
+    GetCurrentCulture => "get_CurrentCulture",
+    /// `Thread.CurrentThread` getter.
+    GetCurrentThread => "get_CurrentThread",
+    /// `Thread.CurrentUICulture` getter.
+    GetCurrentUICulture => "get_CurrentUICulture",
+    /// `ParameterInfo.DefaultValue` getter.
+    GetDefaultValue => "get_DefaultValue",
+    /// `Console.Error` getter.
+    GetError => "get_Error",
+    /// `FirstChanceExceptionEventArgs.Exception` getter.
+    GetException => "get_Exception",
+    /// `ParameterInfo.HasDefaultValue` getter.
+    GetHasDefaultValue => "get_HasDefaultValue",
+    /// `Assembly.ImageRuntimeVersion` getter.
+    GetImageRuntimeVersion => "get_ImageRuntimeVersion",
+    /// `CultureInfo.InvariantCulture` getter.
+    GetInvariantCulture => "get_InvariantCulture",
+    /// `ParameterInfo.IsOptional` getter.
+    GetIsOptional => "get_IsOptional",
+    /// `ParameterInfo.IsOut` getter.
+    GetIsOut => "get_IsOut",
+    /// `StringBuilder.Length` getter.
+    GetLength => "get_Length",
+    /// `Trace.Listeners` getter.
+    GetListeners => "get_Listeners",
+    /// `GC.MaxGeneration` getter.
+    GetMaxGeneration => "get_MaxGeneration",
+    /// `Exception.Message` getter.
+    GetMessage => "get_Message",
+    /// `AppDomain.MonitoringIsEnabled` getter.
+    GetMonitoringIsEnabled => "get_MonitoringIsEnabled",
+    /// `AppDomain.MonitoringSurvivedMemorySize` getter.
+    GetMonitoringSurvivedMemorySize => "get_MonitoringSurvivedMemorySize",
+    /// `AppDomain.MonitoringTotalAllocatedMemorySize` getter.
+    GetMonitoringTotalAllocatedMemorySize => "get_MonitoringTotalAllocatedMemorySize",
+    /// `ParameterInfo.Name` getter.
+    GetName => "get_Name",
+    /// `Console.Out` getter.
+    GetOut => "get_Out",
+    /// `ParameterInfo.ParameterType` getter.
+    GetParameterType => "get_ParameterType",
+    /// `ParameterInfo.Position` getter.
+    GetPosition => "get_Position",
+    /// `MethodInfo.ReturnType` getter.
+    GetReturnType => "get_ReturnType",
+    /// `ServicePointManager.SecurityProtocol` getter.
+    GetSecurityProtocol => "get_SecurityProtocol",
+    /// `Exception.Source` getter.
+    GetSource => "get_Source",
+    /// `Thread.CurrentCulture` setter.
+    SetCurrentCulture => "set_CurrentCulture",
+    /// `Thread.CurrentUICulture` setter.
+    SetCurrentUICulture => "set_CurrentUICulture",
+    /// `CultureInfo.DefaultThreadCurrentCulture` setter.
+    SetDefaultThreadCurrentCulture => "set_DefaultThreadCurrentCulture",
+    /// `CultureInfo.DefaultThreadCurrentUICulture` setter.
+    SetDefaultThreadCurrentUICulture => "set_DefaultThreadCurrentUICulture",
+    /// `StringBuilder.Length` setter.
+    SetLength => "set_Length",
+    /// `ServicePointManager.SecurityProtocol` setter.
+    SetSecurityProtocol => "set_SecurityProtocol",
+}
+
+impl ManagedName {
+    /// Returns the string used to look up the name.
+    ///
+    /// # Returns
+    ///
+    /// * The override registered with [`set_name_override`], or the decoded built-in name.
+    pub fn value(self) -> String {
+        if let Some(overrides) = OVERRIDES.get() {
+            let overrides = overrides.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if let Some(value) = overrides.get(&self) {
+                return value.clone();
+            }
+        }
+
+        decode(self.encoded(), self as usize)
+    }
+}
+
+/// Locks the override table, recovering it if a thread panicked while holding the lock.
+fn overrides() -> MutexGuard<'static, HashMap<ManagedName, String>> {
+    OVERRIDES
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Replaces the string used to look up a managed name, for the whole process.
+///
+/// # Arguments
+///
+/// * `name` - The entry of the table to replace.
+/// * `value` - The string to use instead of the built-in name.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{ManagedName, set_name_override};
+///
+/// set_name_override(
+///     ManagedName::Mscorlib,
+///     "mscorlib, Version=4.0.0.0, Culture=neutral, PublicKeyToken=b77a5c561934e089"
+/// );
+/// ```
+pub fn set_name_override(name: ManagedName, value: impl Into<String>) {
+    overrides().insert(name, value.into());
+}
+
+/// Removes every override registered with [`set_name_override`].
+pub fn clear_name_overrides() {
+    overrides().clear();
+}
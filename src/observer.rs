@@ -0,0 +1,235 @@
+use {
+    std::{cell::Cell, sync::mpsc::Sender},
+    windows_sys::Win32::System::Variant::{VARIANT, VT_BSTR},
+};
+
+use crate::{
+    WinStr, Variant, InvocationType, ManagedName, OwnedVariant,
+    error::ClrError,
+    callback::ManagedCallback,
+    schema::{_AppDomain, _Type},
+};
+
+thread_local! {
+    /// Set while a notification is being processed on the current thread.
+    ///
+    /// Reading the exception properties runs managed code, which may itself raise
+    /// first-chance exceptions. The guard prevents those from re-entering the observer.
+    static IN_CALLBACK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A first-chance exception notification raised inside an application domain.
+///
+/// First-chance notifications are delivered as soon as an exception is thrown,
+/// before the runtime searches for a handler, so exceptions that the payload
+/// catches internally are reported as well.
+#[derive(Debug, Clone)]
+pub struct FirstChanceException {
+    /// Full name of the exception type (e.g. `System.IO.FileNotFoundException`).
+    pub type_name: String,
+
+    /// Message associated with the exception.
+    pub message: String,
+
+    /// Name of the application or object that caused the exception, if any.
+    pub source: String,
+}
+
+/// Observes the `AppDomain.FirstChanceException` event of an application domain.
+///
+/// Every exception thrown by managed code running in the domain is converted to a
+/// [`FirstChanceException`] and sent through the provided channel. The subscription
+/// is removed when [`ExceptionObserver::detach`] is called or the observer is dropped.
+pub struct ExceptionObserver<'a> {
+    /// Application domain being observed.
+    domain: &'a _AppDomain,
+
+    /// Channel that receives the notifications.
+    sender: Sender<FirstChanceException>,
+
+    /// The `EventHandler<FirstChanceExceptionEventArgs>` delegate currently subscribed.
+    handler: Option<OwnedVariant>,
+}
+
+impl<'a> ExceptionObserver<'a> {
+    /// Creates a new `ExceptionObserver` for the given application domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - The `_AppDomain` to observe.
+    /// * `sender` - The channel that receives each `FirstChanceException`.
+    ///
+    /// # Returns
+    ///
+    /// * A new, detached instance of `ExceptionObserver`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClrEnv, ExceptionObserver};
+    /// use std::sync::mpsc::channel;
+    ///
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let clr = RustClrEnv::new(None)?;
+    ///     let (sender, receiver) = channel();
+    ///
+    ///     let mut observer = ExceptionObserver::new(&clr.app_domain, sender);
+    ///     observer.attach()?;
+    ///
+    ///     // Run managed code...
+    ///
+    ///     observer.detach()?;
+    ///     for exception in receiver.try_iter() {
+    ///         println!("{}: {}", exception.type_name, exception.message);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(domain: &'a _AppDomain, sender: Sender<FirstChanceException>) -> Self {
+        Self {
+            domain,
+            sender,
+            handler: None,
+        }
+    }
+
+    /// Subscribes to the `FirstChanceException` event of the application domain.
+    ///
+    /// Calling this method on an already attached observer has no effect.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the subscription is successful.
+    /// * `Err(ClrError)` - If the runtime does not expose the event (e.g. .NET 2.0)
+    ///   or the delegate could not be created.
+    pub fn attach(&mut self) -> Result<(), ClrError> {
+        if self.handler.is_some() {
+            return Ok(());
+        }
+
+        let mscorlib = self.domain.load_lib(&ManagedName::Mscorlib.value())?;
+        let dictionary = mscorlib.resolve_type(&ManagedName::IDictionary.value())?;
+        let delegate = mscorlib.resolve_type(&ManagedName::Delegate.value())?;
+        let handler_type = mscorlib.resolve_type(
+            &ManagedName::FirstChanceExceptionHandler.value()
+        )?;
+
+        let types = ExceptionTypes {
+            args: mscorlib.resolve_type(&ManagedName::FirstChanceExceptionEventArgs.value())?,
+            exception: mscorlib.resolve_type(&ManagedName::Exception.value())?,
+        };
+
+        // Builds the Rust sink that the managed delegate will call into
+        let sender = self.sender.clone();
+        let sink = ManagedCallback::create(dictionary.get_Guid()?, Box::new(move |_, args| {
+            if IN_CALLBACK.with(|guard| guard.replace(true)) {
+                return;
+            }
+
+            if let Ok(exception) = types.read(args) {
+                let _ = sender.send(exception);
+            }
+
+            IN_CALLBACK.with(|guard| guard.set(false));
+        }));
+
+        // Binds `IDictionary.Add(object, object)` of the sink to an `EventHandler<T>`
+        let add = dictionary.method(&ManagedName::Add.value())?;
+        let handler = OwnedVariant(delegate.invoke(
+            &ManagedName::CreateDelegate.value(),
+            None,
+            Some(vec![handler_type.to_variant(), sink.to_variant(), add.to_variant()]),
+            InvocationType::Static
+        )?);
+
+        self.domain.GetType()?.invoke(
+            &ManagedName::AddFirstChanceException.value(),
+            Some(self.domain.to_variant()),
+            Some(vec![handler.0]),
+            InvocationType::Instance
+        )?;
+
+        self.handler = Some(handler);
+        Ok(())
+    }
+
+    /// Removes the subscription created by [`ExceptionObserver::attach`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the observer is detached or was not attached.
+    /// * `Err(ClrError)` - If an error occurs while removing the event handler.
+    pub fn detach(&mut self) -> Result<(), ClrError> {
+        if let Some(handler) = self.handler.take() {
+            self.domain.GetType()?.invoke(
+                &ManagedName::RemoveFirstChanceException.value(),
+                Some(self.domain.to_variant()),
+                Some(vec![handler.0]),
+                InvocationType::Instance
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Implements the `Drop` trait to remove the event subscription when `ExceptionObserver` goes out of scope.
+impl<'a> Drop for ExceptionObserver<'a> {
+    fn drop(&mut self) {
+        let _ = self.detach();
+    }
+}
+
+/// Managed types used to read a `FirstChanceExceptionEventArgs` instance.
+struct ExceptionTypes {
+    /// `System.Runtime.ExceptionServices.FirstChanceExceptionEventArgs`.
+    args: _Type,
+
+    /// `System.Exception`.
+    exception: _Type,
+}
+
+// The runtime may raise the event on any managed thread. `System.Type` objects are
+// agile, so their COM-callable wrappers can be used from every thread.
+unsafe impl Send for ExceptionTypes {}
+unsafe impl Sync for ExceptionTypes {}
+
+impl ExceptionTypes {
+    /// Converts a `FirstChanceExceptionEventArgs` instance into a `FirstChanceException`.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - A `VARIANT` holding the event arguments.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FirstChanceException)` - The exception details.
+    /// * `Err(ClrError)` - If any of the exception properties could not be read.
+    fn read(&self, args: VARIANT) -> Result<FirstChanceException, ClrError> {
+        let exception = OwnedVariant(self.args.invoke(&ManagedName::GetException.value(), Some(args), None, InvocationType::Instance)?);
+        let message = OwnedVariant(self.exception.invoke(&ManagedName::GetMessage.value(), Some(exception.0), None, InvocationType::Instance)?);
+        let source = OwnedVariant(self.exception.invoke(&ManagedName::GetSource.value(), Some(exception.0), None, InvocationType::Instance)?);
+
+        // `_Type::from_raw` takes over the reference held by the result
+        let exception_type = self.exception.invoke(&ManagedName::GetType.value(), Some(exception.0), None, InvocationType::Instance)?;
+        let exception_type = _Type::from_raw(unsafe { exception_type.Anonymous.Anonymous.Anonymous.punkVal })?;
+
+        Ok(FirstChanceException {
+            type_name: exception_type.ToString()?,
+            message: bstr_value(&message.0),
+            source: bstr_value(&source.0),
+        })
+    }
+}
+
+/// Extracts a `String` from a `VARIANT`, returning an empty string when it does not hold a `BSTR`.
+fn bstr_value(variant: &VARIANT) -> String {
+    unsafe {
+        if variant.Anonymous.Anonymous.vt == VT_BSTR {
+            variant.Anonymous.Anonymous.Anonymous.bstrVal.to_string()
+        } else {
+            String::new()
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::OnceLock;
+
+use crate::{
+    error::ClrError,
+    file::{PeImage, validate_file},
+    metadata::{AssemblyMetadata, ExecutionLevel},
+};
+
+/// The assembly run by [`RustClr`](crate::RustClr), validated once with the information
+/// derived from its headers cached.
+///
+/// The headers are parsed when the payload is set, and the same view serves the validation,
+/// the elevation check and the metadata reader, so large assemblies are not traversed again
+/// on every run or report. The metadata is read on first use and kept for later calls.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PayloadImage<'a> {
+    /// The raw assembly.
+    buffer: &'a [u8],
+
+    /// The parsed headers, `None` only for the empty image of a default `RustClr`.
+    pe: Option<PeImage<'a>>,
+
+    /// The execution level requested by the embedded manifest.
+    execution_level: Option<ExecutionLevel>,
+
+    /// The metadata of the assembly, read on first use.
+    metadata: OnceLock<AssemblyMetadata>,
+}
+
+impl<'a> PayloadImage<'a> {
+    /// Parses and validates a payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A reference to a byte slice representing the .NET assembly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PayloadImage)` - If the buffer is a .NET executable.
+    /// * `Err(ClrError)` - If the buffer is not a valid executable or not a .NET application.
+    pub(crate) fn parse(buffer: &'a [u8]) -> Result<Self, ClrError> {
+        let pe = PeImage::parse(buffer).ok_or(ClrError::InvalidExecutable)?;
+        validate_file(&pe)?;
+
+        Ok(Self {
+            buffer,
+            pe: Some(pe),
+            execution_level: pe.manifest().and_then(ExecutionLevel::from_manifest),
+            metadata: OnceLock::new(),
+        })
+    }
+
+    /// Returns the bytes of the payload.
+    pub(crate) fn bytes(&self) -> &'a [u8] {
+        self.buffer
+    }
+
+    /// Returns the execution level requested by the manifest of the payload, if any.
+    pub(crate) fn execution_level(&self) -> Option<ExecutionLevel> {
+        self.execution_level
+    }
+
+    /// Returns the metadata of the payload, reading it on first use.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(&AssemblyMetadata)` - The metadata of the assembly.
+    /// * `Err(ClrError)` - If no payload is set or its metadata is malformed.
+    pub(crate) fn metadata(&self) -> Result<&AssemblyMetadata, ClrError> {
+        if let Some(metadata) = self.metadata.get() {
+            return Ok(metadata);
+        }
+
+        let pe = self.pe.as_ref().ok_or(ClrError::InvalidExecutable)?;
+        let metadata = AssemblyMetadata::from_image(pe)?;
+        Ok(self.metadata.get_or_init(|| metadata))
+    }
+}
@@ -0,0 +1,17 @@
+//! The `rustclr` prelude.
+//!
+//! Re-exports the items needed by most programs hosting the CLR, so they can be
+//! brought into scope with a single import:
+//!
+//! ```ignore
+//! use rustclr::prelude::*;
+//! ```
+
+pub use crate::{
+    RustClr, RustClrEnv, ClrOutput, Instance, Dispatch,
+    RuntimeVersion, InvocationType,
+    Variant, VariantArgs, FromVariant, WinStr,
+    safearray::SafeArray,
+    error::ClrError,
+    schema::{_AppDomain, _Assembly, _ConstructorInfo, _EventInfo, _FieldInfo, _MethodInfo, _PropertyInfo, _Type},
+};
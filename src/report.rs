@@ -0,0 +1,104 @@
+use std::fmt;
+
+/// Category of an artifact left in the process by an execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArtifactKind {
+    /// A module mapped into the process.
+    Module,
+
+    /// An application domain created or used to run the payload.
+    AppDomain,
+
+    /// An assembly loaded into the application domain.
+    Assembly,
+
+    /// A managed call made by `rustclr` around the entry point.
+    ManagedCall,
+
+    /// A change to executable memory.
+    Memory,
+
+    /// A file written to disk.
+    File,
+
+    /// An event expected from the CLR ETW providers.
+    Etw,
+
+    /// A process or console created or joined on behalf of the payload.
+    Process,
+}
+
+impl fmt::Display for ArtifactKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ArtifactKind::Module => "module",
+            ArtifactKind::AppDomain => "appdomain",
+            ArtifactKind::Assembly => "assembly",
+            ArtifactKind::ManagedCall => "managed call",
+            ArtifactKind::Memory => "memory",
+            ArtifactKind::File => "file",
+            ArtifactKind::Etw => "etw",
+            ArtifactKind::Process => "process",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// A single artifact of an execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Artifact {
+    /// Category of the artifact.
+    pub kind: ArtifactKind,
+
+    /// Human-readable description of the artifact.
+    pub description: String,
+}
+
+/// Artifacts that running a configured `RustClr` is expected to leave behind.
+///
+/// The report is derived from the configuration alone, without starting a runtime, so it
+/// describes what an execution *would* produce. It is meant for operators checking the
+/// footprint of a configuration and for defenders building detections. `rustclr` does not
+/// patch code or change memory protections itself, so the only executable memory listed is
+/// the one allocated by the JIT compiler.
+#[derive(Debug, Clone, Default)]
+pub struct DetectionReport {
+    /// The artifacts, in the order they are produced.
+    pub artifacts: Vec<Artifact>,
+}
+
+impl DetectionReport {
+    /// Appends an artifact to the report.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The category of the artifact.
+    /// * `description` - The description of the artifact.
+    pub(crate) fn push(&mut self, kind: ArtifactKind, description: impl Into<String>) {
+        self.artifacts.push(Artifact { kind, description: description.into() });
+    }
+
+    /// Returns the artifacts of the given category.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The category to filter by.
+    ///
+    /// # Returns
+    ///
+    /// * An iterator over the matching artifacts.
+    pub fn of_kind(&self, kind: ArtifactKind) -> impl Iterator<Item = &Artifact> {
+        self.artifacts.iter().filter(move |artifact| artifact.kind == kind)
+    }
+}
+
+impl fmt::Display for DetectionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for artifact in &self.artifacts {
+            writeln!(f, "[{}] {}", artifact.kind, artifact.description)?;
+        }
+
+        Ok(())
+    }
+}
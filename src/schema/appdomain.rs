@@ -3,13 +3,18 @@ use {
     std::{ffi::c_void, ops::Deref, ptr::null_mut},
     windows_sys::{
         core::{BSTR, HRESULT},
-        Win32::System::Com::SAFEARRAY
+        Win32::System::{
+            Com::SAFEARRAY,
+            Ole::{SafeArrayGetElement, SafeArrayGetLBound, SafeArrayGetUBound},
+            Variant::{VT_DISPATCH, VT_UNKNOWN},
+        }
     },
 };
 
-use super::{_Type, _Assembly};
+use super::{_Type, _Assembly, BindingFlags};
 use crate::{
-    create_safe_array_buffer,
+    wildcard_match, SafeArray,
+    InvocationType, ManagedName, Variant,
     WinStr, error::ClrError,
 };
 
@@ -41,8 +46,8 @@ impl _AppDomain {
     /// * `Ok(_Assembly)` - If successful, returns an `_Assembly` instance.
     /// * `Err(ClrError)` - If loading fails, returns a `ClrError`.
     pub fn load_assembly(&self, buffer: &[u8]) -> Result<_Assembly, ClrError> {
-        let safe_array = create_safe_array_buffer(&buffer)?;
-        self.Load_3(safe_array)
+        let safe_array = SafeArray::from_bytes(buffer)?;
+        self.Load_3(safe_array.as_ptr())
     }
 
     /// Loads an assembly by its name in the current application domain.
@@ -62,6 +67,148 @@ impl _AppDomain {
         self.Load_2(lib_name)
     }
 
+    /// Retrieves the assemblies loaded into the application domain.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<_Assembly>)` - The loaded assemblies, in load order.
+    /// * `Err(ClrError)` - If the assemblies cannot be retrieved.
+    pub fn assemblies(&self) -> Result<Vec<_Assembly>, ClrError> {
+        let sa_assemblies = self.GetAssemblies()?;
+        if sa_assemblies.is_null() {
+            return Err(ClrError::NullPointerError("GetAssemblies"));
+        }
+
+        let mut assemblies = Vec::new();
+        let mut lbound = 0;
+        let mut ubound = -1;
+        unsafe {
+            SafeArrayGetLBound(sa_assemblies, 1, &mut lbound);
+            SafeArrayGetUBound(sa_assemblies, 1, &mut ubound);
+
+            for i in lbound..=ubound {
+                let mut p_assembly = null_mut::<c_void>();
+                let hr = SafeArrayGetElement(sa_assemblies, &i, &mut p_assembly as *mut _ as *mut _);
+                if hr != 0 || p_assembly.is_null() {
+                    return Err(ClrError::ApiError("SafeArrayGetElement", hr));
+                }
+
+                assemblies.push(_Assembly::from_raw(p_assembly)?);
+            }
+        }
+
+        Ok(assemblies)
+    }
+
+    /// Resolves a type anywhere in the application domain, as `Type.GetType` does.
+    ///
+    /// An assembly-qualified name (`Ns.Type, AssemblyName`) is looked up in the loaded
+    /// assembly with that simple name, and the assembly is loaded if it is not loaded yet.
+    /// A name without assembly is looked up in every loaded assembly, in load order, so the
+    /// caller does not need to know which `_Assembly` declares the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The full name of the type, optionally followed by a comma and the assembly name.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - The resolved type.
+    /// * `Err(ClrError)` - If no assembly declares the type or the assembly cannot be loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let uri = clr.app_domain.resolve_type("System.Uri, System")?;
+    /// let list = clr.app_domain.resolve_type("System.Collections.Generic.List`1")?;
+    /// ```
+    pub fn resolve_type(&self, name: &str) -> Result<_Type, ClrError> {
+        let (type_name, assembly_name) = split_qualified_name(name);
+
+        // Prefers the assemblies already loaded, without going through the binder
+        for assembly in self.assemblies()? {
+            if let Some(assembly_name) = assembly_name {
+                let full_name = assembly.ToString()?;
+                let simple_name = full_name.split(',').next().unwrap_or_default();
+                let wanted = assembly_name.split(',').next().unwrap_or_default().trim();
+                if !simple_name.eq_ignore_ascii_case(wanted) {
+                    continue;
+                }
+            }
+
+            if let Ok(ty) = assembly.resolve_type(type_name) {
+                return Ok(ty);
+            }
+        }
+
+        // Lets `Type.GetType` load the assembly named by the caller
+        let result = self.load_lib(&ManagedName::Mscorlib.value())?
+            .resolve_type(&ManagedName::Type.value())?
+            .invoke(&ManagedName::GetType.value(), None, Some(vec![name.to_variant()]), InvocationType::Static)?;
+
+        match unsafe { result.Anonymous.Anonymous.vt } {
+            VT_UNKNOWN | VT_DISPATCH => _Type::from_raw(unsafe { result.Anonymous.Anonymous.Anonymous.punkVal }),
+            _ => Err(ClrError::NullPointerError("GetType")),
+        }
+    }
+
+    /// Searches the loaded assemblies for methods whose type and name match wildcard patterns.
+    ///
+    /// Patterns use `*` and `?` as in [`wildcard_match`]. Types are matched by their full name
+    /// and methods by their simple name, including non-public and static methods declared by
+    /// the type. Assemblies whose types cannot be loaded are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_pattern` - The pattern matched against full type names, e.g. `*.Program`.
+    /// * `method_pattern` - The pattern matched against method names, e.g. `Run*`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, String, String)>)` - The matches, as `(assembly, type, method)` triples.
+    ///   Overloads of a method are reported once.
+    /// * `Err(ClrError)` - If the loaded assemblies cannot be enumerated.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for (assembly, ty, method) in clr.app_domain.search("*.Program", "Run*")? {
+    ///     println!("[{assembly}] {ty}::{method}");
+    /// }
+    /// ```
+    pub fn search(&self, type_pattern: &str, method_pattern: &str) -> Result<Vec<(String, String, String)>, ClrError> {
+        let flags = BindingFlags::Public | BindingFlags::NonPublic
+            | BindingFlags::Instance | BindingFlags::Static
+            | BindingFlags::DeclaredOnly;
+
+        let mut matches = Vec::new();
+        for assembly in self.assemblies()? {
+            let Ok(types) = assembly.type_objects() else {
+                continue;
+            };
+
+            let assembly_name = assembly.ToString()?;
+            for ty in types {
+                let type_name = ty.ToString()?;
+                if !wildcard_match(type_pattern, &type_name) {
+                    continue;
+                }
+
+                let mut names = Vec::new();
+                for (_, method) in ty.methods_with_flags(flags)? {
+                    let name = method.get_name()?;
+                    if wildcard_match(method_pattern, &name) && !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+
+                matches.extend(names.into_iter().map(|name| (assembly_name.clone(), type_name.clone(), name)));
+            }
+        }
+
+        Ok(matches)
+    }
+
     /// Creates an `_AppDomain` instance from a raw COM interface pointer.
     ///
     /// # Arguments
@@ -74,7 +221,7 @@ impl _AppDomain {
     /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
     #[inline(always)]
     pub fn from_raw(raw: *mut c_void) -> Result<_AppDomain, ClrError> {
-        let iunknown = unsafe { IUnknown::from_raw(raw as *mut c_void) };
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
         iunknown.cast::<_AppDomain>().map_err(|_| ClrError::CastingError("_AppDomain"))
     }
 }
@@ -139,6 +286,22 @@ impl _AppDomain {
         }
     }
     
+    /// Retrieves the assemblies loaded into the application domain.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of assemblies.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn GetAssemblies(&self) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetAssemblies)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetAssemblies", hr))
+        }
+    }
+
     /// Retrieves the primary type associated with the current app domain.
     ///
     /// # Returns
@@ -164,7 +327,7 @@ unsafe impl Interface for _AppDomain {
     /// This GUID is used to identify the `_AppDomain` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `_AppDomain` interface.
-    const IID: GUID = GUID::from_u128(0x05F696DC_2B29_3663_AD8B_C4389CF2A713);
+    const IID: GUID = crate::com::IID__APPDOMAIN;
 }
 
 impl Deref for _AppDomain {
@@ -410,8 +573,20 @@ pub struct _AppDomainVtbl {
     /// Placeholder for the method. Not used directly.
     get_ShadowCopyFiles: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    GetAssemblies: *const c_void,
+    /// Retrieves the assemblies loaded into the application domain.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to a `SAFEARRAY` that receives the assemblies.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetAssemblies: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the method. Not used directly.
     AppendPrivatePath: *const c_void,
@@ -448,4 +623,30 @@ pub struct _AppDomainVtbl {
 
     /// Placeholder for the method. Not used directly.
     get_DynamicDirectory: *const c_void
-}
\ No newline at end of file
+}
+
+/// Splits an assembly-qualified type name into the type and assembly names.
+///
+/// Commas inside the brackets of generic arguments (`List`1[[System.Int32, mscorlib]]`)
+/// belong to the type name.
+///
+/// # Arguments
+///
+/// * `name` - The type name, optionally assembly-qualified.
+///
+/// # Returns
+///
+/// * The type name and, if present, the assembly name.
+fn split_qualified_name(name: &str) -> (&str, Option<&str>) {
+    let mut depth = 0usize;
+    for (index, c) in name.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return (name[..index].trim(), Some(name[index + 1..].trim())),
+            _ => {}
+        }
+    }
+
+    (name.trim(), None)
+}
@@ -4,21 +4,25 @@ use {
     windows_sys::{
         core::{BSTR, HRESULT},
         Win32::{
-            Foundation::VARIANT_BOOL, 
+            Foundation::{VARIANT_BOOL, VARIANT_FALSE}, 
             System::{
                 Com::SAFEARRAY, 
-                Variant::VARIANT,
-                Ole::{SafeArrayGetElement, SafeArrayGetLBound, SafeArrayGetUBound}
+                Variant::{VariantClear, VARIANT, VT_ARRAY, VT_EMPTY, VT_NULL},
+                Ole::{SafeArrayDestroy, SafeArrayGetElement, SafeArrayGetLBound, SafeArrayGetUBound}
             }
         }
     },
 };
 
 use {
-    super::{_MethodInfo, _Type},
-    crate::{error::ClrError, WinStr},
+    super::{_MethodInfo, _Module, _Type, BindingFlags, itype::members},
+    crate::{error::ClrError, EmbeddedDependency, Instance, SafeArray, InvocationType, ManagedName, RuntimeVersion, Variant, WinStr},
+    crate::instance::custom_attributes,
 };
 
+/// `COR_E_MISSINGMETHOD`, raised when no constructor matches the requested arguments.
+pub(crate) const COR_E_MISSINGMETHOD: i32 = 0x80131513u32 as i32;
+
 /// This struct represents the COM `_Assembly` interface, a .NET assembly in the CLR environment.
 /// 
 /// `_Assembly` wraps a COM interface pointer (`IUnknown`) and provides methods
@@ -33,6 +37,9 @@ pub struct _Assembly(windows_core::IUnknown);
 impl _Assembly {
     /// Resolves a type by name within the assembly.
     ///
+    /// Non-public types are resolved as well, and nested types use the `+` separator
+    /// of their full name (e.g. `Ns.Outer+Inner`).
+    ///
     /// # Arguments
     ///
     /// * `name` - A string slice representing the name of the type to resolve.
@@ -52,6 +59,10 @@ impl _Assembly {
     /// to invoke it. It distinguishes between `Main()` and `Main(System.String[])` entry points,
     /// allowing optional arguments to be passed when the latter is detected.
     ///
+    /// Only the parameters of the entry point are checked, not its name or return type, so the
+    /// entry points of other compilers run as well: VB.NET `Sub Main`/`Function Main` in a
+    /// `Module`, F# `[<EntryPoint>] main` and the implicit `main@` of F# programs without one.
+    ///
     /// # Arguments
     ///
     /// * `args` - An `*mut SAFEARRAY` containing arguments to be passed to
@@ -63,10 +74,10 @@ impl _Assembly {
     /// * `Err(ClrError)` - Returns an error if the entry point cannot be resolved or invoked.
     pub fn run(&self, args: *mut SAFEARRAY) -> Result<VARIANT, ClrError> {
         let entrypoint = self.get_EntryPoint()?;
-        let str = entrypoint.ToString()?;
-        match str.as_str() {
-            str if str.ends_with("Main()") => entrypoint.invoke(None, None),
-            str if str.ends_with("Main(System.String[])") =>  {
+        let signature = entrypoint.ToString()?;
+        match signature.rsplit_once('(').map(|(_, parameters)| parameters) {
+            Some(")") => entrypoint.invoke(None, None),
+            Some(parameters) if parameters.strip_suffix(')') == Some(&ManagedName::StringArray.value()) =>  {
                 if args.is_null() {
                     return Err(ClrError::MissingArguments)
                 }
@@ -79,6 +90,10 @@ impl _Assembly {
 
     /// Creates an instance of a type within the assembly.
     ///
+    /// The type is checked before calling its constructor, so interfaces, abstract types and
+    /// static classes are reported with a descriptive `ClrError::CreateInstanceError` instead
+    /// of an opaque HRESULT. Value types are created with their default value and returned boxed.
+    ///
     /// # Arguments
     ///
     /// * `name` - A string slice representing the name of the type.
@@ -86,10 +101,121 @@ impl _Assembly {
     /// # Returns
     ///
     /// * `Ok(VARIANT)` - If successful, returns a `VARIANT` containing the created instance.
-    /// * `Err(ClrError)` - If creation fails, returns a `ClrError`.
+    /// * `Err(ClrError)` - If the type does not exist, cannot be instantiated, or creation fails.
     pub fn create_instance(&self, name: &str) -> Result<VARIANT, ClrError> {
+        let ty = self.resolve_type(name)
+            .map_err(|_| ClrError::CreateInstanceError(name.to_string(), "type not found"))?;
+
+        if ty.get_IsInterface()? {
+            return Err(ClrError::CreateInstanceError(name.to_string(), "interfaces cannot be instantiated"));
+        }
+
+        // Static classes are emitted as abstract sealed types
+        if ty.get_IsAbstract()? {
+            let reason = if ty.get_IsSealed()? {
+                "static classes cannot be instantiated"
+            } else {
+                "abstract types cannot be instantiated"
+            };
+
+            return Err(ClrError::CreateInstanceError(name.to_string(), reason));
+        }
+
         let type_name = name.to_bstr();
-        self.CreateInstance(type_name)
+        match self.CreateInstance(type_name) {
+            Err(ClrError::ApiError(_, COR_E_MISSINGMETHOD)) if !ty.get_IsValueType()? => Err(
+                ClrError::CreateInstanceError(name.to_string(), "the type has no public parameterless constructor")
+            ),
+            result => result,
+        }
+    }
+
+    /// Creates an instance of a type within the assembly, passing arguments to its constructor.
+    ///
+    /// The constructor is selected among the public instance constructors by the runtime's
+    /// default binder. See [`create_instance_with_flags`](Self::create_instance_with_flags)
+    /// to consider non-public constructors.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The full name of the type.
+    /// * `args` - The arguments of the constructor.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The created instance.
+    /// * `Err(ClrError)` - If the type does not exist, no constructor matches the arguments, or the constructor throws.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    /// let version = mscorlib.create_instance_with_args("System.Version", vec![1.to_variant(), 2.to_variant()])?;
+    /// ```
+    pub fn create_instance_with_args(&self, name: &str, args: Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        self.create_instance_with_flags(name, args, BindingFlags::Public | BindingFlags::Instance)
+    }
+
+    /// Creates an instance of a type within the assembly using explicit `BindingFlags`.
+    ///
+    /// Arguments that need a conversion to the parameter types (e.g. a string passed to a
+    /// `double` parameter) are converted with the invariant culture, so the result does not
+    /// depend on the regional settings of the machine.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The full name of the type.
+    /// * `args` - The arguments of the constructor.
+    /// * `flags` - The `BindingFlags` used to find the constructor. Include `BindingFlags::NonPublic`
+    ///   to consider `private` and `internal` constructors.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The created instance.
+    /// * `Err(ClrError)` - If the type does not exist, no constructor matches the arguments, or the constructor throws.
+    pub fn create_instance_with_flags(&self, name: &str, args: Vec<VARIANT>, flags: BindingFlags) -> Result<VARIANT, ClrError> {
+        // `Assembly` is declared by mscorlib, which also declares `CultureInfo`
+        let mut culture = self.GetType()?
+            .get_Assembly()?
+            .resolve_type(&ManagedName::CultureInfo.value())?
+            .invoke(&ManagedName::GetInvariantCulture.value(), None, None, InvocationType::Static)?;
+
+        let args = if args.is_empty() { None } else { Some(SafeArray::copy_from(&args)?) };
+        let result = self.CreateInstance_3(
+            name.to_bstr(),
+            0,
+            flags,
+            args.as_ref().map_or(null_mut(), SafeArray::as_ptr),
+            unsafe { culture.Anonymous.Anonymous.Anonymous.punkVal }
+        );
+
+        unsafe { VariantClear(&mut culture) };
+        match result {
+            Ok(value) if matches!(unsafe { value.Anonymous.Anonymous.vt }, VT_EMPTY | VT_NULL) => {
+                Err(ClrError::CreateInstanceError(name.to_string(), "type not found"))
+            }
+            Err(ClrError::ApiError(_, COR_E_MISSINGMETHOD)) => Err(
+                ClrError::CreateInstanceError(name.to_string(), "no constructor matches the arguments")
+            ),
+            result => result,
+        }
+    }
+
+    /// Creates an instance of a type within the assembly, wrapped for chained method calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice representing the name of the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Instance)` - The created instance together with its type.
+    /// * `Err(ClrError)` - If the type cannot be resolved or the instance cannot be created.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(&self, name: &str) -> Result<Instance, ClrError> {
+        let ty = self.resolve_type(name)?;
+        let value = self.create_instance(name)?;
+        Ok(Instance::new(value, ty))
     }
 
     /// Retrieves all types within the assembly.
@@ -99,6 +225,19 @@ impl _Assembly {
     /// * `Ok(Vec<String>)` - On success, returns a vector of type names as `String`.
     /// * `Err(ClrError)` - On failure, returns an appropriate `ClrError`.
     pub fn types(&self) -> Result<Vec<String>, ClrError> {
+        self.type_objects()?
+            .iter()
+            .map(_Type::ToString)
+            .collect()
+    }
+
+    /// Retrieves all types within the assembly as `_Type` objects.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<_Type>)` - On success, returns the types defined by the assembly.
+    /// * `Err(ClrError)` - On failure, returns an appropriate `ClrError`.
+    pub fn type_objects(&self) -> Result<Vec<_Type>, ClrError> {
         let sa_types = self.GetTypes()?;
         if sa_types.is_null() {
             return Err(ClrError::NullPointerError("GetTypes"));
@@ -118,15 +257,266 @@ impl _Assembly {
                     return Err(ClrError::ApiError("SafeArrayGetElement", hr));
                 }
 
-                let _type = _Type::from_raw(p_type as *mut c_void)?;
-                let type_name = _type.ToString()?;
-                types.push(type_name);
+                types.push(_Type::from_raw(p_type as *mut c_void)?);
+            }
+        }
+
+        Ok(types)
+    }
+
+    /// Retrieves the types of the assembly implementing an interface or deriving from a class.
+    ///
+    /// This is the usual way for plugin loaders to discover the entry types of an assembly.
+    /// The base type itself is not included.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The `_Type` of the interface or base class, e.g. resolved from a contract assembly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<_Type>)` - The matching types, abstract types and interfaces included.
+    /// * `Err(ClrError)` - If the types of the assembly cannot be retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let plugin = contracts.resolve_type("Host.IPlugin")?;
+    /// for ty in assembly.implementations(&plugin)? {
+    ///     println!("{}", ty.ToString()?);
+    /// }
+    /// ```
+    pub fn implementations(&self, base: &_Type) -> Result<Vec<_Type>, ClrError> {
+        let mut types = Vec::new();
+        for ty in self.type_objects()? {
+            if ty.implements(base)? {
+                types.push(ty);
             }
         }
 
         Ok(types)
     }
 
+    /// Retrieves the modules of the assembly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<_Module>)` - On success, returns the modules of the assembly, the manifest module first.
+    /// * `Err(ClrError)` - On failure, returns an appropriate `ClrError`.
+    pub fn modules(&self) -> Result<Vec<_Module>, ClrError> {
+        let sa_modules = self.GetModules()?;
+        if sa_modules.is_null() {
+            return Err(ClrError::NullPointerError("GetModules"));
+        }
+
+        let result = members(sa_modules, _Module::from_raw);
+        unsafe { SafeArrayDestroy(sa_modules) };
+        result
+    }
+
+    /// Retrieves the global methods of the assembly, declared at module level outside of any type.
+    ///
+    /// Global methods are common in obfuscated or merged assemblies and are not reachable
+    /// through [`_Assembly::type_objects`]. `_Module` only exposes `IDispatch`, so they are
+    /// listed by invoking `Module.GetMethods` through reflection. Public and non-public
+    /// methods are included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, _MethodInfo)>)` - The signature of each method (e.g. `System.String Greet(System.String)`),
+    ///   with its `_MethodInfo`.
+    /// * `Err(ClrError)` - If the modules or their methods cannot be retrieved.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for (signature, method) in assembly.global_methods()? {
+    ///     println!("{signature}");
+    /// }
+    /// ```
+    pub fn global_methods(&self) -> Result<Vec<(String, _MethodInfo)>, ClrError> {
+        let mscorlib = self.GetType()?.get_Assembly()?;
+        let module_type = mscorlib.resolve_type(&ManagedName::Module.value())?;
+        let flags = BindingFlags::Public | BindingFlags::NonPublic | BindingFlags::Static;
+
+        let mut methods = Vec::new();
+        for module in self.modules()? {
+            // `GetMethods(BindingFlags)` expects a boxed enum, not a plain integer
+            let binding_flags = mscorlib
+                .resolve_type(&ManagedName::BindingFlags.value())?
+                .enum_from_value(flags.0 as i64)?;
+
+            let result = module_type.invoke(
+                &ManagedName::GetMethods.value(),
+                Some(module.to_variant()),
+                Some(vec![binding_flags]),
+                InvocationType::Instance
+            )?;
+
+            let sa_methods = unsafe {
+                if result.Anonymous.Anonymous.vt & VT_ARRAY == 0 {
+                    return Err(ClrError::VariantUnsupported);
+                }
+
+                result.Anonymous.Anonymous.Anonymous.parray
+            };
+
+            let module_methods = members(sa_methods, _MethodInfo::from_raw);
+            unsafe { SafeArrayDestroy(sa_methods) };
+
+            for method in module_methods? {
+                methods.push((method.ToString()?, method));
+            }
+        }
+
+        Ok(methods)
+    }
+
+    /// Retrieves a global method of the assembly by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method (e.g. `Greet`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_MethodInfo)` - The first global method with this name.
+    /// * `Err(ClrError)` - If the methods cannot be listed, or `ClrError::MethodNotFound` if none matches.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let greet = assembly.global_method("Greet")?;
+    /// let result = greet.invoke_params(None, vec!["rustclr".to_variant()])?;
+    /// ```
+    pub fn global_method(&self, name: &str) -> Result<_MethodInfo, ClrError> {
+        for (_, method) in self.global_methods()? {
+            if method.get_name()? == name {
+                return Ok(method);
+            }
+        }
+
+        Err(ClrError::MethodNotFound)
+    }
+
+    /// Retrieves the names of the resources embedded in the assembly manifest.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - The names of the manifest resources (e.g. `Payload.Properties.Resources.resources`).
+    /// * `Err(ClrError)` - If the names cannot be retrieved.
+    pub fn manifest_resource_names(&self) -> Result<Vec<String>, ClrError> {
+        let sa_names = self.GetManifestResourceNames()?;
+        if sa_names.is_null() {
+            return Err(ClrError::NullPointerError("GetManifestResourceNames"));
+        }
+
+        let result = members(sa_names, |raw| Ok((raw as *const u16).to_string()));
+        unsafe { SafeArrayDestroy(sa_names) };
+        result
+    }
+
+    /// Detects the dependencies packed into the assembly as manifest resources.
+    ///
+    /// Single-file tools built with Costura embed their references as `costura.<name>.dll`
+    /// resources, optionally compressed, and load them from a resolver installed by their
+    /// module initializer. See [`EmbeddedDependency`] to extract them, and
+    /// [`RustClr::with_embedded_dependencies`](crate::RustClr::with_embedded_dependencies)
+    /// to register them with the domain before the entry point runs.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<EmbeddedDependency>)` - The embedded dependencies, empty if the assembly is not packed.
+    /// * `Err(ClrError)` - If the manifest resources cannot be listed.
+    pub fn embedded_dependencies(&self) -> Result<Vec<EmbeddedDependency>, ClrError> {
+        Ok(self
+            .manifest_resource_names()?
+            .iter()
+            .filter_map(|name| EmbeddedDependency::from_resource(name))
+            .collect())
+    }
+
+    /// Retrieves the custom attributes applied to the assembly.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Instance)>)` - The full name of each attribute type (e.g. `System.Reflection.AssemblyTitleAttribute`),
+    ///   with the attribute instance.
+    /// * `Err(ClrError)` - If the attributes cannot be retrieved or one of them fails to construct.
+    pub fn custom_attributes(&self) -> Result<Vec<(String, Instance)>, ClrError> {
+        let attributes = self.GetCustomAttributes_2(VARIANT_FALSE)?;
+        custom_attributes(attributes, &self.GetType()?)
+    }
+
+    /// Indicates whether an attribute is applied to the assembly, without constructing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute_type` - The `_Type` of the attribute, e.g. `System.Reflection.AssemblyTitleAttribute`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if the attribute, or an attribute deriving from it, is applied.
+    /// * `Err(ClrError)` - If the check fails.
+    pub fn is_defined(&self, attribute_type: &_Type) -> Result<bool, ClrError> {
+        self.IsDefined(attribute_type, VARIANT_FALSE)
+    }
+
+    /// Retrieves the entry point of the assembly, if it has one.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(_MethodInfo))` - The entry point of an executable.
+    /// * `Ok(None)` - If the assembly is a library without entry point.
+    /// * `Err(ClrError)` - If the entry point cannot be retrieved.
+    pub fn entry_point(&self) -> Result<Option<_MethodInfo>, ClrError> {
+        match self.get_EntryPoint() {
+            Ok(entrypoint) => Ok(Some(entrypoint)),
+            Err(ClrError::NullPointerError(_)) => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Retrieves the version of the CLR the assembly was built against (e.g. `v4.0.30319`),
+    /// as recorded in its metadata header.
+    ///
+    /// `ImageRuntimeVersion` is not part of the COM `_Assembly` interface, so the property
+    /// is read through reflection.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The runtime version string of the image.
+    /// * `Err(ClrError)` - If the property cannot be read.
+    pub fn get_ImageRuntimeVersion(&self) -> Result<String, ClrError> {
+        let version = self.GetType()?.invoke(
+            &ManagedName::GetImageRuntimeVersion.value(),
+            Some(self.to_variant()),
+            None,
+            InvocationType::Instance
+        )?;
+
+        Ok(unsafe { version.Anonymous.Anonymous.Anonymous.bstrVal }.to_string())
+    }
+
+    /// Retrieves the `RuntimeVersion` the assembly targets.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RuntimeVersion)` - The runtime matching [`_Assembly::get_ImageRuntimeVersion`].
+    /// * `Err(ClrError)` - If the version cannot be read or is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let assembly = clr.app_domain.load_assembly(&buffer)?;
+    /// if assembly.runtime_version()? == RuntimeVersion::V2 && assembly.entry_point()?.is_some() {
+    ///     println!("{} targets .NET 2.0", assembly.get_FullName()?);
+    /// }
+    /// ```
+    pub fn runtime_version(&self) -> Result<RuntimeVersion, ClrError> {
+        self.get_ImageRuntimeVersion()?.parse()
+    }
+
     /// Creates an `_Assembly` instance from a raw COM interface pointer.
     ///
     /// # Arguments
@@ -195,11 +585,15 @@ impl _Assembly {
     /// # Returns
     ///
     /// * `Ok(_MethodInfo)` - If successful, returns the entry point as `_MethodInfo`.
-    /// * `Err(ClrError)` - If retrieval fails, returns a `ClrError`.
+    /// * `Err(ClrError)` - If retrieval fails, or `ClrError::NullPointerError` if the assembly has no entry point.
     pub fn get_EntryPoint(&self) -> Result<_MethodInfo, ClrError> {
         let mut result = null_mut();
         let hr = unsafe { (Interface::vtable(self).get_EntryPoint)(Interface::as_raw(self), &mut result) };
         if hr == 0 {
+            if result.is_null() {
+                return Err(ClrError::NullPointerError("get_EntryPoint"));
+            }
+
             _MethodInfo::from_raw(result as *mut c_void)
         } else {
             Err(ClrError::ApiError("get_EntryPoint", hr))
@@ -220,6 +614,11 @@ impl _Assembly {
         let mut result = null_mut();
         let hr: i32 = unsafe { (Interface::vtable(self).GetType_2)(Interface::as_raw(self), name, &mut result) };
         if hr == 0 {
+            // `Assembly.GetType` returns null when the type does not exist
+            if result.is_null() {
+                return Err(ClrError::NullPointerError("GetType_2"));
+            }
+
             _Type::from_raw(result as *mut c_void)
         } else {
             Err(ClrError::ApiError("GetType_2", hr))
@@ -242,6 +641,38 @@ impl _Assembly {
         }
     }
 
+    /// Retrieves all modules of the assembly as a `SAFEARRAY`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - If successful, returns a pointer to the `SAFEARRAY` of `_Module`.
+    /// * `Err(ClrError)` - If retrieval fails, returns a `ClrError`.
+    pub fn GetModules(&self) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetModules)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetModules", hr))
+        }
+    }
+
+    /// Retrieves the names of all manifest resources of the assembly as a `SAFEARRAY`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - If successful, returns a pointer to the `SAFEARRAY` of `BSTR`.
+    /// * `Err(ClrError)` - If retrieval fails, returns a `ClrError`.
+    pub fn GetManifestResourceNames(&self) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetManifestResourceNames)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetManifestResourceNames", hr))
+        }
+    }
+
     /// Creates an instance of a type using its name as a `BSTR`.
     ///
     /// # Arguments
@@ -262,6 +693,50 @@ impl _Assembly {
         }
     }
 
+    /// Creates an instance of a type, passing arguments to the constructor selected by `bindingAttr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `typeName` - The name of the type to create, as a `BSTR`.
+    /// * `ignoreCase` - Whether the type name is matched case-insensitively.
+    /// * `bindingAttr` - The `BindingFlags` used to find the constructor.
+    /// * `args` - A pointer to a `SAFEARRAY` of constructor arguments, or null for none.
+    /// * `culture` - A pointer to a `CultureInfo` used to convert arguments, or null for the current culture.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The created instance, or an empty `VARIANT` if the type was not found.
+    /// * `Err(ClrError)` - If creation fails, returns a `ClrError`.
+    pub fn CreateInstance_3(
+        &self,
+        typeName: BSTR,
+        ignoreCase: VARIANT_BOOL,
+        bindingAttr: BindingFlags,
+        args: *mut SAFEARRAY,
+        culture: *mut c_void
+    ) -> Result<VARIANT, ClrError> {
+        let mut result = unsafe { std::mem::zeroed::<VARIANT>() };
+        let hr = unsafe {
+            (Interface::vtable(self).CreateInstance_3)(
+                Interface::as_raw(self),
+                typeName,
+                ignoreCase,
+                bindingAttr,
+                null_mut(),
+                args,
+                culture,
+                null_mut(),
+                &mut result
+            )
+        };
+
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("CreateInstance_3", hr))
+        }
+    }
+
     /// Retrieves the main type associated with the assembly.
     ///
     /// # Returns
@@ -421,6 +896,68 @@ impl _Assembly {
             }
         }
     }
+    /// Retrieves the custom attributes of the given type applied to the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributeType` - The `_Type` of the attributes to return.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a `SAFEARRAY` of `VARIANT` attribute instances.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetCustomAttributes(&self, attributeType: &_Type, inherit: VARIANT_BOOL) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetCustomAttributes)(Interface::as_raw(self), attributeType.as_raw(), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetCustomAttributes", hr))
+        }
+    }
+
+    /// Retrieves all the custom attributes applied to the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a `SAFEARRAY` of `VARIANT` attribute instances.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetCustomAttributes_2(&self, inherit: VARIANT_BOOL) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetCustomAttributes_2)(Interface::as_raw(self), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetCustomAttributes_2", hr))
+        }
+    }
+
+    /// Indicates whether custom attributes of the given type are applied to the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributeType` - The `_Type` of the attribute.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the attribute is applied.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn IsDefined(&self, attributeType: &_Type, inherit: VARIANT_BOOL) -> Result<bool, ClrError> {
+        let mut result = VARIANT_FALSE;
+        let hr = unsafe { (Interface::vtable(self).IsDefined)(Interface::as_raw(self), attributeType.as_raw(), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result != VARIANT_FALSE)
+        } else {
+            Err(ClrError::ApiError("IsDefined", hr))
+        }
+    }
+
 }
 
 unsafe impl Interface for _Assembly {
@@ -431,7 +968,7 @@ unsafe impl Interface for _Assembly {
     /// This GUID is used to identify the `_Assembly` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `_Assembly` interface.
-    const IID: GUID = GUID::from_u128(0x17156360_2f1a_384a_bc52_fde93c215c5b);
+    const IID: GUID = crate::com::IID__ASSEMBLY;
 }
 
 impl Deref for _Assembly {
@@ -660,8 +1197,20 @@ pub struct _Assembly_Vtbl {
     /// Placeholder for the method. Not used directly.
     GetFiles_2: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    GetManifestResourceNames: *const c_void,
+    /// Retrieves the names of the manifest resources of the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to a `SAFEARRAY` that receives the resource names as `BSTR`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetManifestResourceNames: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the method. Not used directly.
     GetManifestResourceInfo: *const c_void,
@@ -684,14 +1233,60 @@ pub struct _Assembly_Vtbl {
     /// Placeholder for the method. Not used directly.
     get_Evidence: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    GetCustomAttributes: *const c_void,
+    /// Retrieves the custom attributes of a given type applied to the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `attributeType` - Pointer to the `_Type` of the attributes.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the `SAFEARRAY` of attributes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetCustomAttributes: unsafe extern "system" fn(
+        *mut c_void,
+        attributeType: *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    GetCustomAttributes_2: *const c_void,
+    /// Retrieves all the custom attributes applied to the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the `SAFEARRAY` of attributes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetCustomAttributes_2: unsafe extern "system" fn(
+        *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    IsDefined: *const c_void,
+    /// Indicates whether custom attributes of a given type are applied to the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `attributeType` - Pointer to the `_Type` of the attribute.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    IsDefined: unsafe extern "system" fn(
+        *mut c_void,
+        attributeType: *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
     ///Placeholder for the method. Not used directly.
     GetObjectData: *const c_void,
@@ -737,8 +1332,34 @@ pub struct _Assembly_Vtbl {
     /// Placeholder for the method. Not used directly.
     CreateInstance_2: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    CreateInstance_3: *const c_void,
+    /// Creates an instance of a type, passing arguments to its constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `typeName` - The name of the type as a `BSTR`.
+    /// * `ignoreCase` - Whether the type name is matched case-insensitively.
+    /// * `bindingAttr` - Flags that control how the constructor is found.
+    /// * `binder` - Pointer to a binder; typically `null`.
+    /// * `args` - Pointer to a `SAFEARRAY` of constructor arguments.
+    /// * `culture` - Pointer to a `CultureInfo` used to convert arguments.
+    /// * `activationAttributes` - Pointer to a `SAFEARRAY` of activation attributes; typically `null`.
+    /// * `pRetVal` - Pointer to a `VARIANT` that receives the created instance.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    CreateInstance_3: unsafe extern "system" fn(
+        *mut c_void,
+        typeName: BSTR,
+        ignoreCase: VARIANT_BOOL,
+        bindingAttr: BindingFlags,
+        binder: *mut c_void,
+        args: *mut SAFEARRAY,
+        culture: *mut c_void,
+        activationAttributes: *mut SAFEARRAY,
+        pRetVal: *mut VARIANT
+    ) -> HRESULT,
 
     /// Placeholder for the method. Not used directly.
     GetLoadedModules: *const c_void,
@@ -746,8 +1367,20 @@ pub struct _Assembly_Vtbl {
     /// Placeholder for the method. Not used directly.
     GetLoadedModules_2: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    GetModules: *const c_void,
+    /// Retrieves the modules of the assembly.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to a `SAFEARRAY` that receives the `_Module` objects.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetModules: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the method. Not used directly.
     GetModules_2: *const c_void,
@@ -761,4 +1394,3 @@ pub struct _Assembly_Vtbl {
     /// Placeholder for the method. Not used directly.
     get_GlobalAssemblyCache: *const c_void
 }   
-
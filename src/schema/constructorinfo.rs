@@ -0,0 +1,330 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref, ptr::{null, null_mut}},
+    windows_sys::{
+        core::{BSTR, HRESULT},
+        Win32::System::{
+            Com::SAFEARRAY,
+            Ole::{SafeArrayDestroy, SafeArrayGetLBound, SafeArrayGetUBound},
+            Variant::{VariantClear, VARIANT},
+        }
+    }
+};
+
+use crate::{error::ClrError, SafeArray};
+
+/// The `_ConstructorInfo` struct represents a COM interface for accessing constructor metadata
+/// within the .NET environment, allowing objects to be created through a specific constructor.
+/// This struct encapsulates a `windows_core::IUnknown` COM interface.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct _ConstructorInfo(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `_ConstructorInfo` methods.
+impl _ConstructorInfo {
+    /// Creates a new object by invoking the constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments of the constructor, in declaration order.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The new object.
+    /// * `Err(ClrError)` - If the arguments do not match the parameters or the constructor throws.
+    pub fn invoke(&self, args: Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        let args = if args.is_empty() { None } else { Some(SafeArray::copy_from(&args)?) };
+        self.Invoke_5(args.as_ref().map_or(null_mut(), SafeArray::as_ptr))
+    }
+
+    /// Returns the number of parameters declared by the constructor.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The parameter count.
+    /// * `Err(ClrError)` - If the parameters cannot be retrieved.
+    pub fn parameter_count(&self) -> Result<usize, ClrError> {
+        let sa_parameters = self.GetParameters()?;
+        if sa_parameters.is_null() {
+            return Err(ClrError::NullPointerError("GetParameters"));
+        }
+
+        let mut lbound = 0;
+        let mut ubound = -1;
+        unsafe {
+            SafeArrayGetLBound(sa_parameters, 1, &mut lbound);
+            SafeArrayGetUBound(sa_parameters, 1, &mut ubound);
+            SafeArrayDestroy(sa_parameters);
+        }
+
+        Ok((ubound - lbound + 1).max(0) as usize)
+    }
+
+    /// Creates an `_ConstructorInfo` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_ConstructorInfo)` - Wraps the given COM interface as `_ConstructorInfo`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_ConstructorInfo, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_ConstructorInfo>().map_err(|_| ClrError::CastingError("_ConstructorInfo"))
+    }
+}
+
+/// Implementation of the original `_ConstructorInfo` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl _ConstructorInfo {
+    /// Retrieves the string representation of the constructor (equivalent to `ToString` in .NET).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string representation of the constructor, e.g. `Void .ctor(Int32, Int32)`.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn ToString(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_ToString)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("ToString", hr))
+            }
+        }
+    }
+
+    /// Retrieves the parameters of the constructor.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - A `SAFEARRAY` of `ParameterInfo` objects.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn GetParameters(&self) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetParameters)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetParameters", hr))
+        }
+    }
+
+    /// Invokes the constructor with the given arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameters` - A pointer to a `SAFEARRAY` of arguments, or null for none.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The new object.
+    /// * `Err(ClrError)` - Returns an error if the invocation fails.
+    pub fn Invoke_5(&self, parameters: *mut SAFEARRAY) -> Result<VARIANT, ClrError> {
+        unsafe {
+            let mut result = std::mem::zeroed();
+            let hr = (Interface::vtable(self).Invoke_5)(Interface::as_raw(self), parameters, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                VariantClear(&mut result);
+                Err(ClrError::ApiError("Invoke_5", hr))
+            }
+        }
+    }
+}
+
+unsafe impl Interface for _ConstructorInfo {
+    type Vtable = _ConstructorInfo_Vtbl;
+
+    /// The interface identifier (IID) for the `_ConstructorInfo` COM interface.
+    ///
+    /// This GUID is used to identify the `_ConstructorInfo` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `_ConstructorInfo` interface.
+    const IID: GUID = crate::com::IID__CONSTRUCTORINFO;
+}
+
+impl Deref for _ConstructorInfo {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `_ConstructorInfo` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+#[repr(C)]
+pub struct _ConstructorInfo_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIDsOfNames: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke: *const c_void,
+
+    /// Retrieves the string representation of the constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the string result.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_ToString: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    Equals: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetHashCode: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_MemberType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_name: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_DeclaringType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_ReflectedType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    IsDefined: *const c_void,
+
+    /// Retrieves the parameters of the constructor.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer that receives a `SAFEARRAY` of `ParameterInfo` objects.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetParameters: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    GetMethodImplementationFlags: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_MethodHandle: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_Attributes: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_CallingConvention: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsPublic: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsPrivate: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFamily: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsAssembly: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFamilyAndAssembly: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFamilyOrAssembly: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsStatic: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFinal: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsVirtual: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsHideBySig: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsAbstract: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsSpecialName: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsConstructor: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke_3: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke_4: *const c_void,
+
+    /// Invokes the constructor with the given arguments.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `parameters` - Pointer to a `SAFEARRAY` of arguments.
+    /// * `pRetVal` - Pointer to a `VARIANT` that receives the new object.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    Invoke_5: unsafe extern "system" fn(
+        *mut c_void,
+        parameters: *mut SAFEARRAY,
+        pRetVal: *mut VARIANT
+    ) -> HRESULT,
+}
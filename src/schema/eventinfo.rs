@@ -0,0 +1,507 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref, ptr::{null, null_mut}},
+    windows_sys::{
+        core::{BSTR, HRESULT},
+        Win32::System::Variant::{VARIANT, VT_DISPATCH, VT_UNKNOWN},
+    }
+};
+
+use {
+    super::_Type,
+    crate::{
+        Variant, InvocationType, ManagedName,
+        error::ClrError,
+        callback::ManagedCallback,
+    },
+};
+
+/// The `_EventInfo` struct represents a COM interface for accessing event metadata
+/// within the .NET environment, allowing handlers to be added to and removed from events.
+/// This struct encapsulates a `windows_core::IUnknown` COM interface.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct _EventInfo(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `_EventInfo` methods.
+impl _EventInfo {
+    /// Adds a delegate to the invocation list of the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The object that raises the event, or `None` for a static event.
+    /// * `handler` - The delegate to add, which must be of the event handler type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the handler was added.
+    /// * `Err(ClrError)` - If the delegate has a different type or the `add` accessor throws.
+    pub fn add_handler(&self, target: Option<VARIANT>, handler: &IUnknown) -> Result<(), ClrError> {
+        let target = target.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        self.AddEventHandler(target, handler.as_raw())
+    }
+
+    /// Removes a delegate from the invocation list of the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The object that raises the event, or `None` for a static event.
+    /// * `handler` - The delegate to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the handler was removed, or was not subscribed.
+    /// * `Err(ClrError)` - If the `remove` accessor throws.
+    pub fn remove_handler(&self, target: Option<VARIANT>, handler: &IUnknown) -> Result<(), ClrError> {
+        let target = target.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        self.RemoveEventHandler(target, handler.as_raw())
+    }
+
+    /// Subscribes a Rust closure to the event.
+    ///
+    /// The closure receives the `sender` and the event arguments as `VARIANT` values. It may
+    /// be called from any managed thread, and must not outlive the hosted runtime.
+    ///
+    /// The handler type of the event must have the `void (object, T)` shape with a reference
+    /// type `T`, such as `EventHandler`, `EventHandler<T>` or `AssemblyLoadEventHandler`.
+    /// Handlers that return a value (e.g. `ResolveEventHandler`) cannot be bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The object that raises the event, or `None` for a static event.
+    /// * `callback` - The closure invoked every time the event is raised.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(EventSubscription)` - The subscription, removed when it is dropped.
+    /// * `Err(ClrError)` - If the handler type cannot be bound or the handler cannot be added.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClrEnv, Variant};
+    ///
+    /// let clr = RustClrEnv::new(None)?;
+    /// let event = clr.app_domain.GetType()?.event("AssemblyLoad")?;
+    ///
+    /// let subscription = event.subscribe(Some(clr.app_domain.to_variant()), |_, _| {
+    ///     println!("Assembly loaded");
+    /// })?;
+    ///
+    /// // Load assemblies...
+    ///
+    /// subscription.unsubscribe()?;
+    /// ```
+    pub fn subscribe<F>(&self, target: Option<VARIANT>, callback: F) -> Result<EventSubscription, ClrError>
+    where
+        F: Fn(VARIANT, VARIANT) + Send + Sync + 'static,
+    {
+        // `_EventInfo` is implemented by `RuntimeEventInfo`, declared in mscorlib
+        let mscorlib = self.GetType()?.get_Assembly()?;
+        let dictionary = mscorlib.resolve_type(&ManagedName::IDictionary.value())?;
+        let delegate = mscorlib.resolve_type(&ManagedName::Delegate.value())?;
+
+        // Binds `IDictionary.Add(object, object)` of the Rust sink to the handler type
+        let sink = ManagedCallback::create(dictionary.get_Guid()?, Box::new(callback));
+        let add = dictionary.method(&ManagedName::Add.value())?;
+        let handler = delegate.invoke(
+            &ManagedName::CreateDelegate.value(),
+            None,
+            Some(vec![self.get_EventHandlerType()?.to_variant(), sink.to_variant(), add.to_variant()]),
+            InvocationType::Static
+        )?;
+
+        let handler = match unsafe { handler.Anonymous.Anonymous.vt } {
+            VT_UNKNOWN | VT_DISPATCH => unsafe { IUnknown::from_raw(handler.Anonymous.Anonymous.Anonymous.punkVal) },
+            _ => return Err(ClrError::VariantUnsupported),
+        };
+
+        self.add_handler(target, &handler)?;
+        Ok(EventSubscription {
+            event: self.clone(),
+            target,
+            handler: Some(handler),
+        })
+    }
+
+    /// Creates an `_EventInfo` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_EventInfo)` - Wraps the given COM interface as `_EventInfo`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_EventInfo, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_EventInfo>().map_err(|_| ClrError::CastingError("_EventInfo"))
+    }
+}
+
+/// Implementation of the original `_EventInfo` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl _EventInfo {
+    /// Retrieves the string representation of the event (equivalent to `ToString` in .NET).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string representation of the event, e.g. `System.AssemblyLoadEventHandler AssemblyLoad`.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn ToString(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_ToString)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("ToString", hr))
+            }
+        }
+    }
+
+    /// Retrieves the runtime type of the event object itself.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - The `_Type` of the object implementing the interface.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn GetType(&self) -> Result<_Type, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetType)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            _Type::from_raw(result as *mut c_void)
+        } else {
+            Err(ClrError::ApiError("GetType", hr))
+        }
+    }
+
+    /// Retrieves the name of the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The name of the event.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn get_name(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_name)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("get_name", hr))
+            }
+        }
+    }
+
+    /// Adds an event handler to the event source.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A `VARIANT` representing the event source, or empty for static events.
+    /// * `handler` - A pointer to the delegate to add.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the handler was added.
+    /// * `Err(ClrError)` - Returns an error if the handler cannot be added.
+    pub fn AddEventHandler(&self, target: VARIANT, handler: *mut c_void) -> Result<(), ClrError> {
+        let hr = unsafe { (Interface::vtable(self).AddEventHandler)(Interface::as_raw(self), target, handler) };
+        if hr == 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("AddEventHandler", hr))
+        }
+    }
+
+    /// Removes an event handler from the event source.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - A `VARIANT` representing the event source, or empty for static events.
+    /// * `handler` - A pointer to the delegate to remove.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the handler was removed.
+    /// * `Err(ClrError)` - Returns an error if the handler cannot be removed.
+    pub fn RemoveEventHandler(&self, target: VARIANT, handler: *mut c_void) -> Result<(), ClrError> {
+        let hr = unsafe { (Interface::vtable(self).RemoveEventHandler)(Interface::as_raw(self), target, handler) };
+        if hr == 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("RemoveEventHandler", hr))
+        }
+    }
+
+    /// Retrieves the type of the delegate used by the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - The `_Type` of the event handler delegate.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn get_EventHandlerType(&self) -> Result<_Type, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).get_EventHandlerType)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            _Type::from_raw(result as *mut c_void)
+        } else {
+            Err(ClrError::ApiError("get_EventHandlerType", hr))
+        }
+    }
+}
+
+unsafe impl Interface for _EventInfo {
+    type Vtable = _EventInfo_Vtbl;
+
+    /// The interface identifier (IID) for the `_EventInfo` COM interface.
+    ///
+    /// This GUID is used to identify the `_EventInfo` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `_EventInfo` interface.
+    const IID: GUID = crate::com::IID__EVENTINFO;
+}
+
+impl Deref for _EventInfo {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `_EventInfo` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+/// A Rust closure subscribed to a managed event with [`_EventInfo::subscribe`].
+///
+/// The handler is removed from the event when [`EventSubscription::unsubscribe`]
+/// is called or the subscription is dropped.
+pub struct EventSubscription {
+    /// The event the handler is subscribed to.
+    event: _EventInfo,
+
+    /// The object that raises the event, or `None` for a static event.
+    target: Option<VARIANT>,
+
+    /// The delegate bound to the Rust closure, while it is subscribed.
+    handler: Option<IUnknown>,
+}
+
+impl EventSubscription {
+    /// Returns the managed delegate bound to the closure, e.g. to pass it to other APIs.
+    pub fn handler(&self) -> Option<VARIANT> {
+        self.handler.as_ref().map(|handler| handler.to_variant())
+    }
+
+    /// Removes the handler from the event.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the handler was removed.
+    /// * `Err(ClrError)` - If the `remove` accessor of the event throws.
+    pub fn unsubscribe(mut self) -> Result<(), ClrError> {
+        self.remove()
+    }
+
+    /// Removes the handler, if it is still subscribed.
+    fn remove(&mut self) -> Result<(), ClrError> {
+        if let Some(handler) = self.handler.take() {
+            self.event.remove_handler(self.target, &handler)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Implements the `Drop` trait to remove the handler when `EventSubscription` goes out of scope.
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        let _ = self.remove();
+    }
+}
+
+#[repr(C)]
+pub struct _EventInfo_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIDsOfNames: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke: *const c_void,
+
+    /// Retrieves the string representation of the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the string result.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_ToString: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    Equals: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetHashCode: *const c_void,
+
+    /// Retrieves the runtime type of the event object.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to `_Type` that receives the runtime type.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetType: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut _Type
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_MemberType: *const c_void,
+
+    /// Retrieves the name of the event as a `BSTR`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the event's name.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_name: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_DeclaringType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_ReflectedType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    IsDefined: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetAddMethod: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetRemoveMethod: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetRaiseMethod: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_Attributes: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetAddMethod_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetRemoveMethod_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetRaiseMethod_2: *const c_void,
+
+    /// Adds an event handler to the event source.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `target` - A `VARIANT` representing the event source (or empty for static events).
+    /// * `handler` - Pointer to the `_Delegate` to add.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    AddEventHandler: unsafe extern "system" fn(
+        *mut c_void,
+        target: VARIANT,
+        handler: *mut c_void
+    ) -> HRESULT,
+
+    /// Removes an event handler from the event source.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `target` - A `VARIANT` representing the event source (or empty for static events).
+    /// * `handler` - Pointer to the `_Delegate` to remove.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    RemoveEventHandler: unsafe extern "system" fn(
+        *mut c_void,
+        target: VARIANT,
+        handler: *mut c_void
+    ) -> HRESULT,
+
+    /// Retrieves the type of the event handler delegate.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to `_Type` that receives the delegate type.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_EventHandlerType: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut _Type
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsSpecialName: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsMulticast: *const c_void,
+}
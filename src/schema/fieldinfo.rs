@@ -0,0 +1,452 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref, ptr::{null, null_mut}},
+    windows_sys::{
+        core::{BSTR, HRESULT},
+        Win32::{
+            Foundation::{VARIANT_BOOL, VARIANT_FALSE},
+            System::Variant::{VariantClear, VARIANT},
+        }
+    }
+};
+
+use {
+    super::_Type,
+    crate::error::ClrError,
+};
+
+/// The `_FieldInfo` struct represents a COM interface for accessing field metadata
+/// within the .NET environment, allowing fields of objects and types to be read and written.
+/// This struct encapsulates a `windows_core::IUnknown` COM interface.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct _FieldInfo(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `_FieldInfo` methods.
+impl _FieldInfo {
+    /// Reads the value of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object whose field is read, or `None` for a static field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value of the field.
+    /// * `Err(ClrError)` - If the object does not declare the field or the value cannot be read.
+    pub fn get_value(&self, obj: Option<VARIANT>) -> Result<VARIANT, ClrError> {
+        let obj = obj.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        self.GetValue(obj)
+    }
+
+    /// Writes the value of the field.
+    ///
+    /// `readonly` fields can be written too, since reflection does not enforce
+    /// `initonly`; `const` fields cannot.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object whose field is written, or `None` for a static field.
+    /// * `value` - The new value, which must be convertible to the field type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the field was written.
+    /// * `Err(ClrError)` - If the value has a different type or the field cannot be written.
+    pub fn set_value(&self, obj: Option<VARIANT>, value: VARIANT) -> Result<(), ClrError> {
+        let obj = obj.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        self.SetValue_2(obj, value)
+    }
+
+    /// Creates an `_FieldInfo` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_FieldInfo)` - Wraps the given COM interface as `_FieldInfo`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_FieldInfo, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_FieldInfo>().map_err(|_| ClrError::CastingError("_FieldInfo"))
+    }
+}
+
+/// Implementation of the original `_FieldInfo` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl _FieldInfo {
+    /// Retrieves the string representation of the field (equivalent to `ToString` in .NET).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string representation of the field, e.g. `Int32 MaxValue`.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn ToString(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_ToString)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("ToString", hr))
+            }
+        }
+    }
+
+    /// Retrieves the name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The name of the field.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn get_name(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_name)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("get_name", hr))
+            }
+        }
+    }
+
+    /// Retrieves the type of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - The `_Type` of the values stored in the field.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn get_FieldType(&self) -> Result<_Type, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).get_FieldType)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            _Type::from_raw(result as *mut c_void)
+        } else {
+            Err(ClrError::ApiError("get_FieldType", hr))
+        }
+    }
+
+    /// Indicates whether the field is public.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the field is public.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_IsPublic(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_IsPublic)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_IsPublic", hr))
+            }
+        }
+    }
+
+    /// Indicates whether the field is static.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the field is static.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_IsStatic(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_IsStatic)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_IsStatic", hr))
+            }
+        }
+    }
+
+    /// Reads the value of the field on the given object.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - A `VARIANT` representing the target instance, or empty for static fields.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value of the field.
+    /// * `Err(ClrError)` - Returns an error if the value cannot be read.
+    pub fn GetValue(&self, obj: VARIANT) -> Result<VARIANT, ClrError> {
+        unsafe {
+            let mut result = std::mem::zeroed();
+            let hr = (Interface::vtable(self).GetValue)(Interface::as_raw(self), obj, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                VariantClear(&mut result);
+                Err(ClrError::ApiError("GetValue", hr))
+            }
+        }
+    }
+
+    /// Writes the value of the field on the given object.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - A `VARIANT` representing the target instance, or empty for static fields.
+    /// * `value` - The new value of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the value was written.
+    /// * `Err(ClrError)` - Returns an error if the value cannot be written.
+    pub fn SetValue_2(&self, obj: VARIANT, value: VARIANT) -> Result<(), ClrError> {
+        let hr = unsafe { (Interface::vtable(self).SetValue_2)(Interface::as_raw(self), obj, value) };
+        if hr == 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("SetValue_2", hr))
+        }
+    }
+}
+
+unsafe impl Interface for _FieldInfo {
+    type Vtable = _FieldInfo_Vtbl;
+
+    /// The interface identifier (IID) for the `_FieldInfo` COM interface.
+    ///
+    /// This GUID is used to identify the `_FieldInfo` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `_FieldInfo` interface.
+    const IID: GUID = crate::com::IID__FIELDINFO;
+}
+
+impl Deref for _FieldInfo {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `_FieldInfo` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+#[repr(C)]
+pub struct _FieldInfo_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIDsOfNames: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke: *const c_void,
+
+    /// Retrieves the string representation of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the string result.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_ToString: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    Equals: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetHashCode: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_MemberType: *const c_void,
+
+    /// Retrieves the name of the field as a `BSTR`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the field's name.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_name: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_DeclaringType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_ReflectedType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    IsDefined: *const c_void,
+
+    /// Retrieves the type of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to `_Type` that receives the field type.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_FieldType: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut _Type
+    ) -> HRESULT,
+
+    /// Reads the value of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `obj` - A `VARIANT` representing the target instance (or empty for static fields).
+    /// * `pRetVal` - Pointer to a `VARIANT` that receives the value.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetValue: unsafe extern "system" fn(
+        *mut c_void,
+        obj: VARIANT,
+        pRetVal: *mut VARIANT
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    GetValueDirect: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    SetValue: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    SetValueDirect: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_FieldHandle: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_Attributes: *const c_void,
+
+    /// Writes the value of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `obj` - A `VARIANT` representing the target instance (or empty for static fields).
+    /// * `value` - A `VARIANT` holding the new value.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    SetValue_2: unsafe extern "system" fn(
+        *mut c_void,
+        obj: VARIANT,
+        value: VARIANT
+    ) -> HRESULT,
+
+    /// Indicates whether the field is public.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_IsPublic: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsPrivate: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFamily: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsAssembly: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFamilyAndAssembly: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsFamilyOrAssembly: *const c_void,
+
+    /// Indicates whether the field is static.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_IsStatic: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsInitOnly: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsLiteral: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsNotSerialized: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsSpecialName: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsPinvokeImpl: *const c_void,
+}
@@ -64,30 +64,57 @@ impl ICLRMetaHost {
     ///   are `ICLRRuntimeInfo` instances with details about each runtime.
     /// * `Err(ClrError)` - Returns a `ClrError::CastingError` if casting to `ICLRRuntimeInfo` fails.
     pub fn runtimes(&self) -> Result<HashMap<String, ICLRRuntimeInfo>, ClrError> {
-        let enum_unknown = self.EnumerateInstalledRuntimes()?;
-        let mut fetched = 0;
-        let mut rgelt: [Option<IUnknown>; 1] = [None];
-        let mut runtimes: HashMap<String, ICLRRuntimeInfo> = HashMap::new();
-        
-        while enum_unknown.Next(&mut rgelt, Some(&mut fetched)) == 0 && fetched > 0 {
-            let runtime_info = match &rgelt[0] {
-                Some(unknown) => unknown.cast::<ICLRRuntimeInfo>().map_err(|_| ClrError::CastingError("ICLRRuntimeInfo"))?,
-                None => continue,
-            };
-            
-            let mut version_string = vec![0u16; 256];
-            let mut len = version_string.len() as u32;
-            runtime_info.GetVersionString(PWSTR(version_string.as_mut_ptr()), &mut len)?;
-            version_string.retain(|&c| c != 0);
-            
-            let version = String::from_utf16_lossy(&version_string);
-            runtimes.insert(version, runtime_info);
-        }
+        collect_runtimes(self.EnumerateInstalledRuntimes()?)
+    }
 
-        Ok(runtimes)
+    /// Retrieves the runtimes already loaded into a process.
+    ///
+    /// # Arguments
+    ///
+    /// * `process` - A handle to the process, opened with `PROCESS_QUERY_INFORMATION` and `PROCESS_VM_READ`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<String, ICLRRuntimeInfo>)` - A map of the loaded runtime versions, empty if the process does not host the CLR.
+    /// * `Err(ClrError)` - If the process cannot be inspected.
+    pub fn loaded_runtimes(&self, process: HANDLE) -> Result<HashMap<String, ICLRRuntimeInfo>, ClrError> {
+        collect_runtimes(self.EnumerateLoadedRuntimes(process)?)
     }
 }
 
+/// Reads every runtime of an enumerator, keyed by version string.
+///
+/// # Arguments
+///
+/// * `enum_unknown` - The enumerator returned by `EnumerateInstalledRuntimes` or `EnumerateLoadedRuntimes`.
+///
+/// # Returns
+///
+/// * `Ok(HashMap<String, ICLRRuntimeInfo>)` - The runtimes, keyed by version string.
+/// * `Err(ClrError)` - If an element is not an `ICLRRuntimeInfo` or its version cannot be read.
+fn collect_runtimes(enum_unknown: IEnumUnknown) -> Result<HashMap<String, ICLRRuntimeInfo>, ClrError> {
+    let mut fetched = 0;
+    let mut rgelt: [Option<IUnknown>; 1] = [None];
+    let mut runtimes: HashMap<String, ICLRRuntimeInfo> = HashMap::new();
+    
+    while enum_unknown.Next(&mut rgelt, Some(&mut fetched)) == 0 && fetched > 0 {
+        let runtime_info = match &rgelt[0] {
+            Some(unknown) => unknown.cast::<ICLRRuntimeInfo>().map_err(|_| ClrError::CastingError("ICLRRuntimeInfo"))?,
+            None => continue,
+        };
+        
+        let mut version_string = vec![0u16; 256];
+        let mut len = version_string.len() as u32;
+        runtime_info.GetVersionString(PWSTR(version_string.as_mut_ptr()), &mut len)?;
+        version_string.retain(|&c| c != 0);
+        
+        let version = String::from_utf16_lossy(&version_string);
+        runtimes.insert(version, runtime_info);
+    }
+
+    Ok(runtimes)
+}
+
 /// Implementation of the original `_Assembly` COM interface methods.
 ///
 /// These methods are direct FFI bindings to the corresponding functions in the COM interface.
@@ -253,7 +280,7 @@ unsafe impl Interface for ICLRMetaHost {
     /// This GUID is used to identify the `ICLRMetaHost` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `ICLRMetaHost` interface.
-    const IID: GUID = GUID::from_u128(0xd332db9e_b9b3_4125_8207_a14884f53216);
+    const IID: GUID = crate::com::IID_ICLRMETAHOST;
 }
 
 impl Deref for ICLRMetaHost {
@@ -0,0 +1,178 @@
+use {
+    crate::error::ClrError,
+    std::{ffi::c_void, ops::Deref, time::Duration},
+    windows_core::{Interface, GUID, PCWSTR},
+    windows_sys::core::HRESULT,
+};
+
+/// Represents the COM `ICLRProfiling` interface, which attaches a profiler to a
+/// running .NET Framework 4.0+ process.
+///
+/// The interface is obtained from an `ICLRRuntimeInfo` with
+/// [`CLSID_CLR_PROFILING`](crate::com::CLSID_CLR_PROFILING). The profiler is
+/// supplied by the caller as a DLL implementing `ICorProfilerCallback3`, and
+/// receives its client data through `InitializeForAttach`.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct ICLRProfiling(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `ICLRProfiling` methods.
+impl ICLRProfiling {
+    /// Attaches a profiler DLL to a running process.
+    ///
+    /// Only one profiler can be loaded in a process, and the target must run a 4.0+ runtime.
+    /// The call blocks until the profiler's `InitializeForAttach` returns or the timeout expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `pid` - The identifier of the process to attach to.
+    /// * `timeout` - How long to wait for the profiler to load.
+    /// * `profiler` - The CLSID of the profiler, as registered by its `DllGetClassObject`.
+    /// * `path` - The full path of the profiler DLL.
+    /// * `client_data` - Data passed to `ICorProfilerCallback3::InitializeForAttach`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the profiler was loaded and initialized.
+    /// * `Err(ClrError)` - If the target has no runtime, already has a profiler, or the profiler failed to load.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::{RustClrEnv, com::CLSID_CLR_PROFILING, schema::ICLRProfiling};
+    /// use std::time::Duration;
+    /// use windows_core::GUID;
+    ///
+    /// let clr = RustClrEnv::new(None)?;
+    /// let profiling = clr.runtime_info.GetInterface::<ICLRProfiling>(&CLSID_CLR_PROFILING)?;
+    ///
+    /// let clsid = GUID::from_u128(0x8c29bc4e_1f57_461a_9b51_1200c32e6f1f);
+    /// profiling.attach(1234, Duration::from_secs(10), &clsid, r"C:\profiler\profiler.dll", &[])?;
+    /// ```
+    pub fn attach(&self, pid: u32, timeout: Duration, profiler: &GUID, path: &str, client_data: &[u8]) -> Result<(), ClrError> {
+        let wide_path = path.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+        let timeout = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+        let client_data_ptr = if client_data.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            client_data.as_ptr() as *mut c_void
+        };
+
+        self.AttachProfiler(
+            pid,
+            timeout,
+            profiler,
+            PCWSTR(wide_path.as_ptr()),
+            client_data_ptr,
+            client_data.len() as u32
+        )
+    }
+}
+
+/// Implementation of the original `ICLRProfiling` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl ICLRProfiling {
+    /// Attaches a profiler to the specified process.
+    ///
+    /// # Arguments
+    ///
+    /// * `dwProfileeProcessID` - The identifier of the process to attach to.
+    /// * `dwMillisecondsMax` - The maximum time to wait for the profiler to load.
+    /// * `pClsidProfiler` - The CLSID of the profiler.
+    /// * `wszProfilerPath` - The full path of the profiler DLL.
+    /// * `pvClientData` - Data passed to the profiler, or null.
+    /// * `cbClientData` - The size of the client data in bytes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the profiler was attached.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn AttachProfiler(
+        &self,
+        dwProfileeProcessID: u32,
+        dwMillisecondsMax: u32,
+        pClsidProfiler: *const GUID,
+        wszProfilerPath: PCWSTR,
+        pvClientData: *mut c_void,
+        cbClientData: u32
+    ) -> Result<(), ClrError> {
+        unsafe {
+            let hr = (Interface::vtable(self).AttachProfiler)(
+                Interface::as_raw(self),
+                dwProfileeProcessID,
+                dwMillisecondsMax,
+                pClsidProfiler,
+                wszProfilerPath,
+                pvClientData,
+                cbClientData
+            );
+
+            if hr == 0 {
+                Ok(())
+            } else {
+                Err(ClrError::ApiError("AttachProfiler", hr))
+            }
+        }
+    }
+}
+
+unsafe impl Interface for ICLRProfiling {
+    type Vtable = ICLRProfiling_Vtbl;
+
+    /// The interface identifier (IID) for the `ICLRProfiling` COM interface.
+    ///
+    /// This GUID is used to identify the `ICLRProfiling` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `ICLRProfiling` interface.
+    const IID: GUID = crate::com::IID_ICLRPROFILING;
+}
+
+impl Deref for ICLRProfiling {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `ICLRProfiling` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+/// Vtable structure for the `ICLRProfiling` interface.
+#[repr(C)]
+pub struct ICLRProfiling_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Attaches a profiler to a running process.
+    ///
+    /// # Arguments
+    ///
+    /// * `dwProfileeProcessID` - Identifier of the target process.
+    /// * `dwMillisecondsMax` - Maximum time to wait for the profiler to load.
+    /// * `pClsidProfiler` - CLSID of the profiler.
+    /// * `wszProfilerPath` - Full path of the profiler DLL.
+    /// * `pvClientData` - Data passed to the profiler.
+    /// * `cbClientData` - Size of the client data in bytes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    pub AttachProfiler: unsafe extern "system" fn(
+        *mut c_void,
+        dwProfileeProcessID: u32,
+        dwMillisecondsMax: u32,
+        pClsidProfiler: *const GUID,
+        wszProfilerPath: PCWSTR,
+        pvClientData: *mut c_void,
+        cbClientData: u32,
+    ) -> HRESULT,
+}
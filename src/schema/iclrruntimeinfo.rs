@@ -1,13 +1,19 @@
 use {
-    crate::error::ClrError,
-    std::{ffi::c_void, ops::Deref},
+    crate::{error::{ClrError, last_error}, StartupFlags},
+    std::{ffi::{c_void, CString}, ops::Deref},
     windows_core::{Interface, GUID, PCSTR, PCWSTR, PWSTR},
     windows_sys::{
         core::HRESULT,
-        Win32::Foundation::{BOOL, HANDLE, HMODULE}
+        Win32::{
+            Foundation::{FreeLibrary, BOOL, HANDLE, HMODULE},
+            System::LibraryLoader::GetProcAddress,
+        }
     },
 };
 
+/// `HRESULT_FROM_WIN32(ERROR_INSUFFICIENT_BUFFER)`, returned when a string buffer is too small.
+const E_INSUFFICIENT_BUFFER: HRESULT = 0x8007007Au32 as HRESULT;
+
 /// Represents the COM `ICLRRuntimeInfo` interface, which provides 
 /// information and functionalities for managing .NET runtime instances 
 /// within the CLR environment.
@@ -31,6 +37,183 @@ impl ICLRRuntimeInfo {
         
         self.IsStarted(&mut started, &mut startup_flags).is_ok() && started != 0
     }
+
+    /// Retrieves the version string of the runtime (e.g. `v4.0.30319`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The version of the runtime.
+    /// * `Err(ClrError)` - If `GetVersionString` fails.
+    pub fn version(&self) -> Result<String, ClrError> {
+        read_wide(|buffer, len| self.GetVersionString(buffer, len))
+    }
+
+    /// Retrieves the installation directory of the runtime.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The directory, with a trailing backslash.
+    /// * `Err(ClrError)` - If `GetRuntimeDirectory` fails.
+    pub fn runtime_directory(&self) -> Result<String, ClrError> {
+        read_wide(|buffer, len| self.GetRuntimeDirectory(buffer, len))
+    }
+
+    /// Retrieves the startup flags and host configuration file that will be used to start the runtime.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((StartupFlags, Option<String>))` - The startup flags, and the host configuration
+    ///   file if one was set with `SetDefaultStartupFlags`.
+    /// * `Err(ClrError)` - If `GetDefaultStartupFlags` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let clr = RustClrEnv::new(None)?;
+    /// let (flags, config) = clr.runtime_info.startup_config()?;
+    /// println!("flags: {:#x}, config: {config:?}", flags.0);
+    /// ```
+    pub fn startup_config(&self) -> Result<(StartupFlags, Option<String>), ClrError> {
+        let mut flags = 0;
+        let config = read_wide(|buffer, len| self.GetDefaultStartupFlags(&mut flags, buffer, len))?;
+
+        Ok((StartupFlags(flags), (!config.is_empty()).then_some(config)))
+    }
+
+    /// Resolves a CLR-specific HRESULT into a human-readable message.
+    ///
+    /// # Arguments
+    ///
+    /// * `hr` - The HRESULT to describe.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The message for the HRESULT in the default locale.
+    /// * `None` - If the runtime has no message for the HRESULT.
+    pub fn error_string(&self, hr: i32) -> Option<String> {
+        let mut buffer = [0u16; 512];
+        let mut len = buffer.len() as u32;
+        self.LoadErrorString(hr as u32, PWSTR(buffer.as_mut_ptr()), &mut len, -1).ok()?;
+
+        let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        let message = String::from_utf16_lossy(&buffer[..end]);
+        let message = message.trim();
+        (!message.is_empty()).then(|| message.to_string())
+    }
+
+    /// Loads a DLL shipped with the runtime (e.g. `clrjit.dll`) from the runtime directory.
+    ///
+    /// The DLL is resolved against the installation directory of this runtime version rather
+    /// than the default search order, so the copy matching the loaded runtime is always used.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The file name of the DLL.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(RuntimeDll)` - The loaded module, released when dropped.
+    /// * `Err(ClrError)` - If the DLL cannot be found or loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use rustclr::RustClrEnv;
+    ///
+    /// let clr = RustClrEnv::new(None)?;
+    /// let jit = clr.runtime_info.load_runtime_dll("clrjit.dll")?;
+    /// let get_jit = jit.proc_address("getJit")?;
+    /// ```
+    pub fn load_runtime_dll(&self, name: &str) -> Result<RuntimeDll, ClrError> {
+        let wide_name = name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+        let module = self.LoadLibrary(PCWSTR(wide_name.as_ptr()))?;
+        if module.is_null() {
+            return Err(ClrError::NullPointerError("LoadLibrary"));
+        }
+
+        Ok(RuntimeDll { module, name: name.to_string() })
+    }
+}
+
+/// Reads a string returned through a caller-allocated wide buffer.
+///
+/// The method is first called without a buffer to get the required length, then again with a
+/// buffer of that size.
+///
+/// # Arguments
+///
+/// * `read` - Calls the method with a buffer and a pointer to its length in characters.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The string, or an empty string if the method returned none.
+/// * `Err(ClrError)` - If either call fails.
+fn read_wide(mut read: impl FnMut(PWSTR, *mut u32) -> Result<(), ClrError>) -> Result<String, ClrError> {
+    let mut len = 0;
+    match read(PWSTR::null(), &mut len) {
+        Ok(()) | Err(ClrError::ApiError(_, E_INSUFFICIENT_BUFFER)) => {}
+        Err(error) => return Err(error),
+    }
+
+    if len == 0 {
+        return Ok(String::new());
+    }
+
+    let mut buffer = vec![0u16; len as usize];
+    read(PWSTR(buffer.as_mut_ptr()), &mut len)?;
+
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    Ok(String::from_utf16_lossy(&buffer[..end]))
+}
+
+/// A DLL loaded from the runtime directory through [`ICLRRuntimeInfo::load_runtime_dll`].
+///
+/// The module reference is released with `FreeLibrary` when the value is dropped. The
+/// runtime keeps its own reference to the DLLs it uses, so dropping a `RuntimeDll` never
+/// unloads a module the CLR still depends on.
+#[derive(Debug)]
+pub struct RuntimeDll {
+    /// Handle of the loaded module.
+    module: HMODULE,
+
+    /// File name the module was loaded with.
+    name: String,
+}
+
+impl RuntimeDll {
+    /// Returns the file name the module was loaded with.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the handle of the loaded module.
+    pub fn handle(&self) -> HMODULE {
+        self.module
+    }
+
+    /// Retrieves the address of an exported function.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the export.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut c_void)` - The address of the export.
+    /// * `Err(ClrError)` - If the module does not export the function.
+    pub fn proc_address(&self, name: &str) -> Result<*mut c_void, ClrError> {
+        let name = CString::new(name).map_err(|_| ClrError::ErrorClr("Export names cannot contain NUL bytes"))?;
+        unsafe { GetProcAddress(self.module, name.as_ptr().cast()) }
+            .map(|address| address as *mut c_void)
+            .ok_or_else(|| last_error("GetProcAddress"))
+    }
+}
+
+/// Implements the `Drop` trait to release the module reference when `RuntimeDll` goes out of scope.
+impl Drop for RuntimeDll {
+    fn drop(&mut self) {
+        unsafe { FreeLibrary(self.module) };
+    }
 }
 
 /// Implementation of the original `ICLRRuntimeInfo` COM interface methods.
@@ -171,28 +354,37 @@ impl ICLRRuntimeInfo {
         }
     }
 
-    /// Loads a DLL by name.
+    /// Loads a DLL from the directory of the runtime.
     ///
     /// # Arguments
     ///
-    /// * `pwzDllName` - The name of the DLL to load.
+    /// * `pwzDllName` - The name of the DLL to load, as a wide string.
     ///
     /// # Returns
     ///
     /// * `Ok(HMODULE)` - On success, returns a handle to the loaded module.
     /// * `Err(ClrError)` - If loading fails, returns a `ClrError`.
-    pub fn LoadLibraryA(&self, pwzDllName: PCWSTR) -> Result<HMODULE, ClrError> {
+    pub fn LoadLibrary(&self, pwzDllName: PCWSTR) -> Result<HMODULE, ClrError> {
         unsafe {
             let mut result = std::mem::zeroed();
-            let hr = (Interface::vtable(self).LoadLibraryA)(Interface::as_raw(self), pwzDllName, &mut result);
+            let hr = (Interface::vtable(self).LoadLibrary)(Interface::as_raw(self), pwzDllName, &mut result);
             if hr == 0 {
                 Ok(result)
             } else {
-                Err(ClrError::ApiError("LoadLibraryA", hr))
+                Err(ClrError::ApiError("LoadLibrary", hr))
             }
         }
     }
 
+    /// Loads a DLL from the directory of the runtime.
+    ///
+    /// The method takes a wide string despite its name; use [`ICLRRuntimeInfo::LoadLibrary`]
+    /// or [`ICLRRuntimeInfo::load_runtime_dll`] instead.
+    #[deprecated(note = "use `LoadLibrary` or `load_runtime_dll`")]
+    pub fn LoadLibraryA(&self, pwzDllName: PCWSTR) -> Result<HMODULE, ClrError> {
+        self.LoadLibrary(pwzDllName)
+    }
+
     /// Retrieves the address of a procedure in a loaded DLL.
     ///
     /// # Arguments
@@ -251,7 +443,7 @@ impl ICLRRuntimeInfo {
     /// * `Err(ClrError)` - If retrieval fails, returns a `ClrError`.
     pub fn GetDefaultStartupFlags(&self, pdwstartupflags: *mut u32, pwzhostconfigfile: PWSTR, pcchhostconfigfile: *mut u32) -> Result<(), ClrError> {
         unsafe {
-            let hr = (Interface::vtable(self).GetDefaultStartupFlags)(Interface::as_raw(self), pdwstartupflags, core::mem::transmute(pwzhostconfigfile), pcchhostconfigfile);
+            let hr = (Interface::vtable(self).GetDefaultStartupFlags)(Interface::as_raw(self), pdwstartupflags, core::mem::transmute::<PWSTR, PCWSTR>(pwzhostconfigfile), pcchhostconfigfile);
             if hr == 0 {
                 Ok(())
             } else {
@@ -308,7 +500,7 @@ unsafe impl Interface for ICLRRuntimeInfo {
     /// This GUID is used to identify the `ICLRRuntimeInfo` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `ICLRRuntimeInfo` interface.
-    const IID: GUID = GUID::from_u128(0xbd39d1d2_ba2f_486a_89b0_b4b0cb466891);
+    const IID: GUID = crate::com::IID_ICLRRUNTIMEINFO;
 }
 
 impl Deref for ICLRRuntimeInfo {
@@ -411,7 +603,7 @@ pub struct ICLRRuntimeInfo_Vtbl {
     /// # Returns
     /// 
     /// * Returns an HRESULT indicating success or failure..
-    pub LoadLibraryA: unsafe extern "system" fn(
+    pub LoadLibrary: unsafe extern "system" fn(
         *mut c_void,
         pwzDllName: PCWSTR,
         phndModule: *mut HMODULE,
@@ -8,7 +8,7 @@ use {
 };
 
 use super::_AppDomain;
-use crate::error::ClrError;
+use crate::{error::ClrError, InvocationType, ManagedName, Variant, Zone};
 
 /// Represents the COM `ICorRuntimeHost` interface, which provides 
 /// functionalities for managing .NET runtime hosts within the CLR environment. 
@@ -41,6 +41,41 @@ impl ICorRuntimeHost {
 
         self.CreateDomain(domain_name, null_mut())
     }
+
+    /// Creates a new .NET AppDomain whose evidence places it in the given security zone.
+    ///
+    /// The evidence object returned by `CreateEvidence` receives a `System.Security.Policy.Zone`
+    /// as host evidence before being passed to `CreateDomainEx`. The zone only restricts the
+    /// permissions granted to the domain when Code Access Security policy is in effect, which is
+    /// the default on .NET Framework 2.0/3.5 and requires `NetFx40_LegacySecurityPolicy` on 4.0+.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the AppDomain to be created.
+    /// * `zone` - The security zone of the domain.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_AppDomain)` - The created AppDomain.
+    /// * `Err(ClrError)` - If the evidence cannot be built or the domain creation fails.
+    pub fn create_domain_with_zone(&self, name: &str, zone: Zone) -> Result<_AppDomain, ClrError> {
+        // The evidence types are resolved through the default domain, which always exists
+        let mscorlib = self.GetDefaultDomain()?.load_lib(&ManagedName::Mscorlib.value())?;
+        let zone = mscorlib
+            .resolve_type(&ManagedName::Zone.value())?
+            .new_instance(vec![(zone as i32).to_variant()])?;
+
+        let evidence = self.CreateEvidence()?;
+        mscorlib.resolve_type(&ManagedName::Evidence.value())?.invoke(
+            &ManagedName::AddHost.value(),
+            Some(evidence.to_variant()),
+            Some(vec![zone]),
+            InvocationType::Instance
+        )?;
+
+        let name = name.encode_utf16().chain(Some(0)).collect::<Vec<u16>>();
+        self.CreateDomainEx(PCWSTR(name.as_ptr()), null_mut(), evidence.as_raw() as *mut IUnknown)
+    }
 }
 
 /// Implementation of the original `ICorRuntimeHost` COM interface methods.
@@ -405,7 +440,7 @@ unsafe impl Interface for ICorRuntimeHost {
     /// This GUID is used to identify the `ICorRuntimeHost` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `ICorRuntimeHost` interface.
-    const IID: GUID = GUID::from_u128(0xCB2F6722_AB3A_11d2_9C40_00C04FA30A3E);
+    const IID: GUID = crate::com::IID_ICORRUNTIMEHOST;
 }
 
 impl Deref for ICorRuntimeHost {
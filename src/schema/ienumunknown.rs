@@ -34,8 +34,8 @@ impl IEnumUnknown {
             (Interface::vtable(self).Next)(
                 Interface::as_raw(self), 
                 rgelt.len() as u32, 
-                transmute(rgelt.as_ptr()), 
-                transmute(pceltfetched.unwrap_or(core::ptr::null_mut()))
+                transmute::<*const Option<windows_core::IUnknown>, *mut *mut IUnknown>(rgelt.as_ptr()), 
+                pceltfetched.unwrap_or(core::ptr::null_mut())
             )
         }
     }
@@ -99,7 +99,7 @@ unsafe impl Interface for IEnumUnknown {
     /// This GUID is used to identify the `IEnumUnknown` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `IEnumUnknown` interface.
-    const IID: GUID = GUID::from_u128(0x00000100_0000_0000_c000_000000000046);
+    const IID: GUID = crate::com::IID_IENUMUNKNOWN;
 }
 
 impl Deref for IEnumUnknown {
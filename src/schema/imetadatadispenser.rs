@@ -0,0 +1,148 @@
+use {
+    crate::error::ClrError,
+    super::IMetaDataImport,
+    std::{ffi::c_void, ops::Deref},
+    windows_core::{Interface, GUID},
+    windows_sys::core::HRESULT,
+};
+
+/// `ofCopyMemory`: the scope keeps its own copy of the metadata.
+const OF_COPY_MEMORY: u32 = 0x02;
+
+/// `ofReadOnly`: the scope is opened for reading only.
+const OF_READ_ONLY: u32 = 0x10;
+
+/// Represents the COM `IMetaDataDispenser` interface, which opens metadata scopes
+/// over images on disk or in memory.
+///
+/// The dispenser is obtained from an `ICLRRuntimeInfo` with
+/// [`CLSID_COR_METADATA_DISPENSER`](crate::com::CLSID_COR_METADATA_DISPENSER).
+/// Opening a scope only parses the metadata: the image is not loaded into any domain.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct IMetaDataDispenser(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `IMetaDataDispenser` methods.
+impl IMetaDataDispenser {
+    /// Opens a read-only metadata scope over a .NET image held in memory.
+    ///
+    /// The metadata is copied into the scope, so the buffer can be dropped
+    /// while the returned `IMetaDataImport` is still in use.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - The .NET image (a PE file as stored on disk) or a raw metadata blob.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(IMetaDataImport)` - The import interface of the new scope.
+    /// * `Err(ClrError)` - If the buffer does not contain valid metadata.
+    pub fn open_scope_on_memory(&self, buffer: &[u8]) -> Result<IMetaDataImport, ClrError> {
+        let size = u32::try_from(buffer.len()).map_err(|_| ClrError::InvalidMetadata("image size"))?;
+        self.OpenScopeOnMemory::<IMetaDataImport>(buffer.as_ptr().cast(), size, OF_COPY_MEMORY | OF_READ_ONLY)
+    }
+}
+
+/// Implementation of the original `IMetaDataDispenser` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl IMetaDataDispenser {
+    /// Opens a metadata scope over a region of memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `pData` - Pointer to the start of the image or metadata.
+    /// * `cbData` - The size of the data in bytes.
+    /// * `dwOpenFlags` - A combination of `CorOpenFlags` values.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(T)` - The requested interface on the new scope.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn OpenScopeOnMemory<T>(&self, pData: *const c_void, cbData: u32, dwOpenFlags: u32) -> Result<T, ClrError>
+    where
+        T: Interface,
+    {
+        unsafe {
+            let mut result = core::ptr::null_mut();
+            let hr = (Interface::vtable(self).OpenScopeOnMemory)(
+                Interface::as_raw(self),
+                pData,
+                cbData,
+                dwOpenFlags,
+                &T::IID,
+                &mut result
+            );
+
+            if hr == 0 {
+                Ok(core::mem::transmute_copy(&result))
+            } else {
+                Err(ClrError::ApiError("OpenScopeOnMemory", hr))
+            }
+        }
+    }
+}
+
+unsafe impl Interface for IMetaDataDispenser {
+    type Vtable = IMetaDataDispenser_Vtbl;
+
+    /// The interface identifier (IID) for the `IMetaDataDispenser` COM interface.
+    ///
+    /// This GUID is used to identify the `IMetaDataDispenser` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `IMetaDataDispenser` interface.
+    const IID: GUID = crate::com::IID_IMETADATADISPENSER;
+}
+
+impl Deref for IMetaDataDispenser {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `IMetaDataDispenser` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+/// Vtable structure for the `IMetaDataDispenser` interface.
+#[repr(C)]
+pub struct IMetaDataDispenser_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the `DefineScope` method. Not used directly.
+    DefineScope: *const c_void,
+
+    /// Placeholder for the `OpenScope` method. Not used directly.
+    OpenScope: *const c_void,
+
+    /// Opens a metadata scope over a region of memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `pData` - Pointer to the start of the image or metadata.
+    /// * `cbData` - Size of the data in bytes.
+    /// * `dwOpenFlags` - A combination of `CorOpenFlags` values.
+    /// * `riid` - IID of the interface to return.
+    /// * `ppIUnk` - Pointer that receives the interface.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    pub OpenScopeOnMemory: unsafe extern "system" fn(
+        *mut c_void,
+        pData: *const c_void,
+        cbData: u32,
+        dwOpenFlags: u32,
+        riid: *const GUID,
+        ppIUnk: *mut *mut c_void,
+    ) -> HRESULT,
+}
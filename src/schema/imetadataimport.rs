@@ -0,0 +1,740 @@
+use {
+    crate::error::ClrError,
+    std::{ffi::c_void, ops::Deref, ptr::null_mut},
+    windows_core::{Interface, GUID},
+    windows_sys::core::HRESULT,
+};
+
+/// Handle of an enumeration in progress, as used by the `Enum*` methods.
+pub type HCORENUM = *mut c_void;
+
+/// `CLDB_E_RECORD_NOTFOUND`: the requested record does not exist.
+const CLDB_E_RECORD_NOTFOUND: HRESULT = 0x8013_1130_u32 as HRESULT;
+
+/// Number of tokens requested by each call to an `Enum*` method.
+const ENUM_BATCH: usize = 64;
+
+/// Represents the COM `IMetaDataImport` interface, which reads the metadata tables
+/// of a scope opened by [`IMetaDataDispenser`](super::IMetaDataDispenser).
+///
+/// Members are identified by metadata tokens: `0x02xxxxxx` for `TypeDef`,
+/// `0x06xxxxxx` for `MethodDef` and `0x1Axxxxxx` for `ModuleRef`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::RustClrEnv;
+///
+/// let clr = RustClrEnv::new(None)?;
+/// let import = clr.metadata_import(&std::fs::read("examples/sample.exe")?)?;
+///
+/// for ty in import.type_defs()? {
+///     for method in import.methods(ty)? {
+///         if let Some((dll, function)) = import.pinvoke_map(method)? {
+///             println!("{}::{} -> {dll}!{function}", import.type_def_name(ty)?, import.method_name(method)?);
+///         }
+///     }
+/// }
+/// ```
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub struct IMetaDataImport(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `IMetaDataImport` methods.
+impl IMetaDataImport {
+    /// Retrieves the `TypeDef` tokens of the scope, excluding the `<Module>` pseudo-type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u32>)` - The `TypeDef` tokens, in table order.
+    /// * `Err(ClrError)` - If the enumeration fails.
+    pub fn type_defs(&self) -> Result<Vec<u32>, ClrError> {
+        self.enumerate(|h_enum, tokens, max, count| self.EnumTypeDefs(h_enum, tokens, max, count))
+    }
+
+    /// Retrieves the namespace-qualified name of a type.
+    ///
+    /// # Arguments
+    ///
+    /// * `td` - The `TypeDef` token of the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The name of the type, e.g. `System.Object`.
+    /// * `Err(ClrError)` - If the token is not a valid `TypeDef`.
+    pub fn type_def_name(&self, td: u32) -> Result<String, ClrError> {
+        read_name(|buffer, len, required| self.GetTypeDefProps(td, buffer, len, required))
+    }
+
+    /// Retrieves the `MethodDef` tokens of a type.
+    ///
+    /// # Arguments
+    ///
+    /// * `td` - The `TypeDef` token of the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u32>)` - The `MethodDef` tokens, in declaration order.
+    /// * `Err(ClrError)` - If the enumeration fails.
+    pub fn methods(&self, td: u32) -> Result<Vec<u32>, ClrError> {
+        self.enumerate(|h_enum, tokens, max, count| self.EnumMethods(h_enum, td, tokens, max, count))
+    }
+
+    /// Retrieves the name of a method.
+    ///
+    /// # Arguments
+    ///
+    /// * `mb` - The `MethodDef` token of the method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The name of the method.
+    /// * `Err(ClrError)` - If the token is not a valid `MethodDef`.
+    pub fn method_name(&self, mb: u32) -> Result<String, ClrError> {
+        read_name(|buffer, len, required| self.GetMethodProps(mb, buffer, len, required))
+    }
+
+    /// Retrieves the native function a method is imported from.
+    ///
+    /// # Arguments
+    ///
+    /// * `mb` - The `MethodDef` token of the method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some((String, String)))` - The native library and the name of the imported function.
+    /// * `Ok(None)` - If the method is not a P/Invoke.
+    /// * `Err(ClrError)` - If the mapping cannot be read.
+    pub fn pinvoke_map(&self, mb: u32) -> Result<Option<(String, String)>, ClrError> {
+        let mut module = 0;
+        let function = match read_name(|buffer, len, required| self.GetPinvokeMap(mb, buffer, len, required, &mut module)) {
+            Ok(function) => function,
+            Err(ClrError::ApiError(_, CLDB_E_RECORD_NOTFOUND)) => return Ok(None),
+            Err(error) => return Err(error),
+        };
+
+        let dll = read_name(|buffer, len, required| self.GetModuleRefProps(module, buffer, len, required))?;
+        Ok(Some((dll, function)))
+    }
+
+    /// Drains an `Enum*` method, closing the enumerator afterwards.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - Calls the `Enum*` method with the enumerator handle, the output array,
+    ///   its capacity and a pointer that receives the number of tokens returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u32>)` - Every token returned by the enumerator.
+    /// * `Err(ClrError)` - If a call fails.
+    fn enumerate(
+        &self,
+        next: impl Fn(*mut HCORENUM, *mut u32, u32, *mut u32) -> Result<bool, ClrError>
+    ) -> Result<Vec<u32>, ClrError> {
+        let mut h_enum: HCORENUM = null_mut();
+        let mut tokens = Vec::new();
+        let result = loop {
+            let mut batch = [0u32; ENUM_BATCH];
+            let mut count = 0;
+            match next(&mut h_enum, batch.as_mut_ptr(), ENUM_BATCH as u32, &mut count) {
+                Ok(more) => {
+                    tokens.extend_from_slice(&batch[..count as usize]);
+                    if !more || count == 0 {
+                        break Ok(tokens);
+                    }
+                }
+                Err(error) => break Err(error),
+            }
+        };
+
+        if !h_enum.is_null() {
+            self.CloseEnum(h_enum);
+        }
+
+        result
+    }
+}
+
+/// Reads a name returned by a `Get*Props` method, retrying with a larger buffer if needed.
+///
+/// # Arguments
+///
+/// * `read` - Calls the method with the buffer, its size in characters and a pointer
+///   that receives the required size, including the terminator.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The name.
+/// * `Err(ClrError)` - If the call fails.
+fn read_name(mut read: impl FnMut(*mut u16, u32, *mut u32) -> Result<(), ClrError>) -> Result<String, ClrError> {
+    let mut buffer = vec![0u16; 256];
+    loop {
+        let mut required = 0;
+        read(buffer.as_mut_ptr(), buffer.len() as u32, &mut required)?;
+
+        // The name was truncated: retry with the size reported by the call
+        if required as usize > buffer.len() {
+            buffer.resize(required as usize, 0);
+            continue;
+        }
+
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        return Ok(String::from_utf16_lossy(&buffer[..len]));
+    }
+}
+
+/// Implementation of the original `IMetaDataImport` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl IMetaDataImport {
+    /// Releases an enumerator handle returned by an `Enum*` method.
+    ///
+    /// # Arguments
+    ///
+    /// * `hEnum` - The enumerator handle.
+    pub fn CloseEnum(&self, hEnum: HCORENUM) {
+        unsafe { (Interface::vtable(self).CloseEnum)(Interface::as_raw(self), hEnum) }
+    }
+
+    /// Enumerates the `TypeDef` tokens of the scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `phEnum` - Pointer to the enumerator handle, null on the first call.
+    /// * `rTypeDefs` - Array that receives the tokens.
+    /// * `cMax` - The capacity of the array.
+    /// * `pcTypeDefs` - Pointer that receives the number of tokens returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if tokens were returned, `false` at the end of the enumeration.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn EnumTypeDefs(&self, phEnum: *mut HCORENUM, rTypeDefs: *mut u32, cMax: u32, pcTypeDefs: *mut u32) -> Result<bool, ClrError> {
+        let hr = unsafe { (Interface::vtable(self).EnumTypeDefs)(Interface::as_raw(self), phEnum, rTypeDefs, cMax, pcTypeDefs) };
+        match hr {
+            0 => Ok(true),
+            1 => Ok(false),
+            _ => Err(ClrError::ApiError("EnumTypeDefs", hr)),
+        }
+    }
+
+    /// Retrieves the name of a `TypeDef`.
+    ///
+    /// # Arguments
+    ///
+    /// * `td` - The `TypeDef` token.
+    /// * `szTypeDef` - Buffer that receives the namespace-qualified name.
+    /// * `cchTypeDef` - The size of the buffer in characters.
+    /// * `pchTypeDef` - Pointer that receives the length of the name, including the terminator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the name was read, possibly truncated.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn GetTypeDefProps(&self, td: u32, szTypeDef: *mut u16, cchTypeDef: u32, pchTypeDef: *mut u32) -> Result<(), ClrError> {
+        let (mut flags, mut extends) = (0, 0);
+        let hr = unsafe {
+            (Interface::vtable(self).GetTypeDefProps)(
+                Interface::as_raw(self),
+                td,
+                szTypeDef,
+                cchTypeDef,
+                pchTypeDef,
+                &mut flags,
+                &mut extends
+            )
+        };
+
+        if hr >= 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("GetTypeDefProps", hr))
+        }
+    }
+
+    /// Enumerates the `MethodDef` tokens of a type.
+    ///
+    /// # Arguments
+    ///
+    /// * `phEnum` - Pointer to the enumerator handle, null on the first call.
+    /// * `cl` - The `TypeDef` token of the type.
+    /// * `rMethods` - Array that receives the tokens.
+    /// * `cMax` - The capacity of the array.
+    /// * `pcTokens` - Pointer that receives the number of tokens returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if tokens were returned, `false` at the end of the enumeration.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn EnumMethods(&self, phEnum: *mut HCORENUM, cl: u32, rMethods: *mut u32, cMax: u32, pcTokens: *mut u32) -> Result<bool, ClrError> {
+        let hr = unsafe { (Interface::vtable(self).EnumMethods)(Interface::as_raw(self), phEnum, cl, rMethods, cMax, pcTokens) };
+        match hr {
+            0 => Ok(true),
+            1 => Ok(false),
+            _ => Err(ClrError::ApiError("EnumMethods", hr)),
+        }
+    }
+
+    /// Retrieves the name of a `MethodDef`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mb` - The `MethodDef` token.
+    /// * `szMethod` - Buffer that receives the name of the method.
+    /// * `cchMethod` - The size of the buffer in characters.
+    /// * `pchMethod` - Pointer that receives the length of the name, including the terminator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the name was read, possibly truncated.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn GetMethodProps(&self, mb: u32, szMethod: *mut u16, cchMethod: u32, pchMethod: *mut u32) -> Result<(), ClrError> {
+        let (mut class, mut attr, mut sig, mut sig_len, mut rva, mut impl_flags) = (0, 0, core::ptr::null(), 0, 0, 0);
+        let hr = unsafe {
+            (Interface::vtable(self).GetMethodProps)(
+                Interface::as_raw(self),
+                mb,
+                &mut class,
+                szMethod,
+                cchMethod,
+                pchMethod,
+                &mut attr,
+                &mut sig,
+                &mut sig_len,
+                &mut rva,
+                &mut impl_flags
+            )
+        };
+
+        if hr >= 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("GetMethodProps", hr))
+        }
+    }
+
+    /// Retrieves the name of a `ModuleRef`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mur` - The `ModuleRef` token.
+    /// * `szName` - Buffer that receives the name of the module.
+    /// * `cchName` - The size of the buffer in characters.
+    /// * `pchName` - Pointer that receives the length of the name, including the terminator.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the name was read, possibly truncated.
+    /// * `Err(ClrError)` - If the call fails, returns a `ClrError`.
+    pub fn GetModuleRefProps(&self, mur: u32, szName: *mut u16, cchName: u32, pchName: *mut u32) -> Result<(), ClrError> {
+        let hr = unsafe { (Interface::vtable(self).GetModuleRefProps)(Interface::as_raw(self), mur, szName, cchName, pchName) };
+        if hr >= 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("GetModuleRefProps", hr))
+        }
+    }
+
+    /// Retrieves the P/Invoke mapping of a method.
+    ///
+    /// # Arguments
+    ///
+    /// * `tk` - The `MethodDef` token.
+    /// * `szImportName` - Buffer that receives the name of the native function.
+    /// * `cchImportName` - The size of the buffer in characters.
+    /// * `pchImportName` - Pointer that receives the length of the name, including the terminator.
+    /// * `pmrImportDLL` - Pointer that receives the `ModuleRef` token of the native library.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the mapping was read.
+    /// * `Err(ClrError)` - If the method has no mapping (`CLDB_E_RECORD_NOTFOUND`) or the call fails.
+    pub fn GetPinvokeMap(
+        &self,
+        tk: u32,
+        szImportName: *mut u16,
+        cchImportName: u32,
+        pchImportName: *mut u32,
+        pmrImportDLL: *mut u32
+    ) -> Result<(), ClrError> {
+        let mut flags = 0;
+        let hr = unsafe {
+            (Interface::vtable(self).GetPinvokeMap)(
+                Interface::as_raw(self),
+                tk,
+                &mut flags,
+                szImportName,
+                cchImportName,
+                pchImportName,
+                pmrImportDLL
+            )
+        };
+
+        if hr >= 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("GetPinvokeMap", hr))
+        }
+    }
+}
+
+unsafe impl Interface for IMetaDataImport {
+    type Vtable = IMetaDataImport_Vtbl;
+
+    /// The interface identifier (IID) for the `IMetaDataImport` COM interface.
+    ///
+    /// This GUID is used to identify the `IMetaDataImport` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `IMetaDataImport` interface.
+    const IID: GUID = crate::com::IID_IMETADATAIMPORT;
+}
+
+impl Deref for IMetaDataImport {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `IMetaDataImport` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+/// Vtable structure for the `IMetaDataImport` interface.
+#[repr(C)]
+pub struct IMetaDataImport_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Releases an enumerator handle.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `hEnum` - The enumerator handle to release.
+    CloseEnum: unsafe extern "system" fn(
+        *mut c_void,
+        hEnum: HCORENUM
+    ),
+
+    /// Placeholder for the `CountEnum` method. Not used directly.
+    CountEnum: *const c_void,
+
+    /// Placeholder for the `ResetEnum` method. Not used directly.
+    ResetEnum: *const c_void,
+
+    /// Enumerates the `TypeDef` tokens of the scope.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `phEnum` - Pointer to the enumerator handle, null on the first call.
+    /// * `rTypeDefs` - Array that receives the tokens.
+    /// * `cMax` - The capacity of the array.
+    /// * `pcTypeDefs` - Pointer that receives the number of tokens returned.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success, or `S_FALSE` when there are no more tokens.
+    EnumTypeDefs: unsafe extern "system" fn(
+        *mut c_void,
+        phEnum: *mut HCORENUM,
+        rTypeDefs: *mut u32,
+        cMax: u32,
+        pcTypeDefs: *mut u32
+    ) -> HRESULT,
+
+    /// Placeholder for the `EnumInterfaceImpls` method. Not used directly.
+    EnumInterfaceImpls: *const c_void,
+
+    /// Placeholder for the `EnumTypeRefs` method. Not used directly.
+    EnumTypeRefs: *const c_void,
+
+    /// Placeholder for the `FindTypeDefByName` method. Not used directly.
+    FindTypeDefByName: *const c_void,
+
+    /// Placeholder for the `GetScopeProps` method. Not used directly.
+    GetScopeProps: *const c_void,
+
+    /// Placeholder for the `GetModuleFromScope` method. Not used directly.
+    GetModuleFromScope: *const c_void,
+
+    /// Retrieves the name, flags and base type of a `TypeDef`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `td` - The `TypeDef` token.
+    /// * `szTypeDef` - Buffer that receives the namespace-qualified name.
+    /// * `cchTypeDef` - The size of the buffer in characters.
+    /// * `pchTypeDef` - Pointer that receives the length of the name, including the terminator.
+    /// * `pdwTypeDefFlags` - Pointer that receives the `TypeAttributes`.
+    /// * `ptkExtends` - Pointer that receives the token of the base type.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetTypeDefProps: unsafe extern "system" fn(
+        *mut c_void,
+        td: u32,
+        szTypeDef: *mut u16,
+        cchTypeDef: u32,
+        pchTypeDef: *mut u32,
+        pdwTypeDefFlags: *mut u32,
+        ptkExtends: *mut u32
+    ) -> HRESULT,
+
+    /// Placeholder for the `GetInterfaceImplProps` method. Not used directly.
+    GetInterfaceImplProps: *const c_void,
+
+    /// Placeholder for the `GetTypeRefProps` method. Not used directly.
+    GetTypeRefProps: *const c_void,
+
+    /// Placeholder for the `ResolveTypeRef` method. Not used directly.
+    ResolveTypeRef: *const c_void,
+
+    /// Placeholder for the `EnumMembers` method. Not used directly.
+    EnumMembers: *const c_void,
+
+    /// Placeholder for the `EnumMembersWithName` method. Not used directly.
+    EnumMembersWithName: *const c_void,
+
+    /// Enumerates the `MethodDef` tokens of a type.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `phEnum` - Pointer to the enumerator handle, null on the first call.
+    /// * `cl` - The `TypeDef` token of the type.
+    /// * `rMethods` - Array that receives the tokens.
+    /// * `cMax` - The capacity of the array.
+    /// * `pcTokens` - Pointer that receives the number of tokens returned.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success, or `S_FALSE` when there are no more tokens.
+    EnumMethods: unsafe extern "system" fn(
+        *mut c_void,
+        phEnum: *mut HCORENUM,
+        cl: u32,
+        rMethods: *mut u32,
+        cMax: u32,
+        pcTokens: *mut u32
+    ) -> HRESULT,
+
+    /// Placeholder for the `EnumMethodsWithName` method. Not used directly.
+    EnumMethodsWithName: *const c_void,
+
+    /// Placeholder for the `EnumFields` method. Not used directly.
+    EnumFields: *const c_void,
+
+    /// Placeholder for the `EnumFieldsWithName` method. Not used directly.
+    EnumFieldsWithName: *const c_void,
+
+    /// Placeholder for the `EnumParams` method. Not used directly.
+    EnumParams: *const c_void,
+
+    /// Placeholder for the `EnumMemberRefs` method. Not used directly.
+    EnumMemberRefs: *const c_void,
+
+    /// Placeholder for the `EnumMethodImpls` method. Not used directly.
+    EnumMethodImpls: *const c_void,
+
+    /// Placeholder for the `EnumPermissionSets` method. Not used directly.
+    EnumPermissionSets: *const c_void,
+
+    /// Placeholder for the `FindMember` method. Not used directly.
+    FindMember: *const c_void,
+
+    /// Placeholder for the `FindMethod` method. Not used directly.
+    FindMethod: *const c_void,
+
+    /// Placeholder for the `FindField` method. Not used directly.
+    FindField: *const c_void,
+
+    /// Placeholder for the `FindMemberRef` method. Not used directly.
+    FindMemberRef: *const c_void,
+
+    /// Retrieves the properties of a `MethodDef`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `mb` - The `MethodDef` token.
+    /// * `pClass` - Pointer that receives the token of the declaring type.
+    /// * `szMethod` - Buffer that receives the name of the method.
+    /// * `cchMethod` - The size of the buffer in characters.
+    /// * `pchMethod` - Pointer that receives the length of the name, including the terminator.
+    /// * `pdwAttr` - Pointer that receives the `MethodAttributes`.
+    /// * `ppvSigBlob` - Pointer that receives the signature blob.
+    /// * `pcbSigBlob` - Pointer that receives the size of the signature blob.
+    /// * `pulCodeRVA` - Pointer that receives the RVA of the method body.
+    /// * `pdwImplFlags` - Pointer that receives the `MethodImplAttributes`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetMethodProps: unsafe extern "system" fn(
+        *mut c_void,
+        mb: u32,
+        pClass: *mut u32,
+        szMethod: *mut u16,
+        cchMethod: u32,
+        pchMethod: *mut u32,
+        pdwAttr: *mut u32,
+        ppvSigBlob: *mut *const u8,
+        pcbSigBlob: *mut u32,
+        pulCodeRVA: *mut u32,
+        pdwImplFlags: *mut u32
+    ) -> HRESULT,
+
+    /// Placeholder for the `GetMemberRefProps` method. Not used directly.
+    GetMemberRefProps: *const c_void,
+
+    /// Placeholder for the `EnumProperties` method. Not used directly.
+    EnumProperties: *const c_void,
+
+    /// Placeholder for the `EnumEvents` method. Not used directly.
+    EnumEvents: *const c_void,
+
+    /// Placeholder for the `GetEventProps` method. Not used directly.
+    GetEventProps: *const c_void,
+
+    /// Placeholder for the `EnumMethodSemantics` method. Not used directly.
+    EnumMethodSemantics: *const c_void,
+
+    /// Placeholder for the `GetMethodSemantics` method. Not used directly.
+    GetMethodSemantics: *const c_void,
+
+    /// Placeholder for the `GetClassLayout` method. Not used directly.
+    GetClassLayout: *const c_void,
+
+    /// Placeholder for the `GetFieldMarshal` method. Not used directly.
+    GetFieldMarshal: *const c_void,
+
+    /// Placeholder for the `GetRVA` method. Not used directly.
+    GetRVA: *const c_void,
+
+    /// Placeholder for the `GetPermissionSetProps` method. Not used directly.
+    GetPermissionSetProps: *const c_void,
+
+    /// Placeholder for the `GetSigFromToken` method. Not used directly.
+    GetSigFromToken: *const c_void,
+
+    /// Retrieves the name of a `ModuleRef`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `mur` - The `ModuleRef` token.
+    /// * `szName` - Buffer that receives the name of the module.
+    /// * `cchName` - The size of the buffer in characters.
+    /// * `pchName` - Pointer that receives the length of the name, including the terminator.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetModuleRefProps: unsafe extern "system" fn(
+        *mut c_void,
+        mur: u32,
+        szName: *mut u16,
+        cchName: u32,
+        pchName: *mut u32
+    ) -> HRESULT,
+
+    /// Placeholder for the `EnumModuleRefs` method. Not used directly.
+    EnumModuleRefs: *const c_void,
+
+    /// Placeholder for the `GetTypeSpecFromToken` method. Not used directly.
+    GetTypeSpecFromToken: *const c_void,
+
+    /// Placeholder for the `GetNameFromToken` method. Not used directly.
+    GetNameFromToken: *const c_void,
+
+    /// Placeholder for the `EnumUnresolvedMethods` method. Not used directly.
+    EnumUnresolvedMethods: *const c_void,
+
+    /// Placeholder for the `GetUserString` method. Not used directly.
+    GetUserString: *const c_void,
+
+    /// Retrieves the P/Invoke mapping of a method or field.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `tk` - The `MethodDef` or `FieldDef` token.
+    /// * `pdwMappingFlags` - Pointer that receives the `PInvokeAttributes`.
+    /// * `szImportName` - Buffer that receives the name of the native function.
+    /// * `cchImportName` - The size of the buffer in characters.
+    /// * `pchImportName` - Pointer that receives the length of the name, including the terminator.
+    /// * `pmrImportDLL` - Pointer that receives the `ModuleRef` token of the native library.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success, or `CLDB_E_RECORD_NOTFOUND` if the member has no mapping.
+    GetPinvokeMap: unsafe extern "system" fn(
+        *mut c_void,
+        tk: u32,
+        pdwMappingFlags: *mut u32,
+        szImportName: *mut u16,
+        cchImportName: u32,
+        pchImportName: *mut u32,
+        pmrImportDLL: *mut u32
+    ) -> HRESULT,
+
+    /// Placeholder for the `EnumSignatures` method. Not used directly.
+    EnumSignatures: *const c_void,
+
+    /// Placeholder for the `EnumTypeSpecs` method. Not used directly.
+    EnumTypeSpecs: *const c_void,
+
+    /// Placeholder for the `EnumUserStrings` method. Not used directly.
+    EnumUserStrings: *const c_void,
+
+    /// Placeholder for the `GetParamForMethodIndex` method. Not used directly.
+    GetParamForMethodIndex: *const c_void,
+
+    /// Placeholder for the `EnumCustomAttributes` method. Not used directly.
+    EnumCustomAttributes: *const c_void,
+
+    /// Placeholder for the `GetCustomAttributeProps` method. Not used directly.
+    GetCustomAttributeProps: *const c_void,
+
+    /// Placeholder for the `FindTypeRef` method. Not used directly.
+    FindTypeRef: *const c_void,
+
+    /// Placeholder for the `GetMemberProps` method. Not used directly.
+    GetMemberProps: *const c_void,
+
+    /// Placeholder for the `GetFieldProps` method. Not used directly.
+    GetFieldProps: *const c_void,
+
+    /// Placeholder for the `GetPropertyProps` method. Not used directly.
+    GetPropertyProps: *const c_void,
+
+    /// Placeholder for the `GetParamProps` method. Not used directly.
+    GetParamProps: *const c_void,
+
+    /// Placeholder for the `GetCustomAttributeByName` method. Not used directly.
+    GetCustomAttributeByName: *const c_void,
+
+    /// Placeholder for the `IsValidToken` method. Not used directly.
+    IsValidToken: *const c_void,
+
+    /// Placeholder for the `GetNestedClassProps` method. Not used directly.
+    GetNestedClassProps: *const c_void,
+
+    /// Placeholder for the `GetNativeCallConvSig` method. Not used directly.
+    GetNativeCallConvSig: *const c_void,
+
+    /// Placeholder for the `IsGlobal` method. Not used directly.
+    IsGlobal: *const c_void,
+}
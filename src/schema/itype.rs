@@ -2,24 +2,28 @@ use {
     windows_core::{IUnknown, Interface, GUID},
     std::{
         ffi::c_void, ptr::{null_mut, null}, 
-        ops::{BitOr, Deref}
+        ops::{BitOr, BitOrAssign, Deref}
     },
     windows_sys::{
         core::{BSTR, HRESULT}, 
-        Win32::System::{
-            Com::SAFEARRAY, 
-            Variant::VARIANT,
-            Ole::{
-                SafeArrayGetElement, SafeArrayGetLBound, 
-                SafeArrayGetUBound
-            }, 
+        Win32::{
+            Foundation::{VARIANT_BOOL, VARIANT_FALSE, VARIANT_TRUE},
+            System::{
+                Com::SAFEARRAY, 
+                Variant::{VARIANT, VT_I8},
+                Ole::{
+                    SafeArrayGetElement, SafeArrayGetLBound, 
+                    SafeArrayGetUBound
+                }, 
+            }
         }
     }
 };
 
 use crate::{
-    error::ClrError, schema::_MethodInfo,
-    WinStr, create_safe_args, InvocationType,
+    error::ClrError, schema::{_Assembly, _ConstructorInfo, _EventInfo, _FieldInfo, _MethodInfo, _PropertyInfo, MethodQuery},
+    Variant, WinStr, Instance, InvocationType, ManagedName, SafeArray,
+    instance::custom_attributes,
 };
 
 /// Represents the `_Type` COM interface, allowing for the invocation of
@@ -48,6 +52,98 @@ impl _Type {
         self.GetMethod_6(method_name)
     }
 
+    /// Finds a method by name using explicit `BindingFlags`.
+    ///
+    /// [`method`](Self::method) only considers public members. Include
+    /// `BindingFlags::NonPublic` to find `private` and `internal` methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice representing the method name.
+    /// * `flags` - The `BindingFlags` controlling the search, e.g.
+    ///   `BindingFlags::NonPublic | BindingFlags::Static`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_MethodInfo)` - On success, returns the method's `_MethodInfo`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn method_with_flags(&self, name: &str, flags: BindingFlags) -> Result<_MethodInfo, ClrError> {
+        let method_name = name.to_bstr();
+        self.GetMethod_5(method_name, flags)
+    }
+
+    /// Retrieves a field by its name from the type.
+    ///
+    /// Both instance and static public fields are considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice representing the field name.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_FieldInfo)` - On success, returns the field's `_FieldInfo`.
+    /// * `Err(ClrError)` - If no matching field exists.
+    pub fn field(&self, name: &str) -> Result<_FieldInfo, ClrError> {
+        self.GetField(name.to_bstr(), BindingFlags::Public | BindingFlags::Instance | BindingFlags::Static)
+    }
+
+    /// Finds a field by name using explicit `BindingFlags`.
+    ///
+    /// [`field`](Self::field) only considers public members. Include
+    /// `BindingFlags::NonPublic` to find `private` and `internal` fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice representing the field name.
+    /// * `flags` - The `BindingFlags` controlling the search, e.g.
+    ///   `BindingFlags::NonPublic | BindingFlags::Static`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_FieldInfo)` - On success, returns the field's `_FieldInfo`.
+    /// * `Err(ClrError)` - If no matching field exists.
+    pub fn field_with_flags(&self, name: &str, flags: BindingFlags) -> Result<_FieldInfo, ClrError> {
+        self.GetField(name.to_bstr(), flags)
+    }
+
+    /// Retrieves an event by its name from the type.
+    ///
+    /// Both instance and static public events are considered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice representing the event name.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_EventInfo)` - On success, returns the event's `_EventInfo`.
+    /// * `Err(ClrError)` - If no matching event exists.
+    pub fn event(&self, name: &str) -> Result<_EventInfo, ClrError> {
+        self.GetEvent(name.to_bstr(), BindingFlags::Public | BindingFlags::Instance | BindingFlags::Static)
+    }
+
+    /// Finds a type nested in this type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The simple name of the nested type (e.g. `Inner` for `Ns.Outer+Inner`).
+    /// * `non_public` - Whether `private` and `internal` nested types are considered.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - On success, returns the nested `_Type`.
+    /// * `Err(ClrError)` - If no matching nested type exists.
+    pub fn nested_type(&self, name: &str, non_public: bool) -> Result<_Type, ClrError> {
+        let flags = if non_public {
+            BindingFlags::Public | BindingFlags::NonPublic
+        } else {
+            BindingFlags::Public
+        };
+
+        self.GetNestedType(name.to_bstr(), flags)
+    }
+
     /// Finds a method by signature from the type.
     ///
     /// # Arguments
@@ -71,8 +167,48 @@ impl _Type {
         Err(ClrError::MethodNotFound)
     }
 
+    /// Finds the methods of the type matching a [`MethodQuery`].
+    ///
+    /// Unlike [`_Type::method_signature`], names and signatures are matched against
+    /// wildcard patterns, so methods with mangled or partially known names can be located.
+    /// Public and non-public methods are searched, both instance and static.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The `MethodQuery` the methods must satisfy.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<_MethodInfo>)` - The matching methods, empty if none matches.
+    /// * `Err(ClrError)` - If the methods or their metadata cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let query = MethodQuery::new().with_name("*Decrypt*").with_arity(2);
+    /// let decrypt = ty.find_methods(&query)?.into_iter().next().ok_or(ClrError::MethodNotFound)?;
+    /// ```
+    pub fn find_methods(&self, query: &MethodQuery) -> Result<Vec<_MethodInfo>, ClrError> {
+        let flags = BindingFlags::Public | BindingFlags::NonPublic
+            | BindingFlags::Instance | BindingFlags::Static
+            | BindingFlags::FlattenHierarchy;
+
+        let mut methods = Vec::new();
+        for (signature, method) in self.methods_with_flags(flags)? {
+            if query.matches(&signature, &method)? {
+                methods.push(method);
+            }
+        }
+
+        Ok(methods)
+    }
+
     /// Invokes a method on the type.
     ///
+    /// Overloads and `params` arrays are resolved by the runtime's default binder, so
+    /// `params` arguments can be passed as a flat list. Use
+    /// [`_MethodInfo::invoke_params`] when invoking a resolved `_MethodInfo` directly.
+    ///
     /// # Arguments
     ///
     /// * `name` - The name of the method to invoke.
@@ -91,19 +227,129 @@ impl _Type {
         args: Option<Vec<VARIANT>>, 
         invocation_type: InvocationType
     ) -> Result<VARIANT, ClrError> {
-        let flags = match invocation_type {
-            InvocationType::Static => BindingFlags::Public | BindingFlags::Static | BindingFlags::InvokeMethod,
-            InvocationType::Instance => BindingFlags::Public | BindingFlags::Instance | BindingFlags::InvokeMethod,
-        };
+        self.invoke_with_flags(name, instance, args, invocation_type.binding_flags())
+    }
+
+    /// Invokes a method returning a `Task` or `Task<T>` and waits for it to complete.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method to invoke.
+    /// * `instance` - An optional `VARIANT` representing the instance.
+    /// * `args` - Optional vector of `VARIANT` arguments.
+    /// * `invocation_type` - The `InvocationType`, indicating if it's a static or instance method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The result of the task, or an empty `VARIANT` for a `Task` without result.
+    /// * `Err(ClrError)` - If the invocation fails, the method does not return a `Task` or the task faults.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let client = assembly.resolve_type("Tool.Client")?;
+    /// let body = client.invoke_async("FetchAsync", None, Some(vec!["https://example.com".to_variant()]), InvocationType::Static)?;
+    /// ```
+    pub fn invoke_async(
+        &self, 
+        name: &str, 
+        instance: Option<VARIANT>, 
+        args: Option<Vec<VARIANT>>, 
+        invocation_type: InvocationType
+    ) -> Result<VARIANT, ClrError> {
+        let task = self.invoke(name, instance, args, invocation_type)?;
+        await_task(task, self)
+    }
+
+    /// Invokes a member of the type with explicit `BindingFlags`.
+    ///
+    /// Unlike [`_Type::invoke`], any combination of flags can be used, e.g. `NonPublic` with
+    /// `FlattenHierarchy` to reach the private static methods of a base class, or `IgnoreCase`.
+    /// `BindingFlags::InvokeMethod` is added unless the flags already request a field or
+    /// property access (`GetField`, `SetField`, `GetProperty` or `SetProperty`).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the member to invoke.
+    /// * `instance` - An optional `VARIANT` representing the instance.
+    /// * `args` - Optional vector of `VARIANT` arguments.
+    /// * `flags` - The `BindingFlags` used to look up and invoke the member.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - On success, returns the result as `VARIANT`.
+    /// * `Err(ClrError)` - On failure, returns `ClrError`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let flags = BindingFlags::NonPublic | BindingFlags::Static;
+    /// let message = environment.invoke_with_flags("GetResourceString", None, Some(args), flags)?;
+    /// ```
+    pub fn invoke_with_flags(
+        &self, 
+        name: &str, 
+        instance: Option<VARIANT>, 
+        args: Option<Vec<VARIANT>>, 
+        flags: BindingFlags
+    ) -> Result<VARIANT, ClrError> {
+        let accessors = BindingFlags::GetField | BindingFlags::SetField | BindingFlags::GetProperty | BindingFlags::SetProperty;
+        let flags = if flags.0 & accessors.0 == 0 { flags | BindingFlags::InvokeMethod } else { flags };
 
         let method_name = name.to_bstr();
-        let args = args.as_ref().map_or_else(
-            || Ok(null_mut()),
-            |args| create_safe_args(args.to_vec())
-        )?;
+        let args = args.as_deref().map(SafeArray::copy_from).transpose()?;
 
         let instance = instance.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
-        self.InvokeMember_3(method_name, flags, instance, args)
+        self.InvokeMember_3(method_name, flags, instance, args.as_ref().map_or(null_mut(), SafeArray::as_ptr))
+    }
+
+    /// Reads a static field of the type, public or not.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value of the field.
+    /// * `Err(ClrError)` - If the type declares no static field with this name.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let environment = mscorlib.resolve_type("System.Environment")?;
+    /// let processors = environment.get_static("processorCount")?;
+    /// ```
+    pub fn get_static(&self, name: &str) -> Result<VARIANT, ClrError> {
+        let flags = BindingFlags::GetField | BindingFlags::Public | BindingFlags::NonPublic | BindingFlags::Static;
+        self.invoke_with_flags(name, None, None, flags)
+    }
+
+    /// Writes a static field of the type, public or not.
+    ///
+    /// `readonly` fields are written as well, although code the JIT already compiled may
+    /// keep seeing their previous value.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field.
+    /// * `value` - The new value, converted to the type of the field by the runtime's default binder.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the field is written.
+    /// * `Err(ClrError)` - If the type declares no static field with this name or the value cannot be converted.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let config = assembly.resolve_type("Tool.Config")?;
+    /// config.set_static("Verbose", true.to_variant())?;
+    /// ```
+    pub fn set_static(&self, name: &str, value: VARIANT) -> Result<(), ClrError> {
+        let flags = BindingFlags::SetField | BindingFlags::Public | BindingFlags::NonPublic | BindingFlags::Static;
+        self.invoke_with_flags(name, None, Some(vec![value]), flags)?;
+        Ok(())
     }
 
     /// Retrieves all methods of the type.
@@ -113,119 +359,831 @@ impl _Type {
     /// * `Ok(Vec<(String, _MethodInfo)>)` - On success, returns a vector of method names and `_MethodInfo`.
     /// * `Err(ClrError)` - On failure, returns a `ClrError`.
     pub fn methods(&self) -> Result<Vec<(String, _MethodInfo)>, ClrError> {
-        let binding_flags = BindingFlags::Public | BindingFlags::Instance | 
-            BindingFlags::Static | BindingFlags::FlattenHierarchy;
-        
-        let sa_methods = self.GetMethods(binding_flags)?;
+        self.methods_with_flags(
+            BindingFlags::Public | BindingFlags::Instance | 
+            BindingFlags::Static | BindingFlags::FlattenHierarchy
+        )
+    }
+
+    /// Retrieves the methods of the type matching explicit `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `flags` - The `BindingFlags` controlling the search. Include `BindingFlags::NonPublic`
+    ///   to list `private` and `internal` methods.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, _MethodInfo)>)` - On success, returns a vector of method names and `_MethodInfo`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn methods_with_flags(&self, flags: BindingFlags) -> Result<Vec<(String, _MethodInfo)>, ClrError> {
+        let sa_methods = self.GetMethods(flags)?;
         if sa_methods.is_null() {
             return Err(ClrError::NullPointerError("GetMethods"));
         }
 
-        let mut lbound = 0;
-        let mut ubound = 0;
-        let mut methods = Vec::new();
-        unsafe {
-            SafeArrayGetLBound(sa_methods, 1, &mut lbound);
-            SafeArrayGetUBound(sa_methods, 1, &mut ubound);
-            
-            let mut p_method = null_mut::<_MethodInfo>();
-            for i in lbound..=ubound {
-                let hr = SafeArrayGetElement(sa_methods, &i, &mut p_method as *mut _ as *mut _);
-                if hr != 0 || p_method.is_null() {
-                    return Err(ClrError::ApiError("SafeArrayGetElement", hr));
-                }
-
-                let method = _MethodInfo::from_raw(p_method as *mut c_void)?;
-                let method_name = method.ToString()?;
-                methods.push((method_name, method));
-            }
-        }
+        members(sa_methods, |raw| {
+            let method = _MethodInfo::from_raw(raw)?;
+            Ok((method.ToString()?, method))
+        })
+    }
 
-        Ok(methods)
+    /// Retrieves the public fields of the type, both instance and static.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, _FieldInfo)>)` - On success, returns a vector of field names and `_FieldInfo`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn fields(&self) -> Result<Vec<(String, _FieldInfo)>, ClrError> {
+        self.fields_with_flags(
+            BindingFlags::Public | BindingFlags::Instance | 
+            BindingFlags::Static | BindingFlags::FlattenHierarchy
+        )
     }
 
-    /// Creates an `_Type` instance from a raw COM interface pointer.
+    /// Retrieves the fields of the type matching explicit `BindingFlags`.
     ///
     /// # Arguments
     ///
-    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    /// * `flags` - The `BindingFlags` controlling the search. Include `BindingFlags::NonPublic`
+    ///   to list `private` and `internal` fields, such as compiler-generated backing fields.
     ///
     /// # Returns
     ///
-    /// * `Ok(_Type)` - On success, returns the `_Type` wrapping the COM interface.
-    /// * `Err(ClrError)` - If creation fails, returns a `ClrError`.
-    #[inline(always)]
-    pub fn from_raw(raw: *mut c_void) -> Result<_Type, ClrError> {
-        let iunknown = unsafe { IUnknown::from_raw(raw) };
-        iunknown.cast::<_Type>().map_err(|_| ClrError::CastingError("_Type"))
+    /// * `Ok(Vec<(String, _FieldInfo)>)` - On success, returns a vector of field names and `_FieldInfo`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn fields_with_flags(&self, flags: BindingFlags) -> Result<Vec<(String, _FieldInfo)>, ClrError> {
+        let sa_fields = self.GetFields(flags)?;
+        if sa_fields.is_null() {
+            return Err(ClrError::NullPointerError("GetFields"));
+        }
+
+        members(sa_fields, |raw| {
+            let field = _FieldInfo::from_raw(raw)?;
+            Ok((field.get_name()?, field))
+        })
     }
-}
 
-/// Implementation of the original `_Type` COM interface methods.
-///
-/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
-impl _Type {
-    /// Retrieves the string representation of the type.
+    /// Retrieves the public properties of the type, both instance and static.
     ///
     /// # Returns
     ///
-    /// * `Ok(String)` - On success, returns the type's name as a `String`.
+    /// * `Ok(Vec<(String, _PropertyInfo)>)` - On success, returns a vector of property names and `_PropertyInfo`.
     /// * `Err(ClrError)` - On failure, returns a `ClrError`.
-    pub fn ToString(&self) -> Result<String, ClrError> {
-        unsafe {
-            let mut result= null::<u16>();
-            let hr = (Interface::vtable(self).get_ToString)(Interface::as_raw(self), &mut result);
-            if hr == 0 {
-                let mut len = 0;
-                while *result.add(len) != 0 {
-                    len += 1;
-                }
-    
-                let slice = std::slice::from_raw_parts(result, len);
-                let entrypoint = String::from_utf16_lossy(slice);
+    pub fn properties(&self) -> Result<Vec<(String, _PropertyInfo)>, ClrError> {
+        let sa_properties = self.GetProperties(
+            BindingFlags::Public | BindingFlags::Instance | 
+            BindingFlags::Static | BindingFlags::FlattenHierarchy
+        )?;
 
-                Ok(entrypoint)
-            } else {
-                Err(ClrError::ApiError("ToString", hr))
+        if sa_properties.is_null() {
+            return Err(ClrError::NullPointerError("GetProperties"));
+        }
+
+        members(sa_properties, |raw| {
+            let property = _PropertyInfo::from_raw(raw)?;
+            Ok((property.get_name()?, property))
+        })
+    }
+
+    /// Retrieves the public instance constructors of the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, _ConstructorInfo)>)` - On success, returns a vector of constructor signatures and `_ConstructorInfo`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn constructors(&self) -> Result<Vec<(String, _ConstructorInfo)>, ClrError> {
+        let sa_constructors = self.GetConstructors(BindingFlags::Public | BindingFlags::Instance)?;
+        if sa_constructors.is_null() {
+            return Err(ClrError::NullPointerError("GetConstructors"));
+        }
+
+        members(sa_constructors, |raw| {
+            let constructor = _ConstructorInfo::from_raw(raw)?;
+            Ok((constructor.ToString()?, constructor))
+        })
+    }
+
+    /// Finds the public constructor declaring the given number of parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `arity` - The number of parameters of the constructor.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_ConstructorInfo)` - The first constructor with `arity` parameters.
+    /// * `Err(ClrError)` - If the type declares no such constructor.
+    pub fn constructor(&self, arity: usize) -> Result<_ConstructorInfo, ClrError> {
+        for (_, constructor) in self.constructors()? {
+            if constructor.parameter_count()? == arity {
+                return Ok(constructor);
             }
         }
+
+        Err(ClrError::MethodNotFound)
     }
 
-    /// Retrieves all methods matching the specified `BindingFlags`.
+    /// Retrieves the public members of the type, both instance and static, tagged by kind.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Member)>)` - On success, returns a vector of member signatures and `Member`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for (signature, member) in ty.members()? {
+    ///     match member {
+    ///         Member::Method(_) => println!("method      {signature}"),
+    ///         Member::Property(_) => println!("property    {signature}"),
+    ///         Member::NestedType(_) => println!("nested type {signature}"),
+    ///         _ => println!("{signature}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn members(&self) -> Result<Vec<(String, Member)>, ClrError> {
+        self.members_with_flags(
+            BindingFlags::Public | BindingFlags::Instance | 
+            BindingFlags::Static | BindingFlags::FlattenHierarchy
+        )
+    }
+
+    /// Retrieves the members of the type matching explicit `BindingFlags`, tagged by kind.
     ///
     /// # Arguments
     ///
-    /// * `bindingAttr` - The `BindingFlags` specifying which methods to retrieve.
+    /// * `flags` - The `BindingFlags` controlling the search. Include `BindingFlags::NonPublic`
+    ///   to list `private` and `internal` members.
     ///
     /// # Returns
     ///
-    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of methods.
+    /// * `Ok(Vec<(String, Member)>)` - On success, returns a vector of member signatures and `Member`.
     /// * `Err(ClrError)` - On failure, returns a `ClrError`.
-    pub fn GetMethods(&self, bindingAttr: BindingFlags) -> Result<*mut SAFEARRAY, ClrError> {
-        unsafe {
-            let mut result = null_mut();
-            let hr = (Interface::vtable(self).GetMethods)(Interface::as_raw(self), bindingAttr, &mut result);
-            if hr == 0 {
-                Ok(result)
-            } else {
-                Err(ClrError::ApiError("GetMethods", hr))
-            }
+    pub fn members_with_flags(&self, flags: BindingFlags) -> Result<Vec<(String, Member)>, ClrError> {
+        let sa_members = self.GetMembers(flags)?;
+        if sa_members.is_null() {
+            return Err(ClrError::NullPointerError("GetMembers"));
         }
+
+        members(sa_members, |raw| {
+            let member = Member::from_unknown(&unsafe { IUnknown::from_raw(raw) })?;
+            Ok((member.ToString()?, member))
+        })
     }
 
-    /// Retrieves a method by name.
+    /// Retrieves the interfaces implemented or inherited by the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<_Type>)` - The interfaces of the type, empty if it implements none.
+    /// * `Err(ClrError)` - If the interfaces cannot be retrieved.
+    pub fn interfaces(&self) -> Result<Vec<_Type>, ClrError> {
+        let sa_interfaces = self.GetInterfaces()?;
+        if sa_interfaces.is_null() {
+            return Err(ClrError::NullPointerError("GetInterfaces"));
+        }
+
+        members(sa_interfaces, _Type::from_raw)
+    }
+
+    /// Indicates whether a value of another type can be assigned to this type.
+    ///
+    /// This is the case when both are the same type, when `other` derives from this type,
+    /// or when this type is an interface implemented by `other`.
     ///
     /// # Arguments
     ///
-    /// * `name` - The name of the method as a `BSTR`.
+    /// * `other` - The `_Type` to compare with.
     ///
     /// # Returns
     ///
-    /// * `Ok(_MethodInfo)` - On success, returns the `_MethodInfo` for the method.
-    /// * `Err(ClrError)` - On failure, returns a `ClrError`. 
-    pub fn GetMethod_6(&self, name: BSTR) -> Result<_MethodInfo, ClrError> {
-        unsafe {
-            let mut result = std::mem::zeroed();
+    /// * `Ok(bool)` - `true` if instances of `other` can be assigned to this type.
+    /// * `Err(ClrError)` - If the check fails.
+    pub fn is_assignable_from(&self, other: &_Type) -> Result<bool, ClrError> {
+        self.IsAssignableFrom(other)
+    }
+
+    /// Indicates whether the type derives from another class.
+    ///
+    /// Unlike [`_Type::is_assignable_from`], interfaces and the type itself are not matched.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The `_Type` of the base class.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if `base` is a base class of the type, directly or not.
+    /// * `Err(ClrError)` - If the check fails.
+    pub fn is_subclass_of(&self, base: &_Type) -> Result<bool, ClrError> {
+        self.IsSubclassOf(base)
+    }
+
+    /// Indicates whether the type implements an interface or derives from a class,
+    /// directly or through a base type.
+    ///
+    /// # Arguments
+    ///
+    /// * `interface` - The `_Type` of the interface or base class.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if the type is not `interface` itself and can be assigned to it.
+    /// * `Err(ClrError)` - If the check fails.
+    pub fn implements(&self, interface: &_Type) -> Result<bool, ClrError> {
+        Ok(**self != **interface && interface.IsAssignableFrom(self)?)
+    }
+
+    /// Creates an instance of the type, passing arguments to its constructor.
+    ///
+    /// The constructor is selected by the runtime's default binder from the argument
+    /// types, so overloads with the same number of parameters are told apart. Use
+    /// [`constructor`](Self::constructor) to pick one explicitly.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments of the constructor. An empty vector calls the default constructor.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The new object.
+    /// * `Err(ClrError)` - If no constructor matches the arguments or the constructor throws.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let version = mscorlib.resolve_type("System.Version")?;
+    /// let instance = version.new_instance(vec![1.to_variant(), 2.to_variant()])?;
+    /// ```
+    pub fn new_instance(&self, args: Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        let flags = BindingFlags::CreateInstance | BindingFlags::Public | BindingFlags::Instance;
+        let args = if args.is_empty() { None } else { Some(SafeArray::copy_from(&args)?) };
+        let args = args.as_ref().map_or(null_mut(), SafeArray::as_ptr);
+
+        self.InvokeMember_3("".to_bstr(), flags, unsafe { std::mem::zeroed::<VARIANT>() }, args)
+    }
+
+    /// Retrieves the custom attributes applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `inherit` - Whether attributes inherited from base types or overridden members are included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Instance)>)` - The full name of each attribute type (e.g. `System.STAThreadAttribute`),
+    ///   with the attribute instance.
+    /// * `Err(ClrError)` - If the attributes cannot be retrieved or one of them fails to construct.
+    pub fn custom_attributes(&self, inherit: bool) -> Result<Vec<(String, Instance)>, ClrError> {
+        let attributes = self.GetCustomAttributes_2(if inherit { VARIANT_TRUE } else { VARIANT_FALSE })?;
+        custom_attributes(attributes, self)
+    }
+
+    /// Indicates whether an attribute is applied to the type, without constructing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute_type` - The `_Type` of the attribute, e.g. `System.ObsoleteAttribute`.
+    /// * `inherit` - Whether attributes inherited from base types or overridden members are included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if the attribute, or an attribute deriving from it, is applied.
+    /// * `Err(ClrError)` - If the check fails.
+    pub fn is_defined(&self, attribute_type: &_Type, inherit: bool) -> Result<bool, ClrError> {
+        self.IsDefined(attribute_type, if inherit { VARIANT_TRUE } else { VARIANT_FALSE })
+    }
+
+    /// Converts an integer to a value of this enum type.
+    ///
+    /// The value is produced by `Enum.ToObject`, so it reaches COM with the variant type of the
+    /// underlying type of the enum (`VT_UI1` for a `byte` enum, `VT_I8` for a `long` enum, ...),
+    /// which the binder accepts for parameters of the enum type.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The integer value, which does not need to match a named member.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The enum value, ready to be passed to a managed method.
+    /// * `Err(ClrError)` - If the type is not an enum or the value does not fit its underlying type.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let comparison = mscorlib.resolve_type("System.StringComparison")?;
+    /// let ignore_case = comparison.enum_from_value(5)?;
+    /// ```
+    pub fn enum_from_value(&self, value: i64) -> Result<VARIANT, ClrError> {
+        let mut integer = unsafe { std::mem::zeroed::<VARIANT>() };
+        integer.Anonymous.Anonymous.vt = VT_I8;
+        integer.Anonymous.Anonymous.Anonymous.llVal = value;
+
+        self.enum_type()?.invoke(
+            &ManagedName::ToObject.value(),
+            None,
+            Some(vec![self.to_variant(), integer]),
+            InvocationType::Static
+        )
+    }
+
+    /// Parses the name of a member of this enum type into its value.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The member name, or a comma-separated list of names for `[Flags]` enums.
+    ///   The comparison is case-sensitive.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The enum value, boxed like [`_Type::enum_from_value`].
+    /// * `Err(ClrError)` - If the type is not an enum or a name is not a member of it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let comparison = mscorlib.resolve_type("System.StringComparison")?;
+    /// let ignore_case = comparison.enum_from_name("OrdinalIgnoreCase")?;
+    /// ```
+    pub fn enum_from_name(&self, name: &str) -> Result<VARIANT, ClrError> {
+        self.enum_type()?.invoke(
+            &ManagedName::Parse.value(),
+            None,
+            Some(vec![self.to_variant(), name.to_variant()]),
+            InvocationType::Static
+        )
+    }
+
+    /// Resolves `System.Enum` from the assembly declaring `System.Type`.
+    fn enum_type(&self) -> Result<_Type, ClrError> {
+        self.GetType()?
+            .get_Assembly()?
+            .resolve_type(&ManagedName::Enum.value())
+    }
+
+    /// Creates an `_Type` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - On success, returns the `_Type` wrapping the COM interface.
+    /// * `Err(ClrError)` - If creation fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_Type, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_Type>().map_err(|_| ClrError::CastingError("_Type"))
+    }
+}
+
+/// A member of a type, as returned by `Type.GetMembers`, tagged by kind.
+#[derive(Debug, Clone)]
+pub enum Member {
+    /// A method, instance or static.
+    Method(_MethodInfo),
+
+    /// A constructor (`.ctor`) or type initializer (`.cctor`).
+    Constructor(_ConstructorInfo),
+
+    /// A property, including indexers.
+    Property(_PropertyInfo),
+
+    /// A field, including constants.
+    Field(_FieldInfo),
+
+    /// An event.
+    Event(_EventInfo),
+
+    /// A type declared inside the type.
+    NestedType(_Type),
+}
+
+impl Member {
+    /// Identifies the kind of a `MemberInfo` by the interface it exposes.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - The `IUnknown` of the `MemberInfo`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Member)` - The member, wrapped in the interface of its kind.
+    /// * `Err(ClrError)` - If the member is of none of the known kinds.
+    fn from_unknown(member: &IUnknown) -> Result<Self, ClrError> {
+        if let Ok(method) = member.cast::<_MethodInfo>() {
+            Ok(Self::Method(method))
+        } else if let Ok(constructor) = member.cast::<_ConstructorInfo>() {
+            Ok(Self::Constructor(constructor))
+        } else if let Ok(property) = member.cast::<_PropertyInfo>() {
+            Ok(Self::Property(property))
+        } else if let Ok(field) = member.cast::<_FieldInfo>() {
+            Ok(Self::Field(field))
+        } else if let Ok(event) = member.cast::<_EventInfo>() {
+            Ok(Self::Event(event))
+        } else if let Ok(ty) = member.cast::<_Type>() {
+            Ok(Self::NestedType(ty))
+        } else {
+            Err(ClrError::CastingError("MemberInfo"))
+        }
+    }
+
+    /// Retrieves the string representation of the member (equivalent to `ToString` in .NET).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The signature of methods and constructors, the type and name of
+    ///   fields, properties and events, or the full name of nested types.
+    /// * `Err(ClrError)` - If the representation cannot be retrieved.
+    pub fn ToString(&self) -> Result<String, ClrError> {
+        match self {
+            Self::Method(method) => method.ToString(),
+            Self::Constructor(constructor) => constructor.ToString(),
+            Self::Property(property) => property.ToString(),
+            Self::Field(field) => field.ToString(),
+            Self::Event(event) => event.ToString(),
+            Self::NestedType(ty) => ty.ToString(),
+        }
+    }
+}
+
+/// Reads the members of a `SAFEARRAY` returned by a `Get*s` method of `_Type`.
+///
+/// # Arguments
+///
+/// * `sa_members` - The `SAFEARRAY` of `IUnknown` members.
+/// * `read` - Converts each raw member pointer, taking ownership of its reference.
+///
+/// # Returns
+///
+/// * `Ok(Vec<T>)` - The converted members.
+/// * `Err(ClrError)` - If an element cannot be read or converted.
+pub(crate) fn members<T>(sa_members: *mut SAFEARRAY, read: impl Fn(*mut c_void) -> Result<T, ClrError>) -> Result<Vec<T>, ClrError> {
+    let mut lbound = 0;
+    let mut ubound = -1;
+    let mut members = Vec::new();
+    unsafe {
+        SafeArrayGetLBound(sa_members, 1, &mut lbound);
+        SafeArrayGetUBound(sa_members, 1, &mut ubound);
+
+        let mut p_member = null_mut::<c_void>();
+        for i in lbound..=ubound {
+            let hr = SafeArrayGetElement(sa_members, &i, &mut p_member as *mut _ as *mut _);
+            if hr != 0 || p_member.is_null() {
+                return Err(ClrError::ApiError("SafeArrayGetElement", hr));
+            }
+
+            members.push(read(p_member)?);
+        }
+    }
+
+    Ok(members)
+}
+
+/// Waits for a managed `Task` to complete and returns its result.
+///
+/// The task is awaited through `TaskAwaiter.GetResult`, which blocks the calling thread and,
+/// unlike `Task.Wait`, rethrows the exception of a faulted task instead of an `AggregateException`.
+/// The result is then read from `Task<T>.Result`, except for the `Task<VoidTaskResult>` the
+/// runtime completes for `async Task` methods.
+///
+/// # Arguments
+///
+/// * `task` - The `VARIANT` holding the task. It is released once awaited.
+/// * `via` - Any `_Type` of the runtime, used to reach `mscorlib`.
+///
+/// # Returns
+///
+/// * `Ok(VARIANT)` - The result of the task, or an empty `VARIANT` for a `Task` without result.
+/// * `Err(ClrError)` - If the value is not a `Task` or the task faults.
+pub(crate) fn await_task(task: VARIANT, via: &_Type) -> Result<VARIANT, ClrError> {
+    let mscorlib = via.GetType()?.get_Assembly()?;
+    let task = Instance::resolve(task, via)?;
+    let task_type = task.runtime_type().ok_or(ClrError::NullPointerError("Task"))?;
+
+    let base = mscorlib.resolve_type(&ManagedName::Task.value())?;
+    if !base.is_assignable_from(task_type)? {
+        return Err(ClrError::ErrorClr("The method did not return a Task"));
+    }
+
+    let awaiter = base.invoke(&ManagedName::GetAwaiter.value(), Some(task.variant()), None, InvocationType::Instance)?;
+    mscorlib.resolve_type(&ManagedName::TaskAwaiter.value())?.invoke(
+        &ManagedName::GetResult.value(),
+        Some(awaiter),
+        None,
+        InvocationType::Instance
+    )?;
+
+    let result = ManagedName::Result.value();
+    let void_task = mscorlib.resolve_type(&ManagedName::VoidTask.value())?;
+    let has_result = task_type.properties()?.iter().any(|(name, _)| *name == result);
+    if !has_result || void_task.is_assignable_from(task_type)? {
+        return Ok(unsafe { std::mem::zeroed::<VARIANT>() });
+    }
+
+    let flags = BindingFlags::GetProperty | BindingFlags::Public | BindingFlags::Instance;
+    task_type.invoke_with_flags(&result, Some(task.variant()), None, flags)
+}
+
+/// Implementation of the original `_Type` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl _Type {
+    /// Retrieves the string representation of the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - On success, returns the type's name as a `String`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn ToString(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result= null::<u16>();
+            let hr = (Interface::vtable(self).get_ToString)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+    
+                let slice = std::slice::from_raw_parts(result, len);
+                let entrypoint = String::from_utf16_lossy(slice);
+
+                Ok(entrypoint)
+            } else {
+                Err(ClrError::ApiError("ToString", hr))
+            }
+        }
+    }
+
+    /// Retrieves the runtime type of the type object itself (`System.RuntimeType`).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - On success, returns the `_Type` of the type object.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetType(&self) -> Result<_Type, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetType)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            _Type::from_raw(result as *mut c_void)
+        } else {
+            Err(ClrError::ApiError("GetType", hr))
+        }
+    }
+
+    /// Retrieves the GUID associated with the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GUID)` - On success, returns the type's `GUID`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_Guid(&self) -> Result<GUID, ClrError> {
+        unsafe {
+            let mut result = GUID::zeroed();
+            let hr = (Interface::vtable(self).get_Guid)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("get_Guid", hr))
+            }
+        }
+    }
+
+    /// Retrieves the assembly in which the type is declared.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Assembly)` - On success, returns the declaring `_Assembly`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_Assembly(&self) -> Result<_Assembly, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).get_Assembly)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                _Assembly::from_raw(result)
+            } else {
+                Err(ClrError::ApiError("get_Assembly", hr))
+            }
+        }
+    }
+
+    /// Indicates whether the type is an interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the type is an interface.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_IsInterface(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_IsInterface)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_IsInterface", hr))
+            }
+        }
+    }
+
+    /// Indicates whether the type is a value type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the type is a value type.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_IsValueType(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_IsValueType)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_IsValueType", hr))
+            }
+        }
+    }
+
+    /// Indicates whether the type is abstract.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the type is abstract.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_IsAbstract(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_IsAbstract)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_IsAbstract", hr))
+            }
+        }
+    }
+
+    /// Indicates whether the type is sealed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the type is sealed.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_IsSealed(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_IsSealed)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_IsSealed", hr))
+            }
+        }
+    }
+
+    /// Retrieves all constructors matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindingAttr` - The `BindingFlags` specifying which constructors to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of constructors.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetConstructors(&self, bindingAttr: BindingFlags) -> Result<*mut SAFEARRAY, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetConstructors)(Interface::as_raw(self), bindingAttr, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("GetConstructors", hr))
+            }
+        }
+    }
+
+    /// Retrieves all interfaces implemented or inherited by the type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of interface types.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetInterfaces(&self) -> Result<*mut SAFEARRAY, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetInterfaces)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("GetInterfaces", hr))
+            }
+        }
+    }
+
+    /// Retrieves all methods matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindingAttr` - The `BindingFlags` specifying which methods to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of methods.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetMethods(&self, bindingAttr: BindingFlags) -> Result<*mut SAFEARRAY, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetMethods)(Interface::as_raw(self), bindingAttr, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("GetMethods", hr))
+            }
+        }
+    }
+
+    /// Retrieves all fields matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindingAttr` - The `BindingFlags` specifying which fields to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of fields.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetFields(&self, bindingAttr: BindingFlags) -> Result<*mut SAFEARRAY, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetFields)(Interface::as_raw(self), bindingAttr, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("GetFields", hr))
+            }
+        }
+    }
+
+    /// Retrieves all properties matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindingAttr` - The `BindingFlags` specifying which properties to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of properties.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetProperties(&self, bindingAttr: BindingFlags) -> Result<*mut SAFEARRAY, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetProperties)(Interface::as_raw(self), bindingAttr, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("GetProperties", hr))
+            }
+        }
+    }
+
+    /// Retrieves all members matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bindingAttr` - The `BindingFlags` specifying which members to retrieve.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a pointer to a `SAFEARRAY` of members.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetMembers(&self, bindingAttr: BindingFlags) -> Result<*mut SAFEARRAY, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetMembers)(Interface::as_raw(self), bindingAttr, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                Err(ClrError::ApiError("GetMembers", hr))
+            }
+        }
+    }
+
+    /// Retrieves a method by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method as a `BSTR`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_MethodInfo)` - On success, returns the `_MethodInfo` for the method.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`. 
+    pub fn GetMethod_6(&self, name: BSTR) -> Result<_MethodInfo, ClrError> {
+        unsafe {
+            let mut result = std::mem::zeroed();
             let hr = (Interface::vtable(self).GetMethod_6)(Interface::as_raw(self), name, &mut result);
             if hr == 0 {
                 _MethodInfo::from_raw(result as *mut c_void)
@@ -235,6 +1193,138 @@ impl _Type {
         }
     }
 
+    /// Retrieves a method by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the method as a `BSTR`.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_MethodInfo)` - On success, returns the `_MethodInfo` for the method.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`. 
+    pub fn GetMethod_5(&self, name: BSTR, bindingAttr: BindingFlags) -> Result<_MethodInfo, ClrError> {
+        unsafe {
+            let mut result = std::mem::zeroed();
+            let hr = (Interface::vtable(self).GetMethod_5)(Interface::as_raw(self), name, bindingAttr, &mut result);
+            if hr == 0 {
+                _MethodInfo::from_raw(result as *mut c_void)
+            } else {
+                Err(ClrError::ApiError("GetMethod_5", hr))
+            }
+        }
+    }
+
+    /// Retrieves a field by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the field as a `BSTR`.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_FieldInfo)` - On success, returns the `_FieldInfo` for the field.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`. 
+    pub fn GetField(&self, name: BSTR, bindingAttr: BindingFlags) -> Result<_FieldInfo, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetField)(Interface::as_raw(self), name, bindingAttr, &mut result);
+            if hr == 0 && !result.is_null() {
+                _FieldInfo::from_raw(result as *mut c_void)
+            } else {
+                Err(ClrError::ApiError("GetField", hr))
+            }
+        }
+    }
+
+    /// Retrieves an event by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the event as a `BSTR`.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_EventInfo)` - On success, returns the `_EventInfo` of the event.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetEvent(&self, name: BSTR, bindingAttr: BindingFlags) -> Result<_EventInfo, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetEvent)(Interface::as_raw(self), name, bindingAttr, &mut result);
+            if hr == 0 && !result.is_null() {
+                _EventInfo::from_raw(result as *mut c_void)
+            } else {
+                Err(ClrError::ApiError("GetEvent", hr))
+            }
+        }
+    }
+
+    /// Retrieves a nested type by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the nested type as a `BSTR`.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - On success, returns the nested `_Type`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`. 
+    pub fn GetNestedType(&self, name: BSTR, bindingAttr: BindingFlags) -> Result<_Type, ClrError> {
+        unsafe {
+            let mut result = null_mut();
+            let hr = (Interface::vtable(self).GetNestedType)(Interface::as_raw(self), name, bindingAttr, &mut result);
+            if hr == 0 && !result.is_null() {
+                _Type::from_raw(result)
+            } else {
+                Err(ClrError::ApiError("GetNestedType", hr))
+            }
+        }
+    }
+
+    /// Determines whether the type derives from the specified class.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The `_Type` of the base class.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the type derives from `c`.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn IsSubclassOf(&self, c: &_Type) -> Result<bool, ClrError> {
+        let mut result = VARIANT_FALSE;
+        let hr = unsafe { (Interface::vtable(self).IsSubclassOf)(Interface::as_raw(self), c.as_raw(), &mut result) };
+        if hr == 0 {
+            Ok(result != VARIANT_FALSE)
+        } else {
+            Err(ClrError::ApiError("IsSubclassOf", hr))
+        }
+    }
+
+    /// Determines whether an instance of the specified type can be assigned to this type.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The `_Type` to compare with.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if `c` is assignable to the type.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn IsAssignableFrom(&self, c: &_Type) -> Result<bool, ClrError> {
+        let mut result = VARIANT_FALSE;
+        let hr = unsafe { (Interface::vtable(self).IsAssignableFrom)(Interface::as_raw(self), c.as_raw(), &mut result) };
+        if hr == 0 {
+            Ok(result != VARIANT_FALSE)
+        } else {
+            Err(ClrError::ApiError("IsAssignableFrom", hr))
+        }
+    }
+
     /// Invokes a method (static or instance) by name on the specified type or object.
     ///
     /// # Arguments
@@ -261,6 +1351,68 @@ impl _Type {
             }
         }
     }
+    /// Retrieves the custom attributes of the given type applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributeType` - The `_Type` of the attributes to return.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a `SAFEARRAY` of `VARIANT` attribute instances.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetCustomAttributes(&self, attributeType: &_Type, inherit: VARIANT_BOOL) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetCustomAttributes)(Interface::as_raw(self), attributeType.as_raw(), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetCustomAttributes", hr))
+        }
+    }
+
+    /// Retrieves all the custom attributes applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a `SAFEARRAY` of `VARIANT` attribute instances.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetCustomAttributes_2(&self, inherit: VARIANT_BOOL) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetCustomAttributes_2)(Interface::as_raw(self), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetCustomAttributes_2", hr))
+        }
+    }
+
+    /// Indicates whether custom attributes of the given type are applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributeType` - The `_Type` of the attribute.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the attribute is applied.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn IsDefined(&self, attributeType: &_Type, inherit: VARIANT_BOOL) -> Result<bool, ClrError> {
+        let mut result = VARIANT_FALSE;
+        let hr = unsafe { (Interface::vtable(self).IsDefined)(Interface::as_raw(self), attributeType.as_raw(), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result != VARIANT_FALSE)
+        } else {
+            Err(ClrError::ApiError("IsDefined", hr))
+        }
+    }
+
 }
 
 unsafe impl Interface for _Type {
@@ -271,7 +1423,7 @@ unsafe impl Interface for _Type {
     /// This GUID is used to identify the `_Type` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `_Type` interface.
-    const IID: GUID = GUID::from_u128(0xbca8b44d_aad6_3a86_8ab7_03349f4f2da2);
+    const IID: GUID = crate::com::IID__TYPE;
 }
 
 impl Deref for _Type {
@@ -328,8 +1480,20 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `GetHashCode` method. Not used directly.
     GetHashCode: *const c_void,
     
-    /// Placeholder for the `GetType` method. Not used directly.
-    GetType: *const c_void,
+    /// Retrieves the runtime type of the type object itself (`System.RuntimeType`).
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to `_Type` that receives the runtime type.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetType: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut _Type
+    ) -> HRESULT,
 
     /// Placeholder for the `get_MemberType` method. Not used directly.
     get_MemberType: *const c_void,
@@ -343,23 +1507,93 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `get_ReflectedType` method. Not used directly.
     get_ReflectedType: *const c_void,
 
-    /// Placeholder for the `GetCustomAttributes` method. Not used directly.
-    GetCustomAttributes: *const c_void,
+    /// Retrieves the custom attributes of a given type applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `attributeType` - Pointer to the `_Type` of the attributes.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the `SAFEARRAY` of attributes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetCustomAttributes: unsafe extern "system" fn(
+        *mut c_void,
+        attributeType: *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
-    /// Placeholder for the `GetCustomAttributes_2` method. Not used directly.
-    GetCustomAttributes_2: *const c_void,
+    /// Retrieves all the custom attributes applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the `SAFEARRAY` of attributes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetCustomAttributes_2: unsafe extern "system" fn(
+        *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
-    /// Placeholder for the `IsDefined` method. Not used directly.
-    IsDefined: *const c_void,
+    /// Indicates whether custom attributes of a given type are applied to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `attributeType` - Pointer to the `_Type` of the attribute.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    IsDefined: unsafe extern "system" fn(
+        *mut c_void,
+        attributeType: *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
-    /// Placeholder for the `get_Guid` method. Not used directly.
-    get_Guid: *const c_void,
+    /// Retrieves the GUID associated with the type.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to a `GUID` that receives the result.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    get_Guid: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut GUID
+    ) -> HRESULT,
 
     /// Placeholder for the `get_Module` method. Not used directly.
     get_Module: *const c_void,
 
-    /// Placeholder for the `get_Assembly` method. Not used directly.
-    get_Assembly: *const c_void,
+    /// Retrieves the assembly in which the type is declared.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - A pointer that receives the `_Assembly` interface.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    get_Assembly: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut c_void
+    ) -> HRESULT,
 
     /// Placeholder for the `get_TypeHandle` method. Not used directly.
     get_TypeHandle: *const c_void,
@@ -379,20 +1613,62 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `get_BaseType` method. Not used directly.
     get_BaseType: *const c_void,
 
-    /// Placeholder for the `GetConstructors` method. Not used directly.
-    GetConstructors: *const c_void,
+    /// Retrieves all constructors matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `bindingAttr` - Flags that control which constructors are returned.
+    /// * `pRetVal` - Pointer to a `SAFEARRAY` receiving the constructors.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetConstructors: unsafe extern "system" fn(
+        *mut c_void,
+        bindingAttr: BindingFlags,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
     
     /// Placeholder for the `GetInterface` method. Not used directly.
     GetInterface: *const c_void,
 
-    /// Placeholder for the `GetInterfaces` method. Not used directly.
-    GetInterfaces: *const c_void,
+    /// Retrieves all interfaces implemented or inherited by the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to a `SAFEARRAY` receiving the interface types.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetInterfaces: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the `FindInterfaces` method. Not used directly.
     FindInterfaces: *const c_void,
 
-    /// Placeholder for the `GetEvent` method. Not used directly.
-    GetEvent: *const c_void,
+    /// Retrieves an event by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `name` - The name of the event as a `BSTR`.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    /// * `pRetVal` - A pointer that receives the `_EventInfo`, or null if no event matches.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetEvent: unsafe extern "system" fn(
+        *mut c_void,
+        name: BSTR,
+        bindingAttr: BindingFlags,
+        pRetVal: *mut *mut _EventInfo
+    ) -> HRESULT,
 
     /// Placeholder for the `GetEvents` method. Not used directly.
     GetEvents: *const c_void,
@@ -403,8 +1679,24 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `GetNestedTypes` method. Not used directly.
     GetNestedTypes: *const c_void,
 
-    /// Placeholder for the `GetNestedType` method. Not used directly.
-    GetNestedType: *const c_void,
+    /// Retrieves a nested type by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface. 
+    /// * `name` - A `BSTR` representing the name of the nested type.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    /// * `pRetVal` - Pointer that receives the `_Type` object.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetNestedType: unsafe extern "system" fn(
+        *mut c_void, 
+        name: BSTR, 
+        bindingAttr: BindingFlags,
+        pRetVal: *mut *mut c_void
+    ) -> HRESULT,
 
     /// Placeholder for the `GetMember` method. Not used directly.
     GetMember: *const c_void,
@@ -418,14 +1710,42 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `GetElementType` method. Not used directly.
     GetElementType: *const c_void,
 
-    /// Placeholder for the `IsSubclassOf` method. Not used directly.
-    IsSubclassOf: *const c_void,
+    /// Determines whether the type derives from the specified class.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `c` - Pointer to the `_Type` of the base class.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    IsSubclassOf: unsafe extern "system" fn(
+        *mut c_void,
+        c: *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
     /// Placeholder for the `IsInstanceOfType` method. Not used directly.
     IsInstanceOfType: *const c_void,
 
-    /// Placeholder for the `IsAssignableFrom` method. Not used directly.
-    IsAssignableFrom: *const c_void,
+    /// Determines whether an instance of the specified type can be assigned to the type.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `c` - Pointer to the `_Type` to compare with.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    IsAssignableFrom: unsafe extern "system" fn(
+        *mut c_void,
+        c: *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
     /// Placeholder for the `GetInterfaceMap` method. Not used directly.
     GetInterfaceMap: *const c_void,
@@ -453,11 +1773,41 @@ pub struct _Type_Vtbl {
         pRetVal: *mut *mut SAFEARRAY
     ) -> HRESULT,
 
-    /// Placeholder for the `GetField` method. Not used directly.
-    GetField: *const c_void,
+    /// Retrieves a field by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `name` - The name of the field as a `BSTR`.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    /// * `pRetVal` - A pointer that receives the `_FieldInfo`, or null if no field matches.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetField: unsafe extern "system" fn(
+        *mut c_void,
+        name: BSTR,
+        bindingAttr: BindingFlags,
+        pRetVal: *mut *mut _FieldInfo
+    ) -> HRESULT,
 
-    /// Placeholder for the `GetFields` method. Not used directly.
-    GetFields: *const c_void,
+    /// Retrieves fields matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `bindingAttr` - The `BindingFlags` specifying the fields to retrieve.
+    /// * `pRetVal` - A pointer to a `SAFEARRAY` that receives the retrieved fields.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetFields: unsafe extern "system" fn(
+        *mut c_void, 
+        bindingAttr: BindingFlags, 
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the `GetProperty` method. Not used directly.
     GetProperty: *const c_void,
@@ -465,14 +1815,32 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `GetProperty_2` method. Not used directly.
     GetProperty_2: *const c_void,
 
-    /// Placeholder for the `GetProperties` method. Not used directly.
-    GetProperties: *const c_void,
+    /// Retrieves properties matching the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `bindingAttr` - The `BindingFlags` specifying the properties to retrieve.
+    /// * `pRetVal` - A pointer to a `SAFEARRAY` that receives the retrieved properties.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetProperties: unsafe extern "system" fn(
+        *mut c_void, 
+        bindingAttr: BindingFlags, 
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the `GetMember_2` method. Not used directly.
     GetMember_2: *const c_void,
 
-    /// Placeholder for the `GetMembers` method. Not used directly.
-    GetMembers: *const c_void,
+    /// Retrieves the members of the type matching the given binding flags.
+    GetMembers: unsafe extern "system" fn(
+        *mut c_void, 
+        bindingAttr: BindingFlags, 
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
     /// Placeholder for the `InvokeMember` method. Not used directly.
     InvokeMember: *const c_void,
@@ -529,8 +1897,24 @@ pub struct _Type_Vtbl {
     /// Placeholder for the `GetMethod_4` method. Not used directly.
     GetMethod_4: *const c_void,
 
-    /// Placeholder for the `GetMethod_5` method. Not used directly.
-    GetMethod_5: *const c_void,
+    /// Retrieves a method by name, using the specified `BindingFlags`.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface. 
+    /// * `name` - A `BSTR` representing the method name.
+    /// * `bindingAttr` - The `BindingFlags` controlling the search.
+    /// * `pRetVal` - Pointer that receives the `_MethodInfo` object.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    GetMethod_5: unsafe extern "system" fn(
+        *mut c_void, 
+        name: BSTR, 
+        bindingAttr: BindingFlags,
+        pRetVal: *mut *mut _MethodInfo
+    ) -> HRESULT,
 
     /// Retrieves a method by name.
     ///
@@ -633,17 +2017,65 @@ pub struct _Type_Vtbl {
     /// Placeholder for the method. Not used directly.
     get_IsClass: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    get_IsInterface: *const c_void,
+    /// Indicates whether the type is an interface.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    get_IsInterface: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    get_IsValueType: *const c_void,
+    /// Indicates whether the type is a value type.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    get_IsValueType: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    get_IsAbstract: *const c_void,
+    /// Indicates whether the type is abstract.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    get_IsAbstract: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    get_IsSealed: *const c_void,
+    /// Indicates whether the type is sealed.
+    ///
+    /// # Arguments
+    /// 
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    /// 
+    /// * Returns an HRESULT indicating success or failure.
+    get_IsSealed: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
     
     /// Placeholder for the method. Not used directly.
     get_IsEnum: *const c_void,
@@ -696,67 +2128,79 @@ pub struct _Type_Vtbl {
 /// These flags can be combined using bitwise operations to refine the scope of the invocation or search.
 /// `BindingFlags` are commonly used in .NET reflection to determine if a method or property is
 /// public, static, instance-based, and more.
-#[repr(C)]
-pub enum BindingFlags {
+///
+/// The type is a transparent wrapper over the `Int32` value of `System.Reflection.BindingFlags`,
+/// so any combination of flags is a valid value.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct BindingFlags(pub u32);
+
+#[allow(non_upper_case_globals)]
+impl BindingFlags {
     /// Default binding, no special options.
-    Default = 0,
+    pub const Default: Self = Self(0);
 
     /// Ignores case when looking up members.
-    IgnoreCase = 1,
+    pub const IgnoreCase: Self = Self(1);
 
     /// Only members declared at the level of the supplied type's hierarchy should be considered.
-    DeclaredOnly = 2,
+    pub const DeclaredOnly: Self = Self(2);
 
     /// Specifies instance members.
-    Instance = 4,
+    pub const Instance: Self = Self(4);
 
     /// Specifies static members.
-    Static = 8,
+    pub const Static: Self = Self(8);
 
     /// Specifies public members.
-    Public = 16,
+    pub const Public: Self = Self(16);
 
     /// Specifies non-public members.
-    NonPublic = 32,
+    pub const NonPublic: Self = Self(32);
 
     /// Includes inherited members in the search.
-    FlattenHierarchy = 64,
+    pub const FlattenHierarchy: Self = Self(64);
 
     /// Specifies that the member to invoke is a method.
-    InvokeMethod = 256,
+    pub const InvokeMethod: Self = Self(256);
 
     /// Creates an instance of the object.
-    CreateInstance = 512,
+    pub const CreateInstance: Self = Self(512);
 
     /// Specifies that the member to retrieve is a field.
-    GetField = 1024,
+    pub const GetField: Self = Self(1024);
 
     /// Specifies that the member to set is a field.
-    SetField = 2048,
+    pub const SetField: Self = Self(2048);
 
     /// Specifies that the member to retrieve is a property.
-    GetProperty = 4096,
+    pub const GetProperty: Self = Self(4096);
 
     /// Specifies that the member to set is a property.
-    SetProperty = 8192,
+    pub const SetProperty: Self = Self(8192);
 
     /// Sets a COM object property.
-    PutDispProperty = 16384,
+    pub const PutDispProperty: Self = Self(16384);
 
     /// Sets a COM object reference property.
-    PutRefDispProperty = 32768,
+    pub const PutRefDispProperty: Self = Self(32768);
 
     /// Uses the most precise match during binding.
-    ExactBinding = 65536,
+    pub const ExactBinding: Self = Self(65536);
 
     /// Suppresses coercion of argument types during method invocation.
-    SuppressChangeType = 131072,
+    pub const SuppressChangeType: Self = Self(131072);
 
     /// Allows binding to optional parameters.
-    OptionalParamBinding = 262144,
+    pub const OptionalParamBinding: Self = Self(262144);
 
     /// Ignores the return value of a method.
-    IgnoreReturn = 16777216,
+    pub const IgnoreReturn: Self = Self(16777216);
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
 }
 
 impl BitOr for BindingFlags {
@@ -770,6 +2214,14 @@ impl BitOr for BindingFlags {
     /// let flags = BindingFlags::Public | BindingFlags::Instance;
     /// ```
     fn bitor(self, rhs: Self) -> Self::Output {
-        unsafe { std::mem::transmute::<u32, BindingFlags>(self as u32 | rhs as u32) }
+        Self(self.0 | rhs.0)
     }
 }
+
+impl BitOrAssign for BindingFlags {
+    /// Adds the flags in `rhs` to `self`.
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
@@ -3,16 +3,29 @@ use {
     std::{ffi::c_void, ops::Deref, ptr::{null, null_mut}}, 
     windows_sys::{
         core::{BSTR, HRESULT}, 
-        Win32::System::{
-            Com::SAFEARRAY, 
-            Variant::{VariantClear, VARIANT}
+        Win32::{
+            Foundation::{VARIANT_BOOL, VARIANT_FALSE, VARIANT_TRUE},
+            System::{
+                Com::SAFEARRAY, 
+                Ole::{
+                    SafeArrayCreateVector, SafeArrayGetElement,
+                    SafeArrayGetLBound, SafeArrayGetUBound,
+                    SafeArrayPutElement,
+                },
+                Variant::{
+                    VariantClear, VARIANT, VT_ARRAY, VT_BOOL,
+                    VT_BSTR, VT_DISPATCH, VT_I4, VT_I8, VT_INT, VT_UI4,
+                    VT_UI8, VT_UINT, VT_UNKNOWN, VT_VARIANT,
+                }
+            }
         }
     }
 };
 
 use {
-    super::_Type, 
-    crate::error::ClrError, 
+    super::{_ParameterInfo, _Type, Parameter, itype::await_task},
+    crate::{error::ClrError, element_count, wildcard_match, Instance, InvocationType, ManagedName, OwnedVariant, SafeArray, Variant, WinStr},
+    crate::instance::custom_attributes,
 };
 
 /// The `_MethodInfo` struct represents a COM interface for accessing method metadata
@@ -26,7 +39,6 @@ pub struct _MethodInfo(windows_core::IUnknown);
 /// Implementation of auxiliary methods for convenience.
 ///
 /// These methods provide Rust-friendly wrappers around the original `_MethodInfo` methods.
-/// @TODO: GetParameters
 impl _MethodInfo {
     /// Invokes the method represented by this `_MethodInfo` instance.
     ///
@@ -44,6 +56,388 @@ impl _MethodInfo {
         self.Invoke_3(variant_obj, parameters.unwrap_or(null_mut()))
     }
 
+    /// Invokes a method returning a `Task` or `Task<T>` and waits for it to complete.
+    ///
+    /// The calling thread is blocked until the task completes. The exception of a faulted
+    /// task is reported as is, not wrapped in an `AggregateException`.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - An optional `VARIANT` representing the target object for instance methods.
+    /// * `parameters` - An optional pointer to a `SAFEARRAY` containing the parameters for the method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The result of the task, or an empty `VARIANT` for a `Task` without result.
+    /// * `Err(ClrError)` - If the invocation fails, the method does not return a `Task` or the task faults.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let run = assembly.resolve_type("Tool.Program")?.method("RunAsync")?;
+    /// let exit_code = i32::from_variant(&run.invoke_async(None, None)?)?;
+    /// ```
+    pub fn invoke_async(&self, obj: Option<VARIANT>, parameters: Option<*mut SAFEARRAY>) -> Result<VARIANT, ClrError> {
+        let task = self.invoke(obj, parameters)?;
+        await_task(task, &self.GetType()?)
+    }
+
+    /// Invokes the method with a flat argument list, packing trailing arguments into the
+    /// `params` array if the last parameter is declared with `params`.
+    ///
+    /// `MethodBase.Invoke` expects the `params` array as a single argument. This method reads
+    /// the parameter metadata and builds the array from the trailing arguments, so
+    /// `Format(string, params object[])` can be called with `["{0}-{1}", a, b]`. Arguments that
+    /// already match the parameter count with an array in last position are passed unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - An optional `VARIANT` representing the target object for instance methods.
+    /// * `args` - The arguments of the method, with `params` arguments flattened.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - On successful invocation, returns the result as a `VARIANT`.
+    /// * `Err(ClrError)` - If the parameters cannot be inspected, the `params` element type is
+    ///   not supported, or the invocation fails.
+    pub fn invoke_params(&self, obj: Option<VARIANT>, args: Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        let parameters = self.parameter_objects()?;
+        let args = match parameters.last() {
+            Some(last) if args.len() + 1 >= parameters.len() => {
+                let fixed = parameters.len() - 1;
+                let already_packed = args.len() == parameters.len()
+                    && unsafe { args[fixed].Anonymous.Anonymous.vt } & VT_ARRAY != 0;
+
+                match self.params_element_type(last)? {
+                    Some(element) if !already_packed => {
+                        let mut packed = args[..fixed].to_vec();
+                        packed.push(pack_params(&args[fixed..], &element)?);
+                        packed
+                    }
+                    _ => args,
+                }
+            }
+            _ => args,
+        };
+
+        let args = if args.is_empty() { None } else { Some(SafeArray::copy_from(&args)?) };
+        self.invoke(obj, args.as_ref().map(SafeArray::as_ptr))
+    }
+
+    /// Invokes the method and reads the arguments back after the call, so the values
+    /// assigned to `ref` and `out` parameters can be retrieved.
+    ///
+    /// [`_MethodInfo::invoke`] hands the runtime a copy of the arguments, which is discarded
+    /// when the call returns. This method keeps the `object[]` inside the runtime instead:
+    /// it is passed by reference to `MethodBase.Invoke`, which writes the byref outputs into
+    /// it, and each element is read back afterwards. `out` arguments can be given any
+    /// placeholder value, such as an empty `VARIANT`.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - An optional `VARIANT` representing the target object for instance methods.
+    /// * `args` - The arguments of the method, including placeholders for `out` parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((VARIANT, Vec<VARIANT>))` - The result of the method and the arguments after the call.
+    /// * `Err(ClrError)` - If the argument array cannot be created, the invocation fails or the
+    ///   arguments cannot be read back.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let int32 = mscorlib.resolve_type("System.Int32")?;
+    /// let try_parse = int32.method_signature("Boolean TryParse(System.String, Int32 ByRef)")?;
+    ///
+    /// let empty = unsafe { std::mem::zeroed::<VARIANT>() };
+    /// let (result, args) = try_parse.invoke_ref(None, vec!["42".to_variant(), empty])?;
+    /// assert_eq!(i32::from_variant(&args[1])?, 42);
+    /// ```
+    pub fn invoke_ref(&self, obj: Option<VARIANT>, args: Vec<VARIANT>) -> Result<(VARIANT, Vec<VARIANT>), ClrError> {
+        let mscorlib = self.GetType()?.get_Assembly()?;
+        let marshal = mscorlib.resolve_type(&ManagedName::Marshal.value())?;
+        let method_base = mscorlib.resolve_type(&ManagedName::MethodBase.value())?;
+        let array = mscorlib.resolve_type(&ManagedName::Array.value())?;
+
+        // Converts the arguments into a managed `object[]` and takes a reference to it, so
+        // the same array reaches `MethodBase.Invoke` instead of a marshaled copy
+        let count = args.len();
+        let values = OwnedVariant(SafeArray::copy_from(&args)?.into_variant()?);
+
+        let pointer = marshal.invoke(
+            &ManagedName::GetIUnknownForObject.value(),
+            None,
+            Some(vec![values.0]),
+            InvocationType::Static
+        )?;
+
+        let pointer = unsafe {
+            match pointer.Anonymous.Anonymous.vt {
+                VT_I8 | VT_UI8 => pointer.Anonymous.Anonymous.Anonymous.llVal as usize,
+                VT_I4 | VT_UI4 | VT_INT | VT_UINT => pointer.Anonymous.Anonymous.Anonymous.lVal as u32 as usize,
+                _ => return Err(ClrError::VariantUnsupported),
+            }
+        };
+
+        // Owns the reference added by `GetIUnknownForObject`, which keeps the array alive
+        let unknown = unsafe { IUnknown::from_raw(pointer as *mut c_void) };
+        let mut values = unsafe { std::mem::zeroed::<VARIANT>() };
+        values.Anonymous.Anonymous.vt = VT_UNKNOWN;
+        values.Anonymous.Anonymous.Anonymous.punkVal = unknown.as_raw();
+
+        let obj = obj.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        let result = method_base.invoke(
+            &ManagedName::Invoke.value(),
+            Some(self.to_variant()),
+            Some(vec![obj, values]),
+            InvocationType::Instance
+        )?;
+
+        let args = (0..count as i32)
+            .map(|index| {
+                array.invoke(
+                    &ManagedName::GetValue.value(),
+                    Some(values),
+                    Some(vec![index.to_variant()]),
+                    InvocationType::Instance
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok((result, args))
+    }
+
+    /// Closes a generic method definition over concrete type arguments.
+    ///
+    /// The returned `_MethodInfo` is produced by `MethodInfo.MakeGenericMethod` and can be
+    /// invoked like any other method.
+    ///
+    /// # Arguments
+    ///
+    /// * `type_args` - The type arguments, in the order of the generic parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_MethodInfo)` - The constructed method.
+    /// * `Err(ClrError)` - If the method is not a generic method definition, the number of type
+    ///   arguments does not match, or a type argument violates a constraint.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let activator = mscorlib.resolve_type("System.Activator")?;
+    /// let string_builder = mscorlib.resolve_type("System.Text.StringBuilder")?;
+    ///
+    /// let create = activator.method_signature("T CreateInstance[T]()")?;
+    /// let builder = create.make_generic_method(&[string_builder])?.invoke(None, None)?;
+    /// ```
+    pub fn make_generic_method(&self, type_args: &[_Type]) -> Result<_MethodInfo, ClrError> {
+        let method_info = self.GetType()?
+            .get_Assembly()?
+            .resolve_type(&ManagedName::MethodInfo.value())?;
+
+        // `MakeGenericMethod` takes `params Type[]`, which the default binder packs from a flat list
+        let args = type_args.iter().map(|ty| (**ty).to_variant()).collect::<Vec<_>>();
+        let method = method_info.invoke(
+            &ManagedName::MakeGenericMethod.value(),
+            Some(self.to_variant()),
+            Some(args),
+            InvocationType::Instance
+        )?;
+
+        match unsafe { method.Anonymous.Anonymous.vt } {
+            VT_UNKNOWN | VT_DISPATCH => _MethodInfo::from_raw(unsafe { method.Anonymous.Anonymous.Anonymous.punkVal }),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+
+    /// Retrieves the return type of the method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - The declared return type, `System.Void` for methods returning nothing.
+    /// * `Err(ClrError)` - If the return type cannot be read.
+    pub fn return_type(&self) -> Result<_Type, ClrError> {
+        let return_type = self.GetType()?
+            .get_Assembly()?
+            .resolve_type(&ManagedName::MethodInfo.value())?
+            .invoke(
+                &ManagedName::GetReturnType.value(),
+                Some(self.to_variant()),
+                None,
+                InvocationType::Instance
+            )?;
+
+        match unsafe { return_type.Anonymous.Anonymous.vt } {
+            VT_UNKNOWN | VT_DISPATCH => _Type::from_raw(unsafe { return_type.Anonymous.Anonymous.Anonymous.punkVal }),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+
+    /// Returns the number of parameters declared by the method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - The arity of the method, counting optional and `params` parameters.
+    /// * `Err(ClrError)` - If the parameters cannot be retrieved.
+    pub fn arity(&self) -> Result<usize, ClrError> {
+        Ok(self.parameter_objects()?.len())
+    }
+
+    /// Retrieves the parameters of the method with their metadata.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Parameter>)` - The parameters, in declaration order.
+    /// * `Err(ClrError)` - If the parameters or their metadata cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let method = ty.method("Format")?;
+    /// for parameter in method.parameters()? {
+    ///     println!("{}: {}", parameter.name, parameter.parameter_type.ToString()?);
+    /// }
+    /// ```
+    pub fn parameters(&self) -> Result<Vec<Parameter>, ClrError> {
+        let parameter_info = self.GetType()?
+            .get_Assembly()?
+            .resolve_type(&ManagedName::ParameterInfo.value())?;
+
+        let get = |parameter: VARIANT, name: &str| {
+            parameter_info.invoke(name, Some(parameter), None, InvocationType::Instance)
+        };
+
+        self.parameter_objects()?
+            .into_iter()
+            .map(|parameter| {
+                let parameter_type = get(parameter, &ManagedName::GetParameterType.value())?;
+
+                // `HasDefaultValue` was added in .NET Framework 4.5
+                let has_default = match get(parameter, &ManagedName::GetHasDefaultValue.value()) {
+                    Ok(value) => value,
+                    Err(_) => get(parameter, &ManagedName::GetIsOptional.value())?,
+                };
+
+                let default_value = if unsafe { has_default.Anonymous.Anonymous.Anonymous.boolVal } != VARIANT_FALSE {
+                    Some(get(parameter, &ManagedName::GetDefaultValue.value())?)
+                } else {
+                    None
+                };
+
+                Ok(Parameter {
+                    name: unsafe { get(parameter, &ManagedName::GetName.value())?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(),
+                    position: unsafe { get(parameter, &ManagedName::GetPosition.value())?.Anonymous.Anonymous.Anonymous.lVal } as usize,
+                    parameter_type: _Type::from_raw(unsafe { parameter_type.Anonymous.Anonymous.Anonymous.punkVal })?,
+                    default_value,
+                    is_out: unsafe { get(parameter, &ManagedName::GetIsOut.value())?.Anonymous.Anonymous.Anonymous.boolVal } != VARIANT_FALSE,
+                    is_params: self.params_element_type(&parameter)?.is_some(),
+                    info: _ParameterInfo::from_raw(unsafe { parameter.Anonymous.Anonymous.Anonymous.punkVal })?,
+                })
+            })
+            .collect()
+    }
+
+    /// Retrieves the `ParameterInfo` objects of the method.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<VARIANT>)` - The parameters, as `VT_UNKNOWN` variants.
+    /// * `Err(ClrError)` - If the parameters cannot be retrieved.
+    fn parameter_objects(&self) -> Result<Vec<VARIANT>, ClrError> {
+        let sa_parameters = self.GetParameters()?;
+        if sa_parameters.is_null() {
+            return Err(ClrError::NullPointerError("GetParameters"));
+        }
+
+        let mut lbound = 0;
+        let mut ubound = -1;
+        let mut parameters = Vec::new();
+        unsafe {
+            SafeArrayGetLBound(sa_parameters, 1, &mut lbound);
+            SafeArrayGetUBound(sa_parameters, 1, &mut ubound);
+
+            for i in lbound..=ubound {
+                let mut p_parameter = null_mut::<c_void>();
+                let hr = SafeArrayGetElement(sa_parameters, &i, &mut p_parameter as *mut _ as *mut _);
+                if hr != 0 || p_parameter.is_null() {
+                    return Err(ClrError::ApiError("SafeArrayGetElement", hr));
+                }
+
+                parameters.push(IUnknown::from_raw(p_parameter).to_variant());
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    /// Returns the element type name of a `params` parameter.
+    ///
+    /// # Arguments
+    ///
+    /// * `parameter` - The `ParameterInfo` object to inspect.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` - The full name of the array element type, if the parameter has `ParamArrayAttribute`.
+    /// * `Ok(None)` - If the parameter is not a `params` array.
+    /// * `Err(ClrError)` - If the parameter metadata cannot be read.
+    fn params_element_type(&self, parameter: &VARIANT) -> Result<Option<String>, ClrError> {
+        let mscorlib = self.GetType()?.get_Assembly()?;
+        let parameter_info = mscorlib.resolve_type(&ManagedName::ParameterInfo.value())?;
+        let param_array = mscorlib.resolve_type(&ManagedName::ParamArrayAttribute.value())?;
+
+        let is_params = parameter_info.invoke(
+            &ManagedName::IsDefined.value(),
+            Some(*parameter),
+            Some(vec![(*param_array).to_variant(), false.to_variant()]),
+            InvocationType::Instance
+        )?;
+
+        if unsafe { is_params.Anonymous.Anonymous.Anonymous.boolVal } == VARIANT_FALSE {
+            return Ok(None);
+        }
+
+        let array_type = parameter_info.invoke(&ManagedName::GetParameterType.value(), Some(*parameter), None, InvocationType::Instance)?;
+        let element_type = mscorlib.resolve_type(&ManagedName::Type.value())?
+            .invoke(&ManagedName::GetElementType.value(), Some(array_type), None, InvocationType::Instance)?;
+
+        let element_type = _Type::from_raw(unsafe { element_type.Anonymous.Anonymous.Anonymous.punkVal })?;
+        Ok(Some(element_type.ToString()?))
+    }
+
+    /// Retrieves the custom attributes applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `inherit` - Whether attributes inherited from base types or overridden members are included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(String, Instance)>)` - The full name of each attribute type (e.g. `System.STAThreadAttribute`),
+    ///   with the attribute instance.
+    /// * `Err(ClrError)` - If the attributes cannot be retrieved or one of them fails to construct.
+    pub fn custom_attributes(&self, inherit: bool) -> Result<Vec<(String, Instance)>, ClrError> {
+        let attributes = self.GetCustomAttributes_2(if inherit { VARIANT_TRUE } else { VARIANT_FALSE })?;
+        custom_attributes(attributes, &self.GetType()?)
+    }
+
+    /// Indicates whether an attribute is applied to the method, without constructing it.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute_type` - The `_Type` of the attribute, e.g. `System.ObsoleteAttribute`.
+    /// * `inherit` - Whether attributes inherited from base types or overridden members are included.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - `true` if the attribute, or an attribute deriving from it, is applied.
+    /// * `Err(ClrError)` - If the check fails.
+    pub fn is_defined(&self, attribute_type: &_Type, inherit: bool) -> Result<bool, ClrError> {
+        self.IsDefined(attribute_type, if inherit { VARIANT_TRUE } else { VARIANT_FALSE })
+    }
+
     /// Creates an `_MethodInfo` instance from a raw COM interface pointer.
     ///
     /// # Arguments
@@ -205,6 +599,68 @@ impl _MethodInfo {
             Err(ClrError::ApiError("GetType", hr))
         }
     }
+    /// Retrieves the custom attributes of the given type applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributeType` - The `_Type` of the attributes to return.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a `SAFEARRAY` of `VARIANT` attribute instances.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetCustomAttributes(&self, attributeType: &_Type, inherit: VARIANT_BOOL) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetCustomAttributes)(Interface::as_raw(self), attributeType.as_raw(), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetCustomAttributes", hr))
+        }
+    }
+
+    /// Retrieves all the custom attributes applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(*mut SAFEARRAY)` - On success, returns a `SAFEARRAY` of `VARIANT` attribute instances.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn GetCustomAttributes_2(&self, inherit: VARIANT_BOOL) -> Result<*mut SAFEARRAY, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).GetCustomAttributes_2)(Interface::as_raw(self), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result)
+        } else {
+            Err(ClrError::ApiError("GetCustomAttributes_2", hr))
+        }
+    }
+
+    /// Indicates whether custom attributes of the given type are applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributeType` - The `_Type` of the attribute.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the attribute is applied.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn IsDefined(&self, attributeType: &_Type, inherit: VARIANT_BOOL) -> Result<bool, ClrError> {
+        let mut result = VARIANT_FALSE;
+        let hr = unsafe { (Interface::vtable(self).IsDefined)(Interface::as_raw(self), attributeType.as_raw(), inherit, &mut result) };
+        if hr == 0 {
+            Ok(result != VARIANT_FALSE)
+        } else {
+            Err(ClrError::ApiError("IsDefined", hr))
+        }
+    }
+
 }
 
 unsafe impl Interface for _MethodInfo {
@@ -215,7 +671,7 @@ unsafe impl Interface for _MethodInfo {
     /// This GUID is used to identify the `_MethodInfo` interface when calling 
     /// COM methods like `QueryInterface`. It is defined based on the standard 
     /// .NET CLR IID for the `_MethodInfo` interface.
-    const IID: GUID = GUID::from_u128(0xffcc1b5d_ecb8_38dd_9b01_3dc8abc2aa5f);
+    const IID: GUID = crate::com::IID__METHODINFO;
 }
 
 impl Deref for _MethodInfo {
@@ -231,6 +687,130 @@ impl Deref for _MethodInfo {
     }
 }
 
+/// Criteria for locating methods by pattern rather than by exact signature.
+///
+/// Patterns use `*` and `?` as in [`wildcard_match`], so prefixes (`Run*`) and substrings
+/// (`*Decrypt*`) are expressed as globs. Unset criteria match every method.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Any method taking a single `byte[]` and returning a `string`
+/// let query = MethodQuery::new()
+///     .with_return_type("System.String")
+///     .with_signature("*(Byte[])");
+///
+/// for method in ty.find_methods(&query)? {
+///     println!("{}", method.ToString()?);
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MethodQuery {
+    /// Pattern matched against the simple name of the method.
+    name: Option<String>,
+
+    /// Pattern matched against the signature, as formatted by `MethodInfo.ToString`.
+    signature: Option<String>,
+
+    /// Pattern matched against the full name of the return type.
+    return_type: Option<String>,
+
+    /// Exact number of parameters.
+    arity: Option<usize>,
+}
+
+impl MethodQuery {
+    /// Creates a query matching every method.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Matches the simple name of the method, e.g. `Run*`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern matched against the name.
+    pub fn with_name(mut self, pattern: &str) -> Self {
+        self.name = Some(pattern.to_string());
+        self
+    }
+
+    /// Matches the signature of the method, e.g. `Void *(System.String[])`.
+    ///
+    /// Primitive types are formatted with their short name (`Int32`, `Byte[]`) by
+    /// `MethodInfo.ToString`, other types with their full name.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern matched against the signature.
+    pub fn with_signature(mut self, pattern: &str) -> Self {
+        self.signature = Some(pattern.to_string());
+        self
+    }
+
+    /// Matches the full name of the return type, e.g. `System.Void` or `*Task*`.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern matched against the return type.
+    pub fn with_return_type(mut self, pattern: &str) -> Self {
+        self.return_type = Some(pattern.to_string());
+        self
+    }
+
+    /// Matches methods declaring exactly `arity` parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `arity` - The number of parameters.
+    pub fn with_arity(mut self, arity: usize) -> Self {
+        self.arity = Some(arity);
+        self
+    }
+
+    /// Checks whether a method satisfies every criterion of the query.
+    ///
+    /// The signature is checked first, so methods are only inspected through
+    /// reflection when the cheaper criteria match.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - The signature of the method, as formatted by `MethodInfo.ToString`.
+    /// * `method` - The `_MethodInfo` to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - Whether the method matches.
+    /// * `Err(ClrError)` - If the name, parameters or return type cannot be read.
+    pub(crate) fn matches(&self, signature: &str, method: &_MethodInfo) -> Result<bool, ClrError> {
+        if let Some(pattern) = &self.signature {
+            if !wildcard_match(pattern, signature) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(pattern) = &self.name {
+            if !wildcard_match(pattern, &method.get_name()?) {
+                return Ok(false);
+            }
+        }
+
+        if let Some(arity) = self.arity {
+            if method.arity()? != arity {
+                return Ok(false);
+            }
+        }
+
+        if let Some(pattern) = &self.return_type {
+            if !wildcard_match(pattern, &method.return_type()?.ToString()?) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
 #[repr(C)]
 pub struct _MethodInfo_Vtbl {
     /// Base vtable inherited from the `IUnknown` interface.
@@ -323,14 +903,60 @@ pub struct _MethodInfo_Vtbl {
     /// Placeholder for the method. Not used directly.
     get_ReflectedType: *const c_void,
 
-    /// Placeholder for the method. Not used directly.
-    GetCustomAttributes: *const c_void,
+    /// Retrieves the custom attributes of a given type applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `attributeType` - Pointer to the `_Type` of the attributes.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the `SAFEARRAY` of attributes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetCustomAttributes: unsafe extern "system" fn(
+        *mut c_void,
+        attributeType: *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    GetCustomAttributes_2: *const c_void,
+    /// Retrieves all the custom attributes applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the `SAFEARRAY` of attributes.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetCustomAttributes_2: unsafe extern "system" fn(
+        *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut *mut SAFEARRAY
+    ) -> HRESULT,
 
-    /// Placeholder for the method. Not used directly.
-    IsDefined: *const c_void,
+    /// Indicates whether custom attributes of a given type are applied to the method.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `attributeType` - Pointer to the `_Type` of the attribute.
+    /// * `inherit` - Whether the inheritance chain is searched.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    IsDefined: unsafe extern "system" fn(
+        *mut c_void,
+        attributeType: *mut c_void,
+        inherit: VARIANT_BOOL,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
 
     /// Retrieves the method parameters as a `SAFEARRAY`.
     ///
@@ -440,4 +1066,57 @@ pub struct _MethodInfo_Vtbl {
         *mut c_void,
         pRetVal: *mut *mut _MethodInfo
     ) -> HRESULT,
-}
\ No newline at end of file
+}
+
+/// Packs `params` arguments into an array `VARIANT` of the given element type.
+///
+/// # Arguments
+///
+/// * `args` - The trailing arguments to pack.
+/// * `element` - The full name of the array element type.
+///
+/// # Returns
+///
+/// * `Ok(VARIANT)` - A `VT_ARRAY` variant owning the packed arguments.
+/// * `Err(ClrError)` - If the element type is not supported or an argument has a different type.
+fn pack_params(args: &[VARIANT], element: &str) -> Result<VARIANT, ClrError> {
+    let vartype = [
+        (ManagedName::Object, VT_VARIANT),
+        (ManagedName::String, VT_BSTR),
+        (ManagedName::Int32, VT_I4),
+        (ManagedName::Boolean, VT_BOOL),
+    ]
+    .into_iter()
+    .find(|(name, _)| element == name.value())
+    .map(|(_, vartype)| vartype)
+    .ok_or(ClrError::VariantUnsupported)?;
+
+    unsafe {
+        let psa = SafeArrayCreateVector(vartype, 0, element_count(args.len())?);
+        if psa.is_null() {
+            return Err(ClrError::NullPointerError("SafeArrayCreateVector"));
+        }
+
+        // Owned from here, so the array is destroyed if an argument is refused
+        let array = SafeArray::from_raw(psa);
+        for (i, arg) in args.iter().enumerate() {
+            if vartype != VT_VARIANT && arg.Anonymous.Anonymous.vt != vartype {
+                return Err(ClrError::VariantUnsupported);
+            }
+
+            let value_ptr = match vartype {
+                VT_VARIANT => arg as *const VARIANT as *const c_void,
+                VT_BOOL => &arg.Anonymous.Anonymous.Anonymous.boolVal as *const _ as *const c_void,
+                VT_I4 => &arg.Anonymous.Anonymous.Anonymous.lVal as *const _ as *const c_void,
+                _ => arg.Anonymous.Anonymous.Anonymous.bstrVal as *const c_void,
+            };
+
+            let hr = SafeArrayPutElement(psa, &(i as i32), value_ptr);
+            if hr != 0 {
+                return Err(ClrError::ApiError("SafeArrayPutElement", hr));
+            }
+        }
+
+        array.into_variant()
+    }
+}
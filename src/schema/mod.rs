@@ -7,9 +7,18 @@ mod assembly;
 mod appdomain;
 mod iclrmetahost;
 mod iclrruntimeinfo;
+mod iclrprofiling;
+mod imetadatadispenser;
+mod imetadataimport;
 mod icorruntimehost;
 mod ienumunknown;
 mod methodinfo;
+mod fieldinfo;
+mod propertyinfo;
+mod eventinfo;
+mod constructorinfo;
+mod parameterinfo;
+mod module;
 mod itype;
 
 pub use itype::*;
@@ -18,5 +27,14 @@ pub use appdomain::*;
 pub use ienumunknown::*;
 pub use iclrmetahost::*;
 pub use iclrruntimeinfo::*;
+pub use iclrprofiling::*;
+pub use imetadatadispenser::*;
+pub use imetadataimport::*;
 pub use icorruntimehost::*;
-pub use methodinfo::*;
\ No newline at end of file
+pub use methodinfo::*;
+pub use fieldinfo::*;
+pub use propertyinfo::*;
+pub use eventinfo::*;
+pub use constructorinfo::*;
+pub use parameterinfo::*;
+pub use module::*;
@@ -0,0 +1,82 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref},
+};
+
+use crate::error::ClrError;
+
+/// The `_Module` struct represents the COM interface of a `System.Reflection.Module` object.
+/// This struct encapsulates a `windows_core::IUnknown` COM interface.
+///
+/// `_Module` only exposes the `IDispatch` methods, so the members of the module are reached
+/// through reflection. [`_Assembly::global_methods`](super::_Assembly::global_methods) lists
+/// the global methods declared by the modules of an assembly.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct _Module(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `_Module` methods.
+impl _Module {
+    /// Creates an `_Module` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Module)` - Wraps the given COM interface as `_Module`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_Module, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_Module>().map_err(|_| ClrError::CastingError("_Module"))
+    }
+}
+
+unsafe impl Interface for _Module {
+    type Vtable = _Module_Vtbl;
+
+    /// The interface identifier (IID) for the `_Module` COM interface.
+    ///
+    /// This GUID is used to identify the `_Module` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `_Module` interface.
+    const IID: GUID = crate::com::IID__MODULE;
+}
+
+impl Deref for _Module {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `_Module` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+#[repr(C)]
+pub struct _Module_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIDsOfNames: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke: *const c_void,
+}
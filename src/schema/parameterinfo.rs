@@ -0,0 +1,113 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref},
+    windows_sys::Win32::System::Variant::VARIANT,
+};
+
+use {
+    super::_Type,
+    crate::error::ClrError,
+};
+
+/// The `_ParameterInfo` struct represents the COM interface of a `System.Reflection.ParameterInfo`
+/// object. This struct encapsulates a `windows_core::IUnknown` COM interface.
+///
+/// `_ParameterInfo` only exposes the `IDispatch` methods, so the members of the parameter are
+/// read through reflection. [`_MethodInfo::parameters`](super::_MethodInfo::parameters) returns
+/// them already read as [`Parameter`] values.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct _ParameterInfo(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `_ParameterInfo` methods.
+impl _ParameterInfo {
+    /// Creates an `_ParameterInfo` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_ParameterInfo)` - Wraps the given COM interface as `_ParameterInfo`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_ParameterInfo, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_ParameterInfo>().map_err(|_| ClrError::CastingError("_ParameterInfo"))
+    }
+}
+
+/// A parameter of a method, as declared in its metadata.
+#[derive(Clone)]
+pub struct Parameter {
+    /// The name of the parameter.
+    pub name: String,
+
+    /// The zero-based position of the parameter in the signature.
+    pub position: usize,
+
+    /// The declared type of the parameter. `ref` and `out` parameters have a by-ref type (e.g. `Int32&`).
+    pub parameter_type: _Type,
+
+    /// The default value of an optional parameter, or `None` if the parameter is required.
+    ///
+    /// A default of `null` is reported as an empty `VARIANT`.
+    pub default_value: Option<VARIANT>,
+
+    /// Whether the parameter is declared with `out`.
+    pub is_out: bool,
+
+    /// Whether the parameter is a `params` array.
+    pub is_params: bool,
+
+    /// The underlying `ParameterInfo` object.
+    pub info: _ParameterInfo,
+}
+
+unsafe impl Interface for _ParameterInfo {
+    type Vtable = _ParameterInfo_Vtbl;
+
+    /// The interface identifier (IID) for the `_ParameterInfo` COM interface.
+    ///
+    /// This GUID is used to identify the `_ParameterInfo` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `_ParameterInfo` interface.
+    const IID: GUID = crate::com::IID__PARAMETERINFO;
+}
+
+impl Deref for _ParameterInfo {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `_ParameterInfo` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+#[repr(C)]
+pub struct _ParameterInfo_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIDsOfNames: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke: *const c_void,
+}
@@ -0,0 +1,477 @@
+use {
+    windows_core::{IUnknown, Interface, GUID},
+    std::{ffi::c_void, ops::Deref, ptr::{null, null_mut}},
+    windows_sys::{
+        core::{BSTR, HRESULT},
+        Win32::{
+            Foundation::{VARIANT_BOOL, VARIANT_FALSE},
+            System::{
+                Com::SAFEARRAY,
+                Variant::{VariantClear, VARIANT},
+            },
+        }
+    }
+};
+
+use {
+    super::_Type,
+    crate::{error::ClrError, SafeArray},
+};
+
+/// The `_PropertyInfo` struct represents a COM interface for accessing property metadata
+/// within the .NET environment, allowing properties of objects and types to be read and written.
+/// This struct encapsulates a `windows_core::IUnknown` COM interface.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct _PropertyInfo(windows_core::IUnknown);
+
+/// Implementation of auxiliary methods for convenience.
+///
+/// These methods provide Rust-friendly wrappers around the original `_PropertyInfo` methods.
+impl _PropertyInfo {
+    /// Reads the value of the property.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object whose property is read, or `None` for a static property.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value of the property.
+    /// * `Err(ClrError)` - If the property has no getter or the getter throws.
+    pub fn get_value(&self, obj: Option<VARIANT>) -> Result<VARIANT, ClrError> {
+        let obj = obj.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        self.GetValue(obj, null_mut())
+    }
+
+    /// Writes the value of the property.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object whose property is written, or `None` for a static property.
+    /// * `value` - The new value, which must be convertible to the property type.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the property was written.
+    /// * `Err(ClrError)` - If the property has no setter, the value has a different type or the setter throws.
+    pub fn set_value(&self, obj: Option<VARIANT>, value: VARIANT) -> Result<(), ClrError> {
+        let obj = obj.unwrap_or(unsafe { std::mem::zeroed::<VARIANT>() });
+        self.SetValue(obj, value, null_mut())
+    }
+
+    /// Reads the value of an indexed property (an indexer such as `Item`).
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object whose property is read.
+    /// * `index` - The index arguments, e.g. a position for `List<T>` or a key for `Dictionary<TKey, TValue>`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value at the index.
+    /// * `Err(ClrError)` - If the index does not match the indexer or the getter throws (e.g. a missing key).
+    pub fn get_indexed(&self, obj: VARIANT, index: Vec<VARIANT>) -> Result<VARIANT, ClrError> {
+        self.GetValue(obj, SafeArray::copy_from(&index)?.as_ptr())
+    }
+
+    /// Writes the value of an indexed property (an indexer such as `Item`).
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - The object whose property is written.
+    /// * `value` - The new value, which must be convertible to the property type.
+    /// * `index` - The index arguments, e.g. a position for `List<T>` or a key for `Dictionary<TKey, TValue>`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the value was written.
+    /// * `Err(ClrError)` - If the index does not match the indexer or the setter throws.
+    pub fn set_indexed(&self, obj: VARIANT, value: VARIANT, index: Vec<VARIANT>) -> Result<(), ClrError> {
+        self.SetValue(obj, value, SafeArray::copy_from(&index)?.as_ptr())
+    }
+
+    /// Creates an `_PropertyInfo` instance from a raw COM interface pointer.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - A raw pointer to an `IUnknown` COM interface.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_PropertyInfo)` - Wraps the given COM interface as `_PropertyInfo`.
+    /// * `Err(ClrError)` - If casting fails, returns a `ClrError`.
+    #[inline(always)]
+    pub fn from_raw(raw: *mut c_void) -> Result<_PropertyInfo, ClrError> {
+        let iunknown = unsafe { IUnknown::from_raw(raw) };
+        iunknown.cast::<_PropertyInfo>().map_err(|_| ClrError::CastingError("_PropertyInfo"))
+    }
+}
+
+/// Implementation of the original `_PropertyInfo` COM interface methods.
+///
+/// These methods are direct FFI bindings to the corresponding functions in the COM interface.
+impl _PropertyInfo {
+    /// Retrieves the string representation of the property (equivalent to `ToString` in .NET).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The string representation of the property, e.g. `Int32 Length`.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn ToString(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_ToString)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("ToString", hr))
+            }
+        }
+    }
+
+    /// Retrieves the name of the property.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The name of the property.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn get_name(&self) -> Result<String, ClrError> {
+        unsafe {
+            let mut result = null::<u16>();
+            let hr = (Interface::vtable(self).get_name)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                let mut len = 0;
+                while *result.add(len) != 0 {
+                    len += 1;
+                }
+
+                let slice = std::slice::from_raw_parts(result, len);
+                Ok(String::from_utf16_lossy(slice))
+            } else {
+                Err(ClrError::ApiError("get_name", hr))
+            }
+        }
+    }
+
+    /// Retrieves the type of the property.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(_Type)` - The `_Type` of the property value.
+    /// * `Err(ClrError)` - Returns an error if the retrieval fails.
+    pub fn get_PropertyType(&self) -> Result<_Type, ClrError> {
+        let mut result = null_mut();
+        let hr = unsafe { (Interface::vtable(self).get_PropertyType)(Interface::as_raw(self), &mut result) };
+        if hr == 0 {
+            _Type::from_raw(result as *mut c_void)
+        } else {
+            Err(ClrError::ApiError("get_PropertyType", hr))
+        }
+    }
+
+    /// Indicates whether the property can be read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the property has a getter.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_CanRead(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_CanRead)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_CanRead", hr))
+            }
+        }
+    }
+
+    /// Indicates whether the property can be written.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(bool)` - On success, returns `true` if the property has a setter.
+    /// * `Err(ClrError)` - On failure, returns a `ClrError`.
+    pub fn get_CanWrite(&self) -> Result<bool, ClrError> {
+        unsafe {
+            let mut result = VARIANT_FALSE;
+            let hr = (Interface::vtable(self).get_CanWrite)(Interface::as_raw(self), &mut result);
+            if hr == 0 {
+                Ok(result != VARIANT_FALSE)
+            } else {
+                Err(ClrError::ApiError("get_CanWrite", hr))
+            }
+        }
+    }
+
+    /// Reads the value of the property on the given object.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - A `VARIANT` representing the target instance, or empty for static properties.
+    /// * `index` - A `SAFEARRAY` of index values for indexed properties, or null.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - The value of the property.
+    /// * `Err(ClrError)` - Returns an error if the value cannot be read.
+    pub fn GetValue(&self, obj: VARIANT, index: *mut SAFEARRAY) -> Result<VARIANT, ClrError> {
+        unsafe {
+            let mut result = std::mem::zeroed();
+            let hr = (Interface::vtable(self).GetValue)(Interface::as_raw(self), obj, index, &mut result);
+            if hr == 0 {
+                Ok(result)
+            } else {
+                VariantClear(&mut result);
+                Err(ClrError::ApiError("GetValue", hr))
+            }
+        }
+    }
+
+    /// Writes the value of the property on the given object.
+    ///
+    /// # Arguments
+    ///
+    /// * `obj` - A `VARIANT` representing the target instance, or empty for static properties.
+    /// * `value` - The new value of the property.
+    /// * `index` - A `SAFEARRAY` of index values for indexed properties, or null.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the value was written.
+    /// * `Err(ClrError)` - Returns an error if the value cannot be written.
+    pub fn SetValue(&self, obj: VARIANT, value: VARIANT, index: *mut SAFEARRAY) -> Result<(), ClrError> {
+        let hr = unsafe { (Interface::vtable(self).SetValue)(Interface::as_raw(self), obj, value, index) };
+        if hr == 0 {
+            Ok(())
+        } else {
+            Err(ClrError::ApiError("SetValue", hr))
+        }
+    }
+}
+
+unsafe impl Interface for _PropertyInfo {
+    type Vtable = _PropertyInfo_Vtbl;
+
+    /// The interface identifier (IID) for the `_PropertyInfo` COM interface.
+    ///
+    /// This GUID is used to identify the `_PropertyInfo` interface when calling
+    /// COM methods like `QueryInterface`. It is defined based on the standard
+    /// .NET CLR IID for the `_PropertyInfo` interface.
+    const IID: GUID = crate::com::IID__PROPERTYINFO;
+}
+
+impl Deref for _PropertyInfo {
+    type Target = windows_core::IUnknown;
+
+    /// Provides a reference to the underlying `IUnknown` interface.
+    ///
+    /// This implementation allows `_PropertyInfo` to be used as an `IUnknown`
+    /// pointer, enabling access to basic COM methods like `AddRef`, `Release`,
+    /// and `QueryInterface`.
+    fn deref(&self) -> &Self::Target {
+        unsafe { core::mem::transmute(self) }
+    }
+}
+
+#[repr(C)]
+pub struct _PropertyInfo_Vtbl {
+    /// Base vtable inherited from the `IUnknown` interface.
+    ///
+    /// This field contains the basic methods for reference management,
+    /// like `AddRef`, `Release`, and `QueryInterface`.
+    pub base__: windows_core::IUnknown_Vtbl,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfoCount: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetTypeInfo: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIDsOfNames: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    Invoke: *const c_void,
+
+    /// Retrieves the string representation of the property.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object implementing the interface.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the string result.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_ToString: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    Equals: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetHashCode: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_MemberType: *const c_void,
+
+    /// Retrieves the name of the property as a `BSTR`.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to a `BSTR` that receives the property's name.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_name: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut BSTR
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    get_DeclaringType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_ReflectedType: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetCustomAttributes_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    IsDefined: *const c_void,
+
+    /// Retrieves the type of the property.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer to `_Type` that receives the property type.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_PropertyType: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut *mut _Type
+    ) -> HRESULT,
+
+    /// Reads the value of the property.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `obj` - A `VARIANT` representing the target instance (or empty for static properties).
+    /// * `index` - A `SAFEARRAY` of index values, or null for non-indexed properties.
+    /// * `pRetVal` - Pointer to a `VARIANT` that receives the value.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    GetValue: unsafe extern "system" fn(
+        *mut c_void,
+        obj: VARIANT,
+        index: *mut SAFEARRAY,
+        pRetVal: *mut VARIANT
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    GetValue_2: *const c_void,
+
+    /// Writes the value of the property.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `obj` - A `VARIANT` representing the target instance (or empty for static properties).
+    /// * `value` - A `VARIANT` holding the new value.
+    /// * `index` - A `SAFEARRAY` of index values, or null for non-indexed properties.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    SetValue: unsafe extern "system" fn(
+        *mut c_void,
+        obj: VARIANT,
+        value: VARIANT,
+        index: *mut SAFEARRAY
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    SetValue_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetAccessors: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetGetMethod: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetSetMethod: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetIndexParameters: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_Attributes: *const c_void,
+
+    /// Indicates whether the property can be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_CanRead: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
+
+    /// Indicates whether the property can be written.
+    ///
+    /// # Arguments
+    ///
+    /// * `*mut c_void` - Pointer to the COM object.
+    /// * `pRetVal` - Pointer that receives the result as a `VARIANT_BOOL`.
+    ///
+    /// # Returns
+    ///
+    /// * Returns an HRESULT indicating success or failure.
+    get_CanWrite: unsafe extern "system" fn(
+        *mut c_void,
+        pRetVal: *mut VARIANT_BOOL
+    ) -> HRESULT,
+
+    /// Placeholder for the method. Not used directly.
+    GetAccessors_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetGetMethod_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    GetSetMethod_2: *const c_void,
+
+    /// Placeholder for the method. Not used directly.
+    get_IsSpecialName: *const c_void,
+}
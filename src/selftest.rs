@@ -0,0 +1,146 @@
+use std::{fmt, sync::mpsc::channel};
+
+use crate::{RustClr, error::ClrError, events::ExecutionEvent};
+
+/// Assembly run by [`selftest`], compiled from `assets/selftest.cs` by the build script.
+static SELFTEST_ASSEMBLY: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/selftest.exe"));
+
+/// Arguments passed to the self-test assembly, including one with a space.
+const SELFTEST_ARGS: [&str; 2] = ["rust clr", "host"];
+
+/// Output expected from the self-test assembly.
+const SELFTEST_OUTPUT: &str = "selftest:rust clr,host";
+
+/// A single stage of the pipeline checked by [`selftest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestStep {
+    /// Name of the stage (e.g. `runtime start`).
+    pub name: &'static str,
+
+    /// Whether the stage completed as expected.
+    pub passed: bool,
+
+    /// Why the stage failed, if it did.
+    pub detail: Option<String>,
+}
+
+/// Result of [`selftest`], with one step per stage of the pipeline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// The stages checked, in execution order. Stages following a failure are reported as
+    /// not passed, with a `skipped` detail.
+    pub steps: Vec<SelfTestStep>,
+}
+
+impl SelfTestReport {
+    /// Returns `true` if every stage passed.
+    pub fn passed(&self) -> bool {
+        self.steps.iter().all(|step| step.passed)
+    }
+
+    /// Returns the first stage that failed, if any.
+    pub fn failure(&self) -> Option<&SelfTestStep> {
+        self.steps.iter().find(|step| !step.passed)
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    /// Formats the report with one line per stage.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for step in &self.steps {
+            let status = if step.passed { "ok" } else { "FAILED" };
+            match &step.detail {
+                Some(detail) => writeln!(f, "{}: {status} ({detail})", step.name)?,
+                None => writeln!(f, "{}: {status}", step.name)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Verifies that the host environment can run assemblies, end to end.
+///
+/// A tiny assembly embedded in the crate is run twice through [`RustClr`], with arguments,
+/// output redirection and [`RustClr::with_soft_unload`]: the first run covers the runtime
+/// start, the assembly load, the argument passing, the redirection and the unload of its
+/// domain, and the second one checks that the runtime can still run an assembly afterwards.
+///
+/// The assembly targets the .NET Framework 4.x runtime and is compiled when the crate is
+/// built on a machine with the .NET Framework. Builds without it (e.g. cross-compilation)
+/// report the first stage as failed.
+///
+/// # Returns
+///
+/// * A `SelfTestReport` with the outcome of each stage.
+///
+/// # Examples
+///
+/// ```ignore
+/// let report = rustclr::selftest();
+/// if !report.passed() {
+///     eprint!("{report}");
+/// }
+/// ```
+pub fn selftest() -> SelfTestReport {
+    let mut steps = Vec::new();
+    let (sender, receiver) = channel();
+
+    let mut step = |name: &'static str, result: Result<(), String>| {
+        let failed = steps.iter().any(|step: &SelfTestStep| !step.passed);
+        steps.push(match (failed, result) {
+            (true, _) => SelfTestStep { name, passed: false, detail: Some("skipped".to_string()) },
+            (false, Ok(())) => SelfTestStep { name, passed: true, detail: None },
+            (false, Err(detail)) => SelfTestStep { name, passed: false, detail: Some(detail) },
+        });
+    };
+
+    let clr = if SELFTEST_ASSEMBLY.is_empty() {
+        Err("the self-test assembly was not compiled into this build".to_string())
+    } else {
+        RustClr::new(SELFTEST_ASSEMBLY).map_err(|e| e.to_string())
+    };
+
+    let mut clr = match clr {
+        Ok(clr) => clr
+            .with_args(SELFTEST_ARGS.to_vec())
+            .with_output_redirection(true)
+            .with_soft_unload(true)
+            .with_event_sender(sender),
+        Err(error) => {
+            step("runtime start", Err(error));
+            return SelfTestReport { steps };
+        }
+    };
+
+    let output = clr.run();
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    let reached = |expected: fn(&ExecutionEvent) -> bool| {
+        if events.iter().any(expected) {
+            Ok(())
+        } else {
+            Err(match &output {
+                Err(error) => error.to_string(),
+                Ok(_) => "the stage was not reached".to_string(),
+            })
+        }
+    };
+
+    step("runtime start", reached(|e| matches!(e, ExecutionEvent::RuntimeStarted)));
+    step("assembly load", reached(|e| matches!(e, ExecutionEvent::AssemblyLoaded { .. })));
+    step("entry point", reached(|e| matches!(e, ExecutionEvent::EntryReturned)));
+    step("output redirection", reached(|e| matches!(e, ExecutionEvent::OutputCaptured { .. })));
+
+    let check_output = match &output {
+        Ok(output) if output.trim_end() == SELFTEST_OUTPUT => Ok(()),
+        Ok(output) => Err(format!("unexpected output {:?}", output.trim_end())),
+        Err(error) => Err(error.to_string()),
+    };
+    step("argument passing", check_output);
+
+    // The domain is unloaded at the end of a successful soft-unload run
+    step("domain unload", output.as_ref().map(|_| ()).map_err(ClrError::to_string));
+    step("runtime reuse", clr.run().map(|_| ()).map_err(|e| e.to_string()));
+
+    SelfTestReport { steps }
+}
@@ -0,0 +1,331 @@
+use {
+    std::{
+        ptr::null_mut,
+        sync::mpsc::channel,
+        thread::{self, JoinHandle},
+    },
+    windows_sys::Win32::System::Variant::{VariantClear, VARIANT},
+};
+
+use crate::{
+    ControlSequences, InvocationType, ManagedName, OwnedVariant, SafeArray, WinStr,
+    console::ConsoleGuard,
+    error::ClrError,
+    gate::ExecutionPermit,
+    schema::{_Assembly, _Type},
+};
+
+/// An entry point running on a background thread, started by
+/// [`RustClr::run_service`](crate::RustClr::run_service).
+///
+/// Payloads that never return on their own (listeners, implants) keep running until
+/// [`ServiceHandle::stop`] aborts their thread with `Thread.Abort`. The abort is raised when
+/// the thread runs or waits in managed code, so a payload blocked in a native call stops once
+/// the call returns. Dropping the handle stops the service as well.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut clr = RustClr::new(&buffer)?.with_output_redirection(true);
+/// let service = clr.run_service()?;
+///
+/// std::thread::sleep(Duration::from_secs(30));
+/// println!("{}", service.output_so_far()?);
+///
+/// let output = service.stop()?;
+/// ```
+pub struct ServiceHandle {
+    /// Thread invoking the entry point, taken once joined.
+    thread: Option<JoinHandle<Result<(), ClrError>>>,
+
+    /// The managed `System.Threading.Thread` of the entry point.
+    managed_thread: VARIANT,
+
+    /// `System.Threading.Thread`.
+    thread_type: _Type,
+
+    /// The console streams redirected for the service, if output redirection is enabled.
+    output: Option<ServiceOutput>,
+
+    /// Console provided to the service, released once it stops.
+    _console: ConsoleGuard,
+
+    /// Admission of the service by the execution limits, released once it stops.
+    _permit: ExecutionPermit,
+}
+
+/// The entry point handed to the service thread.
+struct Entry {
+    /// The loaded assembly.
+    assembly: _Assembly,
+
+    /// The arguments of `Main`, destroyed once the entry point returns.
+    parameters: Option<SafeArray>,
+
+    /// `System.Threading.Thread`, used to read the managed thread of the service.
+    thread_type: _Type,
+}
+
+/// A `VARIANT` moved to or from the service thread.
+struct SendVariant(VARIANT);
+
+// Managed objects are exposed through free-threaded COM callable wrappers, so they can be
+// used from the service thread. The argument array is only touched by that thread.
+unsafe impl Send for Entry {}
+unsafe impl Send for SendVariant {}
+
+impl ServiceHandle {
+    /// Invokes the entry point of an assembly on a new thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `mscorlib` - The `mscorlib` assembly of the domain.
+    /// * `assembly` - The assembly whose entry point is invoked.
+    /// * `parameters` - The arguments passed to `Main`, if any.
+    /// * `output` - The redirected console streams, if any.
+    /// * `console` - The console provided to the service.
+    /// * `permit` - The admission of the service by the execution limits.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ServiceHandle)` - Once the entry point is about to be invoked.
+    /// * `Err(ClrError)` - If the managed thread of the service cannot be read.
+    pub(crate) fn start(
+        mscorlib: &_Assembly,
+        assembly: _Assembly,
+        parameters: Option<SafeArray>,
+        output: Option<ServiceOutput>,
+        console: ConsoleGuard,
+        permit: ExecutionPermit
+    ) -> Result<Self, ClrError> {
+        let thread_type = mscorlib.resolve_type(&ManagedName::Thread.value())?;
+        let entry = Entry { assembly, parameters, thread_type: thread_type.clone() };
+
+        // The service thread reports its managed `Thread` before invoking the entry point
+        let (sender, receiver) = channel();
+        let thread = thread::spawn(move || {
+            let entry = entry;
+            let current = entry.thread_type.invoke(
+                &ManagedName::GetCurrentThread.value(),
+                None,
+                None,
+                InvocationType::Static
+            );
+
+            match current {
+                Ok(current) => {
+                    let _ = sender.send(Ok(SendVariant(current)));
+                    let parameters = entry.parameters.as_ref().map_or(null_mut(), SafeArray::as_ptr);
+                    entry.assembly.run(parameters).map(|_| ())
+                }
+                Err(error) => {
+                    let _ = sender.send(Err(error));
+                    Ok(())
+                }
+            }
+        });
+
+        let managed_thread = receiver
+            .recv()
+            .map_err(|_| ClrError::ErrorClr("The service thread exited before invoking the entry point"))??;
+
+        Ok(Self {
+            thread: Some(thread),
+            managed_thread: managed_thread.0,
+            thread_type,
+            output,
+            _console: console,
+            _permit: permit,
+        })
+    }
+
+    /// Returns `true` if the entry point has returned or failed on its own.
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+
+    /// Reads the output written by the service so far, without clearing it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The output, empty if output redirection is not enabled.
+    /// * `Err(ClrError)` - If the output cannot be read.
+    pub fn output_so_far(&self) -> Result<String, ClrError> {
+        self.output.as_ref().map_or_else(|| Ok(String::new()), ServiceOutput::capture)
+    }
+
+    /// Stops the service and restores the console streams.
+    ///
+    /// The entry point is aborted unless it already returned, and its thread is joined, so
+    /// the `finally` blocks of the payload have run when this method returns.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The complete output of the service, empty if output redirection is not enabled.
+    /// * `Err(ClrError)` - If the thread cannot be aborted, or the entry point failed on its own
+    ///   before being stopped.
+    pub fn stop(mut self) -> Result<String, ClrError> {
+        self.shutdown()
+    }
+
+    /// Aborts and joins the service thread, if not done yet, then captures the output.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The output of the service.
+    /// * `Err(ClrError)` - If the thread cannot be aborted or the entry point failed on its own.
+    fn shutdown(&mut self) -> Result<String, ClrError> {
+        let Some(thread) = self.thread.take() else {
+            return Ok(String::new());
+        };
+
+        let finished = thread.is_finished();
+        if !finished {
+            let aborted = self.thread_type.invoke(
+                &ManagedName::Abort.value(),
+                Some(self.managed_thread),
+                None,
+                InvocationType::Instance
+            );
+
+            if let Err(error) = aborted {
+                self.thread = Some(thread);
+                return Err(error);
+            }
+        }
+
+        let entry = thread
+            .join()
+            .unwrap_or(Err(ClrError::ErrorClr("The service thread panicked")));
+
+        // The streams are restored even if the output cannot be read
+        let output = match self.output.as_mut() {
+            Some(output) => {
+                let text = output.capture();
+                output.restore()?;
+                text?
+            }
+            None => String::new(),
+        };
+
+        // An aborted entry point always fails, so only the errors of a service that ended
+        // on its own are reported
+        if finished {
+            entry?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// Implements the `Drop` trait to stop the service when `ServiceHandle` goes out of scope.
+impl Drop for ServiceHandle {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+        unsafe { VariantClear(&mut self.managed_thread) };
+    }
+}
+
+/// Console streams of the domain redirected to a `StringWriter` for a service.
+///
+/// Unlike `ClrOutput`, it owns the types it needs, so it can outlive the call that started
+/// the service.
+pub(crate) struct ServiceOutput {
+    /// `System.Console`.
+    console: _Type,
+
+    /// `System.IO.StringWriter`.
+    string_writer: _Type,
+
+    /// The `StringWriter` receiving the output.
+    writer: OwnedVariant,
+
+    /// Original standard output stream, taken once restored.
+    out: Option<OwnedVariant>,
+
+    /// Original standard error stream, taken once restored.
+    error: Option<OwnedVariant>,
+
+    /// How control sequences in the captured output are handled.
+    control_sequences: ControlSequences,
+}
+
+impl ServiceOutput {
+    /// Redirects the standard output and error streams of the domain to a new `StringWriter`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mscorlib` - The `mscorlib` assembly of the domain.
+    /// * `control_sequences` - How control sequences in the captured output are handled.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ServiceOutput)` - The redirected streams.
+    /// * `Err(ClrError)` - If the streams cannot be redirected.
+    pub(crate) fn redirect(mscorlib: &_Assembly, control_sequences: ControlSequences) -> Result<Self, ClrError> {
+        let console = mscorlib.resolve_type(&ManagedName::Console.value())?;
+        let string_writer = mscorlib.resolve_type(&ManagedName::StringWriter.value())?;
+        let writer = OwnedVariant(mscorlib.create_instance(&ManagedName::StringWriter.value())?);
+
+        let out = OwnedVariant(console.invoke(&ManagedName::GetOut.value(), None, None, InvocationType::Static)?);
+        let error = OwnedVariant(console.invoke(&ManagedName::GetError.value(), None, None, InvocationType::Static)?);
+        let output = Self {
+            console,
+            string_writer,
+            writer,
+            out: Some(out),
+            error: Some(error),
+            control_sequences,
+        };
+
+        // Once built, a failed redirection is undone when `output` is dropped
+        output.console.invoke(&ManagedName::SetOut.value(), None, Some(vec![output.writer.0]), InvocationType::Static)?;
+        output.console.invoke(&ManagedName::SetError.value(), None, Some(vec![output.writer.0]), InvocationType::Static)?;
+
+        Ok(output)
+    }
+
+    /// Reads the content of the `StringWriter`.
+    ///
+    /// `Console.SetOut` synchronizes the writes of the service, but not this read, so the
+    /// text written while it runs may be missing from the result.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The output written so far.
+    /// * `Err(ClrError)` - If `StringWriter.ToString` fails.
+    fn capture(&self) -> Result<String, ClrError> {
+        let result = OwnedVariant(self.string_writer
+            .method(&ManagedName::ToString.value())?
+            .invoke(Some(self.writer.0), None)?);
+
+        let bstr = unsafe { result.0.Anonymous.Anonymous.Anonymous.bstrVal };
+        Ok(self.control_sequences.apply(bstr.to_string()))
+    }
+
+    /// Restores the original standard output and error streams.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the streams are restored or were already restored.
+    /// * `Err(ClrError)` - If `Console.SetOut` or `Console.SetError` fails.
+    fn restore(&mut self) -> Result<(), ClrError> {
+        if let Some(out) = self.out.take() {
+            self.console.invoke(&ManagedName::SetOut.value(), None, Some(vec![out.0]), InvocationType::Static)?;
+        }
+
+        if let Some(error) = self.error.take() {
+            self.console.invoke(&ManagedName::SetError.value(), None, Some(vec![error.0]), InvocationType::Static)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Implements the `Drop` trait to restore the console streams, if not done yet, before the
+/// `StringWriter` and the original streams are released.
+impl Drop for ServiceOutput {
+    fn drop(&mut self) {
+        let _ = self.restore();
+    }
+}
@@ -0,0 +1,249 @@
+use {
+    std::{
+        fs::{self, File, OpenOptions},
+        io::{self, BufReader, BufWriter, Read, Write},
+        path::{Path, PathBuf},
+        process,
+        sync::atomic::{AtomicU32, Ordering},
+        time::{SystemTime, UNIX_EPOCH},
+    },
+    windows_sys::Win32::Security::Cryptography::{
+        CryptProtectMemory, CryptUnprotectMemory,
+        CRYPTPROTECTMEMORY_BLOCK_SIZE, CRYPTPROTECTMEMORY_SAME_PROCESS,
+    },
+};
+
+use crate::error::ClrError;
+
+/// Counter used to generate unique spill file names within the process.
+static SPILL_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+/// Output captured from a .NET assembly.
+///
+/// Small outputs are kept in memory, while outputs larger than the configured threshold
+/// are written to an encrypted temporary file and exposed through a [`SpillFile`] reader.
+#[derive(Debug)]
+pub enum CapturedOutput {
+    /// The output held in memory.
+    Memory(String),
+
+    /// The output spilled to an encrypted temporary file.
+    Spilled(SpillFile),
+}
+
+impl CapturedOutput {
+    /// Reads the whole output into a `String`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The captured output.
+    /// * `Err(ClrError)` - If the spill file could not be read.
+    pub fn into_string(self) -> Result<String, ClrError> {
+        match self {
+            CapturedOutput::Memory(output) => Ok(output),
+            CapturedOutput::Spilled(mut file) => {
+                let mut output = String::new();
+                file.read_to_string(&mut output).map_err(|e| ClrError::IoError(format!("{e}")))?;
+                Ok(output)
+            }
+        }
+    }
+}
+
+/// A temporary file holding captured output encrypted with a key bound to the current process.
+///
+/// The text is stored as UTF-8 in chunks encrypted with `CryptProtectMemory`
+/// (`CRYPTPROTECTMEMORY_SAME_PROCESS`), so the file can only be decrypted by this process.
+/// Reading the `SpillFile` yields the decrypted UTF-8 bytes. The file is deleted on drop.
+#[derive(Debug)]
+pub struct SpillFile {
+    /// Path to the temporary file.
+    path: PathBuf,
+
+    /// Reader over the encrypted file.
+    reader: Option<BufReader<File>>,
+
+    /// Decrypted bytes of the current chunk.
+    chunk: Vec<u8>,
+
+    /// Read position inside the current chunk.
+    position: usize,
+
+    /// Total number of plaintext bytes written to the file.
+    len: u64,
+}
+
+impl SpillFile {
+    /// Returns the path of the temporary file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the size of the decrypted output in bytes.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if no output was written.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reads and decrypts the next chunk from the file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If a chunk was read.
+    /// * `Ok(false)` - If the end of the file was reached.
+    /// * `Err(io::Error)` - If the file could not be read or decrypted.
+    fn next_chunk(&mut self) -> io::Result<bool> {
+        if self.reader.is_none() {
+            self.reader = Some(BufReader::new(File::open(&self.path)?));
+        }
+
+        let reader = self.reader.as_mut().ok_or(io::ErrorKind::NotFound)?;
+        let mut header = [0u8; 4];
+        match reader.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(false),
+            Err(e) => return Err(e),
+        }
+
+        let len = u32::from_le_bytes(header) as usize;
+        let mut chunk = vec![0u8; padded_len(len)];
+        reader.read_exact(&mut chunk)?;
+
+        if unsafe { CryptUnprotectMemory(chunk.as_mut_ptr().cast(), chunk.len() as u32, CRYPTPROTECTMEMORY_SAME_PROCESS) } == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        chunk.truncate(len);
+        self.chunk = chunk;
+        self.position = 0;
+
+        Ok(true)
+    }
+}
+
+impl Read for SpillFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.position >= self.chunk.len() {
+            if !self.next_chunk()? {
+                return Ok(0);
+            }
+        }
+
+        let available = &self.chunk[self.position..];
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.position += count;
+
+        Ok(count)
+    }
+}
+
+/// Implements the `Drop` trait to delete the temporary file when `SpillFile` goes out of scope.
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        self.reader.take();
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Writes encrypted chunks of text to a new [`SpillFile`].
+pub(crate) struct SpillWriter {
+    /// Path to the temporary file.
+    path: PathBuf,
+
+    /// Buffered writer over the temporary file.
+    writer: BufWriter<File>,
+
+    /// Total number of plaintext bytes written.
+    len: u64,
+}
+
+impl SpillWriter {
+    /// Creates a new temporary file in the system temporary directory.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpillWriter)` - The writer for the new file.
+    /// * `Err(ClrError)` - If the file could not be created.
+    pub(crate) fn new() -> Result<Self, ClrError> {
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.subsec_nanos()).unwrap_or_default();
+        let name = format!(
+            "{:x}{:x}{:x}.tmp",
+            process::id(),
+            SPILL_COUNTER.fetch_add(1, Ordering::Relaxed),
+            nanos
+        );
+
+        let path = std::env::temp_dir().join(name);
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .map_err(|e| ClrError::IoError(format!("{e}")))?;
+
+        Ok(Self { path, writer: BufWriter::new(file), len: 0 })
+    }
+
+    /// Encrypts and appends a chunk of text to the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to append.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the chunk was written.
+    /// * `Err(ClrError)` - If the chunk could not be encrypted or written.
+    pub(crate) fn write(&mut self, text: &str) -> Result<(), ClrError> {
+        let mut chunk = text.as_bytes().to_vec();
+        chunk.resize(padded_len(text.len()), 0);
+
+        if unsafe { CryptProtectMemory(chunk.as_mut_ptr().cast(), chunk.len() as u32, CRYPTPROTECTMEMORY_SAME_PROCESS) } == 0 {
+            return Err(ClrError::IoError(format!("{}", io::Error::last_os_error())));
+        }
+
+        self.writer.write_all(&(text.len() as u32).to_le_bytes())
+            .and_then(|_| self.writer.write_all(&chunk))
+            .map_err(|e| ClrError::IoError(format!("{e}")))?;
+
+        self.len += text.len() as u64;
+        Ok(())
+    }
+
+    /// Flushes the file and returns a [`SpillFile`] reader over it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SpillFile)` - The reader for the spilled output.
+    /// * `Err(ClrError)` - If the file could not be flushed.
+    pub(crate) fn finish(mut self) -> Result<SpillFile, ClrError> {
+        self.writer.flush().map_err(|e| ClrError::IoError(format!("{e}")))?;
+
+        Ok(SpillFile {
+            path: std::mem::take(&mut self.path),
+            reader: None,
+            chunk: Vec::new(),
+            position: 0,
+            len: self.len,
+        })
+    }
+}
+
+/// Implements the `Drop` trait to delete the temporary file if the writer is not finished.
+impl Drop for SpillWriter {
+    fn drop(&mut self) {
+        if !self.path.as_os_str().is_empty() {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Rounds a length up to the block size required by `CryptProtectMemory`.
+fn padded_len(len: usize) -> usize {
+    let block = CRYPTPROTECTMEMORY_BLOCK_SIZE as usize;
+    len.div_ceil(block).max(1) * block
+}
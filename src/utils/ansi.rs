@@ -0,0 +1,164 @@
+/// Specifies how console control sequences in captured output are handled.
+///
+/// Assemblies that emit ANSI escape sequences (colors, cursor movement, progress bars)
+/// produce captured text that is hard to parse or display outside a terminal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ControlSequences {
+    /// Keeps the output exactly as written by the assembly.
+    #[default]
+    Preserve,
+
+    /// Removes ANSI escape sequences (CSI, OSC and two-character escapes).
+    Strip,
+
+    /// Removes ANSI escape sequences and other control characters, converting
+    /// `\r\n` and lone `\r` into `\n`. Tabs and line feeds are kept.
+    Normalize,
+}
+
+impl ControlSequences {
+    /// Applies the selected handling to a captured string.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The captured output.
+    ///
+    /// # Returns
+    ///
+    /// * The processed output.
+    pub(crate) fn apply(self, text: String) -> String {
+        match self {
+            ControlSequences::Preserve => text,
+            _ => ControlSequenceFilter::new(self).push(&text),
+        }
+    }
+}
+
+/// Position of the filter within an escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FilterState {
+    /// Outside of any escape sequence.
+    Text,
+
+    /// After an `ESC`, waiting for the character selecting the sequence.
+    Escape,
+
+    /// Inside a CSI sequence, waiting for its final byte (0x40..=0x7E).
+    Csi,
+
+    /// Inside an OSC sequence, waiting for `BEL` or `ESC \`.
+    Osc,
+
+    /// After an `ESC` inside an OSC sequence.
+    OscEscape,
+}
+
+/// Applies [`ControlSequences`] to output processed in several pieces.
+///
+/// The state of a sequence, or of a `\r\n` pair, split between two pieces is kept from one
+/// call to the next, so the result is the same as processing the whole output at once.
+#[derive(Debug, Clone)]
+pub(crate) struct ControlSequenceFilter {
+    /// The handling applied to the output.
+    mode: ControlSequences,
+
+    /// The position within an escape sequence.
+    state: FilterState,
+
+    /// Whether a `\r` was just converted, so a following `\n` is dropped.
+    after_cr: bool,
+}
+
+impl ControlSequenceFilter {
+    /// Creates a filter for the given handling.
+    pub(crate) fn new(mode: ControlSequences) -> Self {
+        Self { mode, state: FilterState::Text, after_cr: false }
+    }
+
+    /// Processes the next piece of the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The piece, following the ones already processed.
+    ///
+    /// # Returns
+    ///
+    /// * The processed piece.
+    pub(crate) fn push(&mut self, text: &str) -> String {
+        if self.mode == ControlSequences::Preserve {
+            return text.to_string();
+        }
+
+        text.chars().filter_map(|c| self.next(c)).collect()
+    }
+
+    /// Processes the next character of the output.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The character.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(char)` - The character to keep, possibly converted.
+    /// * `None` - If the character is removed.
+    pub(crate) fn next(&mut self, c: char) -> Option<char> {
+        let normalize = match self.mode {
+            ControlSequences::Preserve => return Some(c),
+            ControlSequences::Strip => false,
+            ControlSequences::Normalize => true,
+        };
+
+        let after_cr = std::mem::take(&mut self.after_cr);
+        match self.state {
+            FilterState::Text => {}
+            FilterState::Escape => {
+                self.state = match c {
+                    '[' => FilterState::Csi,
+                    ']' => FilterState::Osc,
+
+                    // Two-character escape sequences
+                    _ => FilterState::Text,
+                };
+
+                return None;
+            }
+            FilterState::Csi => {
+                if ('\x40'..='\x7e').contains(&c) {
+                    self.state = FilterState::Text;
+                }
+
+                return None;
+            }
+            FilterState::Osc | FilterState::OscEscape => {
+                self.state = match c {
+                    '\\' if self.state == FilterState::OscEscape => FilterState::Text,
+                    '\x07' => FilterState::Text,
+                    '\x1b' => FilterState::OscEscape,
+                    _ => FilterState::Osc,
+                };
+
+                return None;
+            }
+        }
+
+        match c {
+            '\x1b' => self.state = FilterState::Escape,
+
+            // 8-bit CSI
+            '\u{9b}' => self.state = FilterState::Csi,
+
+            '\r' if normalize => {
+                self.after_cr = true;
+                return Some('\n');
+            }
+
+            '\n' if after_cr => {}
+            '\n' | '\t' => return Some(c),
+            c if normalize && c.is_control() => {}
+            c => return Some(c),
+        }
+
+        None
+    }
+}
@@ -0,0 +1,260 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use windows_sys::Win32::System::Variant::{VARIANT, VT_DATE};
+
+use crate::{FromVariant, Variant, error::ClrError};
+
+/// Milliseconds in a day.
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Days between the OLE Automation epoch (1899-12-30) and the Unix epoch (1970-01-01).
+const UNIX_EPOCH_DAYS: i64 = 25_569;
+
+/// Earliest OLE Automation date accepted by `System.DateTime`, 0100-01-01.
+const MIN_OLE_DATE: f64 = -657_435.0;
+
+/// Latest OLE Automation date accepted by `System.DateTime`, 9999-12-31 23:59:59.999.
+const MAX_OLE_DATE: f64 = 2_958_465.999_999_99;
+
+/// A date and time marshaled as a `VT_DATE` `VARIANT`, the COM form of `System.DateTime`.
+///
+/// `VT_DATE` stores an OLE Automation date: the number of days since 1899-12-30, with the
+/// time of day as the fraction, and millisecond precision. It carries no time zone, like a
+/// `DateTime` of kind `Unspecified`; the conversions to and from `SystemTime` and Unix
+/// timestamps treat it as UTC.
+///
+/// `SystemTime` (and `chrono` types with the `chrono` feature) implement [`Variant`] and
+/// [`FromVariant`] through `ClrDate`, so they can be passed to and read from managed calls
+/// directly.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::time::SystemTime;
+///
+/// let date_time = mscorlib.resolve_type("System.DateTime")?;
+/// let now = SystemTime::from_variant(&date_time.invoke("get_UtcNow", None, None, InvocationType::Static)?)?;
+///
+/// let convert = mscorlib.resolve_type("System.Convert")?;
+/// let text = convert.invoke("ToString", None, Some(vec![ClrDate::from_unix(1_700_000_000).to_variant()]), InvocationType::Static)?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ClrDate(f64);
+
+impl ClrDate {
+    /// Wraps an OLE Automation date.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The number of days since 1899-12-30, with the time of day as the fraction.
+    ///
+    /// # Returns
+    ///
+    /// * A new `ClrDate`.
+    pub fn from_ole(value: f64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the OLE Automation date.
+    pub fn ole(&self) -> f64 {
+        self.0
+    }
+
+    /// Creates a date from a number of milliseconds since the Unix epoch.
+    ///
+    /// Values near the bounds of `i64` saturate instead of overflowing. They are far outside
+    /// the range of `System.DateTime`, which rejects them when the date is converted.
+    ///
+    /// # Arguments
+    ///
+    /// * `millis` - The milliseconds since 1970-01-01 00:00:00 UTC, negative before it.
+    ///
+    /// # Returns
+    ///
+    /// * A new `ClrDate`.
+    pub fn from_unix_millis(millis: i64) -> Self {
+        let mut millis = millis.saturating_add(UNIX_EPOCH_DAYS * MILLIS_PER_DAY);
+
+        // Before 1899-12-30 the integer part counts days backwards but the fraction forwards,
+        // as `DateTime.ToOADate` does
+        if millis < 0 {
+            let fraction = millis % MILLIS_PER_DAY;
+            if fraction != 0 {
+                millis = millis.saturating_sub((MILLIS_PER_DAY + fraction) * 2);
+            }
+        }
+
+        Self(millis as f64 / MILLIS_PER_DAY as f64)
+    }
+
+    /// Creates a date from a number of seconds since the Unix epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - The seconds since 1970-01-01 00:00:00 UTC, negative before it.
+    ///
+    /// # Returns
+    ///
+    /// * A new `ClrDate`.
+    pub fn from_unix(seconds: i64) -> Self {
+        Self::from_unix_millis(seconds.saturating_mul(1000))
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch, as `DateTime.FromOADate` rounds it.
+    pub fn unix_millis(&self) -> i64 {
+        let mut millis = (self.0 * MILLIS_PER_DAY as f64 + if self.0 >= 0.0 { 0.5 } else { -0.5 }) as i64;
+        if millis < 0 {
+            millis -= (millis % MILLIS_PER_DAY) * 2;
+        }
+
+        millis.saturating_sub(UNIX_EPOCH_DAYS * MILLIS_PER_DAY)
+    }
+
+    /// Returns the number of whole seconds since the Unix epoch.
+    pub fn unix(&self) -> i64 {
+        self.unix_millis().div_euclid(1000)
+    }
+
+    /// Converts the date to a `SystemTime`.
+    ///
+    /// Dates outside the range of `System.DateTime` (0100-01-01 to 9999-12-31) are clamped
+    /// to it, so the conversion never overflows.
+    pub fn to_system_time(&self) -> SystemTime {
+        let millis = Self::from_ole(self.0.clamp(MIN_OLE_DATE, MAX_OLE_DATE)).unix_millis();
+        let duration = Duration::from_millis(millis.unsigned_abs());
+        let time = if millis >= 0 {
+            UNIX_EPOCH.checked_add(duration)
+        } else {
+            UNIX_EPOCH.checked_sub(duration)
+        };
+
+        // Only reached on platforms whose `SystemTime` cannot hold the clamped range
+        time.unwrap_or(UNIX_EPOCH)
+    }
+}
+
+impl From<SystemTime> for ClrDate {
+    /// Converts a `SystemTime`, truncated to the millisecond.
+    fn from(time: SystemTime) -> Self {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_millis() as i64,
+            Err(before) => -(before.duration().as_millis() as i64),
+        };
+
+        Self::from_unix_millis(millis)
+    }
+}
+
+impl From<ClrDate> for SystemTime {
+    /// Converts the date, read as UTC.
+    fn from(date: ClrDate) -> Self {
+        date.to_system_time()
+    }
+}
+
+impl Variant for ClrDate {
+    /// Converts the date to a `VT_DATE` `VARIANT`.
+    fn to_variant(&self) -> VARIANT {
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        variant.Anonymous.Anonymous.vt = Self::var_type();
+        variant.Anonymous.Anonymous.Anonymous.date = self.0;
+
+        variant
+    }
+
+    /// Returns the VARIANT type ID for dates.
+    fn var_type() -> u16 {
+        VT_DATE
+    }
+}
+
+impl FromVariant for ClrDate {
+    /// Reads a `VT_DATE` `VARIANT`, such as a `System.DateTime` returned by a managed call.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        match unsafe { variant.Anonymous.Anonymous.vt } {
+            VT_DATE => Ok(Self(unsafe { variant.Anonymous.Anonymous.Anonymous.date })),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+}
+
+impl Variant for SystemTime {
+    /// Converts the time to a `VT_DATE` `VARIANT`, in UTC.
+    fn to_variant(&self) -> VARIANT {
+        ClrDate::from(*self).to_variant()
+    }
+
+    /// Returns the VARIANT type ID for dates.
+    fn var_type() -> u16 {
+        VT_DATE
+    }
+}
+
+impl FromVariant for SystemTime {
+    /// Reads a `VT_DATE` `VARIANT` as UTC.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        ClrDate::from_variant(variant).map(SystemTime::from)
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use chrono::{DateTime, NaiveDateTime, Utc};
+    use windows_sys::Win32::System::Variant::{VARIANT, VT_DATE};
+
+    use super::ClrDate;
+    use crate::{FromVariant, Variant, error::ClrError};
+
+    impl From<NaiveDateTime> for ClrDate {
+        /// Converts a date and time without time zone, truncated to the millisecond.
+        fn from(time: NaiveDateTime) -> Self {
+            ClrDate::from_unix_millis(time.and_utc().timestamp_millis())
+        }
+    }
+
+    impl From<DateTime<Utc>> for ClrDate {
+        /// Converts a UTC date and time, truncated to the millisecond.
+        fn from(time: DateTime<Utc>) -> Self {
+            ClrDate::from_unix_millis(time.timestamp_millis())
+        }
+    }
+
+    impl Variant for NaiveDateTime {
+        /// Converts the date and time to a `VT_DATE` `VARIANT`.
+        fn to_variant(&self) -> VARIANT {
+            ClrDate::from(*self).to_variant()
+        }
+
+        /// Returns the VARIANT type ID for dates.
+        fn var_type() -> u16 {
+            VT_DATE
+        }
+    }
+
+    impl FromVariant for NaiveDateTime {
+        /// Reads a `VT_DATE` `VARIANT`.
+        fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+            DateTime::<Utc>::from_variant(variant).map(|time| time.naive_utc())
+        }
+    }
+
+    impl Variant for DateTime<Utc> {
+        /// Converts the date and time to a `VT_DATE` `VARIANT`.
+        fn to_variant(&self) -> VARIANT {
+            ClrDate::from(*self).to_variant()
+        }
+
+        /// Returns the VARIANT type ID for dates.
+        fn var_type() -> u16 {
+            VT_DATE
+        }
+    }
+
+    impl FromVariant for DateTime<Utc> {
+        /// Reads a `VT_DATE` `VARIANT` as UTC.
+        fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+            let date = ClrDate::from_variant(variant)?;
+            DateTime::from_timestamp_millis(date.unix_millis()).ok_or(ClrError::VariantUnsupported)
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::{fmt, str::FromStr};
+
+use windows_sys::Win32::System::Variant::{VARIANT, VT_DECIMAL};
+
+use crate::{FromVariant, Variant, error::ClrError};
+
+/// Largest mantissa of a `System.Decimal`, `2^96 - 1`.
+const MAX_MANTISSA: u128 = (1 << 96) - 1;
+
+/// Largest scale of a `System.Decimal`.
+const MAX_SCALE: u8 = 28;
+
+/// Sign bit of the `sign` field of a `DECIMAL`.
+const DECIMAL_NEG: u8 = 0x80;
+
+/// A `System.Decimal` marshaled as a `VT_DECIMAL` `VARIANT`.
+///
+/// A decimal is a 96-bit integer mantissa divided by a power of ten between `10^0` and
+/// `10^28`, so values such as `0.1` or monetary amounts cross the COM boundary exactly,
+/// unlike `f64`. The scale is kept as given, so `1.50` stays distinct from `1.5` in its
+/// representation, as in .NET.
+///
+/// # Examples
+///
+/// ```ignore
+/// let price: ClrDecimal = "19.99".parse()?;
+/// let decimal = mscorlib.resolve_type("System.Decimal")?;
+///
+/// let total = decimal.invoke("Multiply", None, Some((price, ClrDecimal::from(3)).to_variants()), InvocationType::Static)?;
+/// assert_eq!(ClrDecimal::from_variant(&total)?.to_string(), "59.97");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ClrDecimal {
+    /// The absolute value of the mantissa, below `2^96`.
+    mantissa: u128,
+
+    /// The number of decimal digits after the point, up to 28.
+    scale: u8,
+
+    /// Whether the value is negative. Always `false` for zero.
+    negative: bool,
+}
+
+impl ClrDecimal {
+    /// Creates a decimal from a mantissa and a scale, the value being `mantissa / 10^scale`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mantissa` - The signed mantissa, whose absolute value must fit in 96 bits.
+    /// * `scale` - The number of decimal digits after the point, up to 28.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ClrDecimal)` - The decimal.
+    /// * `Err(ClrError)` - If the mantissa or the scale is out of range.
+    pub fn new(mantissa: i128, scale: u8) -> Result<Self, ClrError> {
+        if scale > MAX_SCALE {
+            return Err(ClrError::InvalidDecimal(format!("scale {scale} is above {MAX_SCALE}")));
+        }
+
+        if mantissa.unsigned_abs() > MAX_MANTISSA {
+            return Err(ClrError::InvalidDecimal(format!("mantissa {mantissa} does not fit in 96 bits")));
+        }
+
+        Ok(Self { mantissa: mantissa.unsigned_abs(), scale, negative: mantissa < 0 })
+    }
+
+    /// Returns the signed mantissa.
+    pub fn mantissa(&self) -> i128 {
+        if self.negative { -(self.mantissa as i128) } else { self.mantissa as i128 }
+    }
+
+    /// Returns the scale, the number of decimal digits after the point.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Converts the decimal to the nearest `f64`, losing precision beyond 15-17 digits.
+    pub fn to_f64(&self) -> f64 {
+        self.mantissa() as f64 / 10f64.powi(self.scale as i32)
+    }
+}
+
+impl FromStr for ClrDecimal {
+    type Err = ClrError;
+
+    /// Parses a decimal written as an optional sign, digits and an optional fractional part,
+    /// e.g. `-1234.5600`. Exponents and group separators are not accepted, and digits beyond
+    /// the range of a decimal are refused rather than rounded.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || ClrError::InvalidDecimal(format!("'{text}' is not a decimal number"));
+        let (negative, digits) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+
+        if fraction.len() > MAX_SCALE as usize {
+            return Err(ClrError::InvalidDecimal(format!("'{text}' has more than {MAX_SCALE} decimal digits")));
+        }
+
+        let mut mantissa = 0u128;
+        for digit in integer.bytes().chain(fraction.bytes()) {
+            if !digit.is_ascii_digit() {
+                return Err(invalid());
+            }
+
+            mantissa = mantissa * 10 + (digit - b'0') as u128;
+            if mantissa > MAX_MANTISSA {
+                return Err(ClrError::InvalidDecimal(format!("'{text}' is out of the range of a decimal")));
+            }
+        }
+
+        Ok(Self { mantissa, scale: fraction.len() as u8, negative: negative && mantissa != 0 })
+    }
+}
+
+impl fmt::Display for ClrDecimal {
+    /// Formats the decimal with all the digits of its scale, e.g. `-1234.5600`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = format!("{:0>width$}", self.mantissa, width = self.scale as usize + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - self.scale as usize);
+        let sign = if self.negative { "-" } else { "" };
+
+        if fraction.is_empty() {
+            write!(f, "{sign}{integer}")
+        } else {
+            write!(f, "{sign}{integer}.{fraction}")
+        }
+    }
+}
+
+/// Implements the lossless conversions from the integer types to `ClrDecimal`.
+macro_rules! impl_decimal_from {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for ClrDecimal {
+                #[doc = concat!("Converts an `", stringify!($ty), "` with a scale of zero.")]
+                fn from(value: $ty) -> Self {
+                    Self { mantissa: (value as i128).unsigned_abs(), scale: 0, negative: (value as i128) < 0 }
+                }
+            }
+        )+
+    };
+}
+
+impl_decimal_from!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl Variant for ClrDecimal {
+    /// Converts the decimal to a `VT_DECIMAL` `VARIANT`.
+    ///
+    /// The `DECIMAL` overlays the whole `VARIANT`, with the type stored in its reserved field.
+    fn to_variant(&self) -> VARIANT {
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        unsafe {
+            let decimal = &mut variant.Anonymous.decVal;
+            decimal.Anonymous1.Anonymous.scale = self.scale;
+            decimal.Anonymous1.Anonymous.sign = if self.negative { DECIMAL_NEG } else { 0 };
+            decimal.Hi32 = (self.mantissa >> 64) as u32;
+            decimal.Anonymous2.Lo64 = self.mantissa as u64;
+        }
+
+        variant.Anonymous.Anonymous.vt = Self::var_type();
+        variant
+    }
+
+    /// Returns the VARIANT type ID for decimals.
+    fn var_type() -> u16 {
+        VT_DECIMAL
+    }
+}
+
+impl FromVariant for ClrDecimal {
+    /// Reads a `VT_DECIMAL` `VARIANT`, such as a `System.Decimal` returned by a managed call.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        if unsafe { variant.Anonymous.Anonymous.vt } != VT_DECIMAL {
+            return Err(ClrError::VariantUnsupported);
+        }
+
+        let decimal = unsafe { variant.Anonymous.decVal };
+        let mantissa = ((decimal.Hi32 as u128) << 64) | unsafe { decimal.Anonymous2.Lo64 } as u128;
+        let scale = unsafe { decimal.Anonymous1.Anonymous.scale };
+        let negative = unsafe { decimal.Anonymous1.Anonymous.sign } & DECIMAL_NEG != 0;
+
+        if scale > MAX_SCALE {
+            return Err(ClrError::InvalidDecimal(format!("scale {scale} is above {MAX_SCALE}")));
+        }
+
+        Ok(Self { mantissa, scale, negative: negative && mantissa != 0 })
+    }
+}
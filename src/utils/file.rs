@@ -1,111 +1,254 @@
 use windows_sys::Win32::System::{
     Diagnostics::Debug::{
-        IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR, IMAGE_FILE_DLL, 
-        IMAGE_FILE_EXECUTABLE_IMAGE, IMAGE_NT_HEADERS64, 
-        IMAGE_SUBSYSTEM_NATIVE
-    }, 
-    SystemServices::{
-        IMAGE_DOS_HEADER, 
-        IMAGE_DOS_SIGNATURE, 
-        IMAGE_NT_SIGNATURE
-    }
+        IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR, IMAGE_DIRECTORY_ENTRY_RESOURCE, IMAGE_FILE_DLL,
+        IMAGE_FILE_EXECUTABLE_IMAGE, IMAGE_NT_OPTIONAL_HDR32_MAGIC,
+        IMAGE_NT_OPTIONAL_HDR64_MAGIC, IMAGE_SUBSYSTEM_NATIVE,
+    },
+    SystemServices::{IMAGE_DOS_SIGNATURE, IMAGE_NT_SIGNATURE},
 };
 
 use crate::error::ClrError;
 
-/// Extracts the NT header from the given buffer if it represents a valid PE file.
-/// 
-/// # Arguments
-/// 
-/// * `buffer` - A reference to a byte slice representing the potential PE file.
-/// 
-/// # Returns
-/// 
-/// * `Some(*const IMAGE_NT_HEADERS64)` - If the buffer contains a valid NT header.
-/// * `None` - If the buffer does not represent a valid NT header.
-unsafe fn get_nt_header(buffer: &[u8]) -> Option<*const IMAGE_NT_HEADERS64> {
-    if buffer.len() < size_of::<IMAGE_DOS_HEADER>() {
-        return None;
+/// Offset of `e_lfanew` in the DOS header.
+const DOS_LFANEW_OFFSET: usize = 0x3C;
+
+/// Size of the PE signature plus the COFF file header.
+const NT_FILE_HEADER_SIZE: usize = 4 + 20;
+
+/// Size of a section header.
+const SECTION_HEADER_SIZE: usize = 40;
+
+/// Size of the CLI header (`IMAGE_COR20_HEADER`).
+const COR20_HEADER_SIZE: usize = 72;
+
+/// Resource type of the side-by-side manifest (`RT_MANIFEST`).
+const RT_MANIFEST: u32 = 24;
+
+/// Flag of `OffsetToData` marking a resource entry that points to a subdirectory.
+const RESOURCE_SUBDIRECTORY: u32 = 0x8000_0000;
+
+/// Reads a little-endian `u16` at `offset`, returning `None` if it is out of bounds.
+pub(crate) fn read_u16(buffer: &[u8], offset: usize) -> Option<u16> {
+    let bytes = buffer.get(offset..offset.checked_add(2)?)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a little-endian `u32` at `offset`, returning `None` if it is out of bounds.
+pub(crate) fn read_u32(buffer: &[u8], offset: usize) -> Option<u32> {
+    let bytes = buffer.get(offset..offset.checked_add(4)?)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+/// A bounds-checked view over the headers of a PE file held in a byte buffer.
+///
+/// Every field is read through checked offsets, so malformed or truncated buffers
+/// (bogus `e_lfanew`, oversized header counts, directories pointing outside the
+/// file) are rejected instead of being dereferenced.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PeImage<'a> {
+    /// The raw PE file.
+    buffer: &'a [u8],
+
+    /// The `Characteristics` field of the file header.
+    characteristics: u16,
+
+    /// The `Subsystem` field of the optional header.
+    subsystem: u16,
+
+    /// Offset of the first data directory.
+    data_directories: usize,
+
+    /// Number of data directories present in the optional header.
+    directory_count: usize,
+
+    /// Offset of the first section header.
+    sections: usize,
+
+    /// Number of section headers.
+    section_count: usize,
+}
+
+impl<'a> PeImage<'a> {
+    /// Parses the headers of a PE file.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A reference to a byte slice representing the potential PE file.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(PeImage)` - If the buffer contains well-formed PE32 or PE32+ headers.
+    /// * `None` - If any header is missing, truncated or inconsistent.
+    pub(crate) fn parse(buffer: &'a [u8]) -> Option<Self> {
+        if read_u16(buffer, 0)? != IMAGE_DOS_SIGNATURE {
+            return None;
+        }
+
+        let nt = usize::try_from(read_u32(buffer, DOS_LFANEW_OFFSET)?).ok()?;
+        if read_u32(buffer, nt)? != IMAGE_NT_SIGNATURE {
+            return None;
+        }
+
+        let section_count = read_u16(buffer, nt.checked_add(6)?)? as usize;
+        let optional_size = read_u16(buffer, nt.checked_add(20)?)? as usize;
+        let characteristics = read_u16(buffer, nt.checked_add(22)?)?;
+
+        let optional = nt.checked_add(NT_FILE_HEADER_SIZE)?;
+        let (count_offset, directories_offset) = match read_u16(buffer, optional)? {
+            IMAGE_NT_OPTIONAL_HDR32_MAGIC => (92, 96),
+            IMAGE_NT_OPTIONAL_HDR64_MAGIC => (108, 112),
+            _ => return None,
+        };
+
+        let subsystem = read_u16(buffer, optional.checked_add(68)?)?;
+        let directory_count = (read_u32(buffer, optional.checked_add(count_offset)?)? as usize).min(16);
+        if directories_offset + directory_count * 8 > optional_size {
+            return None;
+        }
+
+        let sections = optional.checked_add(optional_size)?;
+        let sections_end = sections.checked_add(section_count.checked_mul(SECTION_HEADER_SIZE)?)?;
+        if sections_end > buffer.len() {
+            return None;
+        }
+
+        Some(Self {
+            buffer,
+            characteristics,
+            subsystem,
+            data_directories: optional + directories_offset,
+            directory_count,
+            sections,
+            section_count,
+        })
     }
 
-    let dos_header = buffer.as_ptr() as *const IMAGE_DOS_HEADER;
-    if (*dos_header).e_magic != IMAGE_DOS_SIGNATURE {
-        return None;
+    /// Returns the `(VirtualAddress, Size)` pair of a data directory, if present.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The `IMAGE_DIRECTORY_ENTRY_*` index of the directory.
+    pub(crate) fn data_directory(&self, index: usize) -> Option<(u32, u32)> {
+        if index >= self.directory_count {
+            return None;
+        }
+
+        let offset = self.data_directories + index * 8;
+        Some((read_u32(self.buffer, offset)?, read_u32(self.buffer, offset + 4)?))
     }
 
-    if (*dos_header).e_lfanew >= (buffer.len() - size_of::<IMAGE_NT_HEADERS64>()) as i32 {
-        return None;
+    /// Converts a relative virtual address into an offset in the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `rva` - The relative virtual address to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(usize)` - The file offset, if the address belongs to the raw data of a section.
+    /// * `None` - If no section contains the address.
+    pub(crate) fn rva_to_offset(&self, rva: u32) -> Option<usize> {
+        (0..self.section_count).find_map(|index| {
+            let header = self.sections + index * SECTION_HEADER_SIZE;
+            let virtual_address = read_u32(self.buffer, header + 12)?;
+            let raw_size = read_u32(self.buffer, header + 16)?;
+            let raw_pointer = read_u32(self.buffer, header + 20)?;
+
+            let delta = rva.checked_sub(virtual_address)?;
+            (delta < raw_size).then(|| raw_pointer as usize + delta as usize)
+        })
     }
 
-    let nt_header = (buffer.as_ptr() as usize + (*dos_header).e_lfanew as usize) as *const IMAGE_NT_HEADERS64;
-    if (*nt_header).Signature != IMAGE_NT_SIGNATURE {
-        return None;
+    /// Returns the `size` bytes located at `rva`, if they are entirely inside the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `rva` - The relative virtual address of the data.
+    /// * `size` - The size of the data in bytes.
+    pub(crate) fn slice_at(&self, rva: u32, size: usize) -> Option<&'a [u8]> {
+        let offset = self.rva_to_offset(rva)?;
+        self.buffer.get(offset..offset.checked_add(size)?)
     }
 
-    Some(nt_header)
-}
+    /// Returns the CLI header (`IMAGE_COR20_HEADER`) of a .NET image, if present.
+    pub(crate) fn cor20_header(&self) -> Option<&'a [u8]> {
+        let (rva, size) = self.data_directory(IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize)?;
+        if rva == 0 || (size as usize) < COR20_HEADER_SIZE {
+            return None;
+        }
 
-/// Checks if the given buffer represents a valid PE executable (non-DLL, non-Native).
-/// 
-/// # Arguments
-/// 
-/// * `buffer` - A reference to a byte slice representing the potential PE file.
-/// 
-/// # Returns
-/// 
-/// * `true` - If the buffer represents a valid PE executable.
-/// * `false` - If the buffer is not a valid PE executable.
-pub(crate) fn is_exe(buffer: &[u8]) -> bool {
-    unsafe {
-        if let Some(nt_header) = get_nt_header(buffer) {
-            let characteristics = (*nt_header).FileHeader.Characteristics;
-
-            return characteristics & IMAGE_FILE_EXECUTABLE_IMAGE != 0
-                && characteristics & IMAGE_FILE_DLL == 0
-                && characteristics & IMAGE_SUBSYSTEM_NATIVE == 0;
+        self.slice_at(rva, COR20_HEADER_SIZE)
+    }
+
+    /// Returns the side-by-side manifest embedded as an `RT_MANIFEST` resource, if present.
+    ///
+    /// The first name and language of the resource type are used, as the loader does for
+    /// executables (`CREATEPROCESS_MANIFEST_RESOURCE_ID`).
+    pub(crate) fn manifest(&self) -> Option<&'a [u8]> {
+        let (rva, size) = self.data_directory(IMAGE_DIRECTORY_ENTRY_RESOURCE as usize)?;
+        if rva == 0 {
+            return None;
+        }
+
+        // Type, name and language levels, ending with an `IMAGE_RESOURCE_DATA_ENTRY`
+        let root = self.slice_at(rva, size as usize)?;
+        let names = subdirectory(resource_entry(root, 0, Some(RT_MANIFEST))?)?;
+        let languages = subdirectory(resource_entry(root, names, None)?)?;
+        let data = resource_entry(root, languages, None)?;
+        if data & RESOURCE_SUBDIRECTORY != 0 {
+            return None;
         }
 
-        false
+        let data = data as usize;
+        self.slice_at(read_u32(root, data)?, read_u32(root, data + 4)? as usize)
     }
 }
 
-/// Checks if the given buffer represents a valid .NET executable.
-/// 
+/// Looks up an entry of a resource directory, returning its `OffsetToData` field.
+///
 /// # Arguments
-/// 
-/// * `buffer` - A reference to a byte slice representing the potential .NET assembly.
-/// 
-/// # Returns
-/// 
-/// * `true` - If the buffer represents a valid .NET executable.
-/// * `false` - If the buffer is not a .NET executable.
-pub(crate) fn is_dotnet(buffer: &[u8]) -> bool {
-    unsafe {
-        if let Some(nt_header) = get_nt_header(buffer) {
-            let com_directory = (*nt_header).OptionalHeader.DataDirectory[IMAGE_DIRECTORY_ENTRY_COM_DESCRIPTOR as usize];
-            return com_directory.VirtualAddress != 0 && com_directory.Size != 0;
-        }
+///
+/// * `root` - The resource section, starting at the root directory.
+/// * `directory` - Offset of the directory in `root`.
+/// * `id` - The integer ID of the entry, or `None` for the first entry.
+fn resource_entry(root: &[u8], directory: usize, id: Option<u32>) -> Option<u32> {
+    let named = read_u16(root, directory.checked_add(12)?)? as usize;
+    let ids = read_u16(root, directory.checked_add(14)?)? as usize;
 
-        false
-    }
+    // Named entries come first and have the high bit of their name set, so they never match an ID
+    (0..named + ids)
+        .map(|index| directory + 16 + index * 8)
+        .find_map(|entry| match id {
+            Some(id) if read_u32(root, entry)? != id => None,
+            _ => read_u32(root, entry + 4),
+        })
+}
+
+/// Returns the offset of the subdirectory an `OffsetToData` field points to, if it is one.
+fn subdirectory(offset: u32) -> Option<usize> {
+    (offset & RESOURCE_SUBDIRECTORY != 0).then_some((offset & !RESOURCE_SUBDIRECTORY) as usize)
 }
 
-/// Validates if the given buffer represents a valid .NET executable.
+/// Validates that parsed headers describe a .NET executable.
 ///
 /// # Arguments
 ///
-/// * `buffer` - A reference to a byte slice representing the potential .NET assembly.
+/// * `pe` - The headers of the potential .NET assembly.
 ///
 /// # Returns
-/// 
-/// * `Ok(())` - If the environment is successfully prepared.
-/// * `Err(ClrError)` - If any error occurs during the preparation process.
-pub(crate) fn validate_file(buffer: &[u8]) -> Result<(), ClrError> {
-    if !is_exe(buffer) {
+///
+/// * `Ok(())` - If the image is a .NET executable.
+/// * `Err(ClrError)` - If the image is a DLL or native image, or has no CLI header.
+pub(crate) fn validate_file(pe: &PeImage) -> Result<(), ClrError> {
+    let is_exe = pe.characteristics & IMAGE_FILE_EXECUTABLE_IMAGE != 0
+        && pe.characteristics & IMAGE_FILE_DLL == 0
+        && pe.subsystem != IMAGE_SUBSYSTEM_NATIVE;
+
+    if !is_exe {
         return Err(ClrError::InvalidExecutable);
     }
 
-    if !is_dotnet(buffer) {
+    if pe.cor20_header().is_none() {
         return Err(ClrError::NotDotNet);
     }
 
@@ -1,12 +1,31 @@
-use windows_sys::Win32::Foundation::{SysAllocString, SysStringLen};
+use {
+    windows_sys::Win32::{
+        Foundation::{SysAllocString, SysStringLen},
+        System::Variant::{VariantClear, VARIANT},
+    },
+    crate::schema::BindingFlags,
+};
 
-/// Module related to safearray creation
-mod safearray;
+/// Module related to safearray creation, the way to build the arrays and argument lists
+/// passed to managed code
+pub mod safearray;
 pub use safearray::*;
  
 /// Module used to validate that the file corresponds to what is expected
 pub(crate) mod file;
 
+/// Module used to strip or normalize console control sequences
+mod ansi;
+pub use ansi::*;
+
+/// Module used to marshal dates as `VT_DATE` variants
+mod date;
+pub use date::*;
+
+/// Module used to marshal decimals as `VT_DECIMAL` variants
+mod decimal;
+pub use decimal::*;
+
 /// The `WinStr` trait provides methods for working with BSTRs (Binary String),
 /// a format commonly used in Windows API. BSTRs are wide strings (UTF-16) 
 /// with specific memory layouts, used for interoperation with COM 
@@ -117,6 +136,18 @@ impl WinStr for *const u16 {
     }
 }
 
+/// A `VARIANT` released with `VariantClear` when dropped.
+///
+/// Wraps the results of managed calls that are only read, so the strings and object
+/// references they hold are released on every path, including early returns.
+pub(crate) struct OwnedVariant(pub(crate) VARIANT);
+
+impl Drop for OwnedVariant {
+    fn drop(&mut self) {
+        unsafe { VariantClear(&mut self.0) };
+    }
+}
+
 /// Specifies the invocation type for a method, indicating if it is static or instance-based.
 pub enum InvocationType {
     /// Indicates that the method to invoke is static.
@@ -124,4 +155,66 @@ pub enum InvocationType {
 
     /// Indicates that the method to invoke is an instance method.
     Instance,
-}
\ No newline at end of file
+
+    /// Indicates that the method to invoke is static and may be non-public (`private` or `internal`).
+    StaticPrivate,
+
+    /// Indicates that the method to invoke is an instance method and may be non-public (`private` or `internal`).
+    InstancePrivate,
+}
+
+impl InvocationType {
+    /// Returns the `BindingFlags` used to look up members for this invocation type.
+    pub fn binding_flags(&self) -> BindingFlags {
+        match self {
+            InvocationType::Static => BindingFlags::Public | BindingFlags::Static,
+            InvocationType::Instance => BindingFlags::Public | BindingFlags::Instance,
+            InvocationType::StaticPrivate => BindingFlags::Public | BindingFlags::NonPublic | BindingFlags::Static,
+            InvocationType::InstancePrivate => BindingFlags::Public | BindingFlags::NonPublic | BindingFlags::Instance,
+        }
+    }
+}
+/// Matches `text` against a wildcard `pattern`, where `*` matches any sequence of
+/// characters (including none) and `?` matches exactly one character.
+///
+/// The comparison is case-sensitive, like .NET type and member names.
+///
+/// # Examples
+///
+/// ```
+/// use rustclr::wildcard_match;
+///
+/// assert!(wildcard_match("*.Program", "Payload.Program"));
+/// assert!(wildcard_match("Run?", "Run2"));
+/// assert!(!wildcard_match("Run*", "Main"));
+/// ```
+pub fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.chars().collect::<Vec<_>>();
+    let text = text.chars().collect::<Vec<_>>();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            // Let the last `*` absorb one more character and retry
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
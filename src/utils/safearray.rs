@@ -1,24 +1,32 @@
 use {
     std::{
         ffi::c_void, 
-        ptr::{copy_nonoverlapping, null_mut}
+        io::Read,
+        ptr::null_mut
     },
-    windows_sys::Win32::{
-        Foundation::{
-            SysFreeString, VARIANT_FALSE, 
-            VARIANT_TRUE
-        }, 
-        System::{
-            Com::{SAFEARRAY, SAFEARRAYBOUND}, 
-            Ole::{
-                SafeArrayAccessData, SafeArrayCreate, 
-                SafeArrayCreateVector, SafeArrayPutElement, 
-                SafeArrayUnaccessData
+    windows_sys::{
+        core::BSTR,
+        Win32::{
+            Foundation::{
+                DECIMAL, VARIANT_BOOL, 
+                VARIANT_FALSE, VARIANT_TRUE
             }, 
-            Variant::{
-                VARIANT, VT_ARRAY, VT_BSTR, VT_BOOL, 
-                VT_I4, VT_UI1, VT_VARIANT,
-            } 
+            System::{
+                Com::{SAFEARRAY, SAFEARRAYBOUND}, 
+                Ole::{
+                    SafeArrayAccessData, SafeArrayCreate, 
+                    SafeArrayCreateVector, SafeArrayDestroy,
+                    SafeArrayGetVartype, SafeArrayPutElement,
+                    SafeArrayUnaccessData
+                }, 
+                Variant::{
+                    VariantClear, VARIANT, VT_ARRAY, VT_BSTR, VT_BOOL, 
+                    VT_I1, VT_I2, VT_I4, VT_I8, VT_UI1,
+                    VT_UI2, VT_UI4, VT_UI8, VT_R4, VT_R8,
+                    VT_VARIANT, VT_UNKNOWN, VT_EMPTY, VT_NULL,
+                    VT_DECIMAL,
+                } 
+            }
         }
     }
 };
@@ -26,6 +34,7 @@ use {
 use {
     super::WinStr,
     crate::error::ClrError,
+    windows_core::{IUnknown, Interface},
 };
 
 /// Trait to convert various Rust types to Windows COM-compatible `VARIANT` types.
@@ -33,7 +42,10 @@ use {
 /// `VARIANT` is a COM structure used to represent different data types in a unified format,
 /// making it possible to pass arguments of different types in the COM interface.
 /// 
-/// This trait is implemented for common Rust types like `String`, `&str`, `bool`, and `i32`.
+/// This trait is implemented for common Rust types like `String`, `&str`, `bool`, the integer
+/// and floating-point types, as well as for `IUnknown` COM objects. Application types can implement it to control how
+/// they cross the COM boundary (e.g. serialized to a JSON `String`); see [`VariantArgs`] for
+/// types that expand into several arguments.
 pub trait Variant {
     /// Converts the Rust type to a `VARIANT`.
     ///
@@ -120,69 +132,695 @@ impl Variant for i32 {
     }
 }
 
-/// Creates a `SAFEARRAY` from a vector of elements implementing the `Variant` trait.
-/// 
-/// This function is used to pass arrays of arguments to COM methods, where each element is 
-/// converted to its corresponding `VARIANT` type.
+/// Implements `Variant` and `FromVariant` for a numeric type stored in a field of the `VARIANT`.
 ///
-/// # Arguments
+/// `isize` and `usize` are stored with the width of a pointer, like `IntPtr` and `UIntPtr`.
+macro_rules! impl_variant_numeric {
+    ($ty:ty, $vt:ident, $field:ident) => {
+        impl_variant_numeric!(@impl $ty, $ty, $vt, $field);
+    };
+    ($ty:ty as $repr:ty, $vt:ident, $field:ident) => {
+        impl_variant_numeric!(@impl $ty, $repr, $vt, $field);
+    };
+    (@impl $ty:ty, $repr:ty, $vt:ident, $field:ident) => {
+        impl Variant for $ty {
+            #[doc = concat!("Converts an `", stringify!($ty), "` to a `", stringify!($vt), "` `VARIANT`.")]
+            #[allow(clippy::unnecessary_cast)]
+            fn to_variant(&self) -> VARIANT {
+                let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+                variant.Anonymous.Anonymous.vt = Self::var_type();
+                variant.Anonymous.Anonymous.Anonymous.$field = *self as $repr;
+
+                variant
+            }
+
+            #[doc = concat!("Returns `", stringify!($vt), "`.")]
+            fn var_type() -> u16 {
+                $vt
+            }
+        }
+
+        impl FromVariant for $ty {
+            #[doc = concat!("Reads a `", stringify!($vt), "` `VARIANT`.")]
+            #[allow(clippy::unnecessary_cast)]
+            fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+                match unsafe { variant.Anonymous.Anonymous.vt } {
+                    $vt => Ok(unsafe { variant.Anonymous.Anonymous.Anonymous.$field } as $ty),
+                    _ => Err(ClrError::VariantUnsupported),
+                }
+            }
+        }
+    };
+}
+
+impl_variant_numeric!(i8, VT_I1, cVal);
+impl_variant_numeric!(i16, VT_I2, iVal);
+impl_variant_numeric!(i64, VT_I8, llVal);
+impl_variant_numeric!(u8, VT_UI1, bVal);
+impl_variant_numeric!(u16, VT_UI2, uiVal);
+impl_variant_numeric!(u32, VT_UI4, ulVal);
+impl_variant_numeric!(u64, VT_UI8, ullVal);
+impl_variant_numeric!(f32, VT_R4, fltVal);
+impl_variant_numeric!(f64, VT_R8, dblVal);
+
+#[cfg(target_pointer_width = "64")]
+impl_variant_numeric!(isize as i64, VT_I8, llVal);
+#[cfg(target_pointer_width = "64")]
+impl_variant_numeric!(usize as u64, VT_UI8, ullVal);
+#[cfg(target_pointer_width = "32")]
+impl_variant_numeric!(isize as i32, VT_I4, lVal);
+#[cfg(target_pointer_width = "32")]
+impl_variant_numeric!(usize as u32, VT_UI4, ulVal);
+
+impl Variant for IUnknown {
+    /// Converts an `IUnknown` to an object `VARIANT`, adding a reference owned by the `VARIANT`.
+    fn to_variant(&self) -> VARIANT {
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        variant.Anonymous.Anonymous.vt = Self::var_type();
+        variant.Anonymous.Anonymous.Anonymous.punkVal = self.clone().into_raw();
+
+        variant
+    }
+
+    /// Returns the VARIANT type ID for `IUnknown` objects.
+    fn var_type() -> u16 {
+        VT_UNKNOWN
+    }
+}
+
+impl<T: Variant> Variant for Option<T> {
+    /// Converts `Some(value)` like `value` and `None` to an empty `VARIANT`.
+    ///
+    /// Boxed `Nullable<T>` values cross the COM boundary as their underlying value or as
+    /// `null`, so `Option<T>` can be passed directly to parameters such as `int?` or `bool?`.
+    fn to_variant(&self) -> VARIANT {
+        match self {
+            Some(value) => value.to_variant(),
+            None => unsafe { std::mem::zeroed::<VARIANT>() },
+        }
+    }
+
+    /// Returns the VARIANT type ID of the underlying type.
+    fn var_type() -> u16 {
+        T::var_type()
+    }
+}
+
+/// Trait to convert a `VARIANT` returned by a managed call back into a Rust type.
 ///
-/// * `args` - A vector of elements implementing the `Variant` trait.
+/// This trait is implemented for `String`, `bool`, the integer and floating-point types, and
+/// for `Option<T>` to read values that may be `null`, such as `Nullable<T>` return values.
+pub trait FromVariant: Sized {
+    /// Converts the `VARIANT` to the Rust type.
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - The `VARIANT` to convert.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Self)` - The converted value.
+    /// * `Err(ClrError)` - If the `VARIANT` does not hold a value of the expected type.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError>;
+}
+
+impl FromVariant for String {
+    /// Reads a BSTR-based `VARIANT`.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        match unsafe { variant.Anonymous.Anonymous.vt } {
+            VT_BSTR => Ok(unsafe { variant.Anonymous.Anonymous.Anonymous.bstrVal }.to_string()),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+}
+
+impl FromVariant for bool {
+    /// Reads a boolean `VARIANT`.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        match unsafe { variant.Anonymous.Anonymous.vt } {
+            VT_BOOL => Ok(unsafe { variant.Anonymous.Anonymous.Anonymous.boolVal } != VARIANT_FALSE),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+}
+
+impl FromVariant for i32 {
+    /// Reads an integer `VARIANT`.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        match unsafe { variant.Anonymous.Anonymous.vt } {
+            VT_I4 => Ok(unsafe { variant.Anonymous.Anonymous.Anonymous.lVal }),
+            _ => Err(ClrError::VariantUnsupported),
+        }
+    }
+}
+
+impl<T: FromVariant> FromVariant for Option<T> {
+    /// Reads `null` (`VT_EMPTY` or `VT_NULL`) as `None` and any other value as `Some`.
+    ///
+    /// A `Nullable<T>` returned by a managed method is boxed as its underlying value when
+    /// `HasValue` is `true` and as `null` otherwise.
+    fn from_variant(variant: &VARIANT) -> Result<Self, ClrError> {
+        match unsafe { variant.Anonymous.Anonymous.vt } {
+            VT_EMPTY | VT_NULL => Ok(None),
+            _ => T::from_variant(variant).map(Some),
+        }
+    }
+}
+
+/// Trait to convert a Rust value into the set of `VARIANT` arguments of a managed call.
 ///
-/// # Returns
+/// `Variant` covers single values; `VariantArgs` lets application types describe how they
+/// expand into a full argument list, so a struct can be passed directly to
+/// [`_Type::invoke`](crate::schema::_Type::invoke) or as a managed `object[]`.
 ///
-/// * `Ok(*mut SAFEARRAY)` - The created `SAFEARRAY`.
-/// * `Err(ClrError)` - If the creation or element insertion into the `SAFEARRAY` fails.
-pub fn create_safe_array_args<T: Variant>(args: Vec<T>) -> Result<*mut SAFEARRAY, ClrError> {
-    unsafe {
+/// It is implemented for single `Variant` values, `()` (no arguments), vectors and slices of
+/// `Variant` values, and tuples of up to eight `Variant` values.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::{Variant, VariantArgs};
+/// use windows_sys::Win32::System::Variant::VARIANT;
+///
+/// struct Credentials {
+///     user: String,
+///     password: String,
+///     persist: bool,
+/// }
+///
+/// impl VariantArgs for Credentials {
+///     fn to_variants(&self) -> Vec<VARIANT> {
+///         (self.user.as_str(), self.password.as_str(), self.persist).to_variants()
+///     }
+/// }
+///
+/// let args = credentials.to_variants();
+/// login.invoke("Login", None, Some(args), InvocationType::Static)?;
+/// ```
+pub trait VariantArgs {
+    /// Converts the value to a list of `VARIANT` arguments.
+    ///
+    /// # Returns
+    ///
+    /// * The `VARIANT` arguments, in the order expected by the managed method.
+    fn to_variants(&self) -> Vec<VARIANT>;
+
+    /// Converts the value to a single `VARIANT` holding a managed `object[]`.
+    ///
+    /// This is useful for methods taking a `params object[]` parameter.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - A `VT_ARRAY | VT_VARIANT` variant owning the array.
+    /// * `Err(ClrError)` - If the `SAFEARRAY` could not be created.
+    fn to_object_array(&self) -> Result<VARIANT, ClrError> {
+        SafeArray::from_args(self)?.into_variant()
+    }
+}
+
+impl<T: Variant> VariantArgs for T {
+    /// Converts a single value to a one-element argument list.
+    fn to_variants(&self) -> Vec<VARIANT> {
+        vec![self.to_variant()]
+    }
+}
+
+impl VariantArgs for () {
+    /// Returns an empty argument list.
+    fn to_variants(&self) -> Vec<VARIANT> {
+        Vec::new()
+    }
+}
+
+impl<T: Variant> VariantArgs for [T] {
+    /// Converts each element of the slice to a `VARIANT`.
+    fn to_variants(&self) -> Vec<VARIANT> {
+        self.iter().map(Variant::to_variant).collect()
+    }
+}
+
+impl<T: Variant> VariantArgs for Vec<T> {
+    /// Converts each element of the vector to a `VARIANT`.
+    fn to_variants(&self) -> Vec<VARIANT> {
+        self.as_slice().to_variants()
+    }
+}
+
+/// Implements `VariantArgs` for tuples whose elements implement `Variant`.
+macro_rules! impl_variant_args_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: Variant),+> VariantArgs for ($($name,)+) {
+            /// Converts each element of the tuple to a `VARIANT`.
+            fn to_variants(&self) -> Vec<VARIANT> {
+                let ($($name,)+) = self;
+                vec![$($name.to_variant()),+]
+            }
+        }
+    };
+}
+
+impl_variant_args_tuple!(A);
+impl_variant_args_tuple!(A, B);
+impl_variant_args_tuple!(A, B, C);
+impl_variant_args_tuple!(A, B, C, D);
+impl_variant_args_tuple!(A, B, C, D, E);
+impl_variant_args_tuple!(A, B, C, D, E, F);
+impl_variant_args_tuple!(A, B, C, D, E, F, G);
+impl_variant_args_tuple!(A, B, C, D, E, F, G, H);
+
+/// An owned `SAFEARRAY`, destroyed when dropped.
+///
+/// This is the way to build the arrays passed to managed code: the typed vectors behind
+/// `string[]`, `int[]` or `byte[]` parameters, and the `object[]` argument lists of
+/// reflection calls. Each constructor copies or converts its input, so the array never
+/// shares memory with the caller, and the array is released once, when the `SafeArray`
+/// is dropped. [`SafeArray::into_raw`] and [`SafeArray::into_variant`] hand the array
+/// over to COM calls that take ownership of it.
+///
+/// # Examples
+///
+/// ```ignore
+/// use rustclr::safearray::SafeArray;
+///
+/// // `string[] args` for an entry point
+/// let main_args = SafeArray::from_strings(&["-v", "--output", "report.txt"])?;
+///
+/// // The argument list of `Invoke`, holding the `string[]` as its single element
+/// let args = SafeArray::from_variants(vec![main_args.into_variant()?])?;
+/// method.Invoke_3(instance, args.as_ptr())?;
+/// ```
+#[derive(Debug)]
+pub struct SafeArray(*mut SAFEARRAY);
+
+impl SafeArray {
+    /// Creates a one-dimensional array of values of the same `Variant` type.
+    ///
+    /// The array is typed after `T::var_type()`, e.g. a `string[]` for `String` and `&str`
+    /// or an `int[]` for `i32`, as expected by parameters such as `Main(string[] args)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The elements of the array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The typed array.
+    /// * `Err(ClrError)` - If the element type is not supported or the array cannot be created.
+    pub fn from_values<T: Variant>(values: &[T]) -> Result<Self, ClrError> {
         let vartype = T::var_type();
-        let psa = SafeArrayCreateVector(vartype, 0, args.len() as u32);
-        if psa.is_null() {
-            return Err(ClrError::NullPointerError("SafeArrayCreateVector"));
-        }
-        
-        for (i, arg) in args.iter().enumerate() {
-            let variant = arg.to_variant();
-            let index = i as i32;
-            let value_ptr = match vartype {
-                VT_BOOL => &variant.Anonymous.Anonymous.Anonymous.boolVal as *const _ as *const c_void,
-                VT_I4 => &variant.Anonymous.Anonymous.Anonymous.lVal as *const _ as *const c_void,
-                VT_BSTR => variant.Anonymous.Anonymous.Anonymous.bstrVal as *const c_void,
-                _ => return Err(ClrError::VariantUnsupported),
-            };
-
-            let hr = SafeArrayPutElement(psa, &index, value_ptr);
-            if hr != 0 {
-                return Err(ClrError::ApiError("SafeArrayPutElement", hr));
+        if !matches!(
+            vartype,
+            VT_BOOL | VT_BSTR | VT_I1 | VT_I2 | VT_I4 | VT_I8
+                | VT_UI1 | VT_UI2 | VT_UI4 | VT_UI8 | VT_R4 | VT_R8
+                | VT_DECIMAL
+        ) {
+            return Err(ClrError::VariantUnsupported);
+        }
+
+        Self::create(vartype, values.len(), |p_data| unsafe {
+            for (i, arg) in values.iter().enumerate() {
+                let variant = arg.to_variant();
+                let value = &variant.Anonymous.Anonymous.Anonymous;
+                match vartype {
+                    VT_BOOL => *p_data.cast::<VARIANT_BOOL>().add(i) = value.boolVal,
+                    VT_I1 => *p_data.cast::<i8>().add(i) = value.cVal,
+                    VT_I2 => *p_data.cast::<i16>().add(i) = value.iVal,
+                    VT_I4 => *p_data.cast::<i32>().add(i) = value.lVal,
+                    VT_I8 => *p_data.cast::<i64>().add(i) = value.llVal,
+                    VT_UI1 => *p_data.cast::<u8>().add(i) = value.bVal,
+                    VT_UI2 => *p_data.cast::<u16>().add(i) = value.uiVal,
+                    VT_UI4 => *p_data.cast::<u32>().add(i) = value.ulVal,
+                    VT_UI8 => *p_data.cast::<u64>().add(i) = value.ullVal,
+                    VT_R4 => *p_data.cast::<f32>().add(i) = value.fltVal,
+                    VT_R8 => *p_data.cast::<f64>().add(i) = value.dblVal,
+                    VT_DECIMAL => {
+                        // The reserved field of a `DECIMAL` held by a `VARIANT` is its type
+                        let mut decimal = variant.Anonymous.decVal;
+                        decimal.wReserved = 0;
+                        *p_data.cast::<DECIMAL>().add(i) = decimal;
+                    }
+                    // The BSTR allocated by `to_variant` is handed over to the array
+                    _ => *p_data.cast::<BSTR>().add(i) = value.bstrVal,
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Creates a `string[]` array.
+    ///
+    /// # Arguments
+    ///
+    /// * `strings` - The elements of the array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_BSTR` array.
+    /// * `Err(ClrError)` - If the array cannot be created.
+    pub fn from_strings<S: AsRef<str>>(strings: &[S]) -> Result<Self, ClrError> {
+        Self::from_values(&strings.iter().map(AsRef::as_ref).collect::<Vec<&str>>())
+    }
+
+    /// Creates an `object[]` array, such as the argument list of a reflection call.
+    ///
+    /// The array takes ownership of the variants, which are released when it is dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `variants` - The elements of the array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_VARIANT` array.
+    /// * `Err(ClrError)` - If the array cannot be created.
+    pub fn from_variants(mut variants: Vec<VARIANT>) -> Result<Self, ClrError> {
+        let array = Self::create(VT_VARIANT, variants.len(), |p_data| {
+            for (i, variant) in variants.drain(..).enumerate() {
+                unsafe { *p_data.cast::<VARIANT>().add(i) = variant };
+            }
+
+            Ok(())
+        });
+
+        // The variants are still owned here if the array could not be created
+        for variant in &mut variants {
+            unsafe { VariantClear(variant) };
+        }
+
+        array
+    }
+
+    /// Creates an `object[]` array from copies of borrowed variants.
+    ///
+    /// The variants are copied with `VariantCopy`, so the caller keeps ownership of
+    /// `variants` and remains responsible for releasing them.
+    ///
+    /// # Arguments
+    ///
+    /// * `variants` - The elements of the array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_VARIANT` array.
+    /// * `Err(ClrError)` - If the array cannot be created or an element cannot be copied.
+    pub fn copy_from(variants: &[VARIANT]) -> Result<Self, ClrError> {
+        unsafe {
+            let psa = SafeArrayCreateVector(VT_VARIANT, 0, element_count(variants.len())?);
+            if psa.is_null() {
+                return Err(ClrError::NullPointerError("SafeArrayCreateVector"));
             }
 
-            if vartype == VT_BSTR {
-                SysFreeString(variant.Anonymous.Anonymous.Anonymous.bstrVal);
+            // Owned from here, so the array is destroyed if an element fails
+            let array = Self(psa);
+            for (i, variant) in variants.iter().enumerate() {
+                let index = i as i32;
+                let hr = SafeArrayPutElement(psa, &index, variant as *const VARIANT as *const c_void);
+                if hr != 0 {
+                    return Err(ClrError::ApiError("SafeArrayPutElement", hr));
+                }
             }
+
+            Ok(array)
         }
-        
-        let args = SafeArrayCreateVector(VT_VARIANT, 0, 1);
-        let mut var_array = std::mem::zeroed::<VARIANT>(); 
-        var_array.Anonymous.Anonymous.vt = VT_ARRAY | vartype;
-        var_array.Anonymous.Anonymous.Anonymous.parray = psa;
-
-        let index = 0;
-        let hr = SafeArrayPutElement(
-            args, 
-            &index, 
-            &mut var_array as *const VARIANT as *const c_void
-        );
+    }
+
+    /// Creates the argument list of a method taking a single typed array, such as `Main(string[] args)`.
+    ///
+    /// The values are converted into a typed array (see [`SafeArray::from_values`]), which is
+    /// wrapped as the only element of an `object[]` argument list.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The elements of the typed array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_VARIANT` argument list.
+    /// * `Err(ClrError)` - If the element type is not supported or an array cannot be created.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let args = SafeArray::from_array_arg(&["-v", "--output", "report.txt"])?;
+    /// assembly.run(args.as_ptr())?;
+    /// ```
+    pub fn from_array_arg<T: Variant>(values: &[T]) -> Result<Self, ClrError> {
+        Self::from_variants(vec![Self::from_values(values)?.into_variant()?])
+    }
+
+    /// Creates an `object[]` argument list from values implementing [`VariantArgs`].
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments, e.g. a tuple such as `("key", 42, true)`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_VARIANT` array.
+    /// * `Err(ClrError)` - If the array cannot be created.
+    pub fn from_args<A: VariantArgs + ?Sized>(args: &A) -> Result<Self, ClrError> {
+        Self::from_variants(args.to_variants())
+    }
+
+    /// Creates a `byte[]` array.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The content of the array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_UI1` array.
+    /// * `Err(ClrError)` - If the array cannot be created.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ClrError> {
+        Self::create_bytes(data.len(), |bytes| {
+            bytes.copy_from_slice(data);
+            Ok(())
+        })
+    }
+
+    /// Creates a `byte[]` array filled from a reader.
+    ///
+    /// The data is read straight into the array memory, in the chunks returned by the reader,
+    /// so a large payload or `byte[]` argument is never held in an intermediate buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the data, e.g. a `File`.
+    /// * `len` - The number of bytes to read.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The `VT_UI1` array.
+    /// * `Err(ClrError)` - If the array cannot be created or the reader fails before `len` bytes.
+    pub fn from_reader(mut reader: impl Read, len: usize) -> Result<Self, ClrError> {
+        Self::create_bytes(len, |bytes| {
+            reader.read_exact(bytes).map_err(|e| ClrError::IoError(e.to_string()))
+        })
+    }
+
+    /// Takes ownership of a raw `SAFEARRAY`.
+    ///
+    /// # Safety
+    ///
+    /// `psa` must be a valid, non-null `SAFEARRAY` that nothing else destroys, such as an
+    /// array returned by a COM call to its caller.
+    pub unsafe fn from_raw(psa: *mut SAFEARRAY) -> Self {
+        Self(psa)
+    }
+
+    /// Returns the raw `SAFEARRAY`, still owned by the `SafeArray`.
+    ///
+    /// The pointer is valid as long as the `SafeArray` is alive, which suits COM calls that
+    /// only read their `SAFEARRAY` parameter, such as `Invoke`.
+    pub fn as_ptr(&self) -> *mut SAFEARRAY {
+        self.0
+    }
+
+    /// Releases the ownership of the array.
+    ///
+    /// # Returns
+    ///
+    /// * The raw `SAFEARRAY`, which the caller must destroy with `SafeArrayDestroy` or hand
+    ///   over to a call that takes ownership of it.
+    pub fn into_raw(self) -> *mut SAFEARRAY {
+        let psa = self.0;
+        std::mem::forget(self);
+        psa
+    }
+
+    /// Wraps the array in a `VT_ARRAY` `VARIANT`, to pass it as a single argument.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VARIANT)` - A `VARIANT` owning the array, released by `VariantClear` or by the
+    ///   array or call that receives it.
+    /// * `Err(ClrError)` - If the element type of the array cannot be read.
+    pub fn into_variant(self) -> Result<VARIANT, ClrError> {
+        let mut vartype = 0;
+        let hr = unsafe { SafeArrayGetVartype(self.0, &mut vartype) };
         if hr != 0 {
-            return Err(ClrError::ApiError("SafeArrayPutElement (2)", hr));
+            return Err(ClrError::ApiError("SafeArrayGetVartype", hr));
         }
-    
-        Ok(args)
+
+        let mut variant = unsafe { std::mem::zeroed::<VARIANT>() };
+        variant.Anonymous.Anonymous.vt = VT_ARRAY | vartype;
+        variant.Anonymous.Anonymous.Anonymous.parray = self.into_raw();
+
+        Ok(variant)
+    }
+
+    /// Copies the content of a one-dimensional `byte[]` array.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The bytes of the array.
+    /// * `Err(ClrError)` - If the array does not hold bytes or its data cannot be accessed.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ClrError> {
+        unsafe {
+            if (*self.0).cDims != 1 || (*self.0).cbElements != 1 {
+                return Err(ClrError::VariantUnsupported);
+            }
+
+            let mut p_data = null_mut();
+            let hr = SafeArrayAccessData(self.0, &mut p_data);
+            if hr != 0 {
+                return Err(ClrError::ApiError("SafeArrayAccessData", hr));
+            }
+
+            let bytes = std::slice::from_raw_parts(p_data as *const u8, self.len()).to_vec();
+            SafeArrayUnaccessData(self.0);
+            Ok(bytes)
+        }
+    }
+
+    /// Returns the number of elements of the array.
+    pub fn len(&self) -> usize {
+        unsafe { (*self.0).rgsabound[0].cElements as usize }
+    }
+
+    /// Returns `true` if the array has no element.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Creates a one-dimensional array and fills it in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `vartype` - The type of the elements.
+    /// * `len` - The number of elements of the array.
+    /// * `fill` - Writes the elements through the data pointer. The array is destroyed if it fails.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The filled array.
+    /// * `Err(ClrError)` - If the array cannot be created or `fill` fails.
+    fn create(
+        vartype: u16,
+        len: usize,
+        fill: impl FnOnce(*mut c_void) -> Result<(), ClrError>
+    ) -> Result<Self, ClrError> {
+        unsafe {
+            let psa = SafeArrayCreateVector(vartype, 0, element_count(len)?);
+            if psa.is_null() {
+                return Err(ClrError::NullPointerError("SafeArrayCreateVector"));
+            }
+
+            let array = Self(psa);
+            let mut p_data = null_mut();
+            let hr = SafeArrayAccessData(psa, &mut p_data);
+            if hr != 0 {
+                return Err(ClrError::ApiError("SafeArrayAccessData", hr));
+            }
+
+            let filled = fill(p_data);
+            SafeArrayUnaccessData(psa);
+            filled.map(|_| array)
+        }
+    }
+
+    /// Creates a `byte[]` array of `len` bytes and fills it in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `len` - The number of bytes of the array.
+    /// * `fill` - Writes the content of the array. The array is destroyed if it fails.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SafeArray)` - The filled array.
+    /// * `Err(ClrError)` - If the array cannot be created or `fill` fails.
+    fn create_bytes(len: usize, fill: impl FnOnce(&mut [u8]) -> Result<(), ClrError>) -> Result<Self, ClrError> {
+        let bounds = SAFEARRAYBOUND {
+            cElements: element_count(len)?,
+            lLbound: 0,
+        };
+
+        unsafe {
+            let psa = SafeArrayCreate(VT_UI1, 1, &bounds);
+            if psa.is_null() {
+                return Err(ClrError::NullPointerError("SafeArrayCreate"));
+            }
+
+            let array = Self(psa);
+            let mut p_data = null_mut();
+            let hr = SafeArrayAccessData(psa, &mut p_data);
+            if hr != 0 {
+                return Err(ClrError::ApiError("SafeArrayAccessData", hr));
+            }
+
+            let filled = fill(std::slice::from_raw_parts_mut(p_data as *mut u8, len));
+            SafeArrayUnaccessData(psa);
+            filled.map(|_| array)
+        }
+    }
+}
+
+/// Converts a number of elements to the 32-bit element count of a `SAFEARRAY`.
+///
+/// # Arguments
+///
+/// * `len` - The number of elements.
+///
+/// # Returns
+///
+/// * `Ok(u32)` - The element count.
+/// * `Err(ClrError)` - If `len` does not fit in a `SAFEARRAY`.
+pub(crate) fn element_count(len: usize) -> Result<u32, ClrError> {
+    u32::try_from(len).map_err(|_| ClrError::SafeArrayError(format!("{len} elements do not fit in a SAFEARRAY")))
+}
+
+impl Drop for SafeArray {
+    /// Destroys the array and the elements it owns.
+    fn drop(&mut self) {
+        unsafe { SafeArrayDestroy(self.0) };
     }
 }
 
+/// Creates the argument list of a method taking a single typed array, such as `Main(string[] args)`.
+///
+/// The elements are converted into a typed array (see [`SafeArray::from_values`]), which is
+/// wrapped as the only element of an `object[]` argument list.
+///
+/// # Arguments
+///
+/// * `args` - A vector of elements implementing the `Variant` trait.
+///
+/// # Returns
+///
+/// * `Ok(*mut SAFEARRAY)` - The argument list, owned by the caller, who must destroy it with
+///   `SafeArrayDestroy` or hand it over to a call that takes ownership of it.
+/// * `Err(ClrError)` - If the element type is not supported or the `SAFEARRAY` cannot be created.
+#[deprecated(note = "use `SafeArray::from_array_arg`, which destroys the array when dropped")]
+pub fn create_safe_array_args<T: Variant>(args: Vec<T>) -> Result<*mut SAFEARRAY, ClrError> {
+    SafeArray::from_array_arg(&args).map(SafeArray::into_raw)
+}
+
 /// Creates a `SAFEARRAY` from a vector of `VARIANT` elements.
 ///
-/// This function is used to pass a vector of `VARIANT` arguments to COM methods.
+/// The variants are copied into the array (see [`SafeArray::copy_from`]).
 ///
 /// # Arguments
 ///
@@ -190,65 +828,51 @@ pub fn create_safe_array_args<T: Variant>(args: Vec<T>) -> Result<*mut SAFEARRAY
 ///
 /// # Returns
 ///
-/// * `Ok(*mut SAFEARRAY)` - The created `SAFEARRAY`.
+/// * `Ok(*mut SAFEARRAY)` - The `object[]` array, owned by the caller, who must destroy it with
+///   `SafeArrayDestroy` or hand it over to a call that takes ownership of it.
 /// * `Err(ClrError)` - If the creation or element insertion into the `SAFEARRAY` fails.
+#[deprecated(note = "use `SafeArray::copy_from`, which destroys the array when dropped")]
 pub fn create_safe_args(args: Vec<VARIANT>) -> Result<*mut SAFEARRAY, ClrError> {
-    unsafe {       
-        let arg = SafeArrayCreateVector(VT_VARIANT, 0, args.len() as u32);
-        for (i, var) in args.iter().enumerate() {
-            let index = i as i32;
-            let mut variant = *var;
-            let hr = SafeArrayPutElement(
-                arg, 
-                &index, 
-                &mut variant as *const VARIANT as *const c_void
-            );
-            if hr != 0 {
-                return Err(ClrError::ApiError("SafeArrayPutElement", hr));
-            }
-        }
-
-        Ok(arg)
-    }
+    SafeArray::copy_from(&args).map(SafeArray::into_raw)
 }
 
 /// Creates a `SAFEARRAY` from a byte buffer for loading assemblies.
 ///
-/// This function is useful for loading byte arrays into COM-compatible structures.
-///
 /// # Arguments
 ///
 /// * `data` - A byte slice representing the data.
 ///
 /// # Returns
 ///
-/// * `Ok(*mut SAFEARRAY)` - The created `SAFEARRAY`.
+/// * `Ok(*mut SAFEARRAY)` - The `byte[]` array, owned by the caller, who must destroy it with
+///   `SafeArrayDestroy` or hand it over to a call that takes ownership of it.
 /// * `Err(ClrError)` - If the creation or data copying into the `SAFEARRAY` fails.
+#[deprecated(note = "use `SafeArray::from_bytes`, which destroys the array when dropped")]
 pub fn create_safe_array_buffer(data: &[u8]) -> Result<*mut SAFEARRAY, ClrError> {
-    let len: u32 = data.len() as u32;
-    let bounds = SAFEARRAYBOUND {
-        cElements: data.len() as _,
-        lLbound: 0,
-    };
+    SafeArray::from_bytes(data).map(SafeArray::into_raw)
+}
 
-    unsafe {
-        let sa = SafeArrayCreate(VT_UI1, 1, &bounds);
-        if sa.is_null() {
-            return Err(ClrError::NullPointerError("SafeArrayCreate"));
-        }
-    
-        let mut p_data = null_mut();
-        let mut hr = SafeArrayAccessData(sa, &mut p_data);
-        if hr != 0 {
-            return Err(ClrError::ApiError("SafeArrayAccessData", hr));
-        }
-    
-        copy_nonoverlapping(data.as_ptr(), p_data as *mut u8, len as usize);
-        hr = SafeArrayUnaccessData(sa);
-        if hr != 0 {
-            return Err(ClrError::ApiError("SafeArrayUnaccessData", hr));
-        }
-    
-        Ok(sa)
-    }
+/// Creates a `SAFEARRAY` of bytes filled from a reader (see [`SafeArray::from_reader`]).
+///
+/// # Arguments
+///
+/// * `reader` - The source of the data, e.g. a `File`.
+/// * `len` - The number of bytes to read.
+///
+/// # Returns
+///
+/// * `Ok(*mut SAFEARRAY)` - The `byte[]` array, owned by the caller, who must destroy it with
+///   `SafeArrayDestroy` or hand it over to a call that takes ownership of it.
+/// * `Err(ClrError)` - If the `SAFEARRAY` cannot be created or the reader fails before `len` bytes.
+///
+/// # Examples
+///
+/// ```ignore
+/// let file = std::fs::File::open("payload.bin")?;
+/// let len = file.metadata()?.len() as usize;
+/// let bytes = create_safe_array_reader(file, len)?;
+/// ```
+#[deprecated(note = "use `SafeArray::from_reader`, which destroys the array when dropped")]
+pub fn create_safe_array_reader(reader: impl Read, len: usize) -> Result<*mut SAFEARRAY, ClrError> {
+    SafeArray::from_reader(reader, len).map(SafeArray::into_raw)
 }
@@ -0,0 +1,10 @@
+using System;
+
+// Compiled with `admin.manifest`, which requests `requireAdministrator`.
+public static class Program
+{
+    public static void Main()
+    {
+        Console.WriteLine("elevated");
+    }
+}
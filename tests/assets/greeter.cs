@@ -0,0 +1,8 @@
+// Library packed into `packed.exe` as a Costura resource.
+public static class Greeter
+{
+    public static string Greet(string name)
+    {
+        return "packed " + name;
+    }
+}
@@ -0,0 +1,17 @@
+using System;
+using System.Runtime.InteropServices;
+
+// Declares P/Invoke imports for the metadata tests.
+public class Native
+{
+    [DllImport("kernel32.dll")]
+    private static extern uint GetTickCount();
+
+    [DllImport("user32.dll", EntryPoint = "MessageBoxW", CharSet = CharSet.Unicode)]
+    private static extern int ShowMessage(IntPtr hWnd, string text, string caption, uint type);
+
+    public static void Main()
+    {
+        Console.WriteLine(GetTickCount() > 0);
+    }
+}
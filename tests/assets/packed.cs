@@ -0,0 +1,10 @@
+using System;
+
+// References `greeter.dll`, which is only available as the `costura.greeter.dll` resource.
+public static class Program
+{
+    public static void Main()
+    {
+        Console.WriteLine(Greeter.Greet("dependency"));
+    }
+}
@@ -0,0 +1,19 @@
+using System;
+using System.Threading;
+
+// Blocks forever like a listener, for the service tests.
+public static class Service
+{
+    public static void Main()
+    {
+        try
+        {
+            Console.WriteLine("listening");
+            Thread.Sleep(Timeout.Infinite);
+        }
+        finally
+        {
+            Console.WriteLine("stopped");
+        }
+    }
+}
@@ -0,0 +1,88 @@
+using System;
+using System.Threading.Tasks;
+
+// Holds public and private state for the reflection tests.
+[System.Diagnostics.DebuggerDisplay("{Name}")]
+public class State
+{
+    public static string Mode = "default";
+
+    private static int secret = 42;
+
+    public int Counter;
+
+    public string Name { get; set; }
+
+    public static int Version { get { return 1; } }
+
+    public event EventHandler Changed;
+
+    public class Options
+    {
+    }
+
+    public static string Repeat(string text, int times = 2, string separator = null)
+    {
+        string[] parts = new string[times];
+        for (int i = 0; i < times; i++)
+        {
+            parts[i] = text;
+        }
+
+        return string.Join(separator ?? "", parts);
+    }
+
+    public static bool TryReadSecret(out int value, params string[] reasons)
+    {
+        value = secret;
+        return reasons.Length == 0;
+    }
+
+    public static async Task<int> AddAsync(int a, int b)
+    {
+        await Task.Delay(10);
+        return a + b;
+    }
+
+    public static async Task TouchAsync()
+    {
+        await Task.Yield();
+        Mode = "touched";
+    }
+
+    public static async Task<int> FailAsync()
+    {
+        await Task.Delay(10);
+        throw new InvalidOperationException("async failure");
+    }
+
+    private string Describe()
+    {
+        return Name + " #" + Counter;
+    }
+
+    [STAThread]
+    public static void Main()
+    {
+        Console.WriteLine(Mode + " " + secret);
+    }
+}
+
+// Plugin contract and implementations for the inheritance tests.
+public interface IPlugin
+{
+    string Run();
+}
+
+public abstract class PluginBase : IPlugin
+{
+    public abstract string Run();
+}
+
+public class EchoPlugin : PluginBase
+{
+    public override string Run()
+    {
+        return "echo";
+    }
+}
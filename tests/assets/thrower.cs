@@ -0,0 +1,16 @@
+using System;
+
+public static class Program
+{
+    public static void Main()
+    {
+        try
+        {
+            throw new InvalidOperationException("thrown by the test assembly");
+        }
+        catch (InvalidOperationException e)
+        {
+            Console.WriteLine("caught: " + e.Message);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+using System;
+using System.IO;
+using Microsoft.Win32;
+
+// Creates and deletes a temporary file and a registry key for the activity monitor tests.
+public static class Touch
+{
+    public static void Main()
+    {
+        string path = Path.Combine(Path.GetTempPath(), "rustclr-touch.txt");
+        File.WriteAllText(path, "touch");
+        File.Delete(path);
+
+        using (RegistryKey key = Registry.CurrentUser.CreateSubKey(@"Software\rustclr-touch"))
+        {
+            key.SetValue("Marker", 1);
+        }
+
+        Registry.CurrentUser.DeleteSubKey(@"Software\rustclr-touch");
+        Console.WriteLine("touched");
+    }
+}
@@ -0,0 +1,14 @@
+#define TRACE
+
+using System;
+using System.Diagnostics;
+
+// Writes to the console and to the trace listeners.
+public class Tracer
+{
+    public static void Main()
+    {
+        Console.WriteLine("console");
+        Trace.WriteLine("trace");
+    }
+}
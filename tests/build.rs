@@ -0,0 +1,86 @@
+//! Compiles the test assemblies in `assets/` with the compilers shipped with the .NET Framework.
+
+use std::{env, fs, path::{Path, PathBuf}, process::Command};
+
+/// Test libraries compiled from `assets/<name>` before the assemblies referencing them.
+const LIBRARIES: &[&str] = &["greeter.cs"];
+
+/// Test assemblies compiled from `assets/<name>`, named after the file stem.
+const ASSEMBLIES: &[&str] = &[
+    "hello.cs", "echo.cs", "thrower.cs", "exit.cs", "state.cs", "native.cs", "trace.cs",
+    "vbecho.vb", "fsentry.il", "globals.il", "packed.cs", "touch.cs", "service.cs",
+    "admin.cs",
+];
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set"));
+
+    for file in LIBRARIES {
+        build(file, &out_dir, "library", &[]);
+    }
+
+    // `packed.exe` carries `greeter.dll` as a Costura resource instead of next to it
+    let greeter = out_dir.join("greeter.dll");
+    let packed = [
+        format!("/reference:{}", greeter.display()),
+        format!("/resource:{},costura.greeter.dll", greeter.display()),
+    ];
+
+    // `admin.exe` embeds a manifest requesting administrator rights
+    println!("cargo:rerun-if-changed=assets/admin.manifest");
+    let admin = ["/win32manifest:assets/admin.manifest".to_string()];
+
+    for file in ASSEMBLIES {
+        let extra: &[String] = match *file {
+            "packed.cs" => &packed,
+            "admin.cs" => &admin,
+            _ => &[],
+        };
+
+        build(file, &out_dir, "exe", extra);
+    }
+}
+
+/// Compiles an asset into `OUT_DIR`, writing an empty placeholder if it fails.
+fn build(file: &str, out_dir: &Path, target: &str, extra: &[String]) {
+    let source = format!("assets/{file}");
+    let (name, extension) = file.split_once('.').expect("asset without extension");
+    let output = out_dir.join(format!("{name}.{}", if target == "library" { "dll" } else { "exe" }));
+    println!("cargo:rerun-if-changed={source}");
+
+    let compiled = compile(extension, &source, &output, target, extra);
+
+    // An empty file lets the crate build; the tests using it fail with a clear message
+    if !compiled {
+        println!("cargo:warning=could not compile {source}, tests using it will fail");
+        fs::write(&output, []).expect("failed to write placeholder assembly");
+    }
+}
+
+/// Compiles a source file with the compiler matching its extension.
+fn compile(extension: &str, source: &str, output: &Path, target: &str, extra: &[String]) -> bool {
+    let (tool, mut args) = match extension {
+        "cs" => ("csc.exe", vec!["/nologo".to_string(), format!("/target:{target}"), format!("/out:{}", output.display())]),
+        "vb" => ("vbc.exe", vec!["/nologo".to_string(), format!("/target:{target}"), format!("/out:{}", output.display())]),
+        "il" => ("ilasm.exe", vec!["/nologo".to_string(), "/quiet".to_string(), format!("/{}", if target == "library" { "dll" } else { "exe" }), format!("/output={}", output.display())]),
+        _ => return false,
+    };
+
+    args.extend_from_slice(extra);
+    find_tool(tool).is_some_and(|tool| {
+        Command::new(tool)
+            .args(args)
+            .arg(source)
+            .status()
+            .is_ok_and(|status| status.success())
+    })
+}
+
+/// Locates a compiler from the .NET Framework 4.x installation.
+fn find_tool(tool: &str) -> Option<PathBuf> {
+    let windir = env::var("WINDIR").ok()?;
+    ["Framework64", "Framework"]
+        .iter()
+        .map(|framework| PathBuf::from(&windir).join("Microsoft.NET").join(framework).join("v4.0.30319").join(tool))
+        .find(|path| path.exists())
+}
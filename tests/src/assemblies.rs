@@ -0,0 +1,51 @@
+//! Test assemblies compiled by `build.rs` from the sources in `assets/`.
+
+/// Prints `Hello, World!`.
+pub const HELLO: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/hello.exe"));
+
+/// Prints its arguments joined by a space.
+pub const ECHO: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/echo.exe"));
+
+/// Throws and catches an `InvalidOperationException`.
+pub const THROWER: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/thrower.exe"));
+
+/// Prints `exiting` and calls `Environment.Exit(3)`.
+pub const EXIT: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/exit.exe"));
+
+/// Declares public and private, static and instance fields and properties, an event and a nested
+/// `Options` type on a `State` class, and an `IPlugin` interface implemented by `PluginBase` and `EchoPlugin`.
+pub const STATE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/state.exe"));
+
+/// Declares P/Invoke imports of `kernel32!GetTickCount` and `user32!MessageBoxW`.
+pub const NATIVE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/native.exe"));
+
+/// Prints `console` and writes `trace` through `System.Diagnostics.Trace`.
+pub const TRACE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/trace.exe"));
+
+/// Prints `vb` and its arguments from the `Function Main(args)` of a VB.NET module.
+pub const VB_ECHO: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/vbecho.exe"));
+
+/// Prints `fsharp` from a parameterless `main@` entry point, like F# programs without `[<EntryPoint>]`.
+pub const FS_ENTRY: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/fsentry.exe"));
+
+/// Declares the module-level global methods `Greet(string)` (public) and `Secret()` (internal).
+pub const GLOBALS: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/globals.exe"));
+
+/// Prints `packed dependency` through `greeter.dll`, embedded as an uncompressed `costura.greeter.dll` resource.
+pub const PACKED: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/packed.exe"));
+
+/// Writes and deletes `%TEMP%\rustclr-touch.txt`, then creates `HKCU\Software\rustclr-touch`,
+/// sets its `Marker` value and deletes it.
+pub const TOUCH: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/touch.exe"));
+
+/// Prints `listening` and sleeps forever, printing `stopped` from a `finally` block when aborted.
+pub const SERVICE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/service.exe"));
+
+/// Prints `elevated`, with a manifest requesting `requireAdministrator`.
+pub const ADMIN: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/admin.exe"));
+
+/// Returns the assembly, failing the test if its compiler was not available at build time.
+pub fn load(assembly: &'static [u8]) -> &'static [u8] {
+    assert!(!assembly.is_empty(), "test assembly was not compiled, the .NET Framework compilers are required to build the tests");
+    assembly
+}
@@ -0,0 +1,1624 @@
+use {
+    std::{ffi::c_void, sync::{Arc, atomic::{AtomicUsize, Ordering}, mpsc::channel}, time::{Duration, Instant, SystemTime, UNIX_EPOCH}},
+    rustclr::{can_host, clr_bind, ActivityKind, ArtifactKind, ClrDate, ClrDecimal, ClrDelegate, ClrDisposable, ClrHandle, ClrObject, ConsoleMode, ControlSequences, ClrOutput, CapturedOutput, OutputEncoding, CrashDump, Dispatch, DumpTarget, DumpType, EmbeddedDependency, ExecutionLevel, ExecutionLimits, execution_limits, set_execution_limits, ExecutionEvent, FromVariant, GcRoot, InvocationType, ManagedName, pinvokes, probe_process, RustClr, RustClrEnv, RuntimeVersion, StartupFlags, Variant, VariantArgs, WinStr, Zone},
+    rustclr::{com::HostingApi, error::{ClrError, ErrorCode}, safearray::SafeArray, schema::{BindingFlags, Member, MethodQuery}},
+    crate::assemblies::{self, load},
+};
+
+#[test]
+fn test_run_hello() -> Result<(), Box<dyn std::error::Error>> {
+    let output = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "Hello, World!");
+
+    Ok(())
+}
+
+#[test]
+fn test_run_again() -> Result<(), Box<dyn std::error::Error>> {
+    for _ in 0..2 {
+        let output = RustClr::new(load(assemblies::HELLO))?
+            .with_runtime_version(RuntimeVersion::V4)
+            .with_output_redirection(true)
+            .run()?;
+
+        assert_eq!(output.trim_end(), "Hello, World!");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_selftest() -> Result<(), Box<dyn std::error::Error>> {
+    let report = rustclr::selftest();
+    assert!(report.passed(), "{report}");
+    assert!(report.steps.iter().any(|step| step.name == "domain unload"));
+
+    Ok(())
+}
+
+#[test]
+fn test_runtime_version_parse() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!("v4.0.30319".parse::<RuntimeVersion>()?, RuntimeVersion::V4);
+    assert_eq!(RuntimeVersion::try_from("v2.0.50727")?, RuntimeVersion::V2);
+    assert_eq!("4.8".parse::<RuntimeVersion>()?, RuntimeVersion::V4);
+    assert!(matches!("v5.0".parse::<RuntimeVersion>(), Err(ClrError::UnsupportedRuntimeVersion(_))));
+    assert!("v4.".parse::<RuntimeVersion>().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_echo_args() -> Result<(), Box<dyn std::error::Error>> {
+    let output = RustClr::new(load(assemblies::ECHO))?
+        .with_domain("EchoDomain")
+        .with_args(vec!["first", "second"])
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "first second");
+
+    Ok(())
+}
+
+#[test]
+fn test_safe_array_reader() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let hello = load(assemblies::HELLO);
+
+    let bytes = SafeArray::from_reader(std::io::Cursor::new(hello), hello.len())?;
+    let assembly = clr.app_domain.Load_3(bytes.as_ptr())?;
+    assert!(assembly.get_FullName()?.starts_with("hello,"));
+
+    // A reader ending early is reported instead of leaving the array partially filled
+    let truncated = SafeArray::from_reader(&hello[..16], hello.len());
+    assert!(matches!(truncated, Err(ClrError::IoError(_))));
+
+    // Large `string[]` arguments are written in place
+    let args = (0..10_000).map(|i| i.to_string()).collect::<Vec<_>>();
+    let output = RustClr::new(load(assemblies::ECHO))?
+        .with_args(args.iter().map(String::as_str).collect())
+        .with_output_redirection(true)
+        .run()?;
+    assert_eq!(output.trim_end(), args.join(" "));
+
+    Ok(())
+}
+
+#[test]
+fn test_exception_observer() -> Result<(), Box<dyn std::error::Error>> {
+    let (sender, receiver) = channel();
+    let output = RustClr::new(load(assemblies::THROWER))?
+        .with_output_redirection(true)
+        .with_exception_observer(sender)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "caught: thrown by the test assembly");
+    assert!(receiver.try_iter().any(|e| e.type_name == "System.InvalidOperationException"));
+
+    Ok(())
+}
+
+#[test]
+fn test_trace_capture() -> Result<(), Box<dyn std::error::Error>> {
+    let output = RustClr::new(load(assemblies::TRACE))?
+        .with_output_redirection(true)
+        .with_trace_capture(true)
+        .run()?;
+
+    assert_eq!(output, "console\r\ntrace\r\n");
+
+    // Without trace capture, only the console output is returned
+    let output = RustClr::new(load(assemblies::TRACE))?
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output, "console\r\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_execution_events() -> Result<(), Box<dyn std::error::Error>> {
+    let (sender, receiver) = channel();
+    RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_event_sender(sender)
+        .run()?;
+
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert_eq!(events.first(), Some(&ExecutionEvent::RuntimeStarted));
+    assert!(matches!(&events[1], ExecutionEvent::AssemblyLoaded { name } if name.starts_with("hello,")));
+    assert_eq!(&events[2..], &[
+        ExecutionEvent::EntryInvoked,
+        ExecutionEvent::EntryReturned,
+        ExecutionEvent::OutputCaptured { bytes: "Hello, World!\r\n".len() as u64 },
+        ExecutionEvent::Completed,
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_run_with_zone() -> Result<(), Box<dyn std::error::Error>> {
+    // Code Access Security is not enforced on 4.0+ by default, so the payload still runs
+    let output = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_domain("ZoneDomain")
+        .run_with_zone(Zone::Internet)?;
+
+    assert_eq!(output.trim_end(), "Hello, World!");
+
+    Ok(())
+}
+
+#[test]
+fn test_name_override() -> Result<(), Box<dyn std::error::Error>> {
+    assert_eq!(ManagedName::Console.value(), "System.Console");
+
+    // The full display name resolves to the same assembly, so concurrent tests are not affected
+    let full_name = "mscorlib, Version=4.0.0.0, Culture=neutral, PublicKeyToken=b77a5c561934e089";
+    rustclr::set_name_override(ManagedName::Mscorlib, full_name);
+    assert_eq!(ManagedName::Mscorlib.value(), full_name);
+
+    let output = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .run();
+
+    rustclr::clear_name_overrides();
+    assert_eq!(output?.trim_end(), "Hello, World!");
+    assert_eq!(ManagedName::Mscorlib.value(), "mscorlib");
+
+    Ok(())
+}
+
+#[test]
+fn test_alternate_entry_points() -> Result<(), Box<dyn std::error::Error>> {
+    let output = RustClr::new(load(assemblies::VB_ECHO))?
+        .with_args(vec!["from", "module"])
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "vb from module");
+
+    let output = RustClr::new(load(assemblies::FS_ENTRY))?
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "fsharp");
+
+    Ok(())
+}
+
+#[test]
+fn test_console_mode() -> Result<(), Box<dyn std::error::Error>> {
+    // The test process already has a console, so it is left untouched
+    let mut clr = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_console(ConsoleMode::Hidden);
+
+    assert_eq!(clr.run()?.trim_end(), "Hello, World!");
+    assert_eq!(clr.detection_report()?.of_kind(ArtifactKind::Process).count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_thread_pool_limits() -> Result<(), Box<dyn std::error::Error>> {
+    let workers = std::thread::available_parallelism()?.get() as i32 * 4;
+    RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_min_threads(2, 2)
+        .with_max_threads(workers, 64)
+        .run()?;
+
+    let clr = RustClrEnv::new(None)?;
+    let get_max_threads = clr.app_domain
+        .load_lib("mscorlib")?
+        .resolve_type("System.Threading.ThreadPool")?
+        .method_signature("Void GetMaxThreads(Int32 ByRef, Int32 ByRef)")?;
+
+    let (_, args) = get_max_threads.invoke_ref(None, vec![0.to_variant(), 0.to_variant()])?;
+    assert_eq!(unsafe { args[0].Anonymous.Anonymous.Anonymous.lVal }, workers);
+    assert_eq!(unsafe { args[1].Anonymous.Anonymous.Anonymous.lVal }, 64);
+
+    // A maximum below the number of processors is rejected
+    let result = RustClr::new(load(assemblies::HELLO))?.with_max_threads(0, 0).run();
+    assert!(matches!(result, Err(ClrError::ErrorClr(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_execution_limits() -> Result<(), Box<dyn std::error::Error>> {
+    let limits = ExecutionLimits { max_concurrent: 1, min_interval: Duration::from_millis(200) };
+    set_execution_limits(limits);
+    assert_eq!(execution_limits(), limits);
+
+    let start = Instant::now();
+    let runs = (0..2)
+        .map(|_| std::thread::spawn(|| RustClr::new(load(assemblies::HELLO))?.with_output_redirection(true).run()))
+        .collect::<Vec<_>>();
+
+    let outputs = runs.into_iter().map(|run| run.join().expect("run panicked")).collect::<Vec<_>>();
+    set_execution_limits(ExecutionLimits::UNLIMITED);
+
+    for output in outputs {
+        assert_eq!(output?.trim_end(), "Hello, World!");
+    }
+
+    // The second run waited for the minimum interval
+    assert!(start.elapsed() >= limits.min_interval);
+
+    Ok(())
+}
+
+#[test]
+fn test_soft_unload() -> Result<(), Box<dyn std::error::Error>> {
+    let mut clr = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_soft_unload(true);
+
+    assert_eq!(clr.run()?.trim_end(), "Hello, World!");
+    assert_eq!(clr.run()?.trim_end(), "Hello, World!");
+
+    let mut clr = clr.with_assembly(load(assemblies::ECHO))?.with_args(vec!["again"]);
+    assert_eq!(clr.run()?.trim_end(), "again");
+
+    // The domain of the last run is gone
+    assert!(clr.gc_stats().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_crash_dump() -> Result<(), Box<dyn std::error::Error>> {
+    let (sender, receiver) = channel();
+    let dump = CrashDump::new(DumpTarget::Channel(sender)).with_type(DumpType::WITH_THREAD_INFO);
+
+    // A successful run does not write the dump
+    let output = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_crash_dump(dump.clone())
+        .run()?;
+
+    assert_eq!(output.trim_end(), "Hello, World!");
+    assert!(receiver.try_recv().is_err());
+
+    dump.write()?;
+    assert!(receiver.try_recv()?.starts_with(b"MDMP"));
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "Environment.Exit terminates the test process unless it is patched"]
+fn test_exit() -> Result<(), Box<dyn std::error::Error>> {
+    let output = RustClr::new(load(assemblies::EXIT))?
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "exiting");
+
+    Ok(())
+}
+
+#[test]
+fn test_inspect_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let metadata = RustClr::new(load(assemblies::HELLO))?.inspect()?;
+    let entry_point = metadata.entry_point.ok_or("missing entry point")?;
+
+    assert_eq!(entry_point.type_name, "Program");
+    assert_eq!(entry_point.method_name, "Main");
+    assert_eq!(entry_point.token >> 24, 0x06);
+    assert!(metadata.runtime_version.starts_with("v4."));
+    assert!(metadata.types.iter().any(|ty| ty.full_name() == "Program"));
+
+    Ok(())
+}
+
+#[test]
+fn test_requires_elevation() -> Result<(), Box<dyn std::error::Error>> {
+    // `csc` embeds an `asInvoker` manifest unless told otherwise
+    assert_eq!(RustClr::new(load(assemblies::HELLO))?.inspect()?.execution_level, Some(ExecutionLevel::AsInvoker));
+    assert_eq!(RustClr::new(load(assemblies::ADMIN))?.inspect()?.execution_level, Some(ExecutionLevel::RequireAdministrator));
+
+    let utf16 = [0xFF, 0xFE].into_iter()
+        .chain("<requestedExecutionLevel level = 'highestAvailable'/>".encode_utf16().flat_map(u16::to_le_bytes))
+        .collect::<Vec<u8>>();
+    assert_eq!(ExecutionLevel::from_manifest(&utf16), Some(ExecutionLevel::HighestAvailable));
+
+    match RustClr::new(load(assemblies::ADMIN))?.with_output_redirection(true).run() {
+        Ok(output) => assert!(output.contains("elevated")),
+        Err(ClrError::RequiresElevation) => {}
+        Err(error) => return Err(error.into()),
+    }
+
+    let output = RustClr::new(load(assemblies::ADMIN))?
+        .with_output_redirection(true)
+        .with_elevation_check(false)
+        .run()?;
+    assert!(output.contains("elevated"));
+
+    Ok(())
+}
+
+#[test]
+fn test_payload_validation() -> Result<(), Box<dyn std::error::Error>> {
+    assert!(matches!(RustClr::new(&[0u8; 64]), Err(ClrError::InvalidExecutable)));
+
+    // The test harness itself is a native executable
+    let native = std::fs::read(std::env::current_exe()?)?;
+    assert!(matches!(RustClr::new(&native), Err(ClrError::NotDotNet)));
+
+    // The metadata is read once and served to every caller
+    let clr = RustClr::new(load(assemblies::HELLO))?;
+    assert_eq!(clr.inspect()?, clr.inspect()?);
+    assert!(clr.detection_report()?.to_string().contains("hello"));
+
+    Ok(())
+}
+
+#[test]
+fn test_malformed_headers() -> Result<(), Box<dyn std::error::Error>> {
+    let image = load(assemblies::HELLO).to_vec();
+    let nt = u32::from_le_bytes(image[0x3C..0x40].try_into()?) as usize;
+    let optional = nt + 24;
+    let optional_size = u16::from_le_bytes([image[nt + 20], image[nt + 21]]) as usize;
+    let patched = |offset: usize, bytes: &[u8]| {
+        let mut image = image.clone();
+        image[offset..offset + bytes.len()].copy_from_slice(bytes);
+        image
+    };
+
+    let invalid = |image: &[u8]| matches!(RustClr::new(image), Err(ClrError::InvalidExecutable));
+
+    // Cut inside the DOS, file, optional and section headers
+    for len in [1, 0x3E, nt + 2, nt + 21, optional + 2, optional + 70, optional + optional_size + 8] {
+        assert!(invalid(&image[..len]), "truncated at {len}");
+    }
+
+    // `e_lfanew` past the end of the buffer, or pointing at a non-NT header
+    for lfanew in [image.len() as u32, image.len() as u32 - 2, u32::MAX, 0] {
+        assert!(invalid(&patched(0x3C, &lfanew.to_le_bytes())), "e_lfanew {lfanew:#x}");
+    }
+
+    // csc builds AnyCPU images as PE32, whose 16 directories end exactly with the optional
+    // header; read as PE32+ they would start 16 bytes further, past its end
+    assert_eq!(u16::from_le_bytes([image[optional], image[optional + 1]]), 0x10B);
+    assert!(invalid(&patched(optional, &0x20Bu16.to_le_bytes())));
+    assert!(invalid(&patched(optional, &0x107u16.to_le_bytes())));
+
+    // Directory counts are capped at 16, but must fit in the declared optional header
+    assert!(RustClr::new(&patched(optional + 92, &u32::MAX.to_le_bytes())).is_ok());
+    assert!(invalid(&patched(nt + 20, &(96u16 + 8).to_le_bytes())));
+    assert!(matches!(RustClr::new(&patched(optional + 92, &0u32.to_le_bytes())), Err(ClrError::NotDotNet)));
+
+    Ok(())
+}
+
+#[test]
+fn test_probe_process() -> Result<(), Box<dyn std::error::Error>> {
+    let _clr = RustClrEnv::new(None)?;
+    let versions = probe_process(std::process::id())?;
+    assert!(versions.iter().any(|version| version.starts_with("v4.")));
+
+    Ok(())
+}
+
+#[test]
+fn test_load_runtime_dll() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let jit = clr.runtime_info.load_runtime_dll("clrjit.dll")?;
+
+    assert_eq!(jit.name(), "clrjit.dll");
+    assert!(!jit.proc_address("getJit")?.is_null());
+    assert!(jit.proc_address("missingExport").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_runtime_details() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let details = clr.runtime_details()?;
+
+    assert_eq!(details.version, "v4.0.30319");
+    assert!(details.directory.to_lowercase().ends_with(r"4.0.30319"));
+    assert!(details.started);
+    assert_eq!(details.config_file, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_attach_profiler_missing_dll() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let profiler = windows_core::GUID::from_u128(0x8c29bc4e_1f57_461a_9b51_1200c32e6f1f);
+    let result = clr.attach_profiler(u32::MAX, Duration::from_secs(1), &profiler, r"C:\missing\profiler.dll", &[]);
+    assert!(matches!(result, Err(ClrError::ApiError("AttachProfiler", _))));
+
+    Ok(())
+}
+
+#[test]
+fn test_detection_report() -> Result<(), Box<dyn std::error::Error>> {
+    let report = RustClr::new(load(assemblies::HELLO))?
+        .with_domain("ReportDomain")
+        .enable_tls12()
+        .detection_report()?;
+
+    assert!(report.of_kind(ArtifactKind::AppDomain).any(|artifact| artifact.description.contains("ReportDomain")));
+    assert!(report.of_kind(ArtifactKind::Module).any(|artifact| artifact.description.starts_with("clr.dll")));
+    assert!(report.of_kind(ArtifactKind::Assembly).any(|artifact| artifact.description.starts_with("System ")));
+    assert_eq!(report.of_kind(ArtifactKind::File).count(), 0);
+
+    Ok(())
+}
+
+clr_bind! {
+    class System.Console {
+        fn WriteLine(value: &str);
+    }
+}
+
+#[test]
+fn test_clr_bind() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    let mut output = ClrOutput::new(&mscorlib);
+    output.redirect()?;
+    Console::resolve(&clr.app_domain)?.WriteLine("Hello from clr_bind")?;
+    output.restore()?;
+
+    assert_eq!(output.capture()?.trim_end(), "Hello from clr_bind");
+
+    Ok(())
+}
+
+#[test]
+fn test_handle_release() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    let handle: ClrHandle = ClrHandle::register(mscorlib.create_instance("System.Text.StringBuilder")?);
+    assert!(handle.is_alive());
+    handle.get()?;
+
+    handle.release()?;
+    assert!(!handle.is_alive());
+    assert!(handle.get().is_err());
+    assert!(handle.release().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_gc_root() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let gc = mscorlib.resolve_type("System.GC")?;
+    let builder = mscorlib.resolve_type("System.Text.StringBuilder")?;
+
+    let root = GcRoot::new(&clr.app_domain, mscorlib.create_instance("System.Text.StringBuilder")?)?;
+    gc.invoke("Collect", None, None, InvocationType::Static)?;
+    gc.invoke("WaitForPendingFinalizers", None, None, InvocationType::Static)?;
+
+    builder.invoke("Append", Some(root.object()?), Some(vec!["rooted".to_variant()]), InvocationType::Instance)?;
+    assert!(root.function_pointer().is_err());
+
+    root.release()?;
+    Ok(())
+}
+
+#[test]
+fn test_field_access() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let state = assembly.resolve_type("State")?;
+
+    let mode = state.field("Mode")?;
+    assert_eq!(unsafe { mode.get_value(None)?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "default");
+    mode.set_value(None, "changed".to_variant())?;
+    assert_eq!(unsafe { mode.get_value(None)?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "changed");
+
+    let instance = assembly.create_instance("State")?;
+    let counter = state.field("Counter")?;
+    counter.set_value(Some(instance), 5.to_variant())?;
+    assert_eq!(unsafe { counter.get_value(Some(instance))?.Anonymous.Anonymous.Anonymous.lVal }, 5);
+    assert!(!counter.get_IsStatic()?);
+
+    assert!(state.field("secret").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_fields_with_flags() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let state = assembly.resolve_type("State")?;
+
+    let public = state.fields()?;
+    assert!(public.iter().any(|(name, _)| name == "Mode"));
+    assert!(!public.iter().any(|(name, _)| name == "secret"));
+
+    let statics = state.fields_with_flags(BindingFlags::NonPublic | BindingFlags::Public | BindingFlags::Static)?;
+    let (_, secret) = statics.iter().find(|(name, _)| name == "secret").ok_or("missing secret")?;
+    assert_eq!(unsafe { secret.get_value(None)?.Anonymous.Anonymous.Anonymous.lVal }, 42);
+    assert!(!statics.iter().any(|(name, _)| name == "Counter"));
+
+    state.field_with_flags("secret", BindingFlags::NonPublic | BindingFlags::Static)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_constructor_with_args() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let version = mscorlib.resolve_type("System.Version")?;
+
+    let instance = version.new_instance(vec![1.to_variant(), 2.to_variant()])?;
+    let text = version.invoke("ToString", Some(instance), None, InvocationType::Instance)?;
+    assert_eq!(unsafe { text.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "1.2");
+
+    let constructor = version.constructor(3)?;
+    assert_eq!(constructor.ToString()?, "Void .ctor(Int32, Int32, Int32)");
+
+    let instance = constructor.invoke(vec![1.to_variant(), 2.to_variant(), 3.to_variant()])?;
+    let text = version.invoke("ToString", Some(instance), None, InvocationType::Instance)?;
+    assert_eq!(unsafe { text.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "1.2.3");
+
+    Ok(())
+}
+
+#[test]
+fn test_create_instance_with_args() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let version = mscorlib.resolve_type("System.Version")?;
+
+    let instance = mscorlib.create_instance_with_args("System.Version", vec!["4.8.1".to_variant()])?;
+    let text = version.invoke("ToString", Some(instance), None, InvocationType::Instance)?;
+    assert_eq!(unsafe { text.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "4.8.1");
+
+    let missing = mscorlib.create_instance_with_args("System.Version", vec![true.to_variant()]);
+    assert!(matches!(missing, Err(ClrError::CreateInstanceError(..))));
+
+    Ok(())
+}
+
+#[test]
+fn test_metadata_import() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let import = clr.metadata_import(load(assemblies::NATIVE))?;
+
+    let native = import.type_defs()?
+        .into_iter()
+        .find(|&ty| import.type_def_name(ty).is_ok_and(|name| name == "Native"))
+        .ok_or("missing Native")?;
+
+    let mut imports = Vec::new();
+    for method in import.methods(native)? {
+        if let Some(map) = import.pinvoke_map(method)? {
+            imports.push((import.method_name(method)?, map));
+        }
+    }
+
+    assert!(imports.contains(&("GetTickCount".into(), ("kernel32.dll".into(), "GetTickCount".into()))));
+    assert!(imports.contains(&("ShowMessage".into(), ("user32.dll".into(), "MessageBoxW".into()))));
+    assert_eq!(imports.len(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_pinvokes() -> Result<(), Box<dyn std::error::Error>> {
+    let imports = pinvokes(load(assemblies::NATIVE))?;
+    assert_eq!(imports, vec![
+        ("kernel32.dll".to_string(), "GetTickCount".to_string()),
+        ("user32.dll".to_string(), "MessageBoxW".to_string()),
+    ]);
+
+    Ok(())
+}
+
+#[test]
+fn test_domain_search() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    clr.app_domain.load_assembly(load(assemblies::STATE))?;
+
+    let matches = clr.app_domain.search("Stat?", "*ain")?;
+    assert_eq!(matches.len(), 1);
+
+    let (assembly, ty, method) = &matches[0];
+    assert!(assembly.starts_with("state,"));
+    assert_eq!((ty.as_str(), method.as_str()), ("State", "Main"));
+
+    assert!(clr.app_domain.search("*.Version", "*").is_ok_and(|matches| !matches.is_empty()));
+    assert!(clr.app_domain.search("State", "Run*")?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_method_parameters() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let state = assembly.resolve_type("State")?;
+
+    let parameters = state.method("Repeat")?.parameters()?;
+    let summary = parameters.iter()
+        .map(|p| (p.name.as_str(), p.position, p.default_value.is_some()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(summary, vec![("text", 0, false), ("times", 1, true), ("separator", 2, true)]);
+    assert_eq!(parameters[0].parameter_type.ToString()?, "System.String");
+    assert_eq!(unsafe { parameters[1].default_value.ok_or("missing default")?.Anonymous.Anonymous.Anonymous.lVal }, 2);
+
+    let parameters = state.method("TryReadSecret")?.parameters()?;
+    assert!(parameters[0].is_out && !parameters[0].is_params);
+    assert_eq!(parameters[0].parameter_type.ToString()?, "System.Int32&");
+    assert!(parameters[1].is_params && !parameters[1].is_out);
+
+    Ok(())
+}
+
+#[test]
+fn test_make_generic_method() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let activator = mscorlib.resolve_type("System.Activator")?;
+    let string_builder = mscorlib.resolve_type("System.Text.StringBuilder")?;
+
+    let create = activator.method_signature("T CreateInstance[T]()")?;
+    let method = create.make_generic_method(std::slice::from_ref(&string_builder))?;
+    assert!(method.ToString()?.contains("StringBuilder]"));
+
+    let builder = method.invoke(None, None)?;
+    let text = string_builder.invoke("ToString", Some(builder), None, InvocationType::Instance)?;
+    assert_eq!(unsafe { text.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "");
+
+    assert!(create.make_generic_method(&[]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_invoke_ref() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let try_parse = mscorlib.resolve_type("System.Int32")?
+        .method_signature("Boolean TryParse(System.String, Int32 ByRef)")?;
+
+    let (result, args) = try_parse.invoke_ref(None, vec!["42".to_variant(), 0.to_variant()])?;
+    assert_eq!(unsafe { result.Anonymous.Anonymous.Anonymous.boolVal }, -1);
+    assert_eq!(unsafe { args[1].Anonymous.Anonymous.Anonymous.lVal }, 42);
+
+    let (result, _) = try_parse.invoke_ref(None, vec!["not a number".to_variant(), 0.to_variant()])?;
+    assert_eq!(unsafe { result.Anonymous.Anonymous.Anonymous.boolVal }, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_custom_attributes() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let state = assembly.resolve_type("State")?;
+
+    let attributes = state.custom_attributes(false)?;
+    let (_, display) = attributes
+        .iter()
+        .find(|(name, _)| name == "System.Diagnostics.DebuggerDisplayAttribute")
+        .ok_or("missing DebuggerDisplay")?;
+
+    assert_eq!(display.call("get_Value", ())?.as_string().as_deref(), Some("{Name}"));
+
+    let sta_thread = clr.app_domain.load_lib("mscorlib")?.resolve_type("System.STAThreadAttribute")?;
+    let main = state.method("Main")?;
+    assert!(main.is_defined(&sta_thread, false)?);
+    assert!(main.custom_attributes(false)?.iter().any(|(name, _)| name == "System.STAThreadAttribute"));
+    assert!(!state.method("Repeat")?.is_defined(&sta_thread, false)?);
+
+    let attributes = assembly.custom_attributes()?;
+    assert!(attributes.iter().any(|(name, _)| name == "System.Runtime.CompilerServices.RuntimeCompatibilityAttribute"));
+
+    Ok(())
+}
+
+#[test]
+fn test_enum_marshaling() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    let day_of_week = mscorlib.resolve_type("System.DayOfWeek")?;
+    let friday = day_of_week.enum_from_name("Friday")?;
+    assert_eq!(unsafe { friday.Anonymous.Anonymous.Anonymous.lVal }, 5);
+    assert_eq!(unsafe { day_of_week.enum_from_value(5)?.Anonymous.Anonymous.Anonymous.lVal }, 5);
+    assert!(day_of_week.enum_from_name("Someday").is_err());
+
+    let comparison = mscorlib.resolve_type("System.StringComparison")?.enum_from_name("OrdinalIgnoreCase")?;
+    let equals = mscorlib.resolve_type("System.String")?.invoke(
+        "Equals",
+        None,
+        Some(vec!["rustclr".to_variant(), "RUSTCLR".to_variant(), comparison]),
+        InvocationType::Static
+    )?;
+    assert_ne!(unsafe { equals.Anonymous.Anonymous.Anonymous.boolVal }, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_assembly_metadata_accessors() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+
+    assert!(assembly.get_FullName()?.starts_with("state, Version="));
+    assert!(assembly.entry_point()?.is_some_and(|main| main.ToString().is_ok_and(|name| name.ends_with("Main()"))));
+    assert!(assembly.get_ImageRuntimeVersion()?.starts_with('v'));
+    assert_eq!(assembly.runtime_version()?, RuntimeVersion::V4);
+
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    assert!(mscorlib.entry_point()?.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_global_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::GLOBALS))?;
+
+    assert_eq!(assembly.modules()?.len(), 1);
+
+    let methods = assembly.global_methods()?;
+    assert!(methods.iter().any(|(signature, _)| signature == "System.String Greet(System.String)"));
+    assert!(methods.iter().any(|(signature, _)| signature == "Int32 Secret()"));
+
+    let greet = assembly.global_method("Greet")?;
+    let result = greet.invoke_params(None, vec!["module".to_variant()])?;
+    assert_eq!(unsafe { result.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "hello module");
+
+    let secret = assembly.global_method("Secret")?.invoke_params(None, vec![])?;
+    assert_eq!(unsafe { secret.Anonymous.Anonymous.Anonymous.lVal }, 42);
+    assert!(matches!(assembly.global_method("Missing"), Err(ClrError::MethodNotFound)));
+
+    Ok(())
+}
+
+#[test]
+fn test_embedded_dependencies() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::PACKED))?;
+
+    let dependencies = assembly.embedded_dependencies()?;
+    assert_eq!(dependencies, vec![EmbeddedDependency {
+        resource: "costura.greeter.dll".to_string(),
+        file_name: "greeter.dll".to_string(),
+        compressed: false,
+    }]);
+
+    assert!(EmbeddedDependency::from_resource("costura.newtonsoft.json.dll.compressed").is_some_and(|dependency| dependency.compressed));
+    assert!(EmbeddedDependency::from_resource("costura64.sqlite.interop.dll").is_none());
+    assert!(dependencies[0].extract(&clr.app_domain, &assembly)?.starts_with(b"MZ"));
+
+    // Without the option the reference cannot be bound
+    assert!(RustClr::new(load(assemblies::PACKED))?.with_output_redirection(true).run().is_err());
+
+    let (sender, receiver) = channel();
+    let output = RustClr::new(load(assemblies::PACKED))?
+        .with_output_redirection(true)
+        .with_embedded_dependencies(true)
+        .with_event_sender(sender)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "packed dependency");
+    assert!(receiver.try_iter().any(|event| matches!(event, ExecutionEvent::DependencyLoaded { name } if name.starts_with("greeter,"))));
+
+    Ok(())
+}
+
+#[test]
+fn test_type_inheritance() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let plugin = assembly.resolve_type("IPlugin")?;
+    let base = assembly.resolve_type("PluginBase")?;
+    let echo = assembly.resolve_type("EchoPlugin")?;
+
+    let interfaces = echo.interfaces()?;
+    assert_eq!(interfaces.len(), 1);
+    assert_eq!(interfaces[0].ToString()?, "IPlugin");
+
+    assert!(plugin.is_assignable_from(&echo)? && plugin.is_assignable_from(&plugin)?);
+    assert!(!echo.is_assignable_from(&plugin)?);
+    assert!(echo.is_subclass_of(&base)? && !echo.is_subclass_of(&plugin)?);
+    assert!(echo.implements(&plugin)? && !plugin.implements(&plugin)?);
+
+    let mut implementations = assembly
+        .implementations(&plugin)?
+        .iter()
+        .map(|ty| ty.ToString())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    implementations.sort();
+    assert_eq!(implementations, ["EchoPlugin", "PluginBase"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_static_fields() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let state = clr.app_domain.load_assembly(load(assemblies::STATE))?.resolve_type("State")?;
+
+    assert_eq!(unsafe { state.get_static("secret")?.Anonymous.Anonymous.Anonymous.lVal }, 42);
+    state.set_static("secret", 7.to_variant())?;
+    assert_eq!(unsafe { state.get_static("secret")?.Anonymous.Anonymous.Anonymous.lVal }, 7);
+
+    state.set_static("Mode", "patched".to_variant())?;
+    assert_eq!(unsafe { state.get_static("Mode")?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "patched");
+
+    // Instance fields and missing fields are not matched
+    assert!(state.get_static("Counter").is_err());
+    assert!(state.set_static("Missing", 1.to_variant()).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[ignore = "requires administrator"]
+fn test_activity_monitor() -> Result<(), Box<dyn std::error::Error>> {
+    let mut clr = RustClr::new(load(assemblies::TOUCH))?
+        .with_output_redirection(true)
+        .with_activity_monitor(true);
+
+    assert_eq!(clr.run()?.trim_end(), "touched");
+
+    let activity = clr.activity().ok_or("no activity recorded")?;
+    assert!(activity.of_kind(ActivityKind::FileDelete).any(|a| a.path.ends_with("rustclr-touch.txt")));
+    assert!(activity.of_kind(ActivityKind::RegistrySetValue).any(|a| a.value.as_deref() == Some("Marker")));
+    assert!(activity.to_string().contains("rustclr-touch"));
+
+    Ok(())
+}
+
+#[test]
+fn test_clr_disposable() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    // Disposed on drop, the other references see a disposed writer
+    let writer = ClrDisposable::new(mscorlib.new("System.IO.StringWriter")?)?;
+    let copy = writer.clone();
+    writer.call("Write", "before")?;
+    drop(writer);
+    assert!(copy.call("Write", "after").is_err());
+
+    let stream = ClrDisposable::new(mscorlib.new("System.IO.MemoryStream")?)?;
+    let copy = stream.clone();
+    stream.dispose()?;
+    assert!(copy.get("Length").is_err());
+
+    assert!(ClrDisposable::new(mscorlib.new("System.Text.StringBuilder")?).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_run_service() -> Result<(), Box<dyn std::error::Error>> {
+    let mut clr = RustClr::new(load(assemblies::SERVICE))?.with_output_redirection(true);
+    let service = clr.run_service()?;
+
+    let start = Instant::now();
+    while !service.output_so_far()?.contains("listening") {
+        assert!(start.elapsed() < Duration::from_secs(10), "the service did not start");
+        std::thread::sleep(Duration::from_millis(50));
+    }
+
+    assert!(!service.is_finished());
+
+    // The `finally` block of the payload has run once `stop` returns
+    let output = service.stop()?;
+    assert_eq!(output.lines().collect::<Vec<_>>(), ["listening", "stopped"]);
+
+    // The console is restored and the runtime can run other assemblies
+    let output = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .run()?;
+
+    assert_eq!(output.trim_end(), "Hello, World!");
+
+    Ok(())
+}
+
+#[test]
+fn test_invoke_async() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let state = clr.app_domain.load_assembly(load(assemblies::STATE))?.resolve_type("State")?;
+
+    let sum = state.invoke_async("AddAsync", None, Some(vec![2.to_variant(), 3.to_variant()]), InvocationType::Static)?;
+    assert_eq!(unsafe { sum.Anonymous.Anonymous.Anonymous.lVal }, 5);
+
+    // `async Task` methods complete without a result
+    let touched = state.method("TouchAsync")?.invoke_async(None, None)?;
+    assert_eq!(unsafe { touched.Anonymous.Anonymous.vt }, 0);
+    assert_eq!(unsafe { state.get_static("Mode")?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "touched");
+
+    assert!(state.invoke_async("FailAsync", None, None, InvocationType::Static).is_err());
+    assert!(state.invoke_async("Repeat", None, Some(vec!["x".to_variant()]), InvocationType::Static).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_find_methods() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let state = clr.app_domain.load_assembly(load(assemblies::STATE))?.resolve_type("State")?;
+    let names = |query: MethodQuery| -> Result<Vec<String>, ClrError> {
+        let mut names = state.find_methods(&query)?.iter().map(|method| method.get_name()).collect::<Result<Vec<_>, _>>()?;
+        names.sort();
+        Ok(names)
+    };
+
+    assert_eq!(names(MethodQuery::new().with_name("*Async"))?, ["AddAsync", "FailAsync", "TouchAsync"]);
+    assert_eq!(names(MethodQuery::new().with_name("*Async").with_arity(2))?, ["AddAsync"]);
+    assert_eq!(names(MethodQuery::new().with_name("Re*").with_return_type("System.String"))?, ["Repeat"]);
+    assert_eq!(names(MethodQuery::new().with_signature("Boolean *(Int32 ByRef, *)"))?, ["TryReadSecret"]);
+
+    // Non-public methods are searched too
+    assert_eq!(names(MethodQuery::new().with_name("Desc?ibe").with_arity(0))?, ["Describe"]);
+    assert!(names(MethodQuery::new().with_name("*Async").with_return_type("System.Void"))?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_clr_object() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+
+    let state: ClrObject = assembly.new("State")?;
+    state.set("Name", "fluent")?;
+    assert_eq!(state.get("Name")?.as_string().as_deref(), Some("fluent"));
+    assert!(state.set("Missing", 1).is_err());
+
+    // Clones hold their own reference to the same object
+    let copy = state.clone();
+    drop(state);
+    assert_eq!(copy.get("Name")?.value::<String>()?, "fluent");
+
+    let builder = clr.app_domain.load_lib("mscorlib")?.new("System.Text.StringBuilder")?;
+    builder.set("Capacity", 64)?;
+    assert!(builder.get("Capacity")?.value::<i32>()? >= 64);
+
+    Ok(())
+}
+
+#[test]
+fn test_indexers() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    let list = mscorlib.new("System.Collections.ArrayList")?;
+    list.call("Add", "first")?;
+    list.call("Add", "second")?;
+    list.set_item(1, "replaced")?;
+    assert_eq!(list.item(1)?.as_string().as_deref(), Some("replaced"));
+    assert!(list.item(5).is_err());
+
+    let dictionary = mscorlib.new("System.Collections.Generic.Dictionary`2[System.String,System.Int32]")?;
+    dictionary.set_item("answer", 42)?;
+    assert_eq!(dictionary.item("answer")?.value::<i32>()?, 42);
+    assert!(dictionary.item("missing").is_err());
+
+    let properties = mscorlib.resolve_type("System.Collections.ArrayList")?.properties()?;
+    let (_, item) = properties.iter().find(|(name, _)| name == "Item").ok_or("missing Item")?;
+    item.set_indexed(list.variant(), "direct".to_variant(), vec![0.to_variant()])?;
+    assert_eq!(unsafe { item.get_indexed(list.variant(), vec![0.to_variant()])?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "direct");
+
+    Ok(())
+}
+
+#[test]
+fn test_properties() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let properties = assembly.resolve_type("State")?.properties()?;
+
+    let (_, version) = properties.iter().find(|(name, _)| name == "Version").ok_or("missing Version")?;
+    assert!(version.get_CanRead()? && !version.get_CanWrite()?);
+    assert_eq!(unsafe { version.get_value(None)?.Anonymous.Anonymous.Anonymous.lVal }, 1);
+
+    let (_, name) = properties.iter().find(|(name, _)| name == "Name").ok_or("missing Name")?;
+    let instance = assembly.create_instance("State")?;
+    name.set_value(Some(instance), "rustclr".to_variant())?;
+    assert_eq!(unsafe { name.get_value(Some(instance))?.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "rustclr");
+
+    Ok(())
+}
+
+#[test]
+fn test_members() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let state = clr.app_domain.load_assembly(load(assemblies::STATE))?.resolve_type("State")?;
+    let members = state.members()?;
+    let has = |signature: &str, kind: fn(&Member) -> bool| {
+        members.iter().any(|(name, member)| name == signature && kind(member))
+    };
+
+    assert!(has("Void Main()", |member| matches!(member, Member::Method(_))));
+    assert!(has("Void .ctor()", |member| matches!(member, Member::Constructor(_))));
+    assert!(has("System.String Name", |member| matches!(member, Member::Property(_))));
+    assert!(has("Int32 Counter", |member| matches!(member, Member::Field(_))));
+    assert!(has("System.EventHandler Changed", |member| matches!(member, Member::Event(_))));
+    assert!(has("State+Options", |member| matches!(member, Member::NestedType(_))));
+    assert!(!members.iter().any(|(name, _)| name.ends_with(" secret")));
+
+    let all = state.members_with_flags(BindingFlags::NonPublic | BindingFlags::Static)?;
+    assert!(all.iter().any(|(name, member)| name == "Int32 secret" && matches!(member, Member::Field(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_event_subscription() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let event = clr.app_domain.GetType()?.event("AssemblyLoad")?;
+    assert_eq!(event.get_name()?, "AssemblyLoad");
+
+    let loads = Arc::new(AtomicUsize::new(0));
+    let counter = loads.clone();
+    let subscription = event.subscribe(Some(clr.app_domain.to_variant()), move |_, _| {
+        counter.fetch_add(1, Ordering::SeqCst);
+    })?;
+
+    clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    assert!(loads.load(Ordering::SeqCst) >= 1);
+
+    // Once unsubscribed, further loads are not reported
+    subscription.unsubscribe()?;
+    let count = loads.load(Ordering::SeqCst);
+    clr.app_domain.load_assembly(load(assemblies::ECHO))?;
+    assert_eq!(loads.load(Ordering::SeqCst), count);
+
+    Ok(())
+}
+
+/// Number of calls made to `native_callback` by managed code.
+static NATIVE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+extern "system" fn native_callback() {
+    NATIVE_CALLS.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn test_clr_delegate() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let thread_start = clr.app_domain.load_lib("mscorlib")?.resolve_type("System.Threading.ThreadStart")?;
+
+    let delegate = ClrDelegate::from_fn(&clr.app_domain, &thread_start, native_callback as *const c_void)?;
+    delegate.invoke(vec![])?;
+    assert_eq!(NATIVE_CALLS.load(Ordering::SeqCst), 1);
+
+    let closure = delegate.into_closure();
+    closure(vec![])?;
+    assert_eq!(NATIVE_CALLS.load(Ordering::SeqCst), 2);
+
+    Ok(())
+}
+
+#[test]
+fn test_instance_chaining() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    let text = mscorlib.new("System.Text.StringBuilder")?
+        .call("Append", "Hello, ")?
+        .call("Append", "World!")?
+        .call("ToString", ())?
+        .as_string();
+
+    assert_eq!(text.as_deref(), Some("Hello, World!"));
+
+    Ok(())
+}
+
+#[test]
+fn test_invoke_params() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let string = mscorlib.resolve_type("System.String")?;
+    let concat = string.method_signature("System.String Concat(System.Object[])")?;
+
+    let result = concat.invoke_params(None, vec!["a".to_variant(), 1.to_variant(), true.to_variant()])?;
+    assert_eq!(unsafe { result.Anonymous.Anonymous.Anonymous.bstrVal }.to_string(), "a1True");
+
+    Ok(())
+}
+
+#[test]
+fn test_invoke_non_public() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let environment = clr.app_domain.load_lib("mscorlib")?.resolve_type("System.Environment")?;
+
+    // `Environment.GetResourceString(string)` is internal
+    let args = vec!["Arg_NullReferenceException".to_variant()];
+    assert!(environment.invoke("GetResourceString", None, Some(args.clone()), InvocationType::Static).is_err());
+
+    let message = environment.invoke("GetResourceString", None, Some(args.clone()), InvocationType::StaticPrivate)?;
+    assert!(!unsafe { message.Anonymous.Anonymous.Anonymous.bstrVal }.to_string().is_empty());
+
+    let flags = BindingFlags::NonPublic | BindingFlags::Static | BindingFlags::IgnoreCase;
+    environment.invoke_with_flags("getresourcestring", None, Some(args), flags)?;
+
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let secret = assembly.resolve_type("State")?.invoke_with_flags("secret", None, None, BindingFlags::NonPublic | BindingFlags::Static | BindingFlags::GetField)?;
+    assert_eq!(unsafe { secret.Anonymous.Anonymous.Anonymous.lVal }, 42);
+
+    let state = assembly.new("State")?;
+    state.call("set_Name", "rustclr")?;
+    assert!(state.call("Describe", ()).is_err());
+    assert_eq!(state.call_non_public("Describe", ())?.as_string().as_deref(), Some("rustclr #0"));
+
+    Ok(())
+}
+
+#[test]
+fn test_non_public_members() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    let environment = mscorlib.resolve_type("System.Environment")?;
+    assert!(environment.method("GetResourceFromDefault").is_err());
+    environment.method_with_flags("GetResourceFromDefault", BindingFlags::NonPublic | BindingFlags::Static)?;
+
+    let list = mscorlib.resolve_type("System.Collections.Generic.List`1")?;
+    let enumerator = list.nested_type("Enumerator", false)?;
+    let resolved = mscorlib.resolve_type("System.Collections.Generic.List`1+Enumerator")?;
+    assert_eq!(enumerator.ToString()?, resolved.ToString()?);
+
+    Ok(())
+}
+
+#[test]
+fn test_create_instance_guardrails() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    for name in ["System.Math", "System.IO.Stream", "System.IDisposable", "System.String", "Missing.Type"] {
+        assert!(matches!(
+            mscorlib.create_instance(name),
+            Err(ClrError::CreateInstanceError(..))
+        ), "{name}");
+    }
+
+    let value = mscorlib.create_instance("System.Int32")?;
+    assert_eq!(unsafe { value.Anonymous.Anonymous.Anonymous.lVal }, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_dispatch() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let list = Dispatch::from_variant(&mscorlib.create_instance("System.Collections.ArrayList")?)?;
+
+    assert_eq!(i32::from_variant(&list.call("Add", "item")?)?, 0);
+    assert_eq!(i32::from_variant(&list.get("Count")?)?, 1);
+
+    list.set("Capacity", 10)?;
+    assert_eq!(i32::from_variant(&list.get("Capacity")?)?, 10);
+
+    assert!(matches!(list.get("Missing"), Err(ClrError::ApiError("GetIDsOfNames", _))));
+    assert!(Dispatch::from_variant(&1.to_variant()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_output_capacity() -> Result<(), Box<dyn std::error::Error>> {
+    let mut clr = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_output_capacity(64 * 1024);
+
+    // The buffer is cleared, not appended to, between runs
+    for _ in 0..3 {
+        assert_eq!(clr.run()?.trim_end(), "Hello, World!");
+    }
+
+    let mut clr = clr.with_soft_unload(true);
+    assert_eq!(clr.run()?.trim_end(), "Hello, World!");
+    assert_eq!(clr.run()?.trim_end(), "Hello, World!");
+
+    Ok(())
+}
+
+#[test]
+fn test_domain_resolve_type() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    clr.app_domain.load_assembly(load(assemblies::STATE))?;
+
+    // Types of any loaded assembly resolve without naming it
+    assert_eq!(clr.app_domain.resolve_type("State")?.ToString()?, "State");
+    assert_eq!(clr.app_domain.resolve_type("System.Collections.Generic.List`1")?.ToString()?, "System.Collections.Generic.List`1[T]");
+
+    // Assembly-qualified names load their assembly when needed
+    assert_eq!(clr.app_domain.resolve_type("System.Uri, System")?.ToString()?, "System.Uri");
+    assert_eq!(
+        clr.app_domain.resolve_type("System.Collections.Generic.List`1[[System.Int32, mscorlib]], mscorlib")?.ToString()?,
+        "System.Collections.Generic.List`1[System.Int32]"
+    );
+
+    assert!(clr.app_domain.resolve_type("Missing.Type").is_err());
+    assert!(clr.app_domain.resolve_type("State, Missing").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_can_host() -> Result<(), Box<dyn std::error::Error>> {
+    let capability = can_host()?;
+
+    assert!(capability.in_process(), "{capability}");
+    assert!(capability.helper_process());
+    assert_eq!(capability.hosting_api, Some(HostingApi::MetaHost));
+    assert!(capability.runtimes.iter().any(|version| version == "v4.0.30319"));
+    assert_eq!(capability.blockers().count(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_numeric_variants() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let math = clr.app_domain.load_lib("mscorlib")?.resolve_type("System.Math")?;
+
+    // Each width binds to its own `Math.Max` overload and comes back with the same type
+    let max = |args| math.invoke("Max", None, Some(args), InvocationType::Static);
+    assert_eq!(i8::from_variant(&max((-3i8, 7i8).to_variants())?)?, 7);
+    assert_eq!(i16::from_variant(&max((-300i16, 300i16).to_variants())?)?, 300);
+    assert_eq!(i64::from_variant(&max((i64::MIN, i64::MAX).to_variants())?)?, i64::MAX);
+    assert_eq!(u8::from_variant(&max((200u8, 20u8).to_variants())?)?, 200);
+    assert_eq!(u16::from_variant(&max((u16::MAX, 1u16).to_variants())?)?, u16::MAX);
+    assert_eq!(u32::from_variant(&max((u32::MAX, 1u32).to_variants())?)?, u32::MAX);
+    assert_eq!(u64::from_variant(&max((u64::MAX, 1u64).to_variants())?)?, u64::MAX);
+    assert_eq!(f32::from_variant(&max((1.5f32, -2.5f32).to_variants())?)?, 1.5);
+    assert_eq!(f64::from_variant(&max((0.25f64, 0.125f64).to_variants())?)?, 0.25);
+    assert_eq!(usize::from_variant(&usize::MAX.to_variant())?, usize::MAX);
+
+    // Typed arrays of every width are built as well
+    let array = SafeArray::from_array_arg(&[1u64, 2, 3])?;
+    assert_eq!(array.len(), 1);
+    assert!(i64::from_variant(&1i32.to_variant()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_date_variants() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let date_time = mscorlib.resolve_type("System.DateTime")?;
+
+    // The OLE Automation epoch and dates before it, whose fraction counts forwards
+    assert_eq!(ClrDate::from_unix(-UNIX_OLE_OFFSET).ole(), 0.0);
+    assert_eq!(ClrDate::from_unix(-UNIX_OLE_OFFSET - 6 * 3600).ole(), -1.75);
+    assert_eq!(ClrDate::from_ole(-1.75).unix(), -UNIX_OLE_OFFSET - 6 * 3600);
+
+    // `DateTime.FromOADate` reads the value back with the same date
+    let date = ClrDate::from_unix_millis(1_700_000_000_123);
+    let parsed = date_time.invoke("FromOADate", None, Some(vec![date.ole().to_variant()]), InvocationType::Static)?;
+    assert_eq!(ClrDate::from_variant(&parsed)?.unix_millis(), 1_700_000_000_123);
+
+    let year = date_time.invoke("get_Year", Some(date.to_variant()), None, InvocationType::Instance)?;
+    assert_eq!(i32::from_variant(&year)?, 2023);
+
+    // `SystemTime` round-trips through managed code
+    let now = SystemTime::now();
+    let echoed = mscorlib.resolve_type("System.Convert")?
+        .invoke("ToDateTime", None, Some(vec![now.to_variant()]), InvocationType::Static)?;
+    let delta = now.duration_since(SystemTime::from_variant(&echoed)?)?;
+    assert!(delta < Duration::from_millis(1));
+
+    let utc_now = SystemTime::from_variant(&date_time.invoke("get_UtcNow", None, None, InvocationType::Static)?)?;
+    assert!(utc_now.duration_since(now).unwrap_or_default() < Duration::from_secs(60));
+
+    Ok(())
+}
+
+/// Seconds between the OLE Automation epoch (1899-12-30) and the Unix epoch.
+const UNIX_OLE_OFFSET: i64 = 25_569 * 86_400;
+
+#[test]
+fn test_error_codes() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+
+    let missing = clr.app_domain.load_lib("Missing.Assembly").unwrap_err();
+    assert_eq!(missing.code(), ErrorCode::FileNotFound);
+    assert_eq!(missing.hresult(), Some(0x8007_0002_u32 as i32));
+
+    let math = clr.app_domain.load_lib("mscorlib")?.resolve_type("System.Math")?;
+    let error = math.invoke("Missing", None, None, InvocationType::Static).err();
+    assert_eq!(error.map(|error| error.code()), Some(ErrorCode::MemberNotFound));
+
+    // The failure reported by `SetHostControl` once the runtime is started
+    let rejected = ClrError::ApiError("SetHostControl", -2147024891);
+    assert_eq!(rejected.code(), ErrorCode::HostControlRejected);
+    assert_eq!(rejected.code().to_string(), "E101:host_control_rejected");
+    assert_eq!(ClrError::ApiError("Start", -2147024891).code(), ErrorCode::AccessDenied);
+
+    let described = ClrError::DescribedApiError("Invoke", 0x8013_1604_u32 as i32, "Exception has been thrown by the target of an invocation.".into());
+    assert_eq!(described.code(), ErrorCode::ManagedException);
+    assert_eq!(described.hresult(), Some(0x8013_1604_u32 as i32));
+    assert_eq!(ClrError::InvalidHandle.code().value(), 21);
+    assert_eq!(ClrError::InvalidHandle.hresult(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_variants() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let decimal = mscorlib.resolve_type("System.Decimal")?;
+
+    // Parsing keeps the scale, as `decimal.Parse` does
+    let price: ClrDecimal = "-1234.5600".parse()?;
+    assert_eq!((price.mantissa(), price.scale()), (-12345600, 4));
+    assert_eq!(price.to_string(), "-1234.5600");
+    assert_eq!("0.1".parse::<ClrDecimal>()?, ClrDecimal::new(1, 1)?);
+    assert_eq!("-0".parse::<ClrDecimal>()?.to_string(), "0");
+    assert_eq!(".5".parse::<ClrDecimal>()?.to_string(), "0.5");
+    assert_eq!("79228162514264337593543950335".parse::<ClrDecimal>()?.mantissa(), (1 << 96) - 1);
+
+    for invalid in ["", "-", "1e5", "1.2.3", "1_000", "79228162514264337593543950336", "0.00000000000000000000000000001"] {
+        let error = invalid.parse::<ClrDecimal>().unwrap_err();
+        assert!(matches!(error, ClrError::InvalidDecimal(_)), "{invalid}: {error}");
+    }
+
+    assert!(ClrDecimal::new(1, 29).is_err());
+
+    // Arithmetic in managed code stays exact
+    let sum = decimal.invoke("Add", None, Some(("0.1".parse::<ClrDecimal>()?, "0.2".parse::<ClrDecimal>()?).to_variants()), InvocationType::Static)?;
+    assert_eq!(ClrDecimal::from_variant(&sum)?.to_string(), "0.3");
+
+    let negated = decimal.invoke("Negate", None, Some(vec![price.to_variant()]), InvocationType::Static)?;
+    assert_eq!(ClrDecimal::from_variant(&negated)?.to_string(), "1234.5600");
+
+    let truncated = decimal.invoke("ToInt32", None, Some(vec![price.to_variant()]), InvocationType::Static)?;
+    assert_eq!(i32::from_variant(&truncated)?, -1234);
+    assert_eq!(price.to_f64(), -1234.56);
+
+    // `decimal[]` arrays
+    let values = vec![ClrDecimal::from(1), "2.5".parse()?, ClrDecimal::from(-3)];
+    let array = SafeArray::from_array_arg(&values)?;
+    assert_eq!(array.len(), 1);
+
+    assert!(ClrDecimal::from_variant(&1.5f64.to_variant()).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_safearray() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+
+    // `string[]`
+    let strings = SafeArray::from_strings(&["a", "b", "c"])?;
+    assert_eq!(strings.len(), 3);
+    let joined = mscorlib.resolve_type("System.String")?
+        .invoke("Join", None, Some(vec![",".to_variant(), strings.into_variant()?]), InvocationType::Static)?;
+    assert_eq!(String::from_variant(&joined)?, "a,b,c");
+
+    // `byte[]`
+    let bytes = SafeArray::from_bytes(b"rustclr")?;
+    let encoded = mscorlib.resolve_type("System.Convert")?
+        .invoke("ToBase64String", None, Some(vec![bytes.into_variant()?]), InvocationType::Static)?;
+    assert_eq!(String::from_variant(&encoded)?, "cnVzdGNscg==");
+
+    let streamed = SafeArray::from_reader(&b"rustclr"[..], 7)?;
+    assert_eq!(streamed.len(), 7);
+    assert!(SafeArray::from_reader(&b"rust"[..], 7).is_err());
+
+    // Lengths beyond the 32-bit element count of a `SAFEARRAY` are refused before allocating
+    let oversized = SafeArray::from_reader(std::io::empty(), u32::MAX as usize + 1);
+    assert!(matches!(oversized.err(), Some(ClrError::SafeArrayError(_))));
+
+    // `object[]`, owning or copying the variants
+    let args = SafeArray::from_args(&("key", 42, true))?;
+    assert_eq!(args.len(), 3);
+    assert!(SafeArray::from_variants(Vec::new())?.is_empty());
+
+    let values = (1, "two").to_variants();
+    assert_eq!(SafeArray::copy_from(&values)?.len(), 2);
+    assert_eq!(SafeArray::from_variants(values)?.len(), 2);
+
+    // Element types without a typed array form are refused
+    let dates = SafeArray::from_values(&[ClrDate::from_unix(0)]);
+    assert!(matches!(dates.err(), Some(ClrError::VariantUnsupported)));
+
+    // The raw pointer can be handed over and taken back
+    let raw = SafeArray::from_values(&[1i32, 2, 3])?.into_raw();
+    assert_eq!(unsafe { SafeArray::from_raw(raw) }.len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_windows_interop() -> Result<(), Box<dyn std::error::Error>> {
+    use windows::Win32::System::Com::IDispatch;
+    use windows_core::{BSTR, VARIANT};
+
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let string = mscorlib.resolve_type("System.String")?;
+
+    // `VARIANT` and `BSTR` values pass in and come back out
+    let args = (VARIANT::from("Hello, "), BSTR::from("World!")).to_variants();
+    let concat = string.invoke("Concat", None, Some(args), InvocationType::Static)?;
+    assert_eq!(BSTR::from_variant(&concat)?, "Hello, World!");
+    assert_eq!(BSTR::try_from(&VARIANT::from_variant(&concat)?)?, "Hello, World!");
+    assert_eq!(WinStr::to_string(&BSTR::from("text")), "text");
+    assert!(BSTR::from_variant(&1.to_variant()).is_err());
+
+    let builder = mscorlib.new("System.Text.StringBuilder")?;
+    builder.set("Capacity", 64)?;
+    let capacity = VARIANT::try_from(&builder.get("Capacity")?)?;
+    assert_eq!(i32::try_from(&capacity)?, 64);
+
+    // `IDispatch` converts to and from `Dispatch`
+    let list = IDispatch::from_variant(&mscorlib.create_instance("System.Collections.ArrayList")?)?;
+    let dispatch = Dispatch::from(list.clone());
+    assert_eq!(i32::from_variant(&dispatch.call("Add", "item")?)?, 0);
+
+    let list = IDispatch::from(dispatch);
+    let count = Dispatch::from_variant(&list.to_variant())?.get("Count")?;
+    assert_eq!(i32::from_variant(&count)?, 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_field_flags() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let assembly = clr.app_domain.load_assembly(load(assemblies::STATE))?;
+    let state = assembly.resolve_type("State")?;
+
+    // A static field that is not public
+    let secret = state.field_with_flags("secret", BindingFlags::NonPublic | BindingFlags::Static)?;
+    assert!(secret.get_IsStatic()?);
+    assert!(!secret.get_IsPublic()?);
+
+    let mode = state.field("Mode")?;
+    assert!(mode.get_IsStatic()?);
+    assert!(mode.get_IsPublic()?);
+
+    let counter = state.field("Counter")?;
+    assert!(!counter.get_IsStatic()?);
+    assert!(counter.get_IsPublic()?);
+
+    // `SetValue` goes through its own slot and leaves the stack intact
+    secret.set_value(None, 7.to_variant())?;
+    assert_eq!(i32::from_variant(&secret.get_value(None)?)?, 7);
+
+    Ok(())
+}
+
+#[test]
+fn test_minimal_startup_flags() {
+    // The loader optimization field is replaced, not OR-ed into `MULTI_DOMAIN_HOST`
+    let flags = (StartupFlags::LOADER_OPTIMIZATION_MULTI_DOMAIN | StartupFlags::CONCURRENT_GC | StartupFlags::SERVER_GC).minimal();
+    assert_eq!(flags, StartupFlags::MINIMAL | StartupFlags::SERVER_GC);
+    assert!(!flags.contains(StartupFlags::LOADER_OPTIMIZATION_MULTI_DOMAIN_HOST));
+    assert!(!flags.contains(StartupFlags::CONCURRENT_GC));
+
+    assert_eq!(StartupFlags::LOADER_OPTIMIZATION_MULTI_DOMAIN_HOST.minimal(), StartupFlags::MINIMAL);
+    assert_eq!(StartupFlags::NONE.minimal(), StartupFlags::MINIMAL);
+    assert_eq!(StartupFlags::MINIMAL.minimal(), StartupFlags::MINIMAL);
+}
+
+#[test]
+fn test_control_sequences_across_spill_chunks() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let console = mscorlib.resolve_type("System.Console")?;
+
+    // An escape sequence straddles the first 64K chunk and a `\r\n` the second one
+    let first = "a".repeat(65535);
+    let second = "b".repeat(131071 - 65540);
+    let text = format!("{first}\x1b[31m{second}\r\nend");
+
+    let mut output = ClrOutput::new(&mscorlib).with_control_sequences(ControlSequences::Normalize);
+    output.redirect()?;
+    console.invoke("Write", None, Some(vec![text.to_variant()]), InvocationType::Static)?;
+    output.restore()?;
+
+    let captured = output.capture_spilled(1024)?;
+    assert!(matches!(captured, CapturedOutput::Spilled(_)));
+    assert_eq!(captured.into_string()?, format!("{first}{second}\nend"));
+
+    // `capture_bytes` applies the same handling
+    let mut output = ClrOutput::new(&mscorlib).with_control_sequences(ControlSequences::Strip);
+    output.redirect()?;
+    console.invoke("Write", None, Some(vec!["\x1b[1mbold\x1b[0m".to_variant()]), InvocationType::Static)?;
+    output.restore()?;
+
+    let captured = output.capture_bytes(OutputEncoding::Utf8)?;
+    assert_eq!(captured.bytes, b"bold");
+
+    let captured = output.capture_bytes(OutputEncoding::Utf16Le)?;
+    assert_eq!(captured.bytes, "bold".encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[test]
+fn test_run_bytes() -> Result<(), Box<dyn std::error::Error>> {
+    let captured = RustClr::new(load(assemblies::ECHO))?
+        .with_args(vec!["héllo", "wörld"])
+        .with_output_redirection(true)
+        .run_bytes(OutputEncoding::Utf8)?;
+
+    assert_eq!(captured.encoding, OutputEncoding::Utf8);
+    assert_eq!(std::str::from_utf8(&captured.bytes)?.trim_end(), "héllo wörld");
+
+    let captured = RustClr::new(load(assemblies::ECHO))?
+        .with_args(vec!["héllo", "wörld"])
+        .with_output_redirection(true)
+        .run_bytes(OutputEncoding::Utf16Le)?;
+
+    assert_eq!(captured.encoding, OutputEncoding::Utf16Le);
+    assert_eq!(captured.into_string().trim_end(), "héllo wörld");
+
+    // Without redirection the output is empty, in the requested encoding
+    let captured = RustClr::new(load(assemblies::HELLO))?.run_bytes(OutputEncoding::Utf16Le)?;
+    assert!(captured.bytes.is_empty());
+    assert_eq!(captured.encoding, OutputEncoding::Utf16Le);
+
+    // Spilling only applies to text captures
+    let spilled = RustClr::new(load(assemblies::HELLO))?
+        .with_output_redirection(true)
+        .with_output_spill(16)
+        .run_bytes(OutputEncoding::Utf8);
+
+    assert!(matches!(spilled, Err(ClrError::ErrorClr(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_date_bounds() -> Result<(), Box<dyn std::error::Error>> {
+    let clr = RustClrEnv::new(None)?;
+    let mscorlib = clr.app_domain.load_lib("mscorlib")?;
+    let date_time = mscorlib.resolve_type("System.DateTime")?;
+
+    // Timestamps at the bounds of `i64` saturate instead of overflowing
+    let latest = ClrDate::from_unix(i64::MAX);
+    let earliest = ClrDate::from_unix(i64::MIN);
+    assert!(latest.ole() > 0.0);
+    assert!(earliest.ole() < 0.0);
+    assert_eq!(ClrDate::from_unix_millis(i64::MAX).ole(), latest.ole());
+    assert!(earliest.unix_millis() < 0);
+    assert!(ClrDate::from_ole(f64::MAX).unix_millis() > 0);
+
+    // Converting them to `SystemTime` clamps them to the range of `DateTime`
+    let clamped = ClrDate::from_ole(1e15).to_system_time();
+    assert_eq!(clamped, ClrDate::from_ole(2_958_465.5).to_system_time() + Duration::from_millis(43_199_999));
+    assert!(ClrDate::from_ole(-1e15).to_system_time() < UNIX_EPOCH);
+    assert_eq!(SystemTime::from_variant(&latest.to_variant())?, SystemTime::from(ClrDate::from_ole(1e15)));
+
+    // They are outside the range of `DateTime`, which refuses them
+    let parsed = date_time.invoke("FromOADate", None, Some(vec![latest.ole().to_variant()]), InvocationType::Static);
+    assert!(parsed.is_err());
+
+    Ok(())
+}
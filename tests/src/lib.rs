@@ -2,6 +2,12 @@
 
 use rustclr::{RustClr, RuntimeVersion};
 
+#[cfg(test)]
+mod assemblies;
+
+#[cfg(test)]
+mod integration;
+
 #[test]
 fn test_create_domain() -> Result<(), Box<dyn std::error::Error>> {
     let buffer = std::fs::read("file").expect("Error reading file");